@@ -7,6 +7,7 @@ use bevy::prelude::*;
 use rand::{rngs::SmallRng, Rng, SeedableRng};
 use std::any::{Any, TypeId};
 use std::fmt::Debug;
+use yarnspinner::runtime::SharedProgram;
 
 pub(crate) fn dialogue_runner_builder_plugin(_app: &mut App) {}
 
@@ -18,7 +19,7 @@ pub struct DialogueRunnerBuilder {
     asset_providers: HashMap<TypeId, Box<dyn AssetProvider>>,
     library: YarnLibrary,
     commands: YarnCommands,
-    compilation: Compilation,
+    program: SharedProgram,
     localizations: Option<Localizations>,
     asset_server: SkipDebug<AssetServer>,
 }
@@ -34,7 +35,7 @@ impl DialogueRunnerBuilder {
             asset_providers: HashMap::default(),
             library: create_extended_standard_library(),
             commands: YarnCommands::builtin_commands(commands),
-            compilation: yarn_project.compilation().clone(),
+            program: yarn_project.program().clone(),
             localizations: yarn_project.localizations().cloned(),
             asset_server: yarn_project.asset_server.clone(),
         }
@@ -78,7 +79,7 @@ impl DialogueRunnerBuilder {
             .set_line_hints_enabled(true)
             .library_mut()
             .extend(self.library);
-        dialogue.add_program(self.compilation.program.unwrap());
+        dialogue.set_program(self.program);
 
         for asset_provider in self.asset_providers.values_mut() {
             if let Some(ref localizations) = self.localizations {
@@ -5,6 +5,7 @@ use bevy::prelude::*;
 pub(crate) use shared_text_provider::SharedTextProvider;
 use std::any::Any;
 use std::collections::HashMap;
+use std::sync::Arc;
 pub use strings_file_text_provider::StringsFileTextProvider;
 
 mod shared_text_provider;
@@ -28,6 +29,12 @@ pub trait TextProvider: UnderlyingTextProvider {
     /// Stores a string table containing the base language strings, i.e. the strings found in the Yarn files themselves.
     fn set_base_string_table(&mut self, string_table: HashMap<LineId, StringInfo>);
 
+    /// Like [`TextProvider::set_base_string_table`], but for a string table that is shared with other [`TextProvider`]s.
+    /// The default implementation clones it, override this if your provider can keep the [`Arc`] instead.
+    fn set_shared_base_string_table(&mut self, string_table: Arc<HashMap<LineId, StringInfo>>) {
+        self.set_base_string_table(Arc::unwrap_or_clone(string_table));
+    }
+
     /// Extends the string table set by [`TextProvider::set_base_string_table`] with additional strings.
     fn extend_base_string_table(&mut self, string_table: HashMap<LineId, StringInfo>);
 
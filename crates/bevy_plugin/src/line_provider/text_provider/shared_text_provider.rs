@@ -29,6 +29,13 @@ impl TextProvider for SharedTextProvider {
         self.0.write().unwrap().set_base_string_table(string_table)
     }
 
+    fn set_shared_base_string_table(&mut self, string_table: Arc<HashMap<LineId, StringInfo>>) {
+        self.0
+            .write()
+            .unwrap()
+            .set_shared_base_string_table(string_table)
+    }
+
     fn extend_base_string_table(&mut self, string_table: HashMap<LineId, StringInfo>) {
         self.0
             .write()
@@ -20,7 +20,7 @@ pub struct StringsFileTextProvider {
     asset_server: SkipDebug<AssetServer>,
     localizations: Option<Localizations>,
     language: Option<Language>,
-    base_string_table: HashMap<LineId, StringInfo>,
+    base_string_table: Arc<HashMap<LineId, StringInfo>>,
     strings_file_handle: Option<Handle<StringsFile>>,
     translation_string_table: Option<HashMap<LineId, String>>,
     event_cursor: Arc<RwLock<EventCursor<AssetEvent<StringsFile>>>>,
@@ -112,7 +112,7 @@ impl StringsFileTextProvider {
             asset_server: yarn_project.asset_server.clone(),
             localizations: yarn_project.localizations.clone(),
             language: None,
-            base_string_table: yarn_project.compilation.string_table.clone(),
+            base_string_table: yarn_project.string_table.clone(),
             strings_file_handle: None,
             translation_string_table: None,
             event_cursor: Default::default(),
@@ -136,11 +136,15 @@ impl StringsFileTextProvider {
 
 impl TextProvider for StringsFileTextProvider {
     fn set_base_string_table(&mut self, string_table: HashMap<LineId, StringInfo>) {
+        self.base_string_table = Arc::new(string_table);
+    }
+
+    fn set_shared_base_string_table(&mut self, string_table: Arc<HashMap<LineId, StringInfo>>) {
         self.base_string_table = string_table;
     }
 
     fn extend_base_string_table(&mut self, string_table: HashMap<LineId, StringInfo>) {
-        self.base_string_table.extend(string_table);
+        Arc::make_mut(&mut self.base_string_table).extend(string_table);
     }
 
     fn take_fetched_assets(&mut self, asset: Box<dyn Any>) {
@@ -7,6 +7,8 @@ pub(crate) use compilation::{
 };
 use std::fmt::Debug;
 use std::iter;
+use std::sync::Arc;
+use yarnspinner::runtime::SharedProgram;
 
 mod compilation;
 
@@ -35,6 +37,10 @@ pub(crate) struct CompilationSystemSet;
 pub struct YarnProject {
     pub(crate) yarn_files: HashSet<Handle<YarnFile>>,
     pub(crate) compilation: Compilation,
+    /// The program of [`YarnProject::compilation`], shared by all [`DialogueRunner`]s built from this project.
+    pub(crate) program: SharedProgram,
+    /// The string table of [`YarnProject::compilation`], shared by all [`StringsFileTextProvider`]s built from this project.
+    pub(crate) string_table: Arc<std::collections::HashMap<LineId, StringInfo>>,
     pub(crate) localizations: Option<Localizations>,
     pub(crate) asset_server: SkipDebug<AssetServer>,
     pub(crate) metadata: HashMap<LineId, Vec<String>>,
@@ -53,6 +59,12 @@ impl YarnProject {
         &self.compilation
     }
 
+    /// Returns the program of this project. All [`DialogueRunner`]s built from this project run this same [`SharedProgram`]
+    /// instead of holding their own copy of it.
+    pub fn program(&self) -> &SharedProgram {
+        &self.program
+    }
+
     /// Returns the [`Localizations`] of this project, if any. These come from [`YarnSpinnerPlugin::with_localizations`] or [`LoadYarnProjectEvent::with_localizations`].
     pub fn localizations(&self) -> Option<&Localizations> {
         self.localizations.as_ref()
@@ -75,6 +87,7 @@ impl YarnProject {
     }
 
     /// Returns the headers associated with the given node, if it exists.
+    /// The headers the compiler adds for itself are left out, see [`yarnspinner::core::Header::is_internal`].
     pub fn headers_for_node(&self, node_name: &str) -> Option<HashMap<&str, Vec<&str>>> {
         self.compilation
             .program
@@ -84,6 +97,7 @@ impl YarnProject {
             .get(node_name)?
             .headers
             .iter()
+            .filter(|header| !header.is_internal())
             .fold(HashMap::default(), |mut map: HashMap<_, Vec<_>>, header| {
                 map.entry(header.key.as_str())
                     .or_default()
@@ -7,11 +7,16 @@ use anyhow::{anyhow, bail, Result};
 use bevy::platform::collections::HashSet;
 use bevy::prelude::*;
 use std::fmt::Debug;
+use std::sync::Arc;
+use yarnspinner::compiler::IncrementalCompiler;
+use yarnspinner::runtime::SharedProgram;
 
 pub(crate) fn project_compilation_plugin(app: &mut App) {
     app.register_type::<YarnFilesToLoad>()
         .init_resource::<YarnFilesToLoad>()
         .init_resource::<YarnFilesBeingLoaded>()
+        // Keeps the parse results of unchanged files around between hot reloads
+        .init_non_send_resource::<IncrementalCompiler>()
         .add_event::<RecompileLoadedYarnFilesEvent>()
         .add_systems(
             Update,
@@ -117,11 +122,13 @@ fn recompile_loaded_yarn_files(
     yarn_project: Option<ResMut<YarnProject>>,
     mut dialogue_runners: Query<&mut DialogueRunner>,
     mut events: ResMut<Events<RecompileLoadedYarnFilesEvent>>,
+    mut incremental_compiler: NonSendMut<IncrementalCompiler>,
 ) -> SystemResult {
     let Some(mut yarn_project) = yarn_project else {
         return Ok(());
     };
     let Some(compilation) = compile_yarn_files(
+        &mut incremental_compiler,
         &yarn_project.yarn_files,
         &yarn_files,
         yarn_project.localizations.as_ref(),
@@ -135,18 +142,19 @@ fn recompile_loaded_yarn_files(
         .iter()
         .map(|(line_id, string_info)| (line_id.clone(), string_info.metadata.clone()))
         .collect();
+    yarn_project.program = SharedProgram::new(compilation.program.clone().unwrap())?;
+    yarn_project.string_table = Arc::new(compilation.string_table.clone());
     yarn_project.compilation = compilation;
     yarn_project.metadata = metadata;
-    let program = yarn_project.compilation.program.clone().unwrap();
     for mut dialogue_runner in dialogue_runners.iter_mut() {
         let current_node = dialogue_runner.current_node();
         dialogue_runner
             .inner_mut()
             .0
-            .replace_program(program.clone());
+            .set_program(yarn_project.program.clone());
         dialogue_runner
             .text_provider
-            .set_base_string_table(yarn_project.compilation.string_table.clone());
+            .set_shared_base_string_table(yarn_project.string_table.clone());
         if let Some(current_node) = current_node {
             dialogue_runner
                 .stop()
@@ -172,6 +180,7 @@ fn compile_loaded_yarn_files(
     yarn_project_config_to_load: Option<Res<YarnProjectConfigToLoad>>,
     asset_server: Res<AssetServer>,
     asset_root: Res<AssetRoot>,
+    mut incremental_compiler: NonSendMut<IncrementalCompiler>,
 ) -> SystemResult {
     if yarn_files_being_loaded.is_changed() {
         *dirty = true;
@@ -198,6 +207,7 @@ fn compile_loaded_yarn_files(
         .as_ref();
     let development_file_generation = yarn_project_config_to_load.development_file_generation;
     let Some(compilation) = compile_yarn_files(
+        &mut incremental_compiler,
         &yarn_files_being_loaded.0,
         &yarn_files,
         localizations,
@@ -243,6 +253,8 @@ fn compile_loaded_yarn_files(
         .collect();
     commands.insert_resource(YarnProject {
         yarn_files: std::mem::take(&mut yarn_files_being_loaded.0),
+        program: SharedProgram::new(compilation.program.clone().unwrap())?,
+        string_table: Arc::new(compilation.string_table.clone()),
         compilation,
         localizations: yarn_project_config_to_load.localizations.clone().unwrap(),
         asset_server: SkipDebug(asset_server.clone()),
@@ -264,6 +276,7 @@ fn clear_temp_yarn_project(mut commands: Commands) {
 }
 
 fn compile_yarn_files(
+    incremental_compiler: &mut IncrementalCompiler,
     yarn_file_handles: &HashSet<Handle<YarnFile>>,
     yarn_files: &Res<Assets<YarnFile>>,
     localizations: Option<&Localizations>,
@@ -291,6 +304,8 @@ fn compile_yarn_files(
         }
     }
     let inner_yarn_files = yarn_files.map(|file| file.file.clone());
-    let compilation = YarnCompiler::new().add_files(inner_yarn_files).compile()?;
+    let mut compiler = YarnCompiler::new();
+    compiler.add_files(inner_yarn_files);
+    let compilation = incremental_compiler.compile(&compiler)?;
     Ok(Some(compilation))
 }
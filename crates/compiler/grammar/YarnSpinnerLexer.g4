@@ -0,0 +1,287 @@
+// The lexer of Yarn files.
+//
+// Based on YarnSpinner.Compiler/YarnSpinnerLexer.g4 of the third-party/YarnSpinner submodule,
+// extended by the LINE_GROUP_ARROW, COMMAND_ONCE and COMMAND_ENDONCE tokens.
+// COMMAND_LOCAL also enters ExpressionMode, like COMMAND_DECLARE does.
+// See crates/compiler/src/parser/generated/readme.md for how to regenerate the parser from this file.
+
+lexer grammar YarnSpinnerLexer;
+
+tokens {
+    INDENT, DEDENT, BLANK_LINE_FOLLOWING_OPTION
+}
+
+channels {
+    WHITESPACE,
+    COMMENTS
+}
+
+// Root mode: skip whitespaces, set up some commonly-seen
+// tokens
+WS : ([ \t])+ -> channel(HIDDEN);
+
+COMMENT: '//' ~('\r'|'\n')* -> channel(COMMENTS);
+
+// Lines that contain only whitespace are ignored
+NEWLINE: ( '\r'? '\n' | '\r' ) [ \t]* -> channel(WHITESPACE);
+
+ID : IDENTIFIER_HEAD IDENTIFIER_CHARACTERS? ;
+
+// The set of characters that identifiers may start with.
+fragment IDENTIFIER_HEAD : [a-zA-Z_]
+    | '\u00A8' | '\u00AA' | '\u00AD' | '\u00AF' | [\u00B2-\u00B5] | [\u00B7-\u00BA]
+    | [\u00BC-\u00BE] | [\u00C0-\u00D6] | [\u00D8-\u00F6] | [\u00F8-\u00FF]
+    | [\u0100-\u02FF] | [\u0370-\u167F] | [\u1681-\u180D] | [\u180F-\u1DBF]
+    | [\u1E00-\u1FFF]
+    | [\u200B-\u200D] | [\u202A-\u202E] | [\u203F-\u2040] | '\u2054' | [\u2060-\u206F]
+    | [\u2070-\u20CF] | [\u2100-\u218F] | [\u2460-\u24FF] | [\u2776-\u2793]
+    | [\u2C00-\u2DFF] | [\u2E80-\u2FFF]
+    | [\u3004-\u3007] | [\u3021-\u302F] | [\u3031-\u303F] | [\u3040-\uD7FF]
+    | [\uF900-\uFD3D] | [\uFD40-\uFDCF] | [\uFDF0-\uFE1F] | [\uFE30-\uFE44]
+    | [\uFE47-\uFFFD]
+    | [\u{10000}-\u{1FFFD}] | [\u{20000}-\u{2FFFD}] | [\u{30000}-\u{3FFFD}] | [\u{40000}-\u{4FFFD}]
+    | [\u{50000}-\u{5FFFD}] | [\u{60000}-\u{6FFFD}] | [\u{70000}-\u{7FFFD}] | [\u{80000}-\u{8FFFD}]
+    | [\u{90000}-\u{9FFFD}] | [\u{A0000}-\u{AFFFD}] | [\u{B0000}-\u{BFFFD}] | [\u{C0000}-\u{CFFFD}]
+    | [\u{D0000}-\u{DFFFD}] | [\u{E0000}-\u{EFFFD}]
+    ;
+
+// The set of characters that identifiers may contain after their first character.
+fragment IDENTIFIER_CHARACTER : [0-9]
+    | [\u0300-\u036F] | [\u1DC0-\u1DFF] | [\u20D0-\u20FF] | [\uFE20-\uFE2F]
+    | IDENTIFIER_HEAD
+    ;
+
+fragment IDENTIFIER_CHARACTERS : IDENTIFIER_CHARACTER+ ;
+
+// The start of the body of a node.
+BODY_START : '---' -> pushMode(BodyMode) ;
+
+// The ':' between a header's key and its value.
+HEADER_DELIMITER : ':' [ ]* -> pushMode(HeaderMode);
+
+// A hashtag. These can appear at the start of a file, or after
+// certain lines (in which case they're matched in TextMode)
+HASHTAG : '#' -> pushMode(HashtagMode) ;
+
+// Headers before a node.
+mode HeaderMode;
+// Allow arbitrary text up to the end of the line.
+REST_OF_LINE : ~('\r'|'\n')+;
+HEADER_NEWLINE : NEWLINE -> type(NEWLINE), channel(WHITESPACE), popMode;
+
+// The main body of a node.
+mode BodyMode;
+
+// Ignore all whitespace and comments
+BODY_WS : WS -> channel(HIDDEN);
+BODY_NEWLINE : NEWLINE -> type(NEWLINE), channel(WHITESPACE);
+BODY_COMMENT : COMMENT -> type(COMMENT), channel(COMMENTS) ;
+
+// End of this node; return to the headers of the next node
+BODY_END : '===' -> popMode;
+
+// Shortcut syntax tokens
+SHORTCUT_ARROW : '->' ;
+
+// Line group syntax tokens
+LINE_GROUP_ARROW : '=>' ;
+
+// Begin commands
+COMMAND_START: '<<' -> pushMode(CommandMode) ;
+
+// Hashtags at the start of a line are lexed as tags of an empty line
+BODY_HASHTAG : '#' -> type(HASHTAG), pushMode(TextCommandOrHashtagMode), pushMode(HashtagMode);
+
+// Begin expressions
+EXPRESSION_START: '{' -> pushMode(TextMode), pushMode(ExpressionMode);
+
+// Escaped text: skip the backslash, lex the rest of the line as text
+// and the character after the backslash as escaped text
+ESCAPED_ANY : '\\' -> skip, pushMode(TextMode), pushMode(TextEscapedMode);
+
+// Any other text means this is a Line. Lex this first character as
+// TEXT, and enter TextMode.
+ANY: . -> type(TEXT), pushMode(TextMode);
+
+// Arbitrary text, punctuated by expressions, and ended with
+// hashtags and/or command.
+mode TextMode;
+
+TEXT_NEWLINE: NEWLINE -> type(NEWLINE), popMode;
+
+// Escaped markup brackets are kept as they are, so that the
+// markup parser can tell them apart from markup.
+TEXT_ESCAPED_MARKUP_BRACKET: ('\\[' | '\\]') -> type(TEXT);
+
+// An escape marker. Skip this token and enter escaped text mode, which
+// allows escaping characters that would otherwise be syntactically
+// significant.
+TEXT_ESCAPE: '\\' -> skip, pushMode(TextEscapedMode);
+
+// The start of a hashtag. Swap to the 'text, command or hashtag' mode,
+// and then immediately enter Hashtag mode.
+TEXT_HASHTAG: HASHTAG -> type(HASHTAG), mode(TextCommandOrHashtagMode), pushMode(HashtagMode);
+
+// The start of an inline expression. Immediately lex as
+// EXPRESSION_START and push into ExpressionMode.
+TEXT_EXPRESSION_START: '{' -> type(EXPRESSION_START), pushMode(ExpressionMode);
+
+// The start of a command. Commands in a line are line conditions,
+// which may be followed by hashtags.
+TEXT_COMMAND_START: '<<' -> type(COMMAND_START), mode(TextCommandOrHashtagMode), pushMode(CommandMode);
+
+// Comments after free text.
+TEXT_COMMENT: COMMENT -> channel(COMMENTS);
+
+// Finally, lex anything up to a newline, a hashtag, the start of an
+// expression or command, an escape or a comment as TEXT. A lone '<' or '/'
+// is text as well.
+TEXT: TEXT_FRAG+ | '<' | '/' ;
+fragment TEXT_FRAG: ~[\\\r\n#{</] ;
+
+// Escaped characters in a line.
+mode TextEscapedMode;
+// Any of the special characters can be escaped
+TEXT_ESCAPED_CHARACTER: [\\<>{}#/] -> type(TEXT), popMode ;
+// Escaping any other character is an error
+UNESCAPABLE_CHARACTER: . -> popMode;
+
+// The remainder of a line after its text: line conditions and hashtags.
+mode TextCommandOrHashtagMode;
+TEXT_COMMANDHASHTAG_WS: WS -> channel(HIDDEN);
+
+// Comments following hashtags and line conditions.
+TEXT_COMMANDHASHTAG_COMMENT: COMMENT -> channel(COMMENTS);
+
+TEXT_COMMANDHASHTAG_COMMAND_START: '<<' -> type(COMMAND_START), pushMode(CommandMode);
+
+TEXT_COMMANDHASHTAG_HASHTAG: '#' -> type(HASHTAG), pushMode(HashtagMode);
+
+TEXT_COMMANDHASHTAG_NEWLINE: NEWLINE -> type(NEWLINE), popMode;
+
+// Text after a line condition or hashtag is an error.
+TEXT_COMMANDHASHTAG_ERROR: . ;
+
+// Hashtags.
+mode HashtagMode;
+HASHTAG_WS: WS -> channel(HIDDEN);
+HASHTAG_TAG: HASHTAG -> type(HASHTAG);
+
+// The text of the hashtag. After we parse it, we're done parsing this
+// hashtag, so leave this mode.
+HASHTAG_TEXT: ~[ \t\r\n#$<]+ -> popMode;
+
+// Expressions, which are found inside braces in lines and
+// commands, and after certain command keywords.
+mode ExpressionMode;
+EXPR_WS : WS -> channel(HIDDEN);
+
+KEYWORD_TRUE  : 'true' ;
+KEYWORD_FALSE : 'false' ;
+KEYWORD_NULL : 'null' ;
+
+OPERATOR_ASSIGNMENT : '=' | 'to' ;
+
+OPERATOR_LOGICAL_LESS_THAN_EQUALS : '<=' | 'lte' ;
+OPERATOR_LOGICAL_GREATER_THAN_EQUALS : '>=' | 'gte' ;
+OPERATOR_LOGICAL_EQUALS : '==' | 'is' | 'eq' ;
+OPERATOR_LOGICAL_LESS : '<' | 'lt' ;
+OPERATOR_LOGICAL_GREATER : '>' | 'gt' ;
+OPERATOR_LOGICAL_NOT_EQUALS : '!=' | 'neq' ;
+OPERATOR_LOGICAL_AND : 'and' | '&&' ;
+OPERATOR_LOGICAL_OR : 'or' | '||' ;
+OPERATOR_LOGICAL_XOR : 'xor' | '^' ;
+OPERATOR_LOGICAL_NOT : 'not' | '!' ;
+
+OPERATOR_MATHS_ADDITION_EQUALS : '+=' ;
+OPERATOR_MATHS_SUBTRACTION_EQUALS : '-=' ;
+OPERATOR_MATHS_MULTIPLICATION_EQUALS : '*=' ;
+OPERATOR_MATHS_MODULUS_EQUALS : '%=' ;
+OPERATOR_MATHS_DIVISION_EQUALS : '/=' ;
+
+OPERATOR_MATHS_ADDITION : '+' ;
+OPERATOR_MATHS_SUBTRACTION : '-' ;
+OPERATOR_MATHS_MULTIPLICATION : '*' ;
+OPERATOR_MATHS_DIVISION : '/' ;
+OPERATOR_MATHS_MODULUS : '%' ;
+
+LPAREN : '(' ;
+RPAREN : ')' ;
+COMMA : ',' ;
+
+EXPRESSION_AS : 'as' ;
+
+// Type names are lexed as function identifiers, which is what
+// the 'as' clause of a declaration expects.
+TYPE_STRING : 'string' -> type(FUNC_ID);
+TYPE_NUMBER : 'number' -> type(FUNC_ID);
+TYPE_BOOL : 'bool' -> type(FUNC_ID);
+
+STRING : '"' (~('"' | '\\' | '\r' | '\n') | '\\' ('"' | '\\'))* '"';
+
+FUNC_ID: ID ;
+
+// The end of an expression. Return to whatever we were lexing before.
+EXPRESSION_END: '}' -> popMode;
+
+// The end of a command. We need to leave both expression mode, and command mode.
+EXPRESSION_COMMAND_END: '>>' -> type(COMMAND_END), popMode, popMode;
+
+VAR_ID : '$' ID ;
+
+DOT : '.' ;
+
+NUMBER
+    : INT
+    | INT '.' INT
+    ;
+
+fragment INT : DIGIT+ ;
+fragment DIGIT : [0-9];
+
+// Commands
+mode CommandMode;
+COMMAND_WS : WS -> channel(HIDDEN);
+
+// Special keywords that can appear in commands. Those that are followed
+// by an expression or identifier switch modes.
+COMMAND_IF: 'if' [\p{White_Space}] -> pushMode(ExpressionMode);
+COMMAND_ELSEIF: 'elseif' [\p{White_Space}] -> pushMode(ExpressionMode);
+COMMAND_ELSE: 'else' [\p{White_Space}]? ;
+COMMAND_SET : 'set' [\p{White_Space}] -> pushMode(ExpressionMode);
+COMMAND_ENDIF: 'endif' ;
+COMMAND_CALL: 'call' [\p{White_Space}] -> pushMode(ExpressionMode);
+COMMAND_DECLARE: 'declare' [\p{White_Space}] -> pushMode(ExpressionMode);
+COMMAND_JUMP: 'jump' [\p{White_Space}] -> pushMode(CommandIDOrExpressionMode);
+COMMAND_ENUM: 'enum' [\p{White_Space}] -> pushMode(CommandIDMode);
+COMMAND_CASE: 'case' [\p{White_Space}] -> pushMode(CommandIDMode);
+COMMAND_ENDENUM: 'endenum' [\p{White_Space}]? ;
+COMMAND_LOCAL: 'local' [\p{White_Space}] -> pushMode(ExpressionMode);
+COMMAND_ONCE: 'once' ;
+COMMAND_ENDONCE: 'endonce' ;
+
+// End of a command.
+COMMAND_END: '>>' -> popMode;
+
+// If we see anything that we don't expect, assume that this
+// is a command with arbitrary text inside it. Replace this
+// lexer state with CommandTextMode so that we only leave it
+// when we see a '>>'.
+COMMAND_ARBITRARY: . -> type(COMMAND_TEXT), mode(CommandTextMode);
+
+// Arbitrary commands, which may contain expressions, and end with a '>>'.
+mode CommandTextMode;
+COMMAND_TEXT_END: '>>' -> popMode;
+COMMAND_EXPRESSION_START: '{' -> pushMode(ExpressionMode);
+COMMAND_TEXT: ~[>{]+;
+
+// Commands that take a single identifier, like <<enum>> and <<case>>.
+mode CommandIDMode;
+COMMAND_ID: ID -> type(ID), popMode;
+COMMAND_ID_END: '>>' -> type(COMMAND_END), popMode;
+
+// Commands that take either an identifier or an expression, like <<jump>>.
+mode CommandIDOrExpressionMode;
+COMMAND_ID_OR_EXPRESSION_ID: ID -> type(ID), popMode;
+COMMAND_ID_OR_EXPRESSION_START: EXPRESSION_START -> type(EXPRESSION_START), mode(ExpressionMode);
+COMMAND_ID_OR_EXPRESSION_END: '>>' -> type(COMMAND_END), popMode;
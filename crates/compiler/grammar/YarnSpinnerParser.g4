@@ -0,0 +1,187 @@
+// The parser of Yarn files.
+//
+// Based on YarnSpinner.Compiler/YarnSpinnerParser.g4 of the third-party/YarnSpinner submodule,
+// extended by enums, type member references, node-local variables, line groups, `<<once>>`,
+// expressions as the values of headers and expressions as the initial values of declarations.
+// See crates/compiler/src/parser/generated/readme.md for how to regenerate the parser from this file.
+
+parser grammar YarnSpinnerParser;
+
+options { tokenVocab=YarnSpinnerLexer; }
+
+dialogue
+    : (file_hashtag*) node+
+    ;
+
+// File-global hashtags, which precede all nodes
+file_hashtag
+    : HASHTAG text=HASHTAG_TEXT
+    ;
+
+node
+    : header+ BODY_START body BODY_END
+    ;
+
+// The value of a header is either arbitrary text or, for `when` headers,
+// a condition. The lexer decides which one it sees.
+header
+    : header_key=ID HEADER_DELIMITER (header_value=REST_OF_LINE | expression)?
+    ;
+
+body
+    : statement*
+    ;
+
+statement
+    : line_statement
+    | if_statement
+    | set_statement
+    | shortcut_option_statement
+    | call_statement
+    | command_statement
+    | declare_statement
+    | jump_statement
+    | INDENT statement* DEDENT
+    | enum_statement
+    | local_statement
+    | line_group_statement
+    | once_statement
+    ;
+
+line_statement
+    :
+        line_formatted_text // text, interspersed with expressions
+        line_condition? // a line condition
+        hashtag*  // any number of hashtags
+        NEWLINE
+    ;
+
+line_formatted_text
+    : ( TEXT+ // one or more chunks of text to show to the player
+      | EXPRESSION_START expression EXPRESSION_END // an expression to evaluate
+      )+
+    ;
+
+hashtag
+    : HASHTAG text=HASHTAG_TEXT
+    ;
+
+line_condition
+    : COMMAND_START COMMAND_IF expression COMMAND_END
+    | COMMAND_START COMMAND_ONCE COMMAND_END
+    ;
+
+expression
+    : '(' expression ')' #expParens
+    | <assoc=right>op='-' expression #expNegative
+    | <assoc=right>op=OPERATOR_LOGICAL_NOT expression #expNot
+    | expression op=('*' | '/' | '%') expression #expMultDivMod
+    | expression op=('+' | '-') expression #expAddSub
+    | expression op=(OPERATOR_LOGICAL_LESS_THAN_EQUALS | OPERATOR_LOGICAL_GREATER_THAN_EQUALS | OPERATOR_LOGICAL_LESS | OPERATOR_LOGICAL_GREATER ) expression #expComparison
+    | expression op=(OPERATOR_LOGICAL_EQUALS | OPERATOR_LOGICAL_NOT_EQUALS) expression #expEquality
+    | expression op=(OPERATOR_LOGICAL_AND | OPERATOR_LOGICAL_OR | OPERATOR_LOGICAL_XOR) expression #expAndOrXor
+    | value #expValue
+    ;
+
+value
+    : NUMBER         #valueNumber
+    | 'true'         #valueTrue
+    | 'false'        #valueFalse
+    | variable       #valueVar
+    | STRING         #valueString
+    | 'null'         #valueNull
+    | function_call  #valueFunc
+    | typeMemberReference #valueTypeMemberReference
+    ;
+
+variable
+    : VAR_ID
+    ;
+
+function_call
+    : FUNC_ID '(' expression? (COMMA expression)* ')'
+    ;
+
+// A case of an enum, like `Fruit.Apple`. The enum's name may be left out
+// if it can be inferred, like in `.Apple`.
+typeMemberReference
+    : (typeName=FUNC_ID)? DOT memberName=FUNC_ID
+    ;
+
+if_statement
+    : if_clause                                 // <<if foo>> statements...
+      (else_if_clause)*                         // <<elseif bar>> statements.. (can have zero or more of these)
+      (else_clause)?                            // <<else>> statements (can have zero or one of these)
+      COMMAND_START COMMAND_ENDIF COMMAND_END   // <<endif>>
+    ;
+
+if_clause
+    : COMMAND_START COMMAND_IF expression COMMAND_END statement*
+    ;
+
+else_if_clause
+    : COMMAND_START COMMAND_ELSEIF expression COMMAND_END statement*
+    ;
+
+else_clause
+    : COMMAND_START COMMAND_ELSE COMMAND_END statement*
+    ;
+
+set_statement
+    : COMMAND_START COMMAND_SET variable op=(OPERATOR_ASSIGNMENT | '*=' | '/=' | '%=' | '+=' | '-=') expression COMMAND_END
+    ;
+
+call_statement
+    : COMMAND_START COMMAND_CALL function_call COMMAND_END
+    ;
+
+command_statement
+    : COMMAND_START command_formatted_text COMMAND_TEXT_END (hashtag*)
+    ;
+
+command_formatted_text
+    : (COMMAND_TEXT | COMMAND_EXPRESSION_START expression EXPRESSION_END)*
+    ;
+
+shortcut_option_statement
+    : shortcut_option* (shortcut_option BLANK_LINE_FOLLOWING_OPTION?)
+    ;
+
+shortcut_option
+    : SHORTCUT_ARROW line_statement (INDENT statement* DEDENT)?
+    ;
+
+// Smart variables are declared with an expression instead of a value.
+declare_statement
+    : COMMAND_START COMMAND_DECLARE variable OPERATOR_ASSIGNMENT expression (EXPRESSION_AS declaration_type=FUNC_ID)? COMMAND_END
+    ;
+
+jump_statement
+    : COMMAND_START COMMAND_JUMP destination=ID COMMAND_END #jumpToNodeName
+    | COMMAND_START COMMAND_JUMP EXPRESSION_START expression EXPRESSION_END COMMAND_END #jumpToExpression
+    ;
+
+enum_statement
+    : COMMAND_START COMMAND_ENUM name=ID COMMAND_END enum_case_statement+ COMMAND_START COMMAND_ENDENUM COMMAND_END
+    ;
+
+enum_case_statement
+    : COMMAND_START COMMAND_CASE name=ID COMMAND_END
+    ;
+
+local_statement
+    : COMMAND_START COMMAND_LOCAL variable OPERATOR_ASSIGNMENT value (EXPRESSION_AS declaration_type=FUNC_ID)? COMMAND_END
+    ;
+
+// A group of lines of which one is picked when the group is run.
+line_group_statement
+    : line_group_item* (line_group_item BLANK_LINE_FOLLOWING_OPTION?)
+    ;
+
+line_group_item
+    : LINE_GROUP_ARROW line_statement (INDENT statement* DEDENT)?
+    ;
+
+once_statement
+    : COMMAND_START COMMAND_ONCE COMMAND_END statement* COMMAND_START COMMAND_ENDONCE COMMAND_END
+    ;
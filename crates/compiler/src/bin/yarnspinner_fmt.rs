@@ -0,0 +1,105 @@
+//! Formats Yarn files in place, see [`yarnspinner_compiler::format`].
+//!
+//! ```text
+//! yarnspinner_fmt [--check] [FILE]...
+//! ```
+//!
+//! Without files, the source is read from stdin and the formatted source is written to stdout.
+//! With `--check`, no files are written. Instead, the files that are not formatted are listed
+//! and the exit code is 1 if there are any.
+//! Files that can't be formatted, e.g. because they have syntax errors, are reported and left unchanged,
+//! which also makes the exit code 1.
+//!
+//! Requires the `yarnspinner_fmt` feature, e.g. `cargo install yarnspinner_compiler --features yarnspinner_fmt`.
+
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use yarnspinner_compiler::prelude::*;
+
+const USAGE: &str = "Usage: yarnspinner_fmt [--check] [FILE]...";
+
+fn main() -> ExitCode {
+    let mut check = false;
+    let mut paths = Vec::new();
+    for argument in std::env::args().skip(1) {
+        match argument.as_str() {
+            "--check" => check = true,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            option if option.starts_with('-') => {
+                eprintln!("Unknown option {option}\n{USAGE}");
+                return ExitCode::from(2);
+            }
+            _ => paths.push(PathBuf::from(argument)),
+        }
+    }
+
+    let result = if paths.is_empty() {
+        format_stdin(check)
+    } else {
+        format_files(&paths, check)
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Returns whether the source could be formatted and was already formatted or `check` is false.
+fn format_stdin(check: bool) -> io::Result<bool> {
+    let mut source = String::new();
+    io::stdin().read_to_string(&mut source)?;
+    let formatted = match format(&File {
+        file_name: "<stdin>".to_owned(),
+        source: source.clone(),
+    }) {
+        Ok(formatted) => formatted,
+        Err(error) => {
+            eprintln!("<stdin> could not be formatted: {error}");
+            return Ok(false);
+        }
+    };
+    if check {
+        return Ok(formatted == source);
+    }
+    io::stdout().write_all(formatted.as_bytes())?;
+    Ok(true)
+}
+
+/// Returns whether all files could be formatted and were already formatted or `check` is false.
+fn format_files(paths: &[PathBuf], check: bool) -> io::Result<bool> {
+    let mut is_formatted = true;
+    for path in paths {
+        let source = std::fs::read_to_string(path).map_err(|error| {
+            io::Error::new(error.kind(), format!("{}: {error}", path.display()))
+        })?;
+        let formatted = match format(&File {
+            file_name: path.display().to_string(),
+            source: source.clone(),
+        }) {
+            Ok(formatted) => formatted,
+            Err(error) => {
+                eprintln!("{} could not be formatted: {error}", path.display());
+                is_formatted = false;
+                continue;
+            }
+        };
+        if formatted == source {
+            continue;
+        }
+        if check {
+            println!("{}", path.display());
+            is_formatted = false;
+        } else {
+            std::fs::write(path, formatted)?;
+        }
+    }
+    Ok(is_formatted)
+}
@@ -1,22 +1,29 @@
 mod add_initial_value_registrations;
 mod add_tracking_declarations;
+mod check_commands;
 mod check_types;
 mod clean_up_diagnostics;
 mod create_declarations_for_tracking_nodes;
 mod early_breaks;
 mod find_tracking_nodes;
 mod generate_code;
+mod generate_node_groups;
 mod get_declarations;
+mod get_enum_declarations;
+mod optimize_code;
 mod parse_files;
 mod register_initial_variables;
 mod register_strings;
 mod resolve_deferred_type_diagnostic;
+mod resolve_smart_variable_types;
+mod validate_node_references;
 mod validate_unique_node_names;
 
 pub(crate) use self::{
-    add_initial_value_registrations::*, add_tracking_declarations::*, check_types::*,
-    clean_up_diagnostics::*, create_declarations_for_tracking_nodes::*, early_breaks::*,
-    find_tracking_nodes::*, generate_code::*, get_declarations::*, parse_files::*,
+    add_initial_value_registrations::*, add_tracking_declarations::*, check_commands::*,
+    check_types::*, clean_up_diagnostics::*, create_declarations_for_tracking_nodes::*,
+    early_breaks::*, find_tracking_nodes::*, generate_code::*, generate_node_groups::*,
+    get_declarations::*, get_enum_declarations::*, optimize_code::*, parse_files::*,
     register_initial_variables::*, register_strings::*, resolve_deferred_type_diagnostic::*,
-    validate_unique_node_names::*,
+    resolve_smart_variable_types::*, validate_node_references::*, validate_unique_node_names::*,
 };
@@ -15,20 +15,28 @@ pub(crate) fn add_initial_value_registrations(
     let declarations = state
         .known_variable_declarations
         .iter()
-        .filter(|decl| !matches!(decl.r#type, Type::Function(_)));
+        .filter(|decl| !matches!(decl.r#type, Type::Function(_)))
+        // Local variables are initialized by their `<<local>>` statement instead
+        .filter(|decl| !decl.is_local)
+        // Smart variables are computed from their expression whenever they're read
+        .filter(|decl| !decl.is_smart);
 
     for declaration in declarations {
         let Some(default_value) = declaration.default_value.clone() else {
-            state.diagnostics.push(Diagnostic::from_message(format!(
+            state.diagnostics.push(Diagnostic::new(
+                DiagnosticCode::NullValue,
+                format!(
                 "Variable declaration {} (type {}) has a null default value. This is not allowed.",
                 declaration.name,
                 declaration.r#type.format()
-            )));
+            ),
+            ));
             continue;
         };
         if let Some(ref mut program) = compilation.program {
             let value = match &declaration.r#type {
                     Type::String => Operand::from(String::from(default_value)),
+                    Type::Enum(_) => Operand::from(String::from(default_value)),
                     Type::Number => Operand::from(f32::try_from(default_value).unwrap()),
                     Type::Boolean => Operand::from(bool::try_from(default_value).unwrap()),
                     _ => panic!("Cannot create initial value registration for type {}. This is a bug. Please report it at https://github.com/YarnSpinnerTool/YarnSpinner-Rust/issues/new", declaration.r#type.format()),
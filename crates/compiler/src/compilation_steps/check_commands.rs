@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use crate::visitors::{CommandCheckVisitor, BUILT_IN_COMMANDS};
+use antlr_rust::tree::ParseTreeVisitorCompat;
+
+/// Checks that commands are run with the parameters they were declared with.
+/// Commands are only checked once at least one was declared with [`Compiler::declare_command`].
+pub(crate) fn check_commands(mut state: CompilationIntermediate) -> CompilationIntermediate {
+    if state.job.command_declarations.is_empty() {
+        return state;
+    }
+    // Running such a command would run the built-in one instead
+    for declaration in &state.job.command_declarations {
+        if BUILT_IN_COMMANDS.contains(&declaration.name.as_str()) {
+            state.diagnostics.push(Diagnostic::new(
+                DiagnosticCode::ReservedCommandName,
+                format!(
+                    "The command {} can't be declared, because <<{}>> is built into Yarn Spinner",
+                    declaration.name, declaration.name
+                ),
+            ));
+        }
+    }
+    for (file, known_types) in &state.parsed_files {
+        let mut visitor = CommandCheckVisitor::new(&state.job, known_types, file);
+        visitor.visit(file.tree.as_ref());
+        state.diagnostics.extend(visitor.diagnostics);
+    }
+    state
+}
@@ -3,19 +3,42 @@ use crate::visitors::TypeCheckVisitor;
 use antlr_rust::tree::ParseTreeVisitorCompat;
 
 pub(crate) fn check_types(mut state: CompilationIntermediate) -> CompilationIntermediate {
-    for (file, known_types) in &mut state.parsed_files {
-        let mut visitor =
-            TypeCheckVisitor::new(state.known_variable_declarations.clone(), file.clone());
-        visitor.visit(file.tree.as_ref());
+    for (index, (file, known_types)) in state.parsed_files.iter_mut().enumerate() {
+        let cached_file = &mut state.cached_files[index];
+        let dependency_hash =
+            type_dependency_hash(file, &state.known_variable_declarations, &state.known_enums);
+        let types = match cached_file.types.take() {
+            Some(types) if types.dependency_hash == dependency_hash => types,
+            _ => {
+                let mut visitor = TypeCheckVisitor::new(
+                    state.known_variable_declarations.clone(),
+                    state.known_enums.clone(),
+                    file.clone(),
+                );
+                visitor.visit(file.tree.as_ref());
+                CachedTypes {
+                    dependency_hash,
+                    new_declarations: visitor.new_declarations,
+                    known_types: visitor.known_types,
+                    diagnostics: visitor.diagnostics,
+                    deferred_types: visitor.deferred_types,
+                }
+            }
+        };
+
         state
             .known_variable_declarations
-            .extend(visitor.new_declarations.clone());
+            .extend(types.new_declarations.clone());
         state
             .derived_variable_declarations
-            .extend(visitor.new_declarations);
-        state.diagnostics.extend(visitor.diagnostics);
-        state.potential_issues.extend(visitor.deferred_types);
-        known_types.extend(visitor.known_types);
+            .extend(types.new_declarations.clone());
+        state.diagnostics.extend_from_slice(&types.diagnostics);
+        state
+            .potential_issues
+            .extend_from_slice(&types.deferred_types);
+        known_types.extend(types.known_types.clone());
+
+        cached_file.types = Some(types);
     }
     state
 }
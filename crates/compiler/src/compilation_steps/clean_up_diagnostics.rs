@@ -13,6 +13,7 @@ pub(crate) fn clean_up_diagnostics(mut state: CompilationIntermediate) -> Compil
     } else {
         state.diagnostics.clone()
     };
+    let total_diagnostics = override_severities(&state.job, total_diagnostics);
     let mut unique_diagnostics: HashSet<Diagnostic> = HashSet::from_iter(total_diagnostics.clone());
     let mut ordered_unique_diagnostics = Vec::new();
 
@@ -31,3 +32,24 @@ pub(crate) fn clean_up_diagnostics(mut state: CompilationIntermediate) -> Compil
     }
     state
 }
+
+/// Applies [`Compiler::diagnostic_severities`] to the diagnostics, leaving out the ones that should not be reported.
+/// Errors are only changed if their [`DiagnosticCode::can_be_downgraded`].
+pub(crate) fn override_severities(
+    compiler: &Compiler,
+    diagnostics: Vec<Diagnostic>,
+) -> Vec<Diagnostic> {
+    diagnostics
+        .into_iter()
+        .filter_map(|diagnostic| override_severity(compiler, diagnostic))
+        .collect()
+}
+
+fn override_severity(compiler: &Compiler, mut diagnostic: Diagnostic) -> Option<Diagnostic> {
+    if diagnostic.severity == DiagnosticSeverity::Warning || diagnostic.code.can_be_downgraded() {
+        if let Some(severity) = compiler.diagnostic_severities.get(&diagnostic.code) {
+            diagnostic.severity = (*severity)?;
+        }
+    }
+    Some(diagnostic)
+}
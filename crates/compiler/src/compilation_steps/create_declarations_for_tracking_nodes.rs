@@ -5,7 +5,10 @@ use yarnspinner_core::types::Type;
 pub(crate) fn create_declarations_for_tracking_nodes(
     mut state: CompilationIntermediate,
 ) -> CompilationIntermediate {
-    let tracking_declarations = state.tracking_nodes.iter().map(|node| {
+    // Sorted so that compiling the same files always declares the variables in the same order
+    let mut tracking_nodes: Vec<_> = state.tracking_nodes.iter().collect();
+    tracking_nodes.sort();
+    let tracking_declarations = tracking_nodes.into_iter().map(|node| {
         let name = Library::generate_unique_visited_variable_for_node(node);
         Declaration::new(name, Type::Number)
             .with_default_value(0.0)
@@ -14,6 +17,17 @@ pub(crate) fn create_declarations_for_tracking_nodes(
             ))
     });
 
+    let mut once_variables: Vec<_> = state.once_variables.values().collect();
+    once_variables.sort();
+    let once_declarations = once_variables.into_iter().map(|name| {
+        Declaration::new(name.clone(), Type::Boolean)
+            .with_default_value(false)
+            .with_description(
+                "The generated variable for tracking whether once-only content has run",
+            )
+    });
+    let tracking_declarations = tracking_declarations.chain(once_declarations);
+
     // adding the generated tracking variables into the declaration list
     // this way any future variable storage system will know about them
     // if we didn't do this later stages wouldn't be able to interface with them
@@ -1,7 +1,8 @@
 use crate::prelude::*;
 use crate::visitors::NodeTrackingVisitor;
 use antlr_rust::tree::ParseTreeVisitorCompat;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use yarnspinner_core::prelude::Library;
 
 pub(crate) fn find_tracking_nodes(mut state: CompilationIntermediate) -> CompilationIntermediate {
     // determining the nodes we need to track visits on
@@ -9,11 +10,33 @@ pub(crate) fn find_tracking_nodes(mut state: CompilationIntermediate) -> Compila
     // so that any tracking variables are included in the compiled declarations
     let mut tracking_nodes = HashSet::new();
     let mut ignore_nodes = HashSet::new();
+    let mut used_once_variables: HashMap<String, usize> = HashMap::new();
     for (file, _) in &state.parsed_files {
         let mut visitor = NodeTrackingVisitor::new();
         visitor.visit(file.tree.as_ref());
         tracking_nodes.extend(visitor.tracking_nodes);
         ignore_nodes.extend(visitor.ignoring_nodes);
+        state.contains_detours |= visitor.contains_detours;
+
+        // Members of a node group share their title, so their once-only content is named after their unique name instead.
+        for site in visitor.once_sites {
+            let node_name = state
+                .node_group_members
+                .get(&(file.name.clone(), site.title_token_index))
+                .cloned()
+                .unwrap_or(site.node_title);
+            let mut variable =
+                Library::generate_unique_once_variable_for_node(&node_name, &site.key);
+            // `<<once>>` blocks with the same contents in the same node are told apart by their order
+            let duplicates = used_once_variables.entry(variable.clone()).or_default();
+            *duplicates += 1;
+            if *duplicates > 1 {
+                variable = format!("{variable}.{duplicates}");
+            }
+            state
+                .once_variables
+                .insert((file.name.clone(), site.token_index), variable);
+        }
     }
     state.tracking_nodes = tracking_nodes.difference(&ignore_nodes).cloned().collect();
     state
@@ -1,4 +1,4 @@
-use crate::listeners::{CompilerListener, DiagnosticVec};
+use crate::listeners::{CompilerListener, DiagnosticVec, NodeGroupCondition};
 use crate::prelude::generated::yarnspinnerparser::YarnSpinnerParserTreeWalker;
 use crate::prelude::*;
 use crate::visitors::KnownTypes;
@@ -21,13 +21,70 @@ pub(crate) fn generate_code(mut state: CompilationIntermediate) -> CompilationIn
         state
             .parsed_files
             .iter()
-            .map(|(file, known_types)| {
-                generate_code_for_file(
+            .zip(&mut state.cached_files)
+            .map(|((file, known_types), cached_file)| {
+                let node_group_members: HashMap<_, _> = state
+                    .node_group_members
+                    .iter()
+                    .filter(|((file_name, _), _)| *file_name == file.name)
+                    .map(|((_, token_index), member_name)| (*token_index, member_name.clone()))
+                    .collect();
+                let once_variables: HashMap<_, _> = state
+                    .once_variables
+                    .iter()
+                    .filter(|((file_name, _), _)| *file_name == file.name)
+                    .map(|((_, token_index), variable)| (*token_index, variable.clone()))
+                    .collect();
+                // Type checking always runs before, so its result is cached
+                let type_dependency_hash = cached_file.types.as_ref().unwrap().dependency_hash;
+                let dependency_hash = |compilation: &Compilation, tracking_nodes: &HashSet<_>| {
+                    code_dependency_hash(
+                        type_dependency_hash,
+                        state.contains_detours,
+                        tracking_nodes,
+                        compilation,
+                        &node_group_members,
+                        &once_variables,
+                    )
+                };
+                if let Some(code) = cached_file.code.as_ref().filter(|code| {
+                    code.dependency_hash
+                        == dependency_hash(&code.compilation, &state.tracking_nodes)
+                }) {
+                    state
+                        .node_group_conditions
+                        .extend(code.node_group_conditions.iter().cloned());
+                    return Ok(Compilation {
+                        program: code.compilation.program.clone(),
+                        warnings: code.compilation.warnings.clone(),
+                        debug_info: code.compilation.debug_info.clone(),
+                        ..template.clone()
+                    });
+                }
+
+                let mut node_group_conditions = Vec::new();
+                let result = generate_code_for_file(
                     &mut state.tracking_nodes,
+                    state.contains_detours,
+                    &mut node_group_conditions,
                     known_types.clone(),
+                    node_group_members.clone(),
+                    once_variables.clone(),
                     template.clone(),
                     file,
-                )
+                );
+                cached_file.code = result.as_ref().ok().map(|compilation| CachedCode {
+                    dependency_hash: dependency_hash(compilation, &state.tracking_nodes),
+                    compilation: Compilation {
+                        program: compilation.program.clone(),
+                        warnings: compilation.warnings.clone(),
+                        debug_info: compilation.debug_info.clone(),
+                        ..Default::default()
+                    },
+                    node_group_conditions: node_group_conditions.clone(),
+                });
+                state.node_group_conditions.extend(node_group_conditions);
+                result
             })
             .collect()
     };
@@ -53,25 +110,35 @@ pub(crate) fn generate_code(mut state: CompilationIntermediate) -> CompilationIn
     state
 }
 
+#[allow(clippy::too_many_arguments)]
 fn generate_code_for_file<'a, 'b: 'a, 'input: 'a + 'b>(
     tracking_nodes: &mut HashSet<String>,
+    contains_detours: bool,
+    node_group_conditions: &mut Vec<NodeGroupCondition>,
     known_types: KnownTypes,
+    node_group_members: HashMap<isize, String>,
+    once_variables: HashMap<isize, String>,
     result_template: Compilation,
     file: &'a FileParseResult<'input>,
 ) -> Result<Compilation> {
     let compiler_listener = Box::new(CompilerListener::new(
         tracking_nodes.clone(),
+        contains_detours,
         known_types,
+        node_group_members,
+        once_variables,
         file.clone(),
     ));
     let compiler_tracking_nodes = compiler_listener.tracking_nodes.clone();
     let compiler_diagnostics = compiler_listener.diagnostics.clone();
     let compiler_program = compiler_listener.program.clone();
     let compiler_debug_infos = compiler_listener.debug_infos.clone();
+    let compiler_node_group_conditions = compiler_listener.node_group_conditions.clone();
 
     YarnSpinnerParserTreeWalker::walk(compiler_listener, file.tree.as_ref());
 
     tracking_nodes.extend(compiler_tracking_nodes.borrow().iter().cloned());
+    node_group_conditions.extend(compiler_node_group_conditions.take());
 
     // Don't attempt to generate debug information if compilation produced errors
     if compiler_diagnostics.borrow().has_errors() {
@@ -0,0 +1,120 @@
+use crate::listeners::NodeGroupCondition;
+use crate::prelude::*;
+use yarnspinner_core::prelude::*;
+
+/// Generates a node for every node group that is named after the group.
+/// When run, it evaluates the conditions of all members, lets the saliency strategy pick one of the eligible ones and jumps to it.
+pub(crate) fn generate_node_groups(mut state: CompilationIntermediate) -> CompilationIntermediate {
+    let Some(Ok(compilation)) = state.result.as_mut() else {
+        return state;
+    };
+    let mut group_names: Vec<&str> = Vec::new();
+    for condition in &state.node_group_conditions {
+        if !group_names.contains(&condition.group_name.as_str()) {
+            group_names.push(&condition.group_name);
+        }
+    }
+    for group_name in group_names {
+        let members: Vec<_> = state
+            .node_group_conditions
+            .iter()
+            .filter(|condition| condition.group_name == group_name)
+            .collect();
+        let (node, debug_info) = generate_node_group(group_name, &members);
+        compilation
+            .program
+            .as_mut()
+            .unwrap()
+            .nodes
+            .insert(group_name.to_owned(), node);
+        compilation
+            .debug_info
+            .insert(group_name.to_owned(), debug_info);
+    }
+    state
+}
+
+fn generate_node_group(group_name: &str, members: &[&NodeGroupCondition]) -> (Node, DebugInfo) {
+    let mut generator = NodeGroupGenerator {
+        node: Node {
+            name: group_name.to_owned(),
+            headers: vec![Header {
+                key: "title".to_owned(),
+                value: group_name.to_owned(),
+            }],
+            ..Default::default()
+        },
+        debug_info: DebugInfo {
+            file_name: members[0].file_name.clone(),
+            node_name: group_name.to_owned(),
+            ..Default::default()
+        },
+    };
+    let no_member_label = "Lnone";
+    let member_labels: Vec<_> = (0..members.len())
+        .map(|index| format!("L{index}member"))
+        .collect();
+
+    // Offer every member whose conditions pass as a candidate
+    for (member, label) in members.iter().zip(&member_labels) {
+        for (index, instruction) in member.instructions.iter().enumerate() {
+            // A group may be spread over several files, but a node's debug info can only refer to one of them
+            let position = (member.file_name == generator.debug_info.file_name)
+                .then(|| member.line_positions.get(&index).copied().flatten())
+                .flatten();
+            generator.emit(instruction.clone(), position);
+        }
+        generator.emit_op(
+            OpCode::AddSaliencyCandidate,
+            vec![member.member_name.clone().into(), label.clone().into()],
+        );
+    }
+    generator.emit_op(
+        OpCode::SelectSaliencyCandidate,
+        vec![no_member_label.to_owned().into()],
+    );
+    generator.emit_op(OpCode::Jump, vec![]);
+
+    // Run the selected member
+    for (member, label) in members.iter().zip(member_labels) {
+        generator.add_label(label);
+        generator.emit_op(OpCode::Pop, vec![]);
+        generator.emit_op(OpCode::PushString, vec![member.member_name.clone().into()]);
+        generator.emit_op(OpCode::RunNode, vec![]);
+    }
+
+    // No member is eligible, so there is nothing to run
+    generator.add_label(no_member_label);
+    generator.emit_op(OpCode::Pop, vec![]);
+    generator.emit_op(OpCode::Return, vec![]);
+
+    (generator.node, generator.debug_info)
+}
+
+struct NodeGroupGenerator {
+    node: Node,
+    debug_info: DebugInfo,
+}
+
+impl NodeGroupGenerator {
+    fn emit(&mut self, instruction: Instruction, position: Option<Position>) {
+        self.debug_info
+            .line_positions
+            .insert(self.node.instructions.len(), position);
+        self.node.instructions.push(instruction);
+    }
+
+    fn emit_op(&mut self, op_code: OpCode, operands: Vec<Operand>) {
+        let instruction = Instruction {
+            opcode: op_code.into(),
+            operands,
+        };
+        self.emit(instruction, None);
+    }
+
+    fn add_label(&mut self, label: impl Into<String>) {
+        self.node
+            .labels
+            .insert(label.into(), self.node.instructions.len() as i32);
+    }
+}
@@ -4,26 +4,56 @@ use antlr_rust::tree::ParseTreeVisitorCompat;
 
 pub(crate) fn get_declarations(mut state: CompilationIntermediate) -> CompilationIntermediate {
     // Find the variable declarations in these files.
-    for (file, _) in &state.parsed_files {
-        let mut variable_declaration_visitor =
-            DeclarationVisitor::new(state.known_variable_declarations.clone(), file.clone());
+    for (index, (file, _)) in state.parsed_files.iter().enumerate() {
+        let cached_file = &mut state.cached_files[index];
+        let declarations = match cached_file.declarations.take() {
+            Some(declarations)
+                if declarations.can_be_reused_after(
+                    &state.known_variable_declarations,
+                    &state.known_enums,
+                ) =>
+            {
+                declarations
+            }
+            _ => {
+                let mut variable_declaration_visitor = DeclarationVisitor::new(
+                    state.known_variable_declarations.clone(),
+                    state.known_enums.clone(),
+                    file.clone(),
+                );
 
-        variable_declaration_visitor.visit(file.tree.as_ref());
+                variable_declaration_visitor.visit(file.tree.as_ref());
+
+                CachedDeclarations {
+                    new_declarations: variable_declaration_visitor.new_declarations,
+                    smart_variables: variable_declaration_visitor.smart_variables,
+                    file_tags: variable_declaration_visitor.file_tags,
+                    diagnostics: variable_declaration_visitor.diagnostics,
+                    known_enums: state.known_enums.clone(),
+                }
+            }
+        };
 
         state
             .known_variable_declarations
-            .extend(variable_declaration_visitor.new_declarations.clone());
+            .extend(declarations.new_declarations.clone());
         state
             .derived_variable_declarations
-            .extend(variable_declaration_visitor.new_declarations);
+            .extend(declarations.new_declarations.clone());
 
         state
             .diagnostics
-            .extend_from_slice(&variable_declaration_visitor.diagnostics);
+            .extend_from_slice(&declarations.diagnostics);
 
         state
             .file_tags
-            .insert(file.name.clone(), variable_declaration_visitor.file_tags);
+            .insert(file.name.clone(), declarations.file_tags.clone());
+
+        state
+            .smart_variables
+            .extend(declarations.smart_variables.clone());
+
+        cached_file.declarations = Some(declarations);
     }
     state
 }
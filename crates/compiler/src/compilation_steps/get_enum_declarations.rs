@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use crate::visitors::EnumDeclarationVisitor;
+use antlr_rust::tree::ParseTreeVisitorCompat;
+
+pub(crate) fn get_enum_declarations(mut state: CompilationIntermediate) -> CompilationIntermediate {
+    // Enums need to be known before any variable declarations are
+    // processed, since those may refer to them regardless of which file
+    // the enum was declared in.
+    for (file, _) in &state.parsed_files {
+        let mut visitor = EnumDeclarationVisitor::new(state.known_enums.clone(), file.clone());
+        visitor.visit(file.tree.as_ref());
+        state.known_enums.extend(visitor.new_enums);
+        state.diagnostics.extend(visitor.diagnostics);
+    }
+    state
+}
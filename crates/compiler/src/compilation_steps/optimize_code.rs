@@ -0,0 +1,16 @@
+use crate::optimizer::optimize_program;
+use crate::prelude::*;
+
+/// Runs the optimizer on the generated program if [`Compiler::with_optimization`] was enabled.
+pub(crate) fn optimize_code(mut state: CompilationIntermediate) -> CompilationIntermediate {
+    if !state.job.optimize {
+        return state;
+    }
+    let Some(Ok(compilation)) = state.result.as_mut() else {
+        return state;
+    };
+    if let Some(program) = compilation.program.as_mut() {
+        optimize_program(program, &mut compilation.debug_info);
+    }
+    state
+}
@@ -1,9 +1,40 @@
 use crate::prelude::*;
+use crate::visitors::LastLineBeforeOptionsVisitor;
+use antlr_rust::tree::ParseTreeVisitorCompat;
 
 pub(crate) fn parse_files(mut state: CompilationIntermediate) -> CompilationIntermediate {
-    for (file, chars) in state.job.files.iter().zip(state.file_chars.iter()) {
-        let parse_result = parse_syntax_tree(file, chars, &mut state.diagnostics);
-        state.parsed_files.push((parse_result, Default::default()));
+    for file in &state.job.files {
+        let content_hash = content_hash(file);
+        let cached_file = state
+            .previously_cached_files
+            .iter()
+            .position(|cached_file| {
+                cached_file.content_hash == content_hash
+                    && cached_file.parse_result.name == file.file_name
+            })
+            .map(|index| state.previously_cached_files.swap_remove(index));
+        let cached_file = cached_file.unwrap_or_else(|| parse_file(file, content_hash));
+
+        state
+            .diagnostics
+            .extend_from_slice(&cached_file.parse_diagnostics);
+        state
+            .parsed_files
+            .push((cached_file.parse_result.clone(), Default::default()));
+        state.cached_files.push(cached_file);
     }
     state
 }
+
+pub(crate) fn parse_file<'input>(file: &File, content_hash: u64) -> CachedFile<'input> {
+    let mut diagnostics = Vec::new();
+    let parse_result = parse_syntax_tree(file, &mut diagnostics);
+
+    // ok now we will add in our lastline tags
+    // we do this BEFORE we build our strings table otherwise the tags will get missed
+    // this should probably be a flag instead of every time though
+    let mut last_line_tagger = LastLineBeforeOptionsVisitor::default();
+    last_line_tagger.visit(parse_result.tree.as_ref());
+
+    CachedFile::new(content_hash, parse_result, diagnostics)
+}
@@ -1,22 +1,45 @@
+use crate::compilation_steps::parse_file;
 use crate::prelude::*;
-use crate::visitors::{LastLineBeforeOptionsVisitor, StringTableGeneratorVisitor};
+use crate::visitors::StringTableGeneratorVisitor;
 use antlr_rust::tree::ParseTreeVisitorCompat;
+use std::collections::HashMap;
 
 pub(crate) fn register_strings(mut state: CompilationIntermediate) -> CompilationIntermediate {
     // First pass: parse all files, generate their syntax trees,
     // and figure out what variables they've declared
-    for (file, _) in &state.parsed_files {
-        // ok now we will add in our lastline tags
-        // we do this BEFORE we build our strings table otherwise the tags will get missed
-        // this should probably be a flag instead of every time though
-        let mut last_line_tagger = LastLineBeforeOptionsVisitor::default();
-        last_line_tagger.visit(file.tree.as_ref());
+    for (index, file) in state.job.files.iter().enumerate() {
+        let cached_file = &mut state.cached_files[index];
+        if let Some(strings) = &cached_file.strings {
+            if strings.can_be_reused_after(&state.string_table) {
+                state.string_table.extend(strings.string_table.clone());
+                continue;
+            }
+            // The tree already got tagged with the implicit line IDs of the last compilation, so start over.
+            *cached_file = parse_file(file, cached_file.content_hash);
+            state.parsed_files[index].0 = cached_file.parse_result.clone();
+        }
 
+        let file = &cached_file.parse_result;
         let mut visitor =
             StringTableGeneratorVisitor::new(state.string_table.clone(), file.clone());
         visitor.visit(file.tree.as_ref());
-        state.diagnostics.extend(visitor.diagnostics);
+        state.diagnostics.extend_from_slice(&visitor.diagnostics);
+
+        // Only cache what this file added or changed
+        let preceding_string_count = state.string_table.len();
+        let string_table: StringTableManager = visitor
+            .string_table_manager
+            .iter()
+            .filter(|(line_id, string_info)| state.string_table.get(line_id) != Some(string_info))
+            .map(|(line_id, string_info)| (line_id.clone(), string_info.clone()))
+            .collect::<HashMap<_, _>>()
+            .into();
         state.string_table.extend(visitor.string_table_manager);
+        cached_file.strings = Some(CachedStrings {
+            string_table,
+            diagnostics: visitor.diagnostics,
+            preceding_string_count,
+        });
     }
 
     state
@@ -0,0 +1,139 @@
+use crate::parser_rule_context_ext::ParserRuleContextExt;
+use crate::prelude::generated::yarnspinnerlexer;
+use crate::prelude::*;
+use crate::visitors::{SmartVariable, TypeCheckVisitor};
+use antlr_rust::parser_rule_context::ParserRuleContext;
+use antlr_rust::token::Token;
+use antlr_rust::token_stream::TokenStream;
+use antlr_rust::tree::ParseTreeVisitorCompat;
+use yarnspinner_core::types::*;
+
+/// Type checks the expressions of smart variables. A smart variable gets the type of its expression
+/// unless it was declared with an explicit one. This happens before the rest of the code is type checked,
+/// since smart variables may be read anywhere, even before their declaration.
+pub(crate) fn resolve_smart_variable_types(
+    mut state: CompilationIntermediate,
+) -> CompilationIntermediate {
+    let mut unresolved = std::mem::take(&mut state.smart_variables);
+    while !unresolved.is_empty() {
+        // Smart variables can read each other, so resolve the ones that don't read unresolved smart variables first.
+        let unresolved_names: Vec<_> = unresolved.iter().map(|v| v.name.clone()).collect();
+        let resolvable = unresolved.iter().position(|smart_variable| {
+            read_variables(&state, smart_variable)
+                .iter()
+                .all(|name| !unresolved_names.contains(name))
+        });
+        let Some(resolvable) = resolvable else {
+            for smart_variable in &unresolved {
+                let file = find_file(&state, smart_variable);
+                let diagnostic = Diagnostic::new(
+                    DiagnosticCode::CyclicSmartVariable,
+                    format!(
+                        "The smart variable {} depends on its own value",
+                        smart_variable.name
+                    ),
+                )
+                .with_file_name(&file.name)
+                .with_parser_context(smart_variable.expression.as_ref(), file.tokens());
+                state.diagnostics.push(diagnostic);
+            }
+            break;
+        };
+        let smart_variable = unresolved.remove(resolvable);
+        resolve_smart_variable_type(&mut state, &smart_variable);
+        state.smart_variables.push(smart_variable);
+    }
+    state
+}
+
+fn resolve_smart_variable_type<'input>(
+    state: &mut CompilationIntermediate<'input>,
+    smart_variable: &SmartVariable<'input>,
+) {
+    let (file, known_types) = state
+        .parsed_files
+        .iter_mut()
+        .find(|(file, _)| file.name == smart_variable.file_name)
+        .unwrap();
+    let mut visitor = TypeCheckVisitor::new(
+        state.known_variable_declarations.clone(),
+        state.known_enums.clone(),
+        file.clone(),
+    );
+    let expression_type = visitor.visit(smart_variable.expression.as_ref());
+    state
+        .known_variable_declarations
+        .extend(visitor.new_declarations.clone());
+    state
+        .derived_variable_declarations
+        .extend(visitor.new_declarations);
+    state.diagnostics.extend(visitor.diagnostics);
+    state.potential_issues.extend(visitor.deferred_types);
+    known_types.extend(visitor.known_types);
+
+    let declared_type = state
+        .known_variable_declarations
+        .iter()
+        .find(|decl| decl.is_smart && decl.name == smart_variable.name)
+        .map(|decl| decl.r#type.clone())
+        .unwrap();
+    let diagnostic = match (declared_type, expression_type) {
+        (Type::Any, Some(expression_type)) => {
+            let declarations = state
+                .known_variable_declarations
+                .iter_mut()
+                .chain(state.derived_variable_declarations.iter_mut())
+                .filter(|decl| decl.is_smart && decl.name == smart_variable.name);
+            for declaration in declarations {
+                declaration.r#type = expression_type.clone();
+            }
+            None
+        }
+        (Type::Any, None) => Some(Diagnostic::new(DiagnosticCode::UndeterminedType, format!(
+            "Can't figure out the type of the smart variable {} from its expression. Specify its type with `as`.",
+            smart_variable.name
+        ))),
+        (declared_type, Some(expression_type)) if !expression_type.is_sub_type_of(&declared_type) => {
+            Some(Diagnostic::new(DiagnosticCode::TypeMismatch, format!(
+                "Type {} does not match value {} ({})",
+                declared_type.format(),
+                smart_variable
+                    .expression
+                    .get_text_with_whitespace(file.tokens()),
+                expression_type.format()
+            )))
+        }
+        _ => None,
+    };
+    if let Some(diagnostic) = diagnostic {
+        let diagnostic = diagnostic
+            .with_file_name(&file.name)
+            .with_parser_context(smart_variable.expression.as_ref(), file.tokens());
+        state.diagnostics.push(diagnostic);
+    }
+}
+
+/// The names of the variables read by a smart variable's expression.
+fn read_variables(state: &CompilationIntermediate, smart_variable: &SmartVariable) -> Vec<String> {
+    let file = find_file(state, smart_variable);
+    let tokens = file.tokens();
+    let start = smart_variable.expression.start().get_token_index();
+    let stop = smart_variable.expression.stop().get_token_index();
+    (start..=stop)
+        .map(|index| tokens.get(index))
+        .filter(|token| token.get_token_type() == yarnspinnerlexer::VAR_ID)
+        .map(|token| token.get_text().to_owned())
+        .collect()
+}
+
+fn find_file<'a, 'input>(
+    state: &'a CompilationIntermediate<'input>,
+    smart_variable: &SmartVariable,
+) -> &'a FileParseResult<'input> {
+    state
+        .parsed_files
+        .iter()
+        .map(|(file, _)| file)
+        .find(|file| file.name == smart_variable.file_name)
+        .unwrap()
+}
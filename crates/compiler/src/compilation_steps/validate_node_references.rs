@@ -0,0 +1,39 @@
+use crate::prelude::generated::yarnspinnerparser::{DialogueContextAttrs, NodeContextAttrs};
+use crate::prelude::*;
+use crate::visitors::NodeReferenceVisitor;
+use antlr_rust::token::Token;
+use antlr_rust::tree::ParseTreeVisitorCompat;
+use std::collections::HashSet;
+
+/// Reports `<<jump>>`s, `<<detour>>`s and `visited` calls that refer to nodes that don't exist,
+/// so that typos are caught now instead of when the dialogue runs.
+pub(crate) fn validate_node_references(
+    mut state: CompilationIntermediate,
+) -> CompilationIntermediate {
+    // Node groups can be referred to by their name, which is shared by all their members,
+    // and their members by their unique names.
+    let titles = state.parsed_files.iter().flat_map(|(file, _)| {
+        file.tree
+            .node_all()
+            .iter()
+            .flat_map(|node| node.header_all())
+            .filter(|header| header.header_key.as_ref().unwrap().get_text() == "title")
+            .filter_map(|header| {
+                header
+                    .header_value
+                    .as_ref()
+                    .map(|value| value.get_text().to_owned())
+            })
+            .collect::<Vec<_>>()
+    });
+    let node_names: HashSet<_> = titles
+        .chain(state.node_group_members.values().cloned())
+        .collect();
+
+    for (file, _) in &state.parsed_files {
+        let mut visitor = NodeReferenceVisitor::new(&node_names, &state.excluded_nodes, file);
+        visitor.visit(file.tree.as_ref());
+        state.diagnostics.extend(visitor.diagnostics);
+    }
+    state
+}
@@ -1,7 +1,9 @@
 use crate::prelude::generated::yarnspinnerparser::{DialogueContextAttrs, NodeContextAttrs};
 use crate::prelude::*;
+use antlr_rust::parser_rule_context::ParserRuleContext;
 use antlr_rust::token::Token;
-use std::collections::HashMap;
+use antlr_rust::tree::ParseTree;
+use std::collections::{HashMap, HashSet};
 
 pub(crate) fn validate_unique_node_names(
     mut state: CompilationIntermediate,
@@ -20,41 +22,89 @@ pub(crate) fn validate_unique_node_names(
     // Pair up every node with its name, and filter out any that don't
     // have a name
     let nodes_with_names = all_nodes.filter_map(|(node, file)| {
+        let has_when_header = node
+            .header_all()
+            .iter()
+            .any(|header| header.header_key.as_ref().unwrap().get_text() == "when");
         node.header_all()
             .iter()
             .find(|header| header.header_key.as_ref().unwrap().get_text() == "title")
-            .map(|title_header| {
-                let title = title_header
-                    .header_value
-                    .as_ref()
-                    .unwrap()
-                    .get_text()
-                    .to_owned();
-                (title, title_header.clone(), file)
+            .and_then(|title_header| {
+                // A title without a value was already reported as a syntax error
+                let title = title_header.header_value.as_ref()?.get_text().to_owned();
+                Some((
+                    title,
+                    title_header.clone(),
+                    file,
+                    has_when_header,
+                    node.get_text(),
+                ))
             })
     });
 
+    // Keep the order in which the names first appear, so that node group members are numbered deterministically
+    let mut names = Vec::new();
     let nodes_by_name = nodes_with_names.fold(
         HashMap::default(),
-        |mut map: HashMap<_, Vec<_>>, (name, header_context, file)| {
-            map.entry(name).or_default().push((header_context, file));
+        |mut map: HashMap<_, Vec<_>>, (name, header_context, file, has_when_header, text)| {
+            if !map.contains_key(&name) {
+                names.push(name.clone());
+            }
+            map.entry(name)
+                .or_default()
+                .push((header_context, file, has_when_header, text));
             map
         },
     );
 
-    // Find groups of nodes with the same name and generate diagnostics
-    // for each
-    for (name, nodes) in nodes_by_name
-        .into_iter()
-        .filter(|(_, nodes)| nodes.len() > 1)
-    {
-        // More than one node has this name! Report an error on both.
-        for (header_context, file) in nodes {
-            state.diagnostics.push(
-                Diagnostic::from_message(format!("More than one node is named {name}",))
+    for name in names {
+        let nodes = &nodes_by_name[&name];
+        if nodes
+            .iter()
+            .any(|(_, _, has_when_header, _)| *has_when_header)
+        {
+            // Nodes with `when` headers form a node group, so they may share a name,
+            // but only if every one of them has a condition.
+            let mut all_nodes_have_conditions = true;
+            for (header_context, file, _, _) in nodes.iter().filter(|(_, _, has_when, _)| !has_when)
+            {
+                all_nodes_have_conditions = false;
+                state.diagnostics.push(
+                    Diagnostic::new(
+                        DiagnosticCode::MissingWhenHeader,
+                        format!("All nodes in the group {name} must have a 'when' header"),
+                    )
+                    .with_file_name(file.name.clone())
+                    .with_parser_context(header_context.as_ref(), file.tokens()),
+                );
+            }
+            if all_nodes_have_conditions {
+                let mut member_names = HashSet::new();
+                for (header_context, file, _, text) in nodes {
+                    let key = (file.name.clone(), header_context.start().get_token_index());
+                    let base_name = get_node_group_member_name(&name, text);
+                    // Only members with the exact same contents need to be told apart by their order
+                    let mut member_name = base_name.clone();
+                    let mut count = 1;
+                    while !member_names.insert(member_name.clone()) {
+                        member_name = format!("{base_name}.{count}");
+                        count += 1;
+                    }
+                    state.node_group_members.insert(key, member_name);
+                }
+            }
+        } else if nodes.len() > 1 {
+            // More than one node has this name! Report an error on both.
+            for (header_context, file, _, _) in nodes {
+                state.diagnostics.push(
+                    Diagnostic::new(
+                        DiagnosticCode::DuplicateNodeName,
+                        format!("More than one node is named {name}",),
+                    )
                     .with_file_name(file.name.clone())
                     .with_parser_context(header_context.as_ref(), file.tokens()),
-            );
+                );
+            }
         }
     }
     state
@@ -2,11 +2,17 @@
 //! and <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/CompilationJob.cs>
 
 use crate::prelude::*;
+use std::collections::HashMap;
 use std::path::Path;
 use yarnspinner_core::prelude::*;
+use yarnspinner_core::types::Type;
+
+pub use incremental_compilation::IncrementalCompiler;
 
 mod add_tags_to_lines;
 pub(crate) mod antlr_rust_ext;
+pub(crate) mod incremental_compilation;
+mod partial_compilation;
 pub(crate) mod run_compilation;
 pub(crate) mod utils;
 
@@ -43,6 +49,16 @@ pub struct Compiler {
 
     /// The declarations for variables.
     pub variable_declarations: Vec<Declaration>,
+
+    /// The declarations for the commands that the Yarn files may run. See [`Compiler::declare_command`].
+    pub command_declarations: Vec<CommandDeclaration>,
+
+    /// Changes how warnings with the given codes are reported. `None` means they are not reported.
+    /// See [`Compiler::with_diagnostic_severity`].
+    pub diagnostic_severities: HashMap<DiagnosticCode, Option<DiagnosticSeverity>>,
+
+    /// Whether the generated code is optimized. See [`Compiler::with_optimization`].
+    pub optimize: bool,
 }
 
 impl Compiler {
@@ -97,10 +113,61 @@ impl Compiler {
         self
     }
 
+    /// Declares a command that the Yarn files may run, e.g. `<<fade_in 1.5>>` for `declare_command("fade_in", [Type::Number])`.
+    /// Running a declared command with the wrong number of parameters or parameters of the wrong type is a compile error.
+    ///
+    /// Parameters declared as [`Type::String`] or [`Type::Any`] accept anything, since every parameter can be read as text.
+    pub fn declare_command(
+        &mut self,
+        name: impl Into<String>,
+        parameter_types: impl IntoIterator<Item = Type>,
+    ) -> &mut Self {
+        self.command_declarations.push(CommandDeclaration {
+            name: name.into(),
+            parameter_types: parameter_types.into_iter().collect(),
+        });
+        self
+    }
+
+    /// Changes how diagnostics with the given [`DiagnosticCode`] are reported, e.g. to treat warnings as errors in CI.
+    /// Pass `None` to not report them at all.
+    ///
+    /// Errors can only be downgraded if their code [`can_be_downgraded`](DiagnosticCode::can_be_downgraded),
+    /// e.g. [`DiagnosticCode::UnknownNode`] when the node is in a file that is compiled separately.
+    /// Other errors always stay errors, since the Yarn files can't be compiled while they have any.
+    pub fn with_diagnostic_severity(
+        &mut self,
+        code: DiagnosticCode,
+        severity: impl Into<Option<DiagnosticSeverity>>,
+    ) -> &mut Self {
+        self.diagnostic_severities.insert(code, severity.into());
+        self
+    }
+
+    /// Enables optimizing the generated code, which makes the VM run fewer instructions for the same dialogue.
+    /// Expressions made up only of constants, like `{1 + 3}`, are computed at compile time,
+    /// `if` statements with a constant condition lose the branch that is never taken,
+    /// jumps to jumps go straight to their final destination and unused labels are removed.
+    ///
+    /// The [`DebugInfo`] of the [`Compilation`] is updated to match. By default, the code is not optimized.
+    pub fn with_optimization(&mut self, optimize: bool) -> &mut Self {
+        self.optimize = optimize;
+        self
+    }
+
     /// Compiles the Yarn files previously added into a [`Compilation`].
     pub fn compile(&self) -> Result<Compilation> {
         run_compilation::compile(self)
     }
+
+    /// Compiles the Yarn files previously added, leaving out the nodes that have errors instead of failing.
+    /// This keeps the rest of a large project playable while some of it is broken, e.g. when hot reloading.
+    ///
+    /// Jumps, detours and `visited` calls that refer to excluded nodes are reported as warnings.
+    /// Returns an [`Err`] only if an error can't be attributed to any node or file.
+    pub fn compile_partial(&self) -> Result<PartialCompilation> {
+        partial_compilation::compile_partial(self)
+    }
 }
 
 /// Represents the contents of a file to compile.
@@ -124,6 +191,23 @@ pub struct File {
     pub source: String,
 }
 
+/// The signature of a command that Yarn files may run. Created by [`Compiler::declare_command`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy", reflect(Debug, PartialEq))]
+#[cfg_attr(
+    all(feature = "bevy", feature = "serde"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CommandDeclaration {
+    /// The name of the command, i.e. the first word between the `<<` and `>>`.
+    pub name: String,
+
+    /// The types of the parameters that follow the name, in order.
+    pub parameter_types: Vec<Type>,
+}
+
 /// The types of compilation that the compiler will do.
 #[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "bevy", derive(Reflect))]
@@ -33,13 +33,12 @@ impl Compiler {
         existing_line_tags: Vec<LineId>,
     ) -> crate::Result<Option<String>> {
         let contents = contents.into();
-        let chars: Vec<_> = contents.chars().map(|c| c as u32).collect();
         // First, get the parse tree for this source code.
         let file = File {
             file_name: "<input>".to_string(),
             source: contents,
         };
-        let (parse_source, diagnostics) = parse_source(&file, &chars);
+        let (parse_source, diagnostics) = parse_source(&file);
         let tree = parse_source.tree.clone();
         // Were there any error-level diagnostics?
         if diagnostics.has_errors() {
@@ -70,13 +69,10 @@ impl Compiler {
 
 /// Parses a string of Yarn source code, and produces a [`FileParseResult`]
 /// and (if there were any problems) a collection of [`Diagnostic`]s.
-fn parse_source<'a, 'b: 'a>(
-    file: &'b File,
-    chars: &'a [u32],
-) -> (FileParseResult<'a>, Vec<Diagnostic>) {
+fn parse_source<'input>(file: &File) -> (FileParseResult<'input>, Vec<Diagnostic>) {
     let mut diagnostics = Vec::new();
 
-    let result = parse_syntax_tree(file, chars, &mut diagnostics);
+    let result = parse_syntax_tree(file, &mut diagnostics);
 
     (result, diagnostics)
 }
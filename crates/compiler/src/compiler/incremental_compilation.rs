@@ -0,0 +1,406 @@
+use crate::compiler::run_compilation::compile_with_cache;
+use crate::listeners::NodeGroupCondition;
+use crate::prelude::generated::yarnspinnerlexer;
+use crate::prelude::*;
+use crate::visitors::{KnownTypes, SmartVariable};
+use crate::Result;
+use antlr_rust::int_stream::IntStream;
+use antlr_rust::token::Token;
+use antlr_rust::token_stream::TokenStream;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use yarnspinner_core::types::EnumType;
+
+/// Compiles the same Yarn files over and over again, e.g. while hot reloading them during development,
+/// and reuses what was compiled for the files that didn't change since the last compilation.
+///
+/// The parse results, string tables, declarations, types and generated code of every file are cached,
+/// keyed by a hash of the file's contents. A cached file is parsed again if it changed, or if the files before it
+/// changed in a way that affects it, e.g. by declaring a variable with the same name.
+/// Its types are checked and its code is generated again only if the declarations of the variables and functions it uses
+/// or any of the enums changed as well, or if other files start or stop using it, e.g. with `visited`.
+/// The steps that look at the project as a whole, like validating node references, still run on every compilation.
+///
+/// The result is always the same as the one of [`Compiler::compile`] for the same [`Compiler`].
+///
+/// ## Example
+///
+/// ```rust
+/// # use yarnspinner_compiler::prelude::*;
+/// let mut compiler = Compiler::new();
+/// compiler.add_file(File {
+///     file_name: "start.yarn".to_string(),
+///     source: "title: Start\n---\nHello!\n===\n".to_string(),
+/// });
+/// let mut incremental_compiler = IncrementalCompiler::new();
+/// let first = incremental_compiler.compile(&compiler).unwrap();
+///
+/// // Only the new file is parsed and compiled
+/// compiler.add_file(File {
+///     file_name: "shop.yarn".to_string(),
+///     source: "title: Shop\n---\nWelcome!\n===\n".to_string(),
+/// });
+/// let second = incremental_compiler.compile(&compiler).unwrap();
+/// assert_eq!(compiler.compile().unwrap(), second);
+/// ```
+///
+/// ## Implementation note
+///
+/// The cached parse trees are reference counted, so this type can't be sent to other threads.
+#[derive(Default)]
+pub struct IncrementalCompiler {
+    cached_files: Vec<CachedFile<'static>>,
+}
+
+impl IncrementalCompiler {
+    /// Creates a new incremental compiler that has nothing cached yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compiles the files of the given [`Compiler`], reusing what was cached by the previous call where possible.
+    /// The cache is replaced by the files of this compilation, so files that were removed since the previous call are forgotten.
+    pub fn compile(&mut self, compiler: &Compiler) -> Result<Compilation> {
+        let cached_files = std::mem::take(&mut self.cached_files);
+        let (result, cached_files) = compile_with_cache(compiler, HashSet::new(), cached_files);
+        self.cached_files = cached_files;
+        result
+    }
+
+    /// Forgets everything that was cached, so that the next compilation starts from scratch.
+    pub fn clear(&mut self) {
+        self.cached_files.clear();
+    }
+
+    /// The names of the files that are currently cached.
+    pub fn cached_file_names(&self) -> impl Iterator<Item = &str> {
+        self.cached_files
+            .iter()
+            .map(|cached_file| cached_file.parse_result.name.as_str())
+    }
+}
+
+impl Debug for IncrementalCompiler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IncrementalCompiler")
+            .field(
+                "cached_files",
+                &self.cached_file_names().collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// What is remembered about a file between two compilations of an [`IncrementalCompiler`].
+#[derive(Clone)]
+pub(crate) struct CachedFile<'input> {
+    pub(crate) content_hash: u64,
+    /// The parse tree, including the `#lastline` tags added right after parsing it.
+    pub(crate) parse_result: FileParseResult<'input>,
+    pub(crate) parse_diagnostics: Vec<Diagnostic>,
+    /// The string table entries of the file. Registering them also tags the parse tree with the implicit line IDs,
+    /// so the file needs to be parsed again if these can't be reused.
+    pub(crate) strings: Option<CachedStrings>,
+    pub(crate) declarations: Option<CachedDeclarations<'input>>,
+    pub(crate) types: Option<CachedTypes>,
+    pub(crate) code: Option<CachedCode>,
+}
+
+impl<'input> CachedFile<'input> {
+    pub(crate) fn new(
+        content_hash: u64,
+        parse_result: FileParseResult<'input>,
+        parse_diagnostics: Vec<Diagnostic>,
+    ) -> Self {
+        Self {
+            content_hash,
+            parse_result,
+            parse_diagnostics,
+            strings: None,
+            declarations: None,
+            types: None,
+            code: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct CachedStrings {
+    /// Only the entries of this file.
+    pub(crate) string_table: StringTableManager,
+    pub(crate) diagnostics: Vec<Diagnostic>,
+    /// Implicit line IDs are numbered by the size of the string table, so they depend on the entries of the files before this one.
+    pub(crate) preceding_string_count: usize,
+}
+
+impl CachedStrings {
+    /// Whether registering the strings again after the given entries of the preceding files would give the same result.
+    pub(crate) fn can_be_reused_after(&self, preceding_strings: &StringTableManager) -> bool {
+        let has_implicit_line_ids = self.string_table.contains_implicit_string_tags();
+        self.diagnostics.is_empty()
+            && (!has_implicit_line_ids || self.preceding_string_count == preceding_strings.len())
+            && self
+                .string_table
+                .keys()
+                .all(|line_id| !preceding_strings.contains_key(line_id))
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct CachedDeclarations<'input> {
+    pub(crate) new_declarations: Vec<Declaration>,
+    pub(crate) smart_variables: Vec<SmartVariable<'input>>,
+    pub(crate) file_tags: Vec<String>,
+    pub(crate) diagnostics: Vec<Diagnostic>,
+    /// The enums known while looking for declarations, which are needed to resolve the declared types.
+    pub(crate) known_enums: Vec<EnumType>,
+}
+
+impl CachedDeclarations<'_> {
+    /// Whether looking for declarations again after the given declarations of the preceding files would give the same result.
+    pub(crate) fn can_be_reused_after(
+        &self,
+        preceding_declarations: &[Declaration],
+        known_enums: &[EnumType],
+    ) -> bool {
+        // Mirrors the check for duplicate declarations in the `DeclarationVisitor`
+        let is_redeclared = |declaration: &Declaration| {
+            preceding_declarations.iter().any(|preceding| {
+                !preceding.is_implicit
+                    && preceding.name == declaration.name
+                    && !(declaration.is_local
+                        && preceding.is_local
+                        && preceding.source_node_name != declaration.source_node_name)
+            })
+        };
+        self.diagnostics.is_empty()
+            && self.known_enums == known_enums
+            && !self.new_declarations.iter().any(is_redeclared)
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct CachedTypes {
+    /// See [`type_dependency_hash`].
+    pub(crate) dependency_hash: u64,
+    pub(crate) new_declarations: Vec<Declaration>,
+    pub(crate) known_types: KnownTypes,
+    pub(crate) diagnostics: Vec<Diagnostic>,
+    pub(crate) deferred_types: Vec<DeferredTypeDiagnostic>,
+}
+
+#[derive(Clone)]
+pub(crate) struct CachedCode {
+    /// See [`code_dependency_hash`].
+    pub(crate) dependency_hash: u64,
+    /// Only the program, warnings and debug infos of this file. The rest is the same for all files.
+    pub(crate) compilation: Compilation,
+    pub(crate) node_group_conditions: Vec<NodeGroupCondition>,
+}
+
+pub(crate) fn content_hash(file: &File) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    file.file_name.hash(&mut hasher);
+    file.source.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Hashes what checking the types of a file depends on besides its contents: the declarations of the variables and
+/// functions it uses, including the ones the preceding files declared implicitly, and all enums, since a case like `.Sunny`
+/// can belong to any of them.
+pub(crate) fn type_dependency_hash(
+    file: &FileParseResult,
+    known_declarations: &[Declaration],
+    known_enums: &[EnumType],
+) -> u64 {
+    let tokens = file.tokens();
+    let used_names: HashSet<_> = (0..tokens.size())
+        .map(|index| tokens.get(index))
+        .filter(|token| {
+            [yarnspinnerlexer::VAR_ID, yarnspinnerlexer::FUNC_ID].contains(&token.get_token_type())
+        })
+        .map(|token| token.get_text().to_owned())
+        .collect();
+    let mut hasher = DefaultHasher::new();
+    for declaration in known_declarations
+        .iter()
+        .filter(|declaration| used_names.contains(&declaration.name))
+    {
+        // Declarations can't implement `Hash` because their default value may be a float
+        format!("{declaration:?}").hash(&mut hasher);
+    }
+    known_enums.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Hashes what generating the code of a file depends on besides the result of checking its types:
+/// which of its nodes are tracked, whether any file detours and the names given to its node group members and `<<once>>` blocks.
+pub(crate) fn code_dependency_hash(
+    type_dependency_hash: u64,
+    contains_detours: bool,
+    tracking_nodes: &HashSet<String>,
+    compilation: &Compilation,
+    node_group_members: &HashMap<isize, String>,
+    once_variables: &HashMap<isize, String>,
+) -> u64 {
+    // The generated nodes are the same as long as the other dependencies are
+    let mut tracked_nodes: Vec<_> = compilation
+        .program
+        .iter()
+        .flat_map(|program| program.nodes.keys())
+        .filter(|node_name| tracking_nodes.contains(*node_name))
+        .collect();
+    tracked_nodes.sort();
+
+    let mut hasher = DefaultHasher::new();
+    type_dependency_hash.hash(&mut hasher);
+    contains_detours.hash(&mut hasher);
+    tracked_nodes.hash(&mut hasher);
+    BTreeMap::from_iter(node_group_members).hash(&mut hasher);
+    BTreeMap::from_iter(once_variables).hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    fn file(file_name: &str, source: &str) -> File {
+        File {
+            file_name: file_name.to_owned(),
+            source: source.to_owned(),
+        }
+    }
+
+    fn tree_of<'a>(
+        incremental_compiler: &'a IncrementalCompiler,
+        file_name: &str,
+    ) -> &'a FileParseResult<'static> {
+        &incremental_compiler
+            .cached_files
+            .iter()
+            .find(|cached_file| cached_file.parse_result.name == file_name)
+            .unwrap()
+            .parse_result
+    }
+
+    #[test]
+    fn only_parses_changed_files_again() {
+        let mut compiler = Compiler::new();
+        compiler
+            .add_file(file("a.yarn", "title: A\n---\nHello! #line:a\n==="))
+            .add_file(file("b.yarn", "title: B\n---\nHi! #line:b\n==="));
+        let mut incremental_compiler = IncrementalCompiler::new();
+        incremental_compiler.compile(&compiler).unwrap();
+        let a = tree_of(&incremental_compiler, "a.yarn").tree.clone();
+        let b = tree_of(&incremental_compiler, "b.yarn").tree.clone();
+
+        compiler.files[0].source = "title: A\n---\nHey! #line:a\n===".to_owned();
+        incremental_compiler.compile(&compiler).unwrap();
+        assert!(!Rc::ptr_eq(
+            &a,
+            &tree_of(&incremental_compiler, "a.yarn").tree
+        ));
+        assert!(Rc::ptr_eq(
+            &b,
+            &tree_of(&incremental_compiler, "b.yarn").tree
+        ));
+    }
+
+    /// Marks the cached code of the node so that it can be told apart from newly generated code.
+    fn mark_cached_code(incremental_compiler: &mut IncrementalCompiler, node_name: &str) {
+        let program = incremental_compiler
+            .cached_files
+            .iter_mut()
+            .filter_map(|cached_file| cached_file.code.as_mut())
+            .filter_map(|code| code.compilation.program.as_mut())
+            .find(|program| program.nodes.contains_key(node_name))
+            .unwrap();
+        program
+            .nodes
+            .get_mut(node_name)
+            .unwrap()
+            .headers
+            .push(Header {
+                key: "cached".to_owned(),
+                value: "true".to_owned(),
+            });
+    }
+
+    fn is_marked(compilation: &Compilation, node_name: &str) -> bool {
+        compilation.program.as_ref().unwrap().nodes[node_name]
+            .headers
+            .iter()
+            .any(|header| header.key == "cached")
+    }
+
+    #[test]
+    fn reuses_the_code_of_unchanged_files() {
+        let mut compiler = Compiler::new();
+        compiler
+            .add_file(file(
+                "a.yarn",
+                "title: A\n---\n<<declare $gold = 1>>\nHello! #line:a\n===",
+            ))
+            .add_file(file("b.yarn", "title: B\n---\nHi {$gold}! #line:b\n==="));
+        let mut incremental_compiler = IncrementalCompiler::new();
+        incremental_compiler.compile(&compiler).unwrap();
+        mark_cached_code(&mut incremental_compiler, "A");
+        mark_cached_code(&mut incremental_compiler, "B");
+
+        compiler.files[0].source =
+            "title: A\n---\n<<declare $gold = 1>>\nHey! #line:a\n===".to_owned();
+        let compilation = incremental_compiler.compile(&compiler).unwrap();
+        assert!(!is_marked(&compilation, "A"));
+        assert!(is_marked(&compilation, "B"));
+    }
+
+    #[test]
+    fn generates_code_again_when_the_declarations_a_file_uses_change() {
+        let mut compiler = Compiler::new();
+        compiler
+            .add_file(file(
+                "a.yarn",
+                "title: A\n---\n<<declare $gold = 1>>\n<<declare $name = \"Sam\">>\n===",
+            ))
+            .add_file(file("b.yarn", "title: B\n---\nHi {$gold}! #line:b\n==="));
+        let mut incremental_compiler = IncrementalCompiler::new();
+        incremental_compiler.compile(&compiler).unwrap();
+        mark_cached_code(&mut incremental_compiler, "B");
+
+        // B doesn't use $name
+        compiler.files[0].source =
+            "title: A\n---\n<<declare $gold = 1>>\n<<declare $name = \"Kim\">>\n===".to_owned();
+        let compilation = incremental_compiler.compile(&compiler).unwrap();
+        assert!(is_marked(&compilation, "B"));
+
+        compiler.files[0].source =
+            "title: A\n---\n<<declare $gold = \"one\">>\n<<declare $name = \"Kim\">>\n==="
+                .to_owned();
+        let compilation = incremental_compiler.compile(&compiler).unwrap();
+        assert!(!is_marked(&compilation, "B"));
+        assert_eq!(compiler.compile().unwrap(), compilation);
+    }
+
+    #[test]
+    fn parses_files_with_implicit_line_ids_again_when_their_numbering_changes() {
+        let mut compiler = Compiler::new();
+        compiler
+            .add_file(file("a.yarn", "title: A\n---\nHello! #line:a\n==="))
+            .add_file(file("b.yarn", "title: B\n---\nHi!\n==="));
+        let mut incremental_compiler = IncrementalCompiler::new();
+        incremental_compiler.compile(&compiler).unwrap();
+        let b = tree_of(&incremental_compiler, "b.yarn").tree.clone();
+
+        compiler.files[0].source = "title: A\n---\nHello! #line:a\nHey! #line:a2\n===".to_owned();
+        let compilation = incremental_compiler.compile(&compiler).unwrap();
+        assert!(!Rc::ptr_eq(
+            &b,
+            &tree_of(&incremental_compiler, "b.yarn").tree
+        ));
+        assert_eq!(compiler.compile().unwrap(), compilation);
+    }
+}
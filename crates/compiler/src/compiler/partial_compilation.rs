@@ -0,0 +1,149 @@
+use crate::compiler::run_compilation::compile_excluding_nodes;
+use crate::compiler::utils::parse_syntax_tree;
+use crate::prelude::generated::yarnspinnerparser::{DialogueContextAttrs, NodeContextAttrs};
+use crate::prelude::*;
+use crate::Result;
+use antlr_rust::token::Token;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+/// How often the job is compiled at most. Leaving out nodes can cause errors in other nodes, e.g. ones that use
+/// variables declared in them, which are only found by the next attempt.
+const MAX_ATTEMPTS: usize = 8;
+
+/// Compiles the job over and over, each time removing the nodes that the previous attempt reported errors in,
+/// until it succeeds. Removed nodes are replaced by empty lines so that the positions in the rest of the file stay the same.
+pub(crate) fn compile_partial(compiler: &Compiler) -> Result<PartialCompilation> {
+    let mut compiler = compiler.clone();
+    let mut errors: Vec<Diagnostic> = Vec::new();
+    let mut excluded_nodes: Vec<String> = Vec::new();
+    for _ in 0..MAX_ATTEMPTS {
+        let excluded: HashSet<_> = excluded_nodes.iter().cloned().collect();
+        let diagnostics = match compile_excluding_nodes(&compiler, excluded) {
+            Ok(compilation) => {
+                return Ok(PartialCompilation {
+                    compilation,
+                    errors,
+                    excluded_nodes,
+                })
+            }
+            Err(CompilerError(diagnostics)) => diagnostics,
+        };
+
+        let new_errors: Vec<_> = diagnostics
+            .into_iter()
+            .filter(|diagnostic| diagnostic.severity == DiagnosticSeverity::Error)
+            .filter(|diagnostic| !errors.contains(diagnostic))
+            .collect();
+        let mut made_progress = false;
+        for file in &mut compiler.files {
+            let file_errors: Vec<_> = new_errors
+                .iter()
+                .filter(|error| error.file_name.as_ref() == Some(&file.file_name))
+                .collect();
+            if file_errors.is_empty() {
+                continue;
+            }
+            let mut lines: Vec<_> = file.source.split('\n').map(ToOwned::to_owned).collect();
+            let nodes = find_nodes(file, &lines);
+            let last_line = lines.len() - 1;
+            for error in file_errors {
+                let error_line = error.range.as_ref().map(|range| range.start.line);
+                let node = error_line.and_then(|line| {
+                    nodes
+                        .iter()
+                        .find(|node| node.lines.contains(&line.min(last_line)))
+                });
+                let (excluded, excluded_lines) = match (node, error_line) {
+                    (Some(node), _) => (vec![node], node.own_lines()),
+                    // Not part of a node, e.g. a file tag, so only the line itself is left out
+                    (None, Some(line)) => (vec![], line..=line),
+                    // Only the file as a whole can be blamed
+                    (None, None) => (nodes.iter().collect(), 0..=last_line),
+                };
+                for title in excluded.into_iter().filter_map(|node| node.title.clone()) {
+                    if !excluded_nodes.contains(&title) {
+                        excluded_nodes.push(title);
+                    }
+                }
+                let excluded_lines =
+                    *excluded_lines.start()..=(*excluded_lines.end()).min(last_line);
+                for line in lines.get_mut(excluded_lines).into_iter().flatten() {
+                    made_progress |= !line.trim().is_empty();
+                    line.clear();
+                }
+            }
+            file.source = lines.join("\n");
+        }
+        // Files that only consist of comments can't be parsed, so leave out the ones that have no nodes left
+        compiler.files.retain(|file| has_content(&file.source));
+        errors.extend(new_errors);
+        if !made_progress {
+            // Nothing left that could be removed to get rid of the errors
+            break;
+        }
+    }
+    Err(CompilerError(errors))
+}
+
+/// A node as the parser sees it, which also holds up when the node has syntax errors.
+struct NodeLines {
+    title: Option<String>,
+    /// From the first header to the `===`. Errors inside these lines belong to the node.
+    lines: RangeInclusive<usize>,
+    /// The first `===` in the node's lines. The parser reads past it when e.g. an expression is not closed,
+    /// in which case the nodes that follow are only part of this one because of its errors.
+    first_body_end: Option<usize>,
+}
+
+impl NodeLines {
+    /// The lines that make up the node itself, leaving out the ones it only took from other nodes.
+    fn own_lines(&self) -> RangeInclusive<usize> {
+        let end = self.first_body_end.unwrap_or(*self.lines.end());
+        *self.lines.start()..=end
+    }
+}
+
+fn find_nodes(file: &File, lines: &[String]) -> Vec<NodeLines> {
+    // The errors were already reported by the compilation
+    let parse_result = parse_syntax_tree(file, &mut Vec::new());
+    parse_result
+        .tree
+        .node_all()
+        .iter()
+        .map(|node| {
+            let title = node
+                .header_all()
+                .iter()
+                .filter(|header| {
+                    header
+                        .header_key
+                        .as_ref()
+                        .is_some_and(|key| key.get_text() == "title")
+                })
+                .find_map(|header| {
+                    let value = header.header_value.as_ref()?;
+                    Some(value.get_text().trim().to_owned())
+                })
+                .filter(|title| !title.is_empty());
+            let range = node.range();
+            // Nodes that the parser recovered from an error can end before they start
+            let lines_of_node = range.start.line..=range.end.line.max(range.start.line);
+            let first_body_end = lines_of_node
+                .clone()
+                .find(|&line| lines.get(line).is_some_and(|line| line.trim() == "==="));
+            NodeLines {
+                title,
+                lines: lines_of_node,
+                first_body_end,
+            }
+        })
+        .collect()
+}
+
+fn has_content(source: &str) -> bool {
+    source.lines().any(|line| {
+        let text = line.trim();
+        !text.is_empty() && !text.starts_with("//")
+    })
+}
@@ -1,90 +1,136 @@
 use crate::compilation_steps::*;
+use crate::listeners::NodeGroupCondition;
 use crate::output::*;
 use crate::prelude::*;
 use crate::string_table_manager::StringTableManager;
 use crate::visitors::*;
 use crate::Result;
 use std::collections::{HashMap, HashSet};
+use yarnspinner_core::types::EnumType;
 
 /// Compile Yarn code, as specified by a compilation job.
 pub(crate) fn compile(compiler: &Compiler) -> Result<Compilation> {
+    compile_excluding_nodes(compiler, HashSet::new())
+}
+
+/// Compile Yarn code, as specified by a compilation job, knowing that the given nodes were removed from it.
+pub(crate) fn compile_excluding_nodes(
+    compiler: &Compiler,
+    excluded_nodes: HashSet<String>,
+) -> Result<Compilation> {
+    let (result, _cached_files) = compile_with_cache(compiler, excluded_nodes, Vec::new());
+    result
+}
+
+/// Compile Yarn code, as specified by a compilation job, reusing what is still valid of the files cached by a previous compilation.
+/// Returns the files to cache for the next compilation alongside the result.
+pub(crate) fn compile_with_cache<'input>(
+    compiler: &Compiler,
+    excluded_nodes: HashSet<String>,
+    cached_files: Vec<CachedFile<'input>>,
+) -> (Result<Compilation>, Vec<CachedFile<'input>>) {
     let compiler_steps: Vec<&CompilationStep> = vec![
         &register_initial_variables,
         &parse_files,
         &register_strings,
         &validate_unique_node_names,
         &break_on_job_with_only_strings,
+        &validate_node_references,
+        &get_enum_declarations,
         &get_declarations,
+        &resolve_smart_variable_types,
         &check_types,
+        &check_commands,
         &find_tracking_nodes,
         &create_declarations_for_tracking_nodes,
         &add_tracking_declarations,
         &resolve_deferred_type_diagnostic,
         &break_on_job_with_only_declarations,
         &generate_code,
+        &generate_node_groups,
+        &optimize_code,
         &add_initial_value_registrations,
     ];
 
-    let chars: Vec<Vec<u32>> = compiler
-        .files
-        .iter()
-        .map(|file| {
-            // Strip the BOM from the source string if it is present before compiling.
-            // Rust does not do this by default
-            // https://github.com/rust-lang/rfcs/issues/2428
-            let source = match file.source.strip_prefix('\u{feff}') {
-                None => file.source.as_str(),
-                Some(sanitized_string) => sanitized_string,
-            };
-            source.chars().map(|c| c as u32).collect()
-        })
-        .collect();
-    let chars: Vec<_> = chars.iter().map(|c| c.as_slice()).collect();
-    let initial = CompilationIntermediate::from_job(compiler, chars);
+    let mut initial = CompilationIntermediate::from_job(compiler.clone());
+    initial.excluded_nodes = excluded_nodes;
+    initial.previously_cached_files = cached_files;
     let intermediate = compiler_steps.into_iter().fold(initial, |state, step| {
         if state.early_break {
             state
         } else {
-            step(state)
+            let mut state = step(state);
+            // Later steps decide whether there are errors, so they need to see the overridden severities
+            state.diagnostics =
+                override_severities(&state.job, std::mem::take(&mut state.diagnostics));
+            state
         }
     });
     // Cleaning up diagnostics doesn't change the state but makes sure
     // that diagnostics are unique, there are no errors in the warnings, etc.
     // So we execute it even if we've had early breaks.
-    let result = clean_up_diagnostics(intermediate).result.unwrap();
-    result
+    let intermediate = clean_up_diagnostics(intermediate);
+    (intermediate.result.unwrap(), intermediate.cached_files)
 }
 
 type CompilationStep = dyn Fn(CompilationIntermediate) -> CompilationIntermediate;
 
 pub(crate) struct CompilationIntermediate<'input> {
-    pub(crate) job: &'input Compiler,
-    pub(crate) file_chars: Vec<&'input [u32]>,
+    /// Owned instead of borrowed so that the parse trees don't have to live shorter than the job, which allows caching them.
+    pub(crate) job: Compiler,
     pub(crate) result: Option<Result<Compilation>>,
     /// All variable declarations that we've encountered, PLUS the ones we knew about before
     pub(crate) known_variable_declarations: Vec<Declaration>,
     /// All variable declarations that we've encountered during this compilation job
     pub(crate) derived_variable_declarations: Vec<Declaration>,
+    /// All enums declared in the files of this compilation job
+    pub(crate) known_enums: Vec<EnumType>,
+    /// The smart variables declared in the files of this compilation job, in the order they were written in
+    pub(crate) smart_variables: Vec<SmartVariable<'input>>,
     pub(crate) potential_issues: Vec<DeferredTypeDiagnostic>,
     pub(crate) parsed_files: Vec<(FileParseResult<'input>, KnownTypes)>,
     pub(crate) tracking_nodes: HashSet<String>,
+    /// Whether any file detours into a node. Only then do nodes need to end with a `Return` instead of a `Stop`.
+    pub(crate) contains_detours: bool,
+    /// The files cached by a previous compilation of an [`IncrementalCompiler`], which are used instead of parsing them again
+    /// if their content didn't change.
+    pub(crate) previously_cached_files: Vec<CachedFile<'input>>,
+    /// What can be cached of the parsed files, in the same order as `parsed_files`.
+    pub(crate) cached_files: Vec<CachedFile<'input>>,
+    /// The nodes that were removed from the files of a partial compilation because they had errors.
+    pub(crate) excluded_nodes: HashSet<String>,
+    /// The variables that remember whether `<<once>>` blocks and once-only options have run,
+    /// keyed by their file name and the token index at which the block or the option's condition starts.
+    pub(crate) once_variables: HashMap<(String, isize), String>,
+    /// The unique names of the members of node groups, keyed by their file name and the token index of their `title` header.
+    pub(crate) node_group_members: HashMap<(String, isize), String>,
+    /// The conditions of all node group members, in the order they were written in.
+    pub(crate) node_group_conditions: Vec<NodeGroupCondition>,
     pub(crate) string_table: StringTableManager,
     pub(crate) diagnostics: Vec<Diagnostic>,
     pub(crate) file_tags: HashMap<String, Vec<String>>,
     pub(crate) early_break: bool,
 }
 
-impl<'input> CompilationIntermediate<'input> {
-    pub(crate) fn from_job(compiler: &'input Compiler, chars: Vec<&'input [u32]>) -> Self {
+impl CompilationIntermediate<'_> {
+    pub(crate) fn from_job(compiler: Compiler) -> Self {
         Self {
             job: compiler,
-            file_chars: chars,
             result: Default::default(),
             known_variable_declarations: Default::default(),
             derived_variable_declarations: Default::default(),
+            known_enums: Default::default(),
+            smart_variables: Default::default(),
             potential_issues: Default::default(),
             parsed_files: Default::default(),
             tracking_nodes: Default::default(),
+            contains_detours: Default::default(),
+            previously_cached_files: Default::default(),
+            cached_files: Default::default(),
+            excluded_nodes: Default::default(),
+            once_variables: Default::default(),
+            node_group_members: Default::default(),
+            node_group_conditions: Default::default(),
             string_table: Default::default(),
             diagnostics: Default::default(),
             file_tags: Default::default(),
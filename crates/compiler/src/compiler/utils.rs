@@ -7,8 +7,9 @@ use crate::prelude::generated::yarnspinnerparser::*;
 use crate::prelude::generated::{yarnspinnerlexer, yarnspinnerparser};
 use crate::prelude::*;
 use antlr_rust::common_token_stream::CommonTokenStream;
-use antlr_rust::input_stream::CodePoint32BitCharStream;
+use antlr_rust::int_stream::IntStream;
 use antlr_rust::token::{Token, TOKEN_DEFAULT_CHANNEL};
+use antlr_rust::token_stream::TokenStream;
 use antlr_rust::Parser;
 use std::collections::HashSet;
 use std::rc::Rc;
@@ -20,24 +21,42 @@ pub(crate) fn get_line_id_tag<'a>(
 ) -> Option<Rc<HashtagContextAll<'a>>> {
     hashtag_contexts
         .iter()
+        // Hashtags that the parser recovered from an error may have no text
         .find(|hashtag| {
-            let hashtag_text = hashtag
+            hashtag
                 .text
                 .as_ref()
-                .expect("Hashtag held no text")
-                .get_text();
-            hashtag_text.starts_with("line:")
+                .is_some_and(|text| text.get_text().starts_with("line:"))
         })
         .cloned()
 }
 
-pub(crate) fn parse_syntax_tree<'a, 'b: 'a>(
-    file: &'b File,
-    file_chars: &'a [u32],
+/// `CommonTokenStream` assumes that the first token is on the default channel, so blank lines and comments at the
+/// start of a file would otherwise reach the parser.
+fn skip_leading_hidden_tokens(tokens: &mut ActualTokenStream) {
+    let first_is_hidden = tokens
+        .lt(1)
+        .is_some_and(|token| token.get_channel() != TOKEN_DEFAULT_CHANNEL);
+    if first_is_hidden {
+        // Looking past the first token skips to the next one on the default channel
+        if let Some(index) = tokens.lt(2).map(|token| token.get_token_index()) {
+            tokens.seek(index);
+        }
+    }
+}
+
+pub(crate) fn parse_syntax_tree<'input>(
+    file: &File,
     diagnostics: &mut Vec<Diagnostic>,
-) -> FileParseResult<'a> {
-    // Using 32 bit codepoints because that's how big a Rust `char` is: 4 bytes.
-    let input = CodePoint32BitCharStream::new(file_chars);
+) -> FileParseResult<'input> {
+    // Strip the BOM from the source string if it is present before compiling.
+    // Rust does not do this by default
+    // https://github.com/rust-lang/rfcs/issues/2428
+    let source = match file.source.strip_prefix('\u{feff}') {
+        None => file.source.as_str(),
+        Some(sanitized_string) => sanitized_string,
+    };
+    let input = OwnedInputStream::new(source);
     let mut lexer = YarnSpinnerLexer::new(input, file.file_name.clone());
 
     // turning off the normal error listener and using ours
@@ -48,7 +67,8 @@ pub(crate) fn parse_syntax_tree<'a, 'b: 'a>(
     lexer.remove_error_listeners();
     lexer.add_error_listener(Box::new(lexer_error_listener));
 
-    let tokens = CommonTokenStream::new(lexer);
+    let mut tokens = CommonTokenStream::new(lexer);
+    skip_leading_hidden_tokens(&mut tokens);
     let mut parser = YarnSpinnerParser::with_strategy(tokens, ErrorStrategy::new());
     let parser_error_listener = ParserErrorListener::new(file.clone());
     let parser_error_listener_diagnostics = parser_error_listener.diagnostics.clone();
@@ -77,6 +97,21 @@ pub(crate) fn get_line_id_for_node_name(name: &str) -> LineId {
     format!("line:{name}").into()
 }
 
+/// Node group members share their title, so each of them gets a unique name based on its contents.
+/// Unlike its position in the group, this does not change when other members are added, removed or reordered.
+pub(crate) fn get_node_group_member_name(group_name: &str, node_text: &str) -> String {
+    format!("{group_name}.{}", stable_hash(node_text))
+}
+
+/// Hashes text with 64 bit FNV-1a, which, unlike [`std::hash::Hash`] implementations,
+/// gives the same result on every platform and with every Rust version.
+pub(crate) fn stable_hash(text: &str) -> String {
+    let hash = text.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
 /// Gets the text of the documentation comments that either immediately
 /// precede `context`, or are on the same line as `context`.
 ///
@@ -198,8 +233,11 @@ where
 /// because Rust's type system already guarantees at compile-time that all registered
 /// functions are valid and compatible with Yarn.
 pub(crate) fn get_declarations_from_library(library: &Library) -> Vec<Declaration> {
+    // All enums share the same operators, so any enum will do here
+    let enum_type = Type::Enum(Default::default());
     let operators: HashSet<_> = Type::EXPLICITLY_CONSTRUCTABLE
         .iter()
+        .chain(std::iter::once(&enum_type))
         .flat_map(|r#type| {
             r#type
                 .methods()
@@ -244,28 +282,26 @@ mod tests {
 ==="
             .to_owned(),
         };
-        let chars: Vec<_> = mixed_indentation_input
-            .source
-            .chars()
-            .map(|c| c as u32)
-            .collect();
-        let _parsed_file = parse_syntax_tree(&mixed_indentation_input, &chars, &mut diagnostics);
+        let _parsed_file = parse_syntax_tree(&mixed_indentation_input, &mut diagnostics);
         assert_eq!(1, diagnostics.len());
         assert_eq!(
-            Diagnostic::from_message("Indentation contains tabs and spaces")
-                .with_context("\t   ")
-                .with_start_line(3)
-                .with_file_name("test.yarn")
-                .with_range(
-                    Position {
-                        line: 3,
-                        character: 0
-                    }..Position {
-                        line: 3,
-                        character: 5
-                    }
-                )
-                .with_severity(DiagnosticSeverity::Warning),
+            Diagnostic::new(
+                DiagnosticCode::MixedIndentation,
+                "Indentation contains tabs and spaces"
+            )
+            .with_context("\t   ")
+            .with_start_line(3)
+            .with_file_name("test.yarn")
+            .with_range(
+                Position {
+                    line: 3,
+                    character: 0
+                }..Position {
+                    line: 3,
+                    character: 5
+                }
+            )
+            .with_severity(DiagnosticSeverity::Warning),
             diagnostics[0]
         );
     }
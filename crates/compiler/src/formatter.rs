@@ -0,0 +1,794 @@
+//! Reprints Yarn files in a canonical form, see [`format`].
+
+use crate::parser::generated::yarnspinnerlexer::*;
+use crate::parser::{OwnedInputStream, YarnSpinnerLexer};
+use crate::prelude::*;
+use antlr_rust::token::{Token, TOKEN_EOF};
+use antlr_rust::TokenSource;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter as FmtFormatter};
+
+/// One level of indentation.
+const INDENTATION: &str = "    ";
+
+/// Reprints a Yarn file in a canonical form, so that files written in different editors don't differ in whitespace.
+///
+/// - Statements are indented by four spaces for every option, line group, `<<if>>`, `<<once>>` and `<<enum>>` they are nested in.
+/// - Commands and expressions are spaced like `<<set $gold to $gold - 1>>` and `{$gold + 1}`.
+///   The parameters of custom commands are separated by single spaces, like `<<wait 2>>`, but quoted text in them is kept as is.
+///   The text of lines is kept as is, apart from the whitespace around it.
+/// - Headers are written as `key: value`.
+/// - Hashtags are separated by single spaces and the `#line:` tag comes last.
+/// - Trailing whitespace is removed, multiple blank lines are collapsed into one and nodes are separated by a blank line.
+///
+/// Comments are kept, and the blank lines in node bodies are kept as well, since they end groups of options.
+/// Formatting a formatted file again does not change it.
+///
+/// The result always means the same as the original.
+///
+/// ## Errors
+///
+/// Returns a [`FormatError`] if the file has syntax errors or if formatting would change anything
+/// but whitespace and the order of hashtags.
+///
+/// ## Example
+///
+/// ```rust
+/// # use yarnspinner_compiler::prelude::*;
+/// let file = File {
+///     file_name: "start.yarn".to_string(),
+///     source: "title:Start\n---\n<<if $gold>1>>\n-> Buy  #line:buy   #shop\n<<endif>>\n===".to_string(),
+/// };
+/// assert_eq!(
+///     "title: Start\n---\n<<if $gold > 1>>\n    -> Buy #shop #line:buy\n<<endif>>\n===\n",
+///     yarnspinner_compiler::format(&file)?
+/// );
+/// # Ok::<(), yarnspinner_compiler::FormatError>(())
+/// ```
+pub fn format(file: &File) -> std::result::Result<String, FormatError> {
+    let source = file.source.strip_prefix('\u{feff}').unwrap_or(&file.source);
+    // Files without any nodes can't be parsed
+    let has_content = source.lines().any(|line| {
+        let text = line.trim();
+        !text.is_empty() && !text.starts_with("//")
+    });
+    if !has_content {
+        return Ok(file.source.clone());
+    }
+    let mut diagnostics = Vec::new();
+    parse_syntax_tree(file, &mut diagnostics);
+    if diagnostics.has_errors() {
+        return Err(FormatError::InvalidSyntax(CompilerError(diagnostics)));
+    }
+
+    let tokens = lex(source);
+    let formatted = Formatter::default().format(&tokens);
+    // Guards against constructs that the formatter doesn't know about
+    if normalize(&tokens) == normalize(&lex(&formatted)) {
+        Ok(formatted)
+    } else {
+        Err(FormatError::WouldChangeMeaning)
+    }
+}
+
+/// The reason why [`format`] could not format a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatError {
+    /// The file has syntax errors.
+    InvalidSyntax(CompilerError),
+    /// Formatting would change more than whitespace and the order of hashtags,
+    /// i.e. the file uses a construct that the formatter doesn't handle correctly.
+    WouldChangeMeaning,
+}
+
+impl Error for FormatError {}
+
+impl Display for FormatError {
+    fn fmt(&self, f: &mut FmtFormatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax(error) => write!(f, "The file has syntax errors:\n{error}"),
+            Self::WouldChangeMeaning => write!(
+                f,
+                "Formatting the file would change its meaning, so it was left unchanged"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FormatToken {
+    token_type: isize,
+    text: String,
+}
+
+impl FormatToken {
+    fn trimmed_text(&self) -> &str {
+        self.text.trim()
+    }
+}
+
+/// All tokens of the source, including the hidden ones and the indents and dedents inserted by the [`YarnSpinnerLexer`].
+fn lex(source: &str) -> Vec<FormatToken> {
+    let mut lexer = YarnSpinnerLexer::new(OwnedInputStream::new(source), String::new());
+    lexer.remove_error_listeners();
+    let mut tokens = Vec::new();
+    loop {
+        let token = lexer.next_token();
+        if token.token_type == TOKEN_EOF {
+            return tokens;
+        }
+        tokens.push(FormatToken {
+            token_type: token.token_type,
+            text: token.get_text().to_owned(),
+        });
+    }
+}
+
+fn is_whitespace(token_type: isize) -> bool {
+    matches!(
+        token_type,
+        WS | BODY_WS | EXPR_WS | COMMAND_WS | HASHTAG_WS | TEXT_COMMANDHASHTAG_WS
+    )
+}
+
+fn is_comment(token_type: isize) -> bool {
+    matches!(
+        token_type,
+        COMMENT | TEXT_COMMENT | TEXT_COMMANDHASHTAG_COMMENT
+    )
+}
+
+fn is_text(token_type: isize) -> bool {
+    matches!(
+        token_type,
+        TEXT | TEXT_ESCAPE | ESCAPED_ANY | UNESCAPABLE_CHARACTER
+    )
+}
+
+/// A line of the source.
+#[derive(Debug, Default)]
+struct Line<'a> {
+    /// The whitespace at the start of the line.
+    indentation: String,
+    /// The indents and dedents that come before the line.
+    indentation_changes: Vec<isize>,
+    /// The tokens of the line, without whitespace.
+    tokens: Vec<&'a FormatToken>,
+}
+
+fn split_lines(tokens: &[FormatToken]) -> Vec<Line<'_>> {
+    let mut lines = vec![Line::default()];
+    for token in tokens {
+        match token.token_type {
+            NEWLINE => {
+                for _ in 1..token.text.matches('\n').count() {
+                    lines.push(Line::default());
+                }
+                lines.push(Line {
+                    indentation: token.text.trim_start_matches(['\r', '\n']).to_owned(),
+                    ..Default::default()
+                });
+            }
+            INDENT | DEDENT => lines
+                .last_mut()
+                .unwrap()
+                .indentation_changes
+                .push(token.token_type),
+            BLANK_LINE_FOLLOWING_OPTION => {}
+            token_type if is_whitespace(token_type) => {}
+            _ => lines.last_mut().unwrap().tokens.push(token),
+        }
+    }
+    lines
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    /// File hashtags, comments and blank lines outside of nodes.
+    #[default]
+    BetweenNodes,
+    Header,
+    Body,
+}
+
+#[derive(Debug, Default)]
+struct Formatter {
+    output: Vec<String>,
+    section: Section,
+    /// The number of options and line groups the current line is nested in.
+    indent_depth: usize,
+    /// The number of `<<if>>`, `<<once>>` and `<<enum>>` blocks the current line is nested in.
+    block_depth: usize,
+}
+
+impl Formatter {
+    fn format(mut self, tokens: &[FormatToken]) -> String {
+        for line in split_lines(tokens) {
+            for change in &line.indentation_changes {
+                if *change == INDENT {
+                    self.indent_depth += 1;
+                } else {
+                    self.indent_depth = self.indent_depth.saturating_sub(1);
+                }
+            }
+            if line.tokens.is_empty() {
+                self.format_blank_line(&line);
+                continue;
+            }
+            match self.section {
+                Section::Body => self.format_body_line(&line.tokens),
+                Section::BetweenNodes | Section::Header => self.format_header_line(&line.tokens),
+            }
+        }
+        while self.output.last().is_some_and(|line| line.is_empty()) {
+            self.output.pop();
+        }
+        let mut formatted = self.output.join("\n");
+        formatted.push('\n');
+        formatted
+    }
+
+    fn format_blank_line(&mut self, line: &Line) {
+        if self.output.last().is_none_or(|line| line.trim().is_empty()) {
+            return;
+        }
+        if self.section == Section::Body && self.indent_depth > 0 && !line.indentation.is_empty() {
+            // Whitespace on a blank line keeps an option's body going
+            self.output.push(INDENTATION.repeat(self.depth()));
+        } else {
+            self.output.push(String::new());
+        }
+    }
+
+    fn format_header_line(&mut self, tokens: &[&FormatToken]) {
+        if self.output.last().is_some_and(|line| line == "===") {
+            self.output.push(String::new());
+        }
+        let first = tokens[0];
+        let line = match first.token_type {
+            BODY_START => {
+                self.section = Section::Body;
+                join_tokens(tokens)
+            }
+            ID => {
+                self.section = Section::Header;
+                let (value, comment) = split_comment(&tokens[1..]);
+                // Skip the `:`
+                let value = value.get(1..).unwrap_or_default();
+                let value = match value {
+                    [] => String::new(),
+                    [value] if value.token_type == REST_OF_LINE => value.trimmed_text().to_owned(),
+                    expression => format_expression(expression),
+                };
+                let header = if value.is_empty() {
+                    format!("{}:", first.trimmed_text())
+                } else {
+                    format!("{}: {}", first.trimmed_text(), value)
+                };
+                join_parts([header].into_iter().chain(comment))
+            }
+            // File hashtags
+            HASHTAG | HASHTAG_TEXT => {
+                let (tags, comment) = split_comment(tokens);
+                let tags = format_hashtags(tags);
+                join_parts(tags.into_iter().chain(comment))
+            }
+            _ => join_tokens(tokens),
+        };
+        self.output.push(line);
+    }
+
+    fn format_body_line(&mut self, tokens: &[&FormatToken]) {
+        if tokens[0].token_type == BODY_END {
+            self.section = Section::BetweenNodes;
+            self.indent_depth = 0;
+            self.block_depth = 0;
+            while self
+                .output
+                .last()
+                .is_some_and(|line| line.trim().is_empty())
+            {
+                self.output.pop();
+            }
+            self.output.push(join_tokens(tokens));
+            return;
+        }
+
+        let command = (tokens[0].token_type == COMMAND_START)
+            .then(|| tokens.get(1).map(|token| token.token_type))
+            .flatten();
+        let depth = match command {
+            Some(COMMAND_IF | COMMAND_ONCE | COMMAND_ENUM) => {
+                self.block_depth += 1;
+                self.depth() - 1
+            }
+            Some(COMMAND_ELSEIF | COMMAND_ELSE) => self.depth().saturating_sub(1),
+            Some(COMMAND_ENDIF | COMMAND_ENDONCE | COMMAND_ENDENUM) => {
+                self.block_depth = self.block_depth.saturating_sub(1);
+                self.depth()
+            }
+            _ => self.depth(),
+        };
+        let line = format_statement(tokens);
+        self.output.push(INDENTATION.repeat(depth) + &line);
+    }
+
+    fn depth(&self) -> usize {
+        self.indent_depth + self.block_depth
+    }
+}
+
+/// Formats a line of a node's body, e.g. a line of dialogue, an option or a command.
+fn format_statement(tokens: &[&FormatToken]) -> String {
+    let (tokens, comment) = split_comment(tokens);
+    let mut arrow = None;
+    let mut text = String::new();
+    let mut commands = Vec::new();
+    let mut hashtags = Vec::new();
+    let mut index = 0;
+    while index < tokens.len() {
+        let token = tokens[index];
+        match token.token_type {
+            SHORTCUT_ARROW | LINE_GROUP_ARROW => arrow = Some(token.trimmed_text().to_owned()),
+            COMMAND_START => {
+                let end = find_from(tokens, index, &[COMMAND_END, COMMAND_TEXT_END]);
+                commands.push(format_command(&tokens[index..=end]));
+                index = end;
+            }
+            EXPRESSION_START => {
+                let end = find_from(tokens, index, &[EXPRESSION_END]);
+                text += &format_inline_expression(&tokens[index..=end]);
+                index = end;
+            }
+            HASHTAG | HASHTAG_TEXT => hashtags.push(token),
+            _ => text += &token.text,
+        }
+        index += 1;
+    }
+    let text = text.trim().to_owned();
+    join_parts(
+        arrow
+            .into_iter()
+            .chain([text])
+            .chain(commands)
+            .chain(format_hashtags(&hashtags))
+            .chain(comment),
+    )
+}
+
+/// Formats a command including its `<<` and `>>`.
+fn format_command(tokens: &[&FormatToken]) -> String {
+    let inner = &tokens[1..tokens.len() - 1];
+    let is_custom_command = inner
+        .first()
+        .is_some_and(|token| matches!(token.token_type, COMMAND_TEXT | COMMAND_EXPRESSION_START));
+    if !is_custom_command {
+        return format!("<<{}>>", format_expression(inner));
+    }
+    // Custom commands are split into their parameters at whitespace outside of quotes, so that is all that may change
+    let mut text = String::new();
+    let mut in_quotes = false;
+    let mut index = 0;
+    while index < inner.len() {
+        if inner[index].token_type == COMMAND_EXPRESSION_START {
+            let end = find_from(inner, index, &[EXPRESSION_END]);
+            text += &format_inline_expression(&inner[index..=end]);
+            index = end;
+        } else {
+            push_collapsing_whitespace(&mut text, &inner[index].text, &mut in_quotes);
+        }
+        index += 1;
+    }
+    format!("<<{}>>", text.trim())
+}
+
+/// Appends the text of a custom command, replacing every run of whitespace outside of quotes with a single space.
+/// `in_quotes` carries over from the previous text of the same command.
+fn push_collapsing_whitespace(output: &mut String, text: &str, in_quotes: &mut bool) {
+    let mut chars = text.chars();
+    while let Some(char) = chars.next() {
+        if *in_quotes {
+            output.push(char);
+            match char {
+                // Escaped quotes don't end the quoted text
+                '\\' => output.extend(chars.next()),
+                '"' => *in_quotes = false,
+                _ => {}
+            }
+        } else if char.is_whitespace() {
+            if !output.ends_with(' ') {
+                output.push(' ');
+            }
+        } else {
+            *in_quotes = char == '"';
+            output.push(char);
+        }
+    }
+}
+
+/// Formats an expression including its `{` and `}`.
+fn format_inline_expression(tokens: &[&FormatToken]) -> String {
+    format!("{{{}}}", format_expression(&tokens[1..tokens.len() - 1]))
+}
+
+/// Formats the tokens of an expression, or of a command without its `<<` and `>>`.
+fn format_expression(tokens: &[&FormatToken]) -> String {
+    let mut formatted = String::new();
+    let mut previous: Option<(&FormatToken, bool)> = None;
+    for &token in tokens {
+        let is_unary = match token.token_type {
+            OPERATOR_LOGICAL_NOT => true,
+            OPERATOR_MATHS_SUBTRACTION => {
+                !previous.is_some_and(|(previous, _)| ends_value(previous.token_type))
+            }
+            _ => false,
+        };
+        if let Some((previous, previous_is_unary)) = previous {
+            if needs_space_between(previous, previous_is_unary, token) {
+                formatted.push(' ');
+            }
+        }
+        formatted += token.trimmed_text();
+        previous = Some((token, is_unary));
+    }
+    formatted
+}
+
+fn ends_value(token_type: isize) -> bool {
+    matches!(
+        token_type,
+        RPAREN
+            | NUMBER
+            | STRING
+            | VAR_ID
+            | FUNC_ID
+            | ID
+            | KEYWORD_TRUE
+            | KEYWORD_FALSE
+            | KEYWORD_NULL
+            | EXPRESSION_END
+    )
+}
+
+fn needs_space_between(
+    previous: &FormatToken,
+    previous_is_unary: bool,
+    next: &FormatToken,
+) -> bool {
+    if previous_is_unary {
+        // `not $a`, but `!$a` and `-$a`
+        return previous.trimmed_text().chars().all(char::is_alphabetic);
+    }
+    let is_type_member = next.token_type == DOT && matches!(previous.token_type, FUNC_ID | ID);
+    let is_call = next.token_type == LPAREN && previous.token_type == FUNC_ID;
+    !matches!(
+        previous.token_type,
+        LPAREN | EXPRESSION_START | COMMAND_EXPRESSION_START | DOT
+    ) && !matches!(next.token_type, RPAREN | COMMA | EXPRESSION_END)
+        && !is_type_member
+        && !is_call
+}
+
+/// Formats hashtags, moving the `#line:` tag to the end.
+fn format_hashtags(tokens: &[&FormatToken]) -> Vec<String> {
+    let mut hashtags: Vec<_> = tokens
+        .iter()
+        .filter(|token| token.token_type == HASHTAG_TEXT)
+        .map(|token| format!("#{}", token.trimmed_text()))
+        .collect();
+    hashtags.sort_by_key(|hashtag| hashtag.starts_with("#line:"));
+    hashtags
+}
+
+/// Splits off a trailing comment.
+fn split_comment<'a, 'b>(tokens: &'b [&'a FormatToken]) -> (&'b [&'a FormatToken], Option<String>) {
+    match tokens.split_last() {
+        Some((last, rest)) if is_comment(last.token_type) => {
+            (rest, Some(last.text.trim_end().to_owned()))
+        }
+        _ => (tokens, None),
+    }
+}
+
+fn find_from(tokens: &[&FormatToken], start: usize, token_types: &[isize]) -> usize {
+    (start..tokens.len())
+        .find(|&index| token_types.contains(&tokens[index].token_type))
+        .unwrap_or(tokens.len() - 1)
+}
+
+fn join_tokens(tokens: &[&FormatToken]) -> String {
+    join_parts(tokens.iter().map(|token| token.text.trim().to_owned()))
+}
+
+fn join_parts(parts: impl IntoIterator<Item = String>) -> String {
+    parts
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Reduces the tokens to what matters to the compiler, so that two sources that only differ in formatting
+/// are reduced to the same tokens.
+fn normalize(tokens: &[FormatToken]) -> Vec<FormatToken> {
+    // Merge the pieces of text, which are split at escapes and the first character
+    let mut merged: Vec<FormatToken> = Vec::new();
+    for token in tokens
+        .iter()
+        .filter(|token| !is_whitespace(token.token_type))
+    {
+        let token_type = if is_text(token.token_type) {
+            TEXT
+        } else {
+            token.token_type
+        };
+        match merged.last_mut() {
+            Some(last)
+                if last.token_type == token_type && matches!(token_type, TEXT | COMMAND_TEXT) =>
+            {
+                last.text += &token.text;
+            }
+            _ => merged.push(FormatToken {
+                token_type,
+                text: token.text.clone(),
+            }),
+        }
+    }
+
+    let mut normalized: Vec<FormatToken> = Vec::new();
+    let mut hashtags = Vec::new();
+    let mut in_quotes = false;
+    let mut collapsed = String::new();
+    for (index, token) in merged.iter().enumerate() {
+        let previous = index.checked_sub(1).map(|index| merged[index].token_type);
+        let next = merged.get(index + 1).map(|token| token.token_type);
+        let text = match token.token_type {
+            // Whitespace around text is only kept between the text and the expressions in it
+            TEXT => {
+                let text = token.text.as_str();
+                let text = if previous == Some(EXPRESSION_END) {
+                    text
+                } else {
+                    text.trim_start()
+                };
+                if next == Some(EXPRESSION_START) {
+                    text
+                } else {
+                    text.trim_end()
+                }
+            }
+            COMMAND_START => {
+                in_quotes = false;
+                token.trimmed_text()
+            }
+            COMMAND_TEXT => {
+                collapsed.clear();
+                push_collapsing_whitespace(&mut collapsed, &token.text, &mut in_quotes);
+                let text = collapsed.as_str();
+                let text = if previous == Some(COMMAND_START) {
+                    text.trim_start()
+                } else {
+                    text
+                };
+                if next == Some(COMMAND_TEXT_END) {
+                    text.trim_end()
+                } else {
+                    text
+                }
+            }
+            HASHTAG => continue,
+            HASHTAG_TEXT => {
+                hashtags.push(token.trimmed_text().to_owned());
+                continue;
+            }
+            NEWLINE | BLANK_LINE_FOLLOWING_OPTION => {
+                hashtags.sort();
+                normalized.extend(hashtags.drain(..).map(|text| FormatToken {
+                    token_type: HASHTAG_TEXT,
+                    text,
+                }));
+                // Only blank lines can be removed, so multiple newlines are equivalent to a single one
+                let previous = normalized.last().map(|token| token.token_type);
+                let is_repeated = match token.token_type {
+                    NEWLINE => {
+                        matches!(previous, None | Some(NEWLINE | BLANK_LINE_FOLLOWING_OPTION))
+                    }
+                    _ => previous == Some(BLANK_LINE_FOLLOWING_OPTION),
+                };
+                if is_repeated {
+                    continue;
+                }
+                ""
+            }
+            BODY_END => {
+                // Blank lines at the end of a node don't end anything that the `===` doesn't end anyway
+                let mut dedent_count = 0;
+                while let Some(last) = normalized.last() {
+                    match last.token_type {
+                        DEDENT => dedent_count += 1,
+                        NEWLINE | BLANK_LINE_FOLLOWING_OPTION => {}
+                        _ => break,
+                    }
+                    normalized.pop();
+                }
+                normalized.push(FormatToken {
+                    token_type: NEWLINE,
+                    text: String::new(),
+                });
+                normalized.extend((0..dedent_count).map(|_| FormatToken {
+                    token_type: DEDENT,
+                    text: String::new(),
+                }));
+                token.trimmed_text()
+            }
+            _ => token.trimmed_text(),
+        };
+        if text.is_empty() && matches!(token.token_type, TEXT | COMMAND_TEXT) {
+            continue;
+        }
+        normalized.push(FormatToken {
+            token_type: token.token_type,
+            text: text.to_owned(),
+        });
+    }
+    hashtags.sort();
+    normalized.extend(hashtags.into_iter().map(|text| FormatToken {
+        token_type: HASHTAG_TEXT,
+        text,
+    }));
+    while normalized
+        .last()
+        .is_some_and(|token| token.token_type == NEWLINE)
+    {
+        normalized.pop();
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn try_format_source(source: &str) -> std::result::Result<String, FormatError> {
+        format(&File {
+            file_name: "test.yarn".to_owned(),
+            source: source.to_owned(),
+        })
+    }
+
+    fn format_source(source: &str) -> String {
+        try_format_source(source).unwrap()
+    }
+
+    #[test]
+    fn formats_headers_and_file_hashtags() {
+        let source = "#file_tag\n// A comment\ntitle:Start\ntags:   a b  \nempty:\nwhen: $gold>1\n---\nHello\n===";
+        assert_eq!(
+            "#file_tag\n// A comment\ntitle: Start\ntags: a b\nempty:\nwhen: $gold > 1\n---\nHello\n===\n",
+            format_source(source)
+        );
+    }
+
+    #[test]
+    fn indents_blocks_and_options() {
+        let source = "\
+title: Start
+---
+<<if visited(\"Shop\") and not $x>>
+ ->   Option one <<if $gold >= 1>> #line:o1
+        Child   line
+  <<elseif Food.Apple == .Pear>>
+Else if
+<<else>>
+=> Group line
+   <<once>>
+      Once
+        <<endonce>>
+<<endif>>
+===";
+        assert_eq!(
+            "\
+title: Start
+---
+<<if visited(\"Shop\") and not $x>>
+    -> Option one <<if $gold >= 1>> #line:o1
+        Child   line
+<<elseif Food.Apple == .Pear>>
+    Else if
+<<else>>
+    => Group line
+        <<once>>
+            Once
+        <<endonce>>
+<<endif>>
+===
+",
+            format_source(source)
+        );
+    }
+
+    #[test]
+    fn formats_commands_and_expressions() {
+        let source = "\
+title: Start
+---
+<<declare $gold = 1 as number>>
+<<set $gold to -1 *( 2+3) >>
+<<set $x to !$y>>
+  Hello,   {$name+1}!   #line:abc  #tag   // trailing
+<<walk  Bob {$x+1}   left >>
+<<detour   Shop>>
+<<wait  2>>
+<<say   \"Hello,   \\\"you\\\"  there\"  {\"  as is  \"}>>
+<<jump {$dest}>>
+<<enum Food>>
+<<case Apple>>
+<<endenum>>
+===";
+        assert_eq!(
+            "\
+title: Start
+---
+<<declare $gold = 1 as number>>
+<<set $gold to -1 * (2 + 3)>>
+<<set $x to !$y>>
+Hello,   {$name + 1}! #tag #line:abc // trailing
+<<walk Bob {$x + 1} left>>
+<<detour Shop>>
+<<wait 2>>
+<<say \"Hello,   \\\"you\\\"  there\" {\"  as is  \"}>>
+<<jump {$dest}>>
+<<enum Food>>
+    <<case Apple>>
+<<endenum>>
+===
+",
+            format_source(source)
+        );
+    }
+
+    #[test]
+    fn keeps_blank_lines_that_end_options_and_separates_nodes() {
+        let source =
+            "\n\ntitle: Start\n---\n-> A\n\n\n-> B\n===\ntitle: Second\n---\nHi   \n===\n\n\n";
+        assert_eq!(
+            "title: Start\n---\n-> A\n\n-> B\n===\n\ntitle: Second\n---\nHi\n===\n",
+            format_source(source)
+        );
+    }
+
+    #[test]
+    fn is_idempotent_and_keeps_the_program() {
+        let source = include_str!("parser/significant_whitespace.yarn");
+        let formatted = format_source(source);
+        assert_ne!(source, formatted);
+        assert_eq!(formatted, format_source(&formatted));
+
+        let compile = |source: &str| {
+            Compiler::new()
+                .add_file(File {
+                    file_name: "test.yarn".to_owned(),
+                    source: source.to_owned(),
+                })
+                .compile()
+                .unwrap()
+                .program
+        };
+        assert_eq!(compile(source), compile(&formatted));
+    }
+
+    #[test]
+    fn rejects_files_with_errors() {
+        let source = "title: Start\n---\n<<if $a>>\n   Oops\n===";
+        assert!(matches!(
+            try_format_source(source),
+            Err(FormatError::InvalidSyntax(_))
+        ));
+    }
+
+    #[test]
+    fn keeps_files_without_nodes_unchanged() {
+        let source = "// Only a comment  ";
+        assert_eq!(source, format_source(source));
+    }
+}
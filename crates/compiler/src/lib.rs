@@ -13,7 +13,9 @@ pub(crate) mod compilation_steps;
 pub(crate) mod compiler;
 pub(crate) mod error_strategy;
 mod file_parse_result;
+mod formatter;
 pub(crate) mod listeners;
+mod optimizer;
 mod output;
 mod parser;
 pub(crate) mod parser_rule_context_ext;
@@ -22,17 +24,22 @@ pub(crate) mod token_ext;
 pub(crate) mod visitors;
 
 pub use crate::compiler::Result;
+pub use crate::formatter::{format, FormatError};
 
 pub mod prelude {
     //! Everything you need to get started with the Yarn Spinner compiler.
     pub(crate) use crate::{
-        compiler::antlr_rust_ext::*, compiler::run_compilation::*, compiler::utils::*,
-        file_parse_result::*, parser::*, parser_rule_context_ext::*, string_table_manager::*,
-        token_ext::*,
+        compiler::antlr_rust_ext::*, compiler::incremental_compilation::*,
+        compiler::run_compilation::*, compiler::utils::*, file_parse_result::*, parser::*,
+        parser_rule_context_ext::*, string_table_manager::*, token_ext::*,
     };
     pub use crate::{
-        compiler::{CompilationType, Compiler, File},
-        listeners::{Diagnostic, DiagnosticSeverity, DiagnosticVec},
+        compiler::{CommandDeclaration, CompilationType, Compiler, File, IncrementalCompiler},
+        formatter::{format, FormatError},
+        listeners::{
+            Diagnostic, DiagnosticCode, DiagnosticEdit, DiagnosticFix, DiagnosticSeverity,
+            DiagnosticVec,
+        },
         output::*,
     };
     pub(crate) use yarnspinner_core::prelude::*;
@@ -3,19 +3,24 @@
 use crate::prelude::*;
 use antlr_rust::parser_rule_context::ParserRuleContext;
 use antlr_rust::token::Token;
-use antlr_rust::tree::{ParseTreeListener, ParseTreeVisitorCompat};
+use antlr_rust::tree::{ParseTree, ParseTreeListener, ParseTreeVisitorCompat};
 use std::cell::RefCell;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::ops::Deref;
 use std::rc::Rc;
 use yarnspinner_core::prelude::*;
+use yarnspinner_core::types::Type;
 
 mod emit;
 use crate::parser::generated::yarnspinnerparser::{
-    BodyContext, HeaderContext, NodeContext, YarnSpinnerParserContextType,
+    BodyContext, Declare_statementContext, ExpressionContextAll, HeaderContext, NodeContext,
+    YarnSpinnerParserContextType,
+};
+use crate::prelude::generated::yarnspinnerparser::{
+    BodyContextAttrs, Declare_statementContextAttrs, HeaderContextAttrs, NodeContextAttrs,
 };
-use crate::prelude::generated::yarnspinnerparser::BodyContextAttrs;
 use crate::prelude::generated::yarnspinnerparserlistener::YarnSpinnerParserListener;
-use crate::visitors::{CodeGenerationVisitor, KnownTypes};
+use crate::visitors::{is_constant, CodeGenerationVisitor, KnownTypes};
 pub(crate) use emit::*;
 use yarnspinner_core::prelude::OpCode;
 
@@ -25,7 +30,11 @@ pub(crate) struct CompilerListener<'input> {
     pub(crate) program: Rc<RefCell<Program>>,
     /// the list of nodes we have to ensure we track visitation
     pub(crate) tracking_nodes: Rc<RefCell<HashSet<String>>>,
+    /// Whether the program detours into nodes, so that falling off the end of a node needs to return to its caller.
+    contains_detours: bool,
     pub(crate) diagnostics: Rc<RefCell<Vec<Diagnostic>>>,
+    /// The conditions of the node group members in this file.
+    pub(crate) node_group_conditions: Rc<RefCell<Vec<NodeGroupCondition>>>,
     pub(crate) types: KnownTypes,
     /// The current node to which instructions are being added.
     pub(crate) current_node: Option<Node>,
@@ -34,24 +43,47 @@ pub(crate) struct CompilerListener<'input> {
     /// Whether we are currently parsing the
     /// current node as a 'raw text' node, or as a fully syntactic node.
     is_current_node_raw_text: bool,
+    /// The unique names of the node group members in this file, keyed by the token index of their `title` header.
+    node_group_members: HashMap<isize, String>,
+    /// The variables that remember whether the `<<once>>` blocks and once-only options in this file have run,
+    /// keyed by the token index at which the block or the option's condition starts.
+    pub(crate) once_variables: HashMap<isize, String>,
     file: FileParseResult<'input>,
     label_count: usize,
 }
 
+/// The code that decides whether a member of a node group may run.
+/// It leaves a boolean on the stack and is run by the node that is named after the group.
+#[derive(Clone)]
+pub(crate) struct NodeGroupCondition {
+    pub(crate) group_name: String,
+    pub(crate) member_name: String,
+    pub(crate) file_name: String,
+    pub(crate) instructions: Vec<Instruction>,
+    pub(crate) line_positions: HashMap<usize, Option<Position>>,
+}
+
 impl<'input> CompilerListener<'input> {
     pub(crate) fn new(
         tracking_nodes: HashSet<String>,
+        contains_detours: bool,
         types: KnownTypes,
+        node_group_members: HashMap<isize, String>,
+        once_variables: HashMap<isize, String>,
         file: FileParseResult<'input>,
     ) -> Self {
         Self {
             file,
             types,
+            node_group_members,
+            once_variables,
             tracking_nodes: Rc::new(RefCell::new(tracking_nodes)),
+            contains_detours,
             current_node: Default::default(),
             current_debug_info: Default::default(),
             is_current_node_raw_text: Default::default(),
             diagnostics: Default::default(),
+            node_group_conditions: Default::default(),
             program: Default::default(),
             label_count: Default::default(),
             debug_infos: Default::default(),
@@ -68,6 +100,75 @@ impl<'input> CompilerListener<'input> {
         self.label_count += 1;
         label
     }
+
+    /// Generates the code for the `when` headers of a node group member.
+    /// The code goes into a [`NodeGroupCondition`] instead of the member itself,
+    /// since it is run by the node that selects one of the group's members.
+    fn generate_node_group_condition(&mut self, ctx: &NodeContext<'input>, group_name: String) {
+        let conditions: Vec<_> = ctx
+            .header_all()
+            .iter()
+            .filter_map(|header| header.expression())
+            .collect();
+
+        let member = self.current_node.replace(Node::default()).unwrap();
+        let member_debug_info = std::mem::take(&mut self.current_debug_info);
+
+        let mut visitor = CodeGenerationVisitor::new(self, None);
+        for condition in &conditions {
+            visitor.visit(condition.as_ref());
+        }
+        // Every condition of a member must pass
+        let and = Type::Boolean.get_canonical_name_for_method(&Operator::And.to_string());
+        for condition in conditions.iter().skip(1) {
+            self.emit(
+                Emit::from_op_code(OpCode::PushFloat)
+                    .with_token(condition.start().deref())
+                    .with_operand(2.),
+            );
+            self.emit(
+                Emit::from_op_code(OpCode::CallFunc)
+                    .with_token(condition.start().deref())
+                    .with_operand(and.clone()),
+            );
+        }
+
+        let condition = self.current_node.replace(member).unwrap();
+        let debug_info = std::mem::replace(&mut self.current_debug_info, member_debug_info);
+        self.node_group_conditions
+            .borrow_mut()
+            .push(NodeGroupCondition {
+                group_name,
+                member_name: self.current_node.as_ref().unwrap().name.clone(),
+                file_name: self.file.name.clone(),
+                instructions: condition.instructions,
+                line_positions: debug_info.line_positions,
+            });
+    }
+
+    /// Generates the node that computes the smart variable `name` from its expression.
+    /// The node is named after the variable and leaves the variable's value on the stack.
+    fn generate_smart_variable(&mut self, name: String, expression: &ExpressionContextAll<'input>) {
+        let smart_variable = Node {
+            name: name.clone(),
+            headers: vec![Header {
+                key: Node::SMART_VARIABLE_HEADER.to_owned(),
+                value: expression.get_text_with_whitespace(self.file.tokens()),
+            }],
+            ..Default::default()
+        };
+        let current_node = self.current_node.replace(smart_variable);
+        let current_debug_info = std::mem::take(&mut self.current_debug_info);
+
+        CodeGenerationVisitor::new(self, None).visit(expression);
+
+        let smart_variable = std::mem::replace(&mut self.current_node, current_node).unwrap();
+        let mut debug_info = std::mem::replace(&mut self.current_debug_info, current_debug_info);
+        debug_info.node_name.clone_from(&name);
+        debug_info.file_name.clone_from(&self.file.name);
+        self.program.borrow_mut().nodes.insert(name, smart_variable);
+        self.debug_infos.borrow_mut().push(debug_info);
+    }
 }
 
 impl<'input> ParseTreeListener<'input, YarnSpinnerParserContextType> for CompilerListener<'input> {}
@@ -85,11 +186,23 @@ impl<'input> YarnSpinnerParserListener<'input> for CompilerListener<'input> {
         if name.is_empty() {
             // We don't have a name for this node. We can't emit code for it.
             self.diagnostics.borrow_mut().push(
-                Diagnostic::from_message("Missing title header for node")
-                    .with_file_name(self.file.name.clone())
-                    .with_parser_context(ctx, self.file.tokens()),
+                Diagnostic::new(
+                    DiagnosticCode::MissingTitle,
+                    "Missing title header for node",
+                )
+                .with_file_name(self.file.name.clone())
+                .with_parser_context(ctx, self.file.tokens()),
             );
         } else {
+            let group_name = self
+                .current_node
+                .as_ref()
+                .unwrap()
+                .node_group()
+                .map(ToOwned::to_owned);
+            if let Some(group_name) = group_name {
+                self.generate_node_group_condition(ctx, group_name);
+            }
             if !self.program.borrow().nodes.contains_key(name) {
                 self.program
                     .borrow_mut()
@@ -123,16 +236,28 @@ impl<'input> YarnSpinnerParserListener<'input> for CompilerListener<'input> {
         // be stored as 'foo', '', consistent with how it was typed.
         // That is, it's not null, because a header was provided, but
         // it was written as an empty line.
-        let header_value = ctx
-            .header_value
-            .as_ref()
-            .map(|v| v.get_text())
-            .unwrap_or_default()
-            .to_owned();
+        // The value of a `when` header is parsed as an expression instead.
+        let header_value = match ctx.expression() {
+            Some(expression) => expression.get_text_with_whitespace(self.file.tokens()),
+            None => ctx
+                .header_value
+                .as_ref()
+                .map(|v| v.get_text())
+                .unwrap_or_default()
+                .to_owned(),
+        };
+        let mut node_group = None;
         match header_key {
             "title" => {
-                // Set the name of the node
-                current_node.name.clone_from(&header_value);
+                // Set the name of the node. Members of a node group share their title,
+                // so they get a unique name and remember the group instead.
+                match self.node_group_members.get(&ctx.start().get_token_index()) {
+                    Some(member_name) => {
+                        current_node.name.clone_from(member_name);
+                        node_group = Some(header_value.clone());
+                    }
+                    None => current_node.name.clone_from(&header_value),
+                }
             }
             "tags" => {
                 // Split the list of tags by spaces, and use that
@@ -150,6 +275,20 @@ impl<'input> YarnSpinnerParserListener<'input> for CompilerListener<'input> {
             value: header_value,
         };
         current_node.headers.push(header);
+        if let Some(node_group) = node_group {
+            current_node.headers.push(Header {
+                key: Node::NODE_GROUP_HEADER.to_owned(),
+                value: node_group,
+            });
+        }
+    }
+
+    fn exit_declare_statement(&mut self, ctx: &Declare_statementContext<'input>) {
+        let expression = ctx.expression().unwrap();
+        if !is_constant(&expression) {
+            let name = ctx.variable().unwrap().get_text();
+            self.generate_smart_variable(name, &expression);
+        }
     }
 
     fn enter_body(&mut self, ctx: &BodyContext<'input>) {
@@ -197,7 +336,14 @@ impl<'input> YarnSpinnerParserListener<'input> for CompilerListener<'input> {
             CodeGenerationVisitor::generate_tracking_code(self, track);
         }
         // We have exited the body; emit a 'stop' opcode here.
-        self.emit(Emit::from_op_code(OpCode::Stop).with_source(Position {
+        // If the program detours into nodes, emit a 'return' instead, which only stops
+        // the dialogue if this node was not entered via a detour.
+        let op_code = if self.contains_detours {
+            OpCode::Return
+        } else {
+            OpCode::Stop
+        };
+        self.emit(Emit::from_op_code(op_code).with_source(Position {
             line: (ctx.stop().line as usize).saturating_sub(1),
             character: 0,
         }));
@@ -11,11 +11,15 @@ use antlr_rust::token::Token;
 use antlr_rust::token_factory::TokenFactory;
 use antlr_rust::tree::ParseTreeListener;
 pub use diagnostic::*;
+pub use diagnostic_code::*;
 use std::cell::RefCell;
 use std::rc::Rc;
 use yarnspinner_core::prelude::*;
 
 mod diagnostic;
+mod diagnostic_code;
+#[cfg(feature = "serde")]
+mod diagnostic_output;
 pub(crate) struct LexerErrorListener {
     pub(crate) diagnostics: RefCell<Vec<Diagnostic>>,
     file_name: String,
@@ -50,7 +54,7 @@ impl<'input, T: Recognizer<'input>> ErrorListener<'input, T> for LexerErrorListe
             character: column + 1,
         };
         self.diagnostics.borrow_mut().push(
-            Diagnostic::from_message(msg)
+            Diagnostic::new(DiagnosticCode::SyntaxError, msg)
                 .with_range(range)
                 .with_file_name(&self.file_name),
         );
@@ -93,7 +97,7 @@ impl<'input, T: Recognizer<'input>> ErrorListener<'input, T> for ParserErrorList
             line: line.saturating_sub(1),
             character: (column + 1) as usize,
         };
-        let mut diagnostic = Diagnostic::from_message(msg)
+        let mut diagnostic = Diagnostic::new(DiagnosticCode::SyntaxError, msg)
             .with_file_name(&self.file.file_name)
             .with_range(range);
         if let Some(offending_symbol) = offending_symbol {
@@ -1,3 +1,5 @@
+#[cfg(feature = "serde")]
+use super::diagnostic_output::{render_json, render_sarif};
 use crate::parser_rule_context_ext::ParserRuleContextExt;
 use crate::prelude::*;
 use annotate_snippets::{Annotation, AnnotationType, Renderer, Slice, Snippet, SourceAnnotation};
@@ -46,17 +48,26 @@ pub struct Diagnostic {
 
     /// The line the context starts on.
     pub start_line: usize,
+
+    /// The kind of the issue, which stays the same even if the wording of [`Diagnostic::message`] changes.
+    pub code: DiagnosticCode,
+
+    /// Changes to the file indicated by [`Diagnostic::file_name`] that would resolve the issue.
+    /// Most diagnostics don't have any, since there is rarely only one obvious way to resolve them.
+    pub fixes: Vec<DiagnosticFix>,
 }
 
 impl Diagnostic {
-    pub(crate) fn from_message(message: impl Into<String>) -> Self {
+    pub(crate) fn new(code: DiagnosticCode, message: impl Into<String>) -> Self {
         Self {
             message: message.into(),
             file_name: Default::default(),
             range: Default::default(),
             context: Default::default(),
-            severity: Default::default(),
+            severity: code.default_severity(),
             start_line: Default::default(),
+            code,
+            fixes: Default::default(),
         }
     }
 
@@ -103,6 +114,11 @@ impl Diagnostic {
         self.severity = severity;
         self
     }
+
+    pub(crate) fn with_fix(mut self, fix: DiagnosticFix) -> Self {
+        self.fixes.push(fix);
+        self
+    }
 }
 
 impl Display for Diagnostic {
@@ -115,7 +131,7 @@ impl Display for Diagnostic {
         let snippet = Snippet {
             title: Some(Annotation {
                 label: Some(label),
-                id: None,
+                id: Some(self.code.as_str()),
                 annotation_type,
             }),
             footer: vec![],
@@ -168,16 +184,70 @@ fn convert_absolute_range_to_relative(diagnostic: &Diagnostic) -> (usize, usize)
     (byte_start, byte_end)
 }
 
+/// A change to a file that resolves the issue described by a [`Diagnostic`], e.g. correcting a misspelled node name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy", reflect(Debug, PartialEq, Hash))]
+#[cfg_attr(
+    all(feature = "bevy", feature = "serde"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DiagnosticFix {
+    /// What the fix does, e.g. "Replace with Start".
+    pub description: String,
+
+    /// The edits to make, which don't overlap.
+    pub edits: Vec<DiagnosticEdit>,
+}
+
+/// Replaces a range of a file with new text. Part of a [`DiagnosticFix`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy", reflect(Debug, PartialEq, Hash))]
+#[cfg_attr(
+    all(feature = "bevy", feature = "serde"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DiagnosticEdit {
+    /// The range to replace. Empty ranges insert the text.
+    pub range: Range<Position>,
+
+    /// The text to replace the range with. Empty text removes the range.
+    pub new_text: String,
+}
+
 /// Trait implemented for `Vec<Diagnostic>` to provide utility methods.
 pub trait DiagnosticVec {
     /// Returns `true` if any of the [`Diagnostic`]s in the vector are of [`DiagnosticSeverity::Error`].
     fn has_errors(&self) -> bool;
+
+    /// Renders the [`Diagnostic`]s as a JSON array for tools that process them further.
+    /// Every object has the fields `code`, `severity`, `message`, `file`, `range` and `fixes`.
+    #[cfg(feature = "serde")]
+    fn to_json(&self) -> String;
+
+    /// Renders the [`Diagnostic`]s as a [SARIF](https://sarifweb.azurewebsites.net/) log,
+    /// the format understood by e.g. GitHub code scanning.
+    #[cfg(feature = "serde")]
+    fn to_sarif(&self) -> String;
 }
 
 impl DiagnosticVec for Vec<Diagnostic> {
     fn has_errors(&self) -> bool {
         self.iter().any(|d| d.severity == DiagnosticSeverity::Error)
     }
+
+    #[cfg(feature = "serde")]
+    fn to_json(&self) -> String {
+        render_json(self)
+    }
+
+    #[cfg(feature = "serde")]
+    fn to_sarif(&self) -> String {
+        render_sarif(self)
+    }
 }
 
 /// The severity of the issue.
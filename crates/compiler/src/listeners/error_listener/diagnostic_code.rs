@@ -0,0 +1,281 @@
+use crate::prelude::*;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// Identifies the kind of problem a [`Diagnostic`] describes, independent of its message.
+///
+/// Every code is written as `YS` followed by four digits, e.g. `YS0012` for [`DiagnosticCode::UndefinedVariable`].
+/// Codes are stable: a code is never reused for another kind of problem, so tools can filter diagnostics by them
+/// and [`Compiler::with_diagnostic_severity`] can change how they are reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy", reflect(Debug, PartialEq, Hash))]
+#[cfg_attr(
+    all(feature = "bevy", feature = "serde"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum DiagnosticCode {
+    /// `YS0001`: The source could not be parsed.
+    SyntaxError,
+    /// `YS0002`: A line is indented with both tabs and spaces.
+    MixedIndentation,
+    /// `YS0003`: A command spans more than one line.
+    NewlineInCommand,
+    /// `YS0004`: A node has no `title` header.
+    MissingTitle,
+    /// `YS0005`: A node's title contains characters that are not allowed in it.
+    InvalidNodeName,
+    /// `YS0006`: Two nodes have the same title.
+    DuplicateNodeName,
+    /// `YS0007`: A node of a node group has no `when` header.
+    MissingWhenHeader,
+    /// `YS0008`: Two lines have the same line ID.
+    DuplicateLineId,
+    /// `YS0009`: A jump, detour or `visited` call refers to a node that does not exist.
+    ///
+    /// If the node is in a file that is compiled separately, make this a warning with [`Compiler::with_diagnostic_severity`].
+    UnknownNode,
+    /// `YS0010`: A jump, detour or `visited` call refers to a node that was left out of a partial compilation.
+    ExcludedNode,
+    /// `YS0011`: The destination of a jump or detour is an expression, so it can't be checked to exist.
+    DynamicNodeReference,
+    /// `YS0012`: The type of a variable that was never declared can't be figured out.
+    UndefinedVariable,
+    /// `YS0013`: A variable is declared more than once.
+    DuplicateDeclaration,
+    /// `YS0014`: A declaration or enum case refers to a type that does not exist.
+    UnknownType,
+    /// `YS0015`: A value does not match the type of the variable it is assigned to.
+    TypeMismatch,
+    /// `YS0016`: The terms of an operation have types that the operation can't be used with.
+    InvalidOperands,
+    /// `YS0017`: The type of an expression can't be figured out.
+    UndeterminedType,
+    /// `YS0018`: A function is called with the wrong number of parameters.
+    WrongParameterCount,
+    /// `YS0019`: A function is called with a parameter of the wrong type.
+    WrongParameterType,
+    /// `YS0020`: The initial value of a declaration is not a constant.
+    NonConstantDeclaration,
+    /// `YS0021`: A number literal can't be read as a number.
+    InvalidNumber,
+    /// `YS0022`: `null` is used as a value.
+    NullValue,
+    /// `YS0023`: A local variable is used before it is declared.
+    LocalVariableUsedBeforeDeclaration,
+    /// `YS0024`: A local variable is used outside of the node that declares it.
+    LocalVariableOutOfScope,
+    /// `YS0025`: A smart variable is assigned a value.
+    SmartVariableModified,
+    /// `YS0026`: The expression of a smart variable depends on the smart variable itself.
+    CyclicSmartVariable,
+    /// `YS0027`: An enum has the same name as a built-in type.
+    InvalidEnumName,
+    /// `YS0028`: An enum is declared more than once.
+    DuplicateEnum,
+    /// `YS0029`: An enum has two cases with the same name.
+    DuplicateEnumCase,
+    /// `YS0030`: An enum has no case with the given name.
+    UnknownEnumCase,
+    /// `YS0031`: The enum of a case written as `.Case` can't be figured out.
+    UnresolvedEnumCase,
+    /// `YS0032`: A command was run that was not declared with [`Compiler::declare_command`].
+    ///
+    /// Commands are only checked once at least one command was declared.
+    /// Silence this with [`Compiler::with_diagnostic_severity`] when the game also handles commands that aren't declared.
+    UnknownCommand,
+    /// `YS0033`: A declared command is run with the wrong number of parameters.
+    WrongCommandParameterCount,
+    /// `YS0034`: A declared command is run with a parameter of the wrong type.
+    WrongCommandParameterType,
+    /// `YS0035`: A command is declared with the name of a command that is built into Yarn Spinner, like `detour`.
+    ReservedCommandName,
+    /// `YS0036`: A command that is built into Yarn Spinner is run with the wrong parameters, like `<<detour A B>>`.
+    MalformedBuiltInCommand,
+}
+
+impl DiagnosticCode {
+    /// All codes, in the order of their numbers.
+    pub const ALL: [Self; 36] = [
+        Self::SyntaxError,
+        Self::MixedIndentation,
+        Self::NewlineInCommand,
+        Self::MissingTitle,
+        Self::InvalidNodeName,
+        Self::DuplicateNodeName,
+        Self::MissingWhenHeader,
+        Self::DuplicateLineId,
+        Self::UnknownNode,
+        Self::ExcludedNode,
+        Self::DynamicNodeReference,
+        Self::UndefinedVariable,
+        Self::DuplicateDeclaration,
+        Self::UnknownType,
+        Self::TypeMismatch,
+        Self::InvalidOperands,
+        Self::UndeterminedType,
+        Self::WrongParameterCount,
+        Self::WrongParameterType,
+        Self::NonConstantDeclaration,
+        Self::InvalidNumber,
+        Self::NullValue,
+        Self::LocalVariableUsedBeforeDeclaration,
+        Self::LocalVariableOutOfScope,
+        Self::SmartVariableModified,
+        Self::CyclicSmartVariable,
+        Self::InvalidEnumName,
+        Self::DuplicateEnum,
+        Self::DuplicateEnumCase,
+        Self::UnknownEnumCase,
+        Self::UnresolvedEnumCase,
+        Self::UnknownCommand,
+        Self::WrongCommandParameterCount,
+        Self::WrongCommandParameterType,
+        Self::ReservedCommandName,
+        Self::MalformedBuiltInCommand,
+    ];
+
+    /// The code as written in diagnostics, e.g. `YS0012`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::SyntaxError => "YS0001",
+            Self::MixedIndentation => "YS0002",
+            Self::NewlineInCommand => "YS0003",
+            Self::MissingTitle => "YS0004",
+            Self::InvalidNodeName => "YS0005",
+            Self::DuplicateNodeName => "YS0006",
+            Self::MissingWhenHeader => "YS0007",
+            Self::DuplicateLineId => "YS0008",
+            Self::UnknownNode => "YS0009",
+            Self::ExcludedNode => "YS0010",
+            Self::DynamicNodeReference => "YS0011",
+            Self::UndefinedVariable => "YS0012",
+            Self::DuplicateDeclaration => "YS0013",
+            Self::UnknownType => "YS0014",
+            Self::TypeMismatch => "YS0015",
+            Self::InvalidOperands => "YS0016",
+            Self::UndeterminedType => "YS0017",
+            Self::WrongParameterCount => "YS0018",
+            Self::WrongParameterType => "YS0019",
+            Self::NonConstantDeclaration => "YS0020",
+            Self::InvalidNumber => "YS0021",
+            Self::NullValue => "YS0022",
+            Self::LocalVariableUsedBeforeDeclaration => "YS0023",
+            Self::LocalVariableOutOfScope => "YS0024",
+            Self::SmartVariableModified => "YS0025",
+            Self::CyclicSmartVariable => "YS0026",
+            Self::InvalidEnumName => "YS0027",
+            Self::DuplicateEnum => "YS0028",
+            Self::DuplicateEnumCase => "YS0029",
+            Self::UnknownEnumCase => "YS0030",
+            Self::UnresolvedEnumCase => "YS0031",
+            Self::UnknownCommand => "YS0032",
+            Self::WrongCommandParameterCount => "YS0033",
+            Self::WrongCommandParameterType => "YS0034",
+            Self::ReservedCommandName => "YS0035",
+            Self::MalformedBuiltInCommand => "YS0036",
+        }
+    }
+
+    /// A short, human readable name of the kind of problem, e.g. `undefined variable`.
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::SyntaxError => "syntax error",
+            Self::MixedIndentation => "mixed indentation",
+            Self::NewlineInCommand => "newline in command",
+            Self::MissingTitle => "missing title",
+            Self::InvalidNodeName => "invalid node name",
+            Self::DuplicateNodeName => "duplicate node name",
+            Self::MissingWhenHeader => "missing when header",
+            Self::DuplicateLineId => "duplicate line ID",
+            Self::UnknownNode => "unknown node",
+            Self::ExcludedNode => "excluded node",
+            Self::DynamicNodeReference => "dynamic node reference",
+            Self::UndefinedVariable => "undefined variable",
+            Self::DuplicateDeclaration => "duplicate declaration",
+            Self::UnknownType => "unknown type",
+            Self::TypeMismatch => "type mismatch",
+            Self::InvalidOperands => "invalid operands",
+            Self::UndeterminedType => "undetermined type",
+            Self::WrongParameterCount => "wrong parameter count",
+            Self::WrongParameterType => "wrong parameter type",
+            Self::NonConstantDeclaration => "non-constant declaration",
+            Self::InvalidNumber => "invalid number",
+            Self::NullValue => "null value",
+            Self::LocalVariableUsedBeforeDeclaration => "local variable used before declaration",
+            Self::LocalVariableOutOfScope => "local variable out of scope",
+            Self::SmartVariableModified => "smart variable modified",
+            Self::CyclicSmartVariable => "cyclic smart variable",
+            Self::InvalidEnumName => "invalid enum name",
+            Self::DuplicateEnum => "duplicate enum",
+            Self::DuplicateEnumCase => "duplicate enum case",
+            Self::UnknownEnumCase => "unknown enum case",
+            Self::UnresolvedEnumCase => "unresolved enum case",
+            Self::UnknownCommand => "unknown command",
+            Self::WrongCommandParameterCount => "wrong command parameter count",
+            Self::WrongCommandParameterType => "wrong command parameter type",
+            Self::ReservedCommandName => "reserved command name",
+            Self::MalformedBuiltInCommand => "malformed built-in command",
+        }
+    }
+
+    /// The severity that diagnostics with this code have unless it is changed with [`Compiler::with_diagnostic_severity`].
+    pub fn default_severity(self) -> DiagnosticSeverity {
+        match self {
+            Self::MixedIndentation
+            | Self::ExcludedNode
+            | Self::DynamicNodeReference
+            | Self::UnknownCommand => DiagnosticSeverity::Warning,
+            _ => DiagnosticSeverity::Error,
+        }
+    }
+
+    /// Whether the Yarn files can still be compiled when diagnostics with this code are not treated as errors.
+    pub fn can_be_downgraded(self) -> bool {
+        self.default_severity() == DiagnosticSeverity::Warning
+            || matches!(
+                self,
+                Self::UnknownNode
+                    | Self::WrongCommandParameterCount
+                    | Self::WrongCommandParameterType
+            )
+    }
+}
+
+impl Display for DiagnosticCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DiagnosticCode {
+    type Err = String;
+
+    /// Parses a code written like `YS0012`.
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|candidate| candidate.as_str().eq_ignore_ascii_case(code))
+            .ok_or_else(|| format!("Unknown diagnostic code {code}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn codes_are_numbered_in_order() {
+        for (index, code) in DiagnosticCode::ALL.into_iter().enumerate() {
+            assert_eq!(format!("YS{:04}", index + 1), code.as_str());
+            assert_eq!(Ok(code), code.as_str().parse());
+        }
+        let descriptions: HashSet<_> = DiagnosticCode::ALL
+            .into_iter()
+            .map(DiagnosticCode::description)
+            .collect();
+        assert_eq!(DiagnosticCode::ALL.len(), descriptions.len());
+    }
+}
@@ -0,0 +1,366 @@
+//! Machine-readable renderings of [`Diagnostic`]s, used by [`DiagnosticVec::to_json`] and [`DiagnosticVec::to_sarif`].
+
+use crate::prelude::*;
+use std::iter;
+use std::ops::Range;
+use yarnspinner_core::prelude::*;
+
+/// Renders diagnostics as a JSON array with one object per diagnostic:
+///
+/// ```json
+/// {
+///   "code": "YS0009",
+///   "severity": "error",
+///   "message": "Can't jump to Strat, because there is no node with that name. Did you mean Start?",
+///   "file": "start.yarn",
+///   "range": { "start": { "line": 2, "character": 7 }, "end": { "line": 2, "character": 12 } },
+///   "fixes": [{ "description": "Replace with Start", "edits": [{ "range": ..., "newText": "Start" }] }]
+/// }
+/// ```
+///
+/// Lines and characters are zero-based, like [`Position`]. `file` and `range` are `null` if unknown.
+pub(crate) fn render_json(diagnostics: &[Diagnostic]) -> String {
+    let diagnostics: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| JsonDiagnostic {
+            code: diagnostic.code.as_str(),
+            severity: severity_name(diagnostic.severity),
+            message: &diagnostic.message,
+            file: diagnostic.file_name.as_deref(),
+            range: diagnostic.range.as_ref(),
+            fixes: diagnostic
+                .fixes
+                .iter()
+                .map(|fix| JsonFix {
+                    description: &fix.description,
+                    edits: fix
+                        .edits
+                        .iter()
+                        .map(|edit| JsonEdit {
+                            range: &edit.range,
+                            new_text: &edit.new_text,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        })
+        .collect();
+    serde_json::to_string(&diagnostics).unwrap()
+}
+
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    code: &'static str,
+    severity: &'static str,
+    message: &'a str,
+    file: Option<&'a str>,
+    range: Option<&'a Range<Position>>,
+    fixes: Vec<JsonFix<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonFix<'a> {
+    description: &'a str,
+    edits: Vec<JsonEdit<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonEdit<'a> {
+    range: &'a Range<Position>,
+    new_text: &'a str,
+}
+
+/// Renders diagnostics as a [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) log
+/// with a single run, which is understood by e.g. GitHub code scanning.
+pub(crate) fn render_sarif(diagnostics: &[Diagnostic]) -> String {
+    let mut codes: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.code)
+        .collect();
+    codes.sort();
+    codes.dedup();
+    let rules = codes
+        .iter()
+        .map(|code| SarifRule {
+            id: code.as_str(),
+            name: format!("{code:?}"),
+            short_description: SarifText {
+                text: code.description(),
+            },
+            default_configuration: SarifConfiguration {
+                level: severity_name(code.default_severity()),
+            },
+        })
+        .collect();
+    let results = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let artifact_location = SarifArtifactLocation {
+                uri: diagnostic.file_name.as_deref().unwrap_or_default(),
+            };
+            let locations = diagnostic
+                .file_name
+                .as_ref()
+                .map(|_| SarifLocation {
+                    physical_location: SarifPhysicalLocation {
+                        artifact_location: artifact_location.clone(),
+                        region: diagnostic
+                            .range
+                            .as_ref()
+                            .map(|range| sarif_region(diagnostic, range)),
+                    },
+                })
+                .into_iter()
+                .collect();
+            let fixes = diagnostic
+                .fixes
+                .iter()
+                .map(|fix| SarifFix {
+                    description: SarifText {
+                        text: &fix.description,
+                    },
+                    artifact_changes: vec![SarifArtifactChange {
+                        artifact_location: artifact_location.clone(),
+                        replacements: fix
+                            .edits
+                            .iter()
+                            .map(|edit| SarifReplacement {
+                                deleted_region: sarif_region(diagnostic, &edit.range),
+                                inserted_content: SarifText {
+                                    text: &edit.new_text,
+                                },
+                            })
+                            .collect(),
+                    }],
+                })
+                .collect();
+            SarifResult {
+                rule_id: diagnostic.code.as_str(),
+                rule_index: codes.binary_search(&diagnostic.code).unwrap(),
+                level: severity_name(diagnostic.severity),
+                message: SarifText {
+                    text: &diagnostic.message,
+                },
+                locations,
+                fixes,
+            }
+        })
+        .collect();
+    let log = SarifLog {
+        schema: "https://json.schemastore.org/sarif-2.1.0.json",
+        version: "2.1.0",
+        runs: [SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: "Yarn Spinner for Rust",
+                    information_uri: "https://docs.yarnspinner.dev/",
+                    version: env!("CARGO_PKG_VERSION"),
+                    rules,
+                },
+            },
+            results,
+        }],
+    };
+    serde_json::to_string(&log).unwrap()
+}
+
+#[derive(Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [SarifRun<'a>; 1],
+}
+
+#[derive(Serialize)]
+struct SarifRun<'a> {
+    tool: SarifTool,
+    results: Vec<SarifResult<'a>>,
+}
+
+#[derive(Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: &'static str,
+    name: String,
+    short_description: SarifText<'static>,
+    default_configuration: SarifConfiguration,
+}
+
+#[derive(Serialize)]
+struct SarifConfiguration {
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+struct SarifText<'a> {
+    text: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult<'a> {
+    rule_id: &'static str,
+    rule_index: usize,
+    level: &'static str,
+    message: SarifText<'a>,
+    locations: Vec<SarifLocation<'a>>,
+    fixes: Vec<SarifFix<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation<'a> {
+    physical_location: SarifPhysicalLocation<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation<'a> {
+    artifact_location: SarifArtifactLocation<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Serialize, Clone)]
+struct SarifArtifactLocation<'a> {
+    uri: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix<'a> {
+    description: SarifText<'a>,
+    artifact_changes: Vec<SarifArtifactChange<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange<'a> {
+    artifact_location: SarifArtifactLocation<'a>,
+    replacements: Vec<SarifReplacement<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement<'a> {
+    deleted_region: SarifRegion,
+    inserted_content: SarifText<'a>,
+}
+
+/// SARIF regions are one-based, and their end column is exclusive like ours.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+fn sarif_region(diagnostic: &Diagnostic, range: &Range<Position>) -> SarifRegion {
+    SarifRegion {
+        start_line: range.start.line + 1,
+        start_column: utf16_character(diagnostic, &range.start) + 1,
+        end_line: range.end.line + 1,
+        end_column: utf16_character(diagnostic, &range.end) + 1,
+    }
+}
+
+/// SARIF counts columns in UTF-16 code units, while [`Position::character`] counts code points.
+/// Converts between them using the [`Diagnostic::context`], assuming one code unit per character it doesn't cover.
+fn utf16_character(diagnostic: &Diagnostic, position: &Position) -> usize {
+    let line = position
+        .line
+        .checked_sub(diagnostic.start_line)
+        .and_then(|line| diagnostic.context.as_ref()?.lines().nth(line))
+        .unwrap_or_default();
+    line.chars()
+        .chain(iter::repeat(' '))
+        .take(position.character)
+        .map(char::len_utf16)
+        .sum()
+}
+
+fn severity_name(severity: DiagnosticSeverity) -> &'static str {
+    match severity {
+        DiagnosticSeverity::Error => "error",
+        DiagnosticSeverity::Warning => "warning",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostic() -> Diagnostic {
+        let range = Position {
+            line: 2,
+            character: 7,
+        }..Position {
+            line: 2,
+            character: 12,
+        };
+        Diagnostic::new(DiagnosticCode::UnknownNode, "No node named \"Strat\"")
+            .with_file_name("start.yarn")
+            .with_severity(DiagnosticSeverity::Error)
+            .with_range(range.clone())
+            .with_fix(DiagnosticFix {
+                description: "Replace with Start".to_owned(),
+                edits: vec![DiagnosticEdit {
+                    range,
+                    new_text: "Start".to_owned(),
+                }],
+            })
+    }
+
+    #[test]
+    fn renders_json() {
+        let warning = Diagnostic::new(DiagnosticCode::UnknownCommand, "Tab\tand\nnewline")
+            .with_severity(DiagnosticSeverity::Warning);
+        let range = r#"{"start":{"line":2,"character":7},"end":{"line":2,"character":12}}"#;
+        assert_eq!(
+            format!(
+                r#"[{{"code":"YS0009","severity":"error","message":"No node named \"Strat\"","file":"start.yarn","range":{range},"fixes":[{{"description":"Replace with Start","edits":[{{"range":{range},"newText":"Start"}}]}}]}},{{"code":"YS0032","severity":"warning","message":"Tab\tand\nnewline","file":null,"range":null,"fixes":[]}}]"#
+            ),
+            render_json(&[diagnostic(), warning])
+        );
+    }
+
+    #[test]
+    fn renders_sarif() {
+        let sarif = render_sarif(&[diagnostic()]);
+        let region = r#"{"startLine":3,"startColumn":8,"endLine":3,"endColumn":13}"#;
+        assert!(sarif.starts_with(r#"{"$schema":"https://json.schemastore.org/sarif-2.1.0.json","version":"2.1.0","runs":[{"tool":{"driver":{"name":"Yarn Spinner for Rust","#));
+        assert!(sarif.contains(r#""rules":[{"id":"YS0009","name":"UnknownNode","shortDescription":{"text":"unknown node"},"defaultConfiguration":{"level":"error"}}]"#));
+        assert!(sarif.contains(&format!(
+            r#""results":[{{"ruleId":"YS0009","ruleIndex":0,"level":"error","message":{{"text":"No node named \"Strat\""}},"locations":[{{"physicalLocation":{{"artifactLocation":{{"uri":"start.yarn"}},"region":{region}}}}}],"fixes":[{{"description":{{"text":"Replace with Start"}},"artifactChanges":[{{"artifactLocation":{{"uri":"start.yarn"}},"replacements":[{{"deletedRegion":{region},"insertedContent":{{"text":"Start"}}}}]}}]}}]}}]"#
+        )));
+    }
+
+    #[test]
+    fn renders_sarif_columns_in_utf16_code_units() {
+        let diagnostic = diagnostic()
+            .with_context("title: Start\n---\n👋 jump Strat\n===")
+            .with_start_line(0);
+        let sarif = render_sarif(&[diagnostic]);
+        // The emoji is one character, but two UTF-16 code units
+        assert!(sarif
+            .contains(r#""region":{"startLine":3,"startColumn":9,"endLine":3,"endColumn":14}"#));
+    }
+}
@@ -0,0 +1,648 @@
+//! Simplifies the bytecode emitted by the [`CodeGenerationVisitor`](crate::visitors::CodeGenerationVisitor),
+//! which translates every statement on its own and thus leaves constant expressions, branches that can never be taken
+//! and chains of jumps in the [`Program`]. See [`Compiler::with_optimization`].
+//!
+//! The optimizations never change what a dialogue does, only how many instructions the VM runs to do it.
+//! Instructions keep their position in [`DebugInfo::line_positions`] when they are moved,
+//! and the instructions that replace folded expressions get the position of the expression.
+
+use crate::prelude::*;
+use std::any::TypeId;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use yarnspinner_core::prelude::*;
+use yarnspinner_core::types::Type;
+
+/// Optimizes every node of `program` in place, updating the positions of its instructions in `debug_infos`.
+pub(crate) fn optimize_program(
+    program: &mut Program,
+    debug_infos: &mut HashMap<String, DebugInfo>,
+) {
+    let optimizer = Optimizer::new();
+    for node in program.nodes.values_mut() {
+        let debug_info = debug_infos.get_mut(&node.name);
+        optimizer.optimize_node(node, debug_info);
+    }
+}
+
+/// An upper bound for how often the optimizations are repeated on a node, as one optimization
+/// can enable another one. Real nodes settle after a few rounds.
+const MAX_ROUNDS: usize = 16;
+
+struct Optimizer {
+    library: Library,
+}
+
+/// The prefixes of the operators of the built-in types, which are the only functions
+/// that are known to always return the same result for the same parameters.
+const PURE_FUNCTION_PREFIXES: [&str; 3] = ["Number.", "String.", "Bool."];
+
+impl Optimizer {
+    fn new() -> Self {
+        Self {
+            library: Library::standard_library(),
+        }
+    }
+
+    fn optimize_node(&self, node: &mut Node, debug_info: Option<&mut DebugInfo>) {
+        let mut code = Code::from_node(node, debug_info.as_deref());
+        for _ in 0..MAX_ROUNDS {
+            let changed = self.fold_constants(&mut code)
+                | eliminate_constant_branches(&mut code)
+                | thread_jumps(&mut code)
+                | remove_jumps_to_next_instruction(&mut code)
+                | remove_unreachable_instructions(&mut code);
+            if !changed {
+                break;
+            }
+        }
+        remove_unused_labels(&mut code);
+        code.write_to(node, debug_info);
+    }
+
+    /// Replaces calls to operators whose parameters are all constants, like `1 + 3`, with their result.
+    fn fold_constants(&self, code: &mut Code) -> bool {
+        let mut changed = false;
+        let mut index = 0;
+        while index < code.items.len() {
+            if let Some((start, result)) = self.evaluate_call(code, index) {
+                code.items[start].instruction = push_instruction(result);
+                code.items.drain(start + 1..=index);
+                index = start;
+                changed = true;
+            }
+            index += 1;
+        }
+        changed
+    }
+
+    /// If the instruction at `index` calls an operator on constants, returns the index of the first constant and the result.
+    fn evaluate_call(&self, code: &Code, index: usize) -> Option<(usize, YarnValue)> {
+        let call = &code.items[index].instruction;
+        if call.opcode() != OpCode::CallFunc {
+            return None;
+        }
+        let name = string_operand(call, 0)?;
+        if !PURE_FUNCTION_PREFIXES
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+        {
+            return None;
+        }
+        let function = self.library.get(name)?;
+        let parameter_types = function.parameter_types();
+        let parameter_count = parameter_types.len();
+        let start = index.checked_sub(parameter_count + 1)?;
+        // Jumping into the middle of the expression would skip parts of it
+        if code.items[start + 1..=index]
+            .iter()
+            .any(|item| !item.labels.is_empty())
+        {
+            return None;
+        }
+        let count = &code.items[index - 1].instruction;
+        if count.opcode() != OpCode::PushFloat
+            || float_operand(count, 0) != Some(parameter_count as f32)
+        {
+            return None;
+        }
+        let parameters = code.items[start..index - 1]
+            .iter()
+            .zip(parameter_types)
+            .map(|(item, parameter_type)| constant_value(&item.instruction, parameter_type))
+            .collect::<Option<Vec<_>>>()?;
+        Some((start, function.call(parameters)))
+    }
+}
+
+/// Removes the checks of `if` statements whose condition is always true and skips the statements whose condition is always false.
+///
+/// The code generator emits `<condition> JUMP_IF_FALSE else; <then> JUMP_TO endif; else: POP; <else>`,
+/// since [`OpCode::JumpIfFalse`] leaves the condition on the stack. The stack is left exactly as it would have been.
+fn eliminate_constant_branches(code: &mut Code) -> bool {
+    let mut changed = false;
+    let mut index = 0;
+    while index + 1 < code.items.len() {
+        let condition = &code.items[index].instruction;
+        let jump = &code.items[index + 1];
+        let (OpCode::PushBool, Some(condition), OpCode::JumpIfFalse, true) = (
+            condition.opcode(),
+            bool_operand(condition, 0),
+            jump.instruction.opcode(),
+            jump.labels.is_empty(),
+        ) else {
+            index += 1;
+            continue;
+        };
+        let Some(target) = string_operand(&jump.instruction, 0).map(ToOwned::to_owned) else {
+            index += 1;
+            continue;
+        };
+        if condition {
+            // Never jumps. If the condition is popped right away, it doesn't need to be pushed at all.
+            let pop = code.items.get(index + 2);
+            if pop
+                .is_some_and(|pop| pop.instruction.opcode() == OpCode::Pop && pop.labels.is_empty())
+            {
+                code.remove(index + 2);
+                code.remove(index + 1);
+                code.remove(index);
+            } else {
+                code.remove(index + 1);
+            }
+        } else {
+            // Always jumps. If the jump target pops the condition, jump right past it instead.
+            match code.label_index(&target) {
+                Some(target_index)
+                    if code.items[target_index].instruction.opcode() == OpCode::Pop =>
+                {
+                    let label = code.label_for(target_index + 1);
+                    code.items[index].instruction = jump_instruction(OpCode::JumpTo, label);
+                    code.remove(index + 1);
+                }
+                _ => {
+                    code.items[index + 1].instruction = jump_instruction(OpCode::JumpTo, target);
+                }
+            }
+        }
+        changed = true;
+    }
+    changed
+}
+
+/// Makes jumps to other jumps go to their final destination right away.
+fn thread_jumps(code: &mut Code) -> bool {
+    let mut changed = false;
+    for index in 0..code.items.len() {
+        let instruction = &code.items[index].instruction;
+        let opcode = instruction.opcode();
+        if !matches!(opcode, OpCode::JumpTo | OpCode::JumpIfFalse) {
+            continue;
+        }
+        let Some(target) = string_operand(instruction, 0) else {
+            continue;
+        };
+        let Some(destination) = final_destination(code, opcode, target) else {
+            continue;
+        };
+        if destination != target {
+            code.items[index].instruction = jump_instruction(opcode, destination);
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Follows the jumps starting at `label`. Returns [`None`] for jumps that loop forever.
+fn final_destination(code: &Code, opcode: OpCode, label: &str) -> Option<String> {
+    let mut visited = HashSet::new();
+    let mut label = label.to_owned();
+    loop {
+        if !visited.insert(label.clone()) {
+            return None;
+        }
+        let next = code
+            .label_index(&label)
+            .and_then(|index| code.items.get(index))
+            .map(|item| &item.instruction)
+            // A failed conditional jump leaves `false` on the stack, so the next conditional jump fails too
+            .filter(|next| {
+                next.opcode() == OpCode::JumpTo
+                    || opcode == OpCode::JumpIfFalse && next.opcode() == OpCode::JumpIfFalse
+            })
+            .and_then(|next| string_operand(next, 0));
+        match next {
+            Some(next) => label = next.to_owned(),
+            None => return Some(label),
+        }
+    }
+}
+
+/// Removes unconditional jumps to the instruction right after them.
+fn remove_jumps_to_next_instruction(code: &mut Code) -> bool {
+    let mut changed = false;
+    let mut index = 0;
+    while index < code.items.len() {
+        let instruction = &code.items[index].instruction;
+        let is_jump_to_next = instruction.opcode() == OpCode::JumpTo
+            && string_operand(instruction, 0).and_then(|target| code.label_index(target))
+                == Some(index + 1);
+        if is_jump_to_next {
+            code.remove(index);
+            changed = true;
+        } else {
+            index += 1;
+        }
+    }
+    changed
+}
+
+/// Removes instructions that can't be reached from the start of the node or from any label that is referred to.
+fn remove_unreachable_instructions(code: &mut Code) -> bool {
+    let mut reachable = vec![false; code.items.len()];
+    let mut pending = vec![0];
+    // Labels can also be jumped to dynamically, e.g. by `JUMP` after options were shown.
+    // Any instruction that mentions a label keeps it alive.
+    for item in &code.items {
+        let opcode = item.instruction.opcode();
+        if matches!(opcode, OpCode::JumpTo | OpCode::JumpIfFalse) {
+            continue;
+        }
+        for operand in &item.instruction.operands {
+            if let Some(OperandValue::StringValue(string)) = &operand.value {
+                pending.extend(code.label_index(string));
+            }
+        }
+    }
+    while let Some(index) = pending.pop() {
+        let Some(item) = code.items.get(index) else {
+            continue;
+        };
+        if std::mem::replace(&mut reachable[index], true) {
+            continue;
+        }
+        let instruction = &item.instruction;
+        let target = || string_operand(instruction, 0).and_then(|label| code.label_index(label));
+        match instruction.opcode() {
+            OpCode::JumpTo => pending.extend(target()),
+            OpCode::JumpIfFalse => {
+                pending.extend(target());
+                pending.push(index + 1);
+            }
+            OpCode::Jump | OpCode::Stop | OpCode::Return | OpCode::RunNode => {}
+            _ => pending.push(index + 1),
+        }
+    }
+    let mut changed = false;
+    for index in (0..code.items.len()).rev() {
+        if !reachable[index] {
+            code.remove(index);
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Removes labels that no instruction refers to.
+fn remove_unused_labels(code: &mut Code) {
+    let used: HashSet<String> = code
+        .items
+        .iter()
+        .flat_map(|item| &item.instruction.operands)
+        .filter_map(|operand| match &operand.value {
+            Some(OperandValue::StringValue(string)) => Some(string.clone()),
+            _ => None,
+        })
+        .collect();
+    let labels = code
+        .items
+        .iter_mut()
+        .map(|item| &mut item.labels)
+        .chain([&mut code.trailing_labels]);
+    for labels in labels {
+        labels.retain(|label| used.contains(label));
+    }
+}
+
+/// The instructions of a node, with their labels and positions attached so that they move together.
+#[derive(Debug, Clone, PartialEq, Default)]
+struct Code {
+    items: Vec<Item>,
+    /// The labels that point past the last instruction.
+    trailing_labels: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Item {
+    instruction: Instruction,
+    labels: Vec<String>,
+    /// The entry of the instruction in [`DebugInfo::line_positions`], if it has one.
+    position: Option<Option<Position>>,
+}
+
+impl Code {
+    fn from_node(node: &Node, debug_info: Option<&DebugInfo>) -> Self {
+        let mut labels: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+        for (label, index) in &node.labels {
+            labels
+                .entry(*index as usize)
+                .or_default()
+                .push(label.clone());
+        }
+        let items = node
+            .instructions
+            .iter()
+            .enumerate()
+            .map(|(index, instruction)| Item {
+                instruction: instruction.clone(),
+                labels: labels.remove(&index).unwrap_or_default(),
+                position: debug_info
+                    .and_then(|debug_info| debug_info.line_positions.get(&index).copied()),
+            })
+            .collect();
+        Self {
+            items,
+            trailing_labels: labels.into_values().flatten().collect(),
+        }
+    }
+
+    fn write_to(self, node: &mut Node, debug_info: Option<&mut DebugInfo>) {
+        let instruction_count = self.items.len();
+        node.labels.clear();
+        node.instructions.clear();
+        let mut line_positions = HashMap::new();
+        for (index, item) in self.items.into_iter().enumerate() {
+            for label in item.labels {
+                node.labels.insert(label, index as i32);
+            }
+            if let Some(position) = item.position {
+                line_positions.insert(index, position);
+            }
+            node.instructions.push(item.instruction);
+        }
+        for label in self.trailing_labels {
+            node.labels.insert(label, instruction_count as i32);
+        }
+        if let Some(debug_info) = debug_info {
+            debug_info.line_positions = line_positions;
+        }
+    }
+
+    /// The index of the instruction that `label` points to, which is the number of instructions if it points past the last one.
+    fn label_index(&self, label: &str) -> Option<usize> {
+        if self.trailing_labels.iter().any(|other| other == label) {
+            return Some(self.items.len());
+        }
+        self.items
+            .iter()
+            .position(|item| item.labels.iter().any(|other| other == label))
+    }
+
+    /// Returns a label that points to the instruction at `index`, adding one if there is none yet.
+    fn label_for(&mut self, index: usize) -> String {
+        let existing = match self.items.get(index) {
+            Some(item) => item.labels.first(),
+            None => self.trailing_labels.first(),
+        };
+        if let Some(label) = existing {
+            return label.clone();
+        }
+        let label = (0..)
+            .map(|number| format!("L{number}optimized"))
+            .find(|label| self.label_index(label).is_none())
+            .unwrap();
+        match self.items.get_mut(index) {
+            Some(item) => item.labels.push(label.clone()),
+            None => self.trailing_labels.push(label.clone()),
+        }
+        label
+    }
+
+    /// Removes the instruction at `index`. Its labels then point to the instruction after it.
+    fn remove(&mut self, index: usize) {
+        let item = self.items.remove(index);
+        let next_labels = match self.items.get_mut(index) {
+            Some(next) => &mut next.labels,
+            None => &mut self.trailing_labels,
+        };
+        next_labels.splice(0..0, item.labels);
+    }
+}
+
+fn string_operand(instruction: &Instruction, index: usize) -> Option<&str> {
+    match instruction.operands.get(index)?.value.as_ref()? {
+        OperandValue::StringValue(value) => Some(value),
+        _ => None,
+    }
+}
+
+fn float_operand(instruction: &Instruction, index: usize) -> Option<f32> {
+    match instruction.operands.get(index)?.value.as_ref()? {
+        OperandValue::FloatValue(value) => Some(*value),
+        _ => None,
+    }
+}
+
+fn bool_operand(instruction: &Instruction, index: usize) -> Option<bool> {
+    match instruction.operands.get(index)?.value.as_ref()? {
+        OperandValue::BoolValue(value) => Some(*value),
+        _ => None,
+    }
+}
+
+/// The value that `instruction` pushes, if it pushes a constant that can be passed as a parameter of the given type.
+fn constant_value(instruction: &Instruction, parameter_type: TypeId) -> Option<YarnValue> {
+    let value = match (instruction.opcode(), Type::try_from(parameter_type).ok()?) {
+        (OpCode::PushFloat, Type::Number) => YarnValue::Number(float_operand(instruction, 0)?),
+        (OpCode::PushString, Type::String) => {
+            YarnValue::String(string_operand(instruction, 0)?.to_owned())
+        }
+        (OpCode::PushBool, Type::Boolean) => YarnValue::Boolean(bool_operand(instruction, 0)?),
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn push_instruction(value: YarnValue) -> Instruction {
+    let (opcode, operand) = match value {
+        YarnValue::Number(value) => (OpCode::PushFloat, Operand::from(value)),
+        YarnValue::String(value) => (OpCode::PushString, Operand::from(value)),
+        YarnValue::Boolean(value) => (OpCode::PushBool, Operand::from(value)),
+    };
+    Instruction {
+        opcode: opcode.into(),
+        operands: vec![operand],
+    }
+}
+
+fn jump_instruction(opcode: OpCode, label: String) -> Instruction {
+    Instruction {
+        opcode: opcode.into(),
+        operands: vec![Operand::from(label)],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compile(source: &str, optimize: bool) -> (Compilation, File) {
+        let file = File {
+            file_name: "test.yarn".to_owned(),
+            source: source.to_owned(),
+        };
+        let compilation = Compiler::new()
+            .add_file(file.clone())
+            .with_optimization(optimize)
+            .compile()
+            .unwrap();
+        (compilation, file)
+    }
+
+    fn disassemble(source: &str) -> String {
+        let (compilation, file) = compile(source, true);
+        compilation
+            .program
+            .unwrap()
+            .disassemble_with_sources(&compilation.debug_info, &[file])
+    }
+
+    fn optimize(disassembly: &str) -> String {
+        let mut program = Program::from_disassembly(disassembly).unwrap();
+        optimize_program(&mut program, &mut HashMap::new());
+        program.disassemble(&HashMap::new())
+    }
+
+    #[test]
+    fn folds_constant_expressions() {
+        let source = "title: Start
+---
+<<declare $name = \"\">>
+{1 + 3 * 2} {\"a\" + \"b\"} {!true} {$name + \"!\"}
+===
+";
+        assert_eq!(
+            r#"initial $name = ""
+
+node Start
+    header "title" "Start"
+    ; test.yarn:4: {1 + 3 * 2} {"a" + "b"} {!true} {$name + "!"}
+    0    PUSH_FLOAT 7
+    1    PUSH_STRING "ab"
+    2    PUSH_BOOL false
+    3    PUSH_VARIABLE "$name"
+    4    PUSH_STRING "!"
+    5    PUSH_FLOAT 2
+    6    CALL_FUNC "String.Add"
+    7    RUN_LINE "line:test.yarn-Start-0" 4
+    8    STOP
+"#,
+            disassemble(source)
+        );
+    }
+
+    #[test]
+    fn removes_branches_that_are_never_taken() {
+        let source = "title: Start
+---
+<<if true>>
+    Always
+<<else>>
+    Never
+<<endif>>
+<<if 1 > 2>>
+    Never either
+<<endif>>
+Done
+===
+";
+        assert_eq!(
+            r#"node Start
+    header "title" "Start"
+    ; test.yarn:3: <<if true>>
+    0    PUSH_BOOL true
+    ; test.yarn:4: Always
+    1    RUN_LINE "line:test.yarn-Start-0" 0
+    ; test.yarn:11: Done
+    2    RUN_LINE "line:test.yarn-Start-3" 0
+    3    STOP
+"#,
+            disassemble(source)
+        );
+    }
+
+    #[test]
+    fn threads_jumps() {
+        let optimized = optimize(
+            r#"node Start
+    JUMP_TO "A"
+    PUSH_STRING "Start"
+A:
+    JUMP_TO "B"
+B:
+    PUSH_VARIABLE "$x"
+    JUMP_IF_FALSE "C"
+    JUMP_TO "Start"
+C:
+    JUMP_IF_FALSE "D"
+    STOP
+D:
+    JUMP_TO "E"
+E:
+    POP
+    STOP
+"#,
+        );
+        assert_eq!(
+            r#"node Start
+    0    PUSH_VARIABLE "$x"
+    1    JUMP_IF_FALSE "E"
+    2    JUMP_TO "Start"
+E:
+    3    POP
+    4    STOP
+"#,
+            optimized
+        );
+    }
+
+    #[test]
+    fn keeps_endless_loops_and_dynamic_jump_targets() {
+        let optimized = optimize(
+            r#"node Start
+    ADD_OPTION "line:a" "Chosen" 0 false
+    SHOW_OPTIONS
+    JUMP
+Chosen:
+    JUMP_TO "Loop"
+Loop:
+    JUMP_TO "Chosen"
+"#,
+        );
+        assert_eq!(
+            r#"node Start
+    0    ADD_OPTION "line:a" "Chosen" 0 false
+    1    SHOW_OPTIONS
+    2    JUMP
+Chosen:
+    3    JUMP_TO "Chosen"
+"#,
+            optimized
+        );
+    }
+
+    #[test]
+    fn behaves_like_unoptimized_code() {
+        let source = "title: Start
+---
+<<declare $gold = 10>>
+<<if $gold > 5 and true>>
+    Rich
+<<elseif false>>
+    Impossible
+<<else>>
+    Poor
+<<endif>>
+-> Buy {2 * 5}
+    <<set $gold -= 2 * 5>>
+-> Leave <<if false>>
+{$gold}
+===
+";
+        let (unoptimized, _) = compile(source, false);
+        let (optimized, _) = compile(source, true);
+        let count = |compilation: &Compilation| {
+            compilation.program.as_ref().unwrap().nodes["Start"]
+                .instructions
+                .len()
+        };
+        assert!(count(&optimized) < count(&unoptimized));
+        assert_eq!(unoptimized.string_table, optimized.string_table);
+        assert_eq!(
+            unoptimized.program.as_ref().unwrap().initial_values,
+            optimized.program.as_ref().unwrap().initial_values
+        );
+        // Every instruction still has a line
+        let debug_info = &optimized.debug_info["Start"];
+        assert_eq!(count(&optimized), debug_info.line_positions.len());
+    }
+}
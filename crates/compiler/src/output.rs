@@ -1,7 +1,7 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/CompilationResult.cs>
 
 use crate::listeners::*;
-pub use crate::output::{debug_info::*, declaration::*, string_info::*};
+pub use crate::output::{debug_info::*, declaration::*, disassembly::*, string_info::*};
 use crate::prelude::*;
 use std::collections::HashMap;
 use std::error::Error;
@@ -10,6 +10,7 @@ use yarnspinner_core::prelude::*;
 
 mod debug_info;
 mod declaration;
+mod disassembly;
 mod string_info;
 
 /// The result of a compilation.
@@ -89,6 +90,29 @@ pub struct Compilation {
 }
 
 impl Compilation {
+    /// The name, type and default value of every variable the program keeps in its `VariableStorage`,
+    /// i.e. all [`Compilation::declarations`] except for functions, smart variables and node-local variables.
+    ///
+    /// Pass them to `TypedVariableStorage::new` to reject values that don't match these declarations.
+    pub fn stored_variable_declarations(
+        &self,
+    ) -> impl Iterator<Item = (String, Type, Option<YarnValue>)> + '_ {
+        self.declarations
+            .iter()
+            .filter(|declaration| {
+                !declaration.is_smart
+                    && !declaration.is_local
+                    && !matches!(declaration.r#type, Type::Function(_))
+            })
+            .map(|declaration| {
+                (
+                    declaration.name.clone(),
+                    declaration.r#type.clone(),
+                    declaration.default_value.clone(),
+                )
+            })
+    }
+
     /// Combines multiple [`CompilationResult`] objects together into one object.
     pub(crate) fn combine(
         compilations: impl Iterator<Item = Compilation>,
@@ -121,6 +145,26 @@ impl Compilation {
     }
 }
 
+/// The result of [`Compiler::compile_partial`].
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default))]
+#[cfg_attr(
+    all(feature = "bevy", feature = "serde"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct PartialCompilation {
+    /// The result of compiling everything except for the excluded nodes.
+    pub compilation: Compilation,
+
+    /// The errors that caused nodes to be excluded.
+    pub errors: Vec<Diagnostic>,
+
+    /// The titles of the nodes that were excluded because they, or the files they are in, had errors.
+    pub excluded_nodes: Vec<String>,
+}
+
 /// A collection of [`Diagnostic`] objects that describe problems that occurred during compilation.
 /// At least one of these diagnostics will have a severity of [`DiagnosticSeverity::Error`].
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -56,6 +56,20 @@ pub struct Declaration {
     /// If `false`, this declaration appears in the source code.
     pub is_implicit: bool,
 
+    /// A value indicating whether this declaration was made with `<<local>>`.
+    ///
+    /// If `true`, the variable only exists while the node named in
+    /// [`Declaration::source_node_name`] is running, and is never
+    /// written to a variable storage.
+    pub is_local: bool,
+
+    /// A value indicating whether this declaration is a smart variable,
+    /// i.e. was declared with an expression like `<<declare $can_afford = $gold >= $price>>`.
+    ///
+    /// If `true`, the variable is read-only, has no [`Declaration::default_value`]
+    /// and is recomputed from its expression every time it is read.
+    pub is_smart: bool,
+
     /// The type of the variable, as represented by an object found
     /// in a variant of [`Type`].
     pub r#type: Type,
@@ -89,6 +103,8 @@ impl Declaration {
             source_file_name: Default::default(),
             source_node_name: Default::default(),
             is_implicit: Default::default(),
+            is_local: Default::default(),
+            is_smart: Default::default(),
             range: Default::default(),
         }
     }
@@ -150,6 +166,18 @@ impl Declaration {
         self
     }
 
+    #[doc(hidden)]
+    pub fn with_local(mut self) -> Self {
+        self.is_local = true;
+        self
+    }
+
+    #[doc(hidden)]
+    pub fn with_smart(mut self) -> Self {
+        self.is_smart = true;
+        self
+    }
+
     #[doc(hidden)]
     pub fn with_range(mut self, range: impl Into<Range<Position>>) -> Self {
         self.range = Some(range.into());
@@ -163,6 +191,8 @@ impl Declaration {
             && self.source_file_name == other.source_file_name
             && self.source_node_name == other.source_node_name
             && self.is_implicit == other.is_implicit
+            && self.is_local == other.is_local
+            && self.is_smart == other.is_smart
             && self.r#type == other.r#type
             && self.range == other.range
             && match (&self.default_value, &other.default_value) {
@@ -0,0 +1,521 @@
+//! A human readable text form of [`Program`]s, see [`ProgramDisassembly`].
+
+use crate::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter, Write};
+use yarnspinner_core::prelude::*;
+
+/// Converts a [`Program`] to and from a readable text form, which is useful for debugging the compiler and the VM.
+///
+/// The text form of a program with a single node looks like this:
+///
+/// ```text
+/// initial $gold = 10
+///
+/// node Start
+///     header "title" "Start"
+/// L0:
+///     ; start.yarn:3: Hello there! #line:hello
+///     0    RUN_LINE "line:hello" 0
+///     ; start.yarn:4: <<jump Shop>>
+///     1    PUSH_STRING "Shop"
+///     2    RUN_NODE
+///     3    STOP
+/// ```
+///
+/// - `program "NAME"` sets the name of the program, if it has one.
+/// - `initial $VARIABLE = VALUE` sets the initial value of a variable.
+/// - `node NAME` starts a node. It is followed by its `header "KEY" "VALUE"`, `tag "TAG"` and `source_text_string_id "ID"` lines.
+/// - `LABEL:` marks the position of a label in the current node.
+/// - Instructions are written as their [`OpCode`] mnemonic followed by their operands.
+///   String operands are quoted, numbers and booleans are written as is and missing operands as `null`.
+///   The instruction's index in the node may be written before it.
+/// - Everything after a `;` is a comment. The disassembler uses comments to show where instructions come from.
+pub trait ProgramDisassembly: Sized {
+    /// Returns the text form of the program, with a comment before the instructions of every source line that
+    /// shows the file name and line number according to the [`DebugInfo::line_positions`] in `debug_info`,
+    /// which maps node names to their debug info like [`Compilation::debug_info`].
+    fn disassemble(&self, debug_info: &HashMap<String, DebugInfo>) -> String;
+
+    /// Like [`ProgramDisassembly::disassemble`], but the comments also show the text of the source lines,
+    /// which are looked up in `files`.
+    fn disassemble_with_sources(
+        &self,
+        debug_info: &HashMap<String, DebugInfo>,
+        files: &[File],
+    ) -> String;
+
+    /// Parses the text form of a program, e.g. one written by [`ProgramDisassembly::disassemble`] or by hand in a test.
+    fn from_disassembly(text: &str) -> Result<Self, DisassemblyError>;
+}
+
+impl ProgramDisassembly for Program {
+    fn disassemble(&self, debug_info: &HashMap<String, DebugInfo>) -> String {
+        self.disassemble_with_sources(debug_info, &[])
+    }
+
+    fn disassemble_with_sources(
+        &self,
+        debug_info: &HashMap<String, DebugInfo>,
+        files: &[File],
+    ) -> String {
+        let mut text = String::new();
+        if !self.name.is_empty() {
+            writeln!(text, "program {}", quote(&self.name)).unwrap();
+        }
+        for (variable, value) in &self.initial_values {
+            writeln!(text, "initial {variable} = {}", format_operand(value)).unwrap();
+        }
+        for node in self.nodes.values() {
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            let debug_info = debug_info.get(&node.name);
+            let source = debug_info.and_then(|debug_info| {
+                files
+                    .iter()
+                    .find(|file| file.file_name == debug_info.file_name)
+                    .map(|file| file.source.lines().collect::<Vec<_>>())
+            });
+            write_node(&mut text, node, debug_info, source.as_deref());
+        }
+        text
+    }
+
+    fn from_disassembly(text: &str) -> Result<Self, DisassemblyError> {
+        Parser::default().parse(text)
+    }
+}
+
+fn write_node(
+    text: &mut String,
+    node: &Node,
+    debug_info: Option<&DebugInfo>,
+    source: Option<&[&str]>,
+) {
+    writeln!(text, "node {}", node.name).unwrap();
+    for header in &node.headers {
+        writeln!(
+            text,
+            "    header {} {}",
+            quote(&header.key),
+            quote(&header.value)
+        )
+        .unwrap();
+    }
+    for tag in &node.tags {
+        writeln!(text, "    tag {}", quote(tag)).unwrap();
+    }
+    if !node.source_text_string_id.is_empty() {
+        writeln!(
+            text,
+            "    source_text_string_id {}",
+            quote(&node.source_text_string_id)
+        )
+        .unwrap();
+    }
+
+    let mut labels: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+    for (label, index) in &node.labels {
+        labels
+            .entry(*index as usize)
+            .or_default()
+            .push(label.as_str());
+    }
+    let mut last_line = None;
+    for index in 0..=node.instructions.len() {
+        for label in labels.get(&index).into_iter().flatten() {
+            writeln!(text, "{label}:").unwrap();
+        }
+        let Some(instruction) = node.instructions.get(index) else {
+            break;
+        };
+        let position = debug_info.and_then(|debug_info| {
+            debug_info
+                .line_positions
+                .get(&index)
+                .copied()
+                .flatten()
+                .map(|position| (debug_info, position))
+        });
+        if let Some((debug_info, position)) = position {
+            if last_line != Some(position.line) {
+                last_line = Some(position.line);
+                write!(text, "    ; {}:{}", debug_info.file_name, position.line + 1).unwrap();
+                match source.and_then(|source| source.get(position.line)) {
+                    Some(line) => writeln!(text, ": {}", line.trim()).unwrap(),
+                    None => text.push('\n'),
+                }
+            }
+        }
+        write!(
+            text,
+            "    {index:<4} {}",
+            instruction.opcode().as_str_name()
+        )
+        .unwrap();
+        for operand in &instruction.operands {
+            write!(text, " {}", format_operand(operand)).unwrap();
+        }
+        text.push('\n');
+    }
+}
+
+fn format_operand(operand: &Operand) -> String {
+    match &operand.value {
+        Some(OperandValue::StringValue(value)) => quote(value),
+        Some(OperandValue::FloatValue(value)) => value.to_string(),
+        Some(OperandValue::BoolValue(value)) => value.to_string(),
+        None => "null".to_owned(),
+    }
+}
+
+fn quote(string: &str) -> String {
+    let mut quoted = String::from('"');
+    for char in string.chars() {
+        match char {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            char => quoted.push(char),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// The text passed to [`ProgramDisassembly::from_disassembly`] is not a valid program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisassemblyError {
+    /// The one-based number of the line that could not be parsed.
+    pub line: usize,
+    /// What is wrong with the line.
+    pub message: String,
+}
+
+impl Error for DisassemblyError {}
+
+impl Display for DisassemblyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid disassembly in line {}: {}",
+            self.line, self.message
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Word {
+    Bare(String),
+    Quoted(String),
+}
+
+#[derive(Debug, Default)]
+struct Parser {
+    program: Program,
+    node: Option<Node>,
+}
+
+impl Parser {
+    fn parse(mut self, text: &str) -> Result<Program, DisassemblyError> {
+        for (index, line) in text.lines().enumerate() {
+            self.parse_line(line).map_err(|message| DisassemblyError {
+                line: index + 1,
+                message,
+            })?;
+        }
+        self.finish_node();
+        Ok(self.program)
+    }
+
+    fn parse_line(&mut self, line: &str) -> Result<(), String> {
+        let words = split_words(line)?;
+        let Some((Word::Bare(first), rest)) = words.split_first() else {
+            return match words.first() {
+                None => Ok(()),
+                Some(_) => Err("Expected a keyword, label or instruction".to_owned()),
+            };
+        };
+        match (first.as_str(), rest) {
+            ("program", [Word::Quoted(name)]) => self.program.name = name.clone(),
+            ("initial", [Word::Bare(variable), Word::Bare(equals), value]) if equals == "=" => {
+                let value = parse_operand(value)?;
+                self.program.initial_values.insert(variable.clone(), value);
+            }
+            ("node", [Word::Bare(name)]) => {
+                self.finish_node();
+                if self.program.nodes.contains_key(name) {
+                    return Err(format!("Node {name} is defined more than once"));
+                }
+                self.node = Some(Node {
+                    name: name.clone(),
+                    ..Default::default()
+                });
+            }
+            ("header", [Word::Quoted(key), Word::Quoted(value)]) => {
+                self.current_node()?.headers.push(Header {
+                    key: key.clone(),
+                    value: value.clone(),
+                });
+            }
+            ("tag", [Word::Quoted(tag)]) => self.current_node()?.tags.push(tag.clone()),
+            ("source_text_string_id", [Word::Quoted(id)]) => {
+                self.current_node()?.source_text_string_id = id.clone();
+            }
+            (label, []) if label.len() > 1 && label.ends_with(':') => {
+                let node = self.current_node()?;
+                let label = &label[..label.len() - 1];
+                let index = node.instructions.len() as i32;
+                if node.labels.insert(label.to_owned(), index).is_some() {
+                    return Err(format!("Label {label} is defined more than once"));
+                }
+            }
+            (index, [Word::Bare(mnemonic), operands @ ..]) if index.parse::<usize>().is_ok() => {
+                let expected = self.current_node()?.instructions.len();
+                if index.parse::<usize>() != Ok(expected) {
+                    return Err(format!("Expected instruction {expected}, found {index}"));
+                }
+                self.push_instruction(mnemonic, operands)?;
+            }
+            (mnemonic, operands) => self.push_instruction(mnemonic, operands)?,
+        }
+        Ok(())
+    }
+
+    fn push_instruction(&mut self, mnemonic: &str, operands: &[Word]) -> Result<(), String> {
+        let opcode = OpCode::from_str_name(mnemonic)
+            .ok_or_else(|| format!("Unknown instruction {mnemonic}"))?;
+        let operands = operands
+            .iter()
+            .map(parse_operand)
+            .collect::<Result<Vec<_>, _>>()?;
+        self.current_node()?.instructions.push(Instruction {
+            opcode: opcode.into(),
+            operands,
+        });
+        Ok(())
+    }
+
+    fn current_node(&mut self) -> Result<&mut Node, String> {
+        self.node
+            .as_mut()
+            .ok_or_else(|| "Expected a node first".to_owned())
+    }
+
+    fn finish_node(&mut self) {
+        if let Some(node) = self.node.take() {
+            self.program.nodes.insert(node.name.clone(), node);
+        }
+    }
+}
+
+fn parse_operand(word: &Word) -> Result<Operand, String> {
+    let value = match word {
+        Word::Quoted(string) => OperandValue::StringValue(string.clone()),
+        Word::Bare(word) => match word.as_str() {
+            "null" => return Ok(Operand { value: None }),
+            "true" => OperandValue::BoolValue(true),
+            "false" => OperandValue::BoolValue(false),
+            number => OperandValue::FloatValue(
+                number
+                    .parse()
+                    .map_err(|_| format!("Invalid operand {number}"))?,
+            ),
+        },
+    };
+    Ok(Operand { value: Some(value) })
+}
+
+/// Splits a line into whitespace separated words, dropping comments.
+fn split_words(line: &str) -> Result<Vec<Word>, String> {
+    let mut words = Vec::new();
+    let mut chars = line.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            ';' => break,
+            '"' => {
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => string.push(match chars.next() {
+                            Some('"') => '"',
+                            Some('\\') => '\\',
+                            Some('n') => '\n',
+                            Some('r') => '\r',
+                            Some('t') => '\t',
+                            Some(other) => {
+                                return Err(format!("Unknown escape sequence \\{other}"))
+                            }
+                            None => return Err("Unterminated string".to_owned()),
+                        }),
+                        Some(char) => string.push(char),
+                        None => return Err("Unterminated string".to_owned()),
+                    }
+                }
+                words.push(Word::Quoted(string));
+            }
+            char if char.is_whitespace() => {}
+            char => {
+                let mut word = String::from(char);
+                while let Some(char) = chars.next_if(|char| !char.is_whitespace() && *char != ';') {
+                    word.push(char);
+                }
+                words.push(Word::Bare(word));
+            }
+        }
+    }
+    Ok(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "title: Start
+---
+<<declare $gold = 10>>
+Hello there! #line:hello
+<<if $gold > 5>>
+    <<jump Shop>>
+<<endif>>
+===
+title: Shop
+tags: market
+---
+Buy something, \"friend\"?
+===
+";
+
+    fn compile() -> (Compilation, File) {
+        let file = File {
+            file_name: "start.yarn".to_owned(),
+            source: SOURCE.to_owned(),
+        };
+        let compilation = Compiler::new().add_file(file.clone()).compile().unwrap();
+        (compilation, file)
+    }
+
+    #[test]
+    fn disassembles_with_source_lines() {
+        let (compilation, file) = compile();
+        let program = compilation.program.unwrap();
+        let text = program.disassemble_with_sources(&compilation.debug_info, &[file]);
+        assert_eq!(
+            r#"initial $gold = 10
+
+node Shop
+    header "title" "Shop"
+    header "tags" "market"
+    tag "market"
+L3:
+    ; start.yarn:12: Buy something, "friend"?
+    0    RUN_LINE "line:start.yarn-Shop-1" 0
+    1    STOP
+
+node Start
+    header "title" "Start"
+L0:
+    ; start.yarn:4: Hello there! #line:hello
+    0    RUN_LINE "line:hello" 0
+    ; start.yarn:5: <<if $gold > 5>>
+    1    PUSH_VARIABLE "$gold"
+    2    PUSH_FLOAT 5
+    3    PUSH_FLOAT 2
+    4    CALL_FUNC "Number.GreaterThan"
+    5    JUMP_IF_FALSE "L2skipclause"
+    ; start.yarn:6: <<jump Shop>>
+    6    PUSH_STRING "Shop"
+    7    RUN_NODE
+    8    JUMP_TO "L1endif"
+L2skipclause:
+    ; start.yarn:5: <<if $gold > 5>>
+    9    POP
+L1endif:
+    ; start.yarn:7: <<endif>>
+    10   STOP
+"#,
+            text
+        );
+        assert!(!program
+            .disassemble(&compilation.debug_info)
+            .contains("Hello there!"));
+    }
+
+    #[test]
+    fn parses_its_own_output() {
+        let (compilation, _) = compile();
+        let mut program = compilation.program.unwrap();
+        program.name = "Tricky \"name\"\twith\\escapes".to_owned();
+        program.nodes.get_mut("Shop").unwrap().source_text_string_id = "line:shop".to_owned();
+        program
+            .nodes
+            .get_mut("Start")
+            .unwrap()
+            .instructions
+            .push(Instruction {
+                opcode: OpCode::AddOption.into(),
+                operands: vec![
+                    Operand::from("line:a;b".to_owned()),
+                    Operand { value: None },
+                    Operand::from(-1.5),
+                    Operand::from(true),
+                ],
+            });
+        let text = program.disassemble(&compilation.debug_info);
+        assert_eq!(Ok(program), Program::from_disassembly(&text));
+    }
+
+    #[test]
+    fn parses_hand_written_snippets() {
+        let program = Program::from_disassembly(
+            "node Start
+                PUSH_BOOL true ; a comment
+            Loop:
+                JUMP_IF_FALSE \"Loop\"
+                STOP",
+        )
+        .unwrap();
+        let node = &program.nodes["Start"];
+        assert_eq!(
+            vec![OpCode::PushBool, OpCode::JumpIfFalse, OpCode::Stop],
+            node.instructions
+                .iter()
+                .map(|instruction| instruction.opcode())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Some(&1), node.labels.get("Loop"));
+    }
+
+    #[test]
+    fn reports_invalid_lines() {
+        let error = |text| Program::from_disassembly(text).unwrap_err();
+        assert_eq!(
+            DisassemblyError {
+                line: 1,
+                message: "Expected a node first".to_owned()
+            },
+            error("STOP")
+        );
+        assert_eq!(
+            "Unknown instruction JUMP_AROUND",
+            error("node Start\nJUMP_AROUND").message
+        );
+        assert_eq!(
+            "Expected instruction 1, found 2",
+            error("node Start\n0 STOP\n2 STOP").message
+        );
+        assert_eq!(
+            "Unterminated string",
+            error("node Start\nPUSH_STRING \"a").message
+        );
+        assert_eq!(
+            "Invalid operand five",
+            error("node Start\nPUSH_FLOAT five").message
+        );
+    }
+}
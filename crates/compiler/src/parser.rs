@@ -3,6 +3,8 @@
 mod actual_types;
 pub(crate) mod generated;
 mod indent_aware_lexer;
+mod owned_input_stream;
 
 pub(crate) use actual_types::*;
 pub(crate) use indent_aware_lexer::IndentAwareYarnSpinnerLexer as YarnSpinnerLexer;
+pub(crate) use owned_input_stream::OwnedInputStream;
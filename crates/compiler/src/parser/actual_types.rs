@@ -3,12 +3,10 @@
 use crate::error_strategy::ErrorStrategy;
 use crate::prelude::*;
 use antlr_rust::common_token_stream::CommonTokenStream;
-use antlr_rust::InputStream;
 use generated::yarnspinnerparser::*;
 
-pub(crate) type ActualInputStream<'input> = InputStream<&'input [u32]>;
-pub(crate) type ActualYarnSpinnerLexer<'input> =
-    YarnSpinnerLexer<'input, ActualInputStream<'input>>;
+pub(crate) type ActualInputStream = OwnedInputStream;
+pub(crate) type ActualYarnSpinnerLexer<'input> = YarnSpinnerLexer<'input, ActualInputStream>;
 pub(crate) type ActualErrorStrategy<'input> = ErrorStrategy<'input, YarnSpinnerParserContextType>;
 pub(crate) type ActualTokenStream<'input> =
     CommonTokenStream<'input, ActualYarnSpinnerLexer<'input>>;
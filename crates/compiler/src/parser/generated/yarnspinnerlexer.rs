@@ -107,6 +107,9 @@ pub const COMMAND_TEXT: isize = 78;
 pub const TYPE_STRING: isize = 79;
 pub const TYPE_NUMBER: isize = 80;
 pub const TYPE_BOOL: isize = 81;
+pub const LINE_GROUP_ARROW: isize = 82;
+pub const COMMAND_ONCE: isize = 83;
+pub const COMMAND_ENDONCE: isize = 84;
 pub const WHITESPACE: usize = 2;
 pub const COMMENTS: usize = 3;
 pub const HeaderMode: usize = 1;
@@ -138,7 +141,7 @@ pub const modeNames: [&'static str; 12] = [
     "CommandIDOrExpressionMode",
 ];
 
-pub const ruleNames: [&'static str; 106] = [
+pub const ruleNames: [&'static str; 109] = [
     "WS",
     "COMMENT",
     "NEWLINE",
@@ -245,9 +248,12 @@ pub const ruleNames: [&'static str; 106] = [
     "COMMAND_ID_OR_EXPRESSION_ID",
     "COMMAND_ID_OR_EXPRESSION_START",
     "COMMAND_ID_OR_EXPRESSION_END",
+    "LINE_GROUP_ARROW",
+    "COMMAND_ONCE",
+    "COMMAND_ENDONCE",
 ];
 
-pub const _LITERAL_NAMES: [Option<&'static str>; 82] = [
+pub const _LITERAL_NAMES: [Option<&'static str>; 85] = [
     None,
     None,
     None,
@@ -330,8 +336,11 @@ pub const _LITERAL_NAMES: [Option<&'static str>; 82] = [
     Some("'string'"),
     Some("'number'"),
     Some("'bool'"),
+    Some("'=>'"),
+    Some("'once'"),
+    Some("'endonce'"),
 ];
-pub const _SYMBOLIC_NAMES: [Option<&'static str>; 82] = [
+pub const _SYMBOLIC_NAMES: [Option<&'static str>; 85] = [
     None,
     Some("INDENT"),
     Some("DEDENT"),
@@ -414,6 +423,9 @@ pub const _SYMBOLIC_NAMES: [Option<&'static str>; 82] = [
     Some("TYPE_STRING"),
     Some("TYPE_NUMBER"),
     Some("TYPE_BOOL"),
+    Some("LINE_GROUP_ARROW"),
+    Some("COMMAND_ONCE"),
+    Some("COMMAND_ENDONCE"),
 ];
 lazy_static! {
     static ref _shared_context_cache: Arc<PredictionContextCache> =
@@ -561,472 +573,501 @@ lazy_static! {
 }
 
 const _serializedATN: &'static str =
-    "\x03\u{608b}\u{a72a}\u{8133}\u{b9ed}\u{417c}\u{3be7}\u{7786}\u{5964}\x02\
-		\x53\u{328}\x08\x01\x08\x01\x08\x01\x08\x01\x08\x01\x08\x01\x08\x01\x08\
-		\x01\x08\x01\x08\x01\x08\x01\x08\x01\x04\x02\x09\x02\x04\x03\x09\x03\x04\
-		\x04\x09\x04\x04\x05\x09\x05\x04\x06\x09\x06\x04\x07\x09\x07\x04\x08\x09\
-		\x08\x04\x09\x09\x09\x04\x0a\x09\x0a\x04\x0b\x09\x0b\x04\x0c\x09\x0c\x04\
-		\x0d\x09\x0d\x04\x0e\x09\x0e\x04\x0f\x09\x0f\x04\x10\x09\x10\x04\x11\x09\
-		\x11\x04\x12\x09\x12\x04\x13\x09\x13\x04\x14\x09\x14\x04\x15\x09\x15\x04\
-		\x16\x09\x16\x04\x17\x09\x17\x04\x18\x09\x18\x04\x19\x09\x19\x04\x1a\x09\
-		\x1a\x04\x1b\x09\x1b\x04\x1c\x09\x1c\x04\x1d\x09\x1d\x04\x1e\x09\x1e\x04\
-		\x1f\x09\x1f\x04\x20\x09\x20\x04\x21\x09\x21\x04\x22\x09\x22\x04\x23\x09\
-		\x23\x04\x24\x09\x24\x04\x25\x09\x25\x04\x26\x09\x26\x04\x27\x09\x27\x04\
-		\x28\x09\x28\x04\x29\x09\x29\x04\x2a\x09\x2a\x04\x2b\x09\x2b\x04\x2c\x09\
-		\x2c\x04\x2d\x09\x2d\x04\x2e\x09\x2e\x04\x2f\x09\x2f\x04\x30\x09\x30\x04\
-		\x31\x09\x31\x04\x32\x09\x32\x04\x33\x09\x33\x04\x34\x09\x34\x04\x35\x09\
-		\x35\x04\x36\x09\x36\x04\x37\x09\x37\x04\x38\x09\x38\x04\x39\x09\x39\x04\
-		\x3a\x09\x3a\x04\x3b\x09\x3b\x04\x3c\x09\x3c\x04\x3d\x09\x3d\x04\x3e\x09\
-		\x3e\x04\x3f\x09\x3f\x04\x40\x09\x40\x04\x41\x09\x41\x04\x42\x09\x42\x04\
-		\x43\x09\x43\x04\x44\x09\x44\x04\x45\x09\x45\x04\x46\x09\x46\x04\x47\x09\
-		\x47\x04\x48\x09\x48\x04\x49\x09\x49\x04\x4a\x09\x4a\x04\x4b\x09\x4b\x04\
-		\x4c\x09\x4c\x04\x4d\x09\x4d\x04\x4e\x09\x4e\x04\x4f\x09\x4f\x04\x50\x09\
-		\x50\x04\x51\x09\x51\x04\x52\x09\x52\x04\x53\x09\x53\x04\x54\x09\x54\x04\
-		\x55\x09\x55\x04\x56\x09\x56\x04\x57\x09\x57\x04\x58\x09\x58\x04\x59\x09\
-		\x59\x04\x5a\x09\x5a\x04\x5b\x09\x5b\x04\x5c\x09\x5c\x04\x5d\x09\x5d\x04\
-		\x5e\x09\x5e\x04\x5f\x09\x5f\x04\x60\x09\x60\x04\x61\x09\x61\x04\x62\x09\
-		\x62\x04\x63\x09\x63\x04\x64\x09\x64\x04\x65\x09\x65\x04\x66\x09\x66\x04\
-		\x67\x09\x67\x04\x68\x09\x68\x04\x69\x09\x69\x04\x6a\x09\x6a\x04\x6b\x09\
-		\x6b\x03\x02\x06\x02\u{e4}\x0a\x02\x0d\x02\x0e\x02\u{e5}\x03\x02\x03\x02\
-		\x03\x03\x03\x03\x03\x03\x03\x03\x07\x03\u{ee}\x0a\x03\x0c\x03\x0e\x03\
-		\u{f1}\x0b\x03\x03\x03\x03\x03\x03\x04\x05\x04\u{f6}\x0a\x04\x03\x04\x03\
-		\x04\x05\x04\u{fa}\x0a\x04\x03\x04\x07\x04\u{fd}\x0a\x04\x0c\x04\x0e\x04\
-		\u{100}\x0b\x04\x03\x04\x03\x04\x03\x05\x03\x05\x05\x05\u{106}\x0a\x05\
-		\x03\x06\x05\x06\u{109}\x0a\x06\x03\x07\x03\x07\x05\x07\u{10d}\x0a\x07\
-		\x03\x08\x06\x08\u{110}\x0a\x08\x0d\x08\x0e\x08\u{111}\x03\x09\x03\x09\
-		\x03\x09\x03\x09\x03\x09\x03\x09\x03\x0a\x03\x0a\x07\x0a\u{11c}\x0a\x0a\
-		\x0c\x0a\x0e\x0a\u{11f}\x0b\x0a\x03\x0a\x03\x0a\x03\x0b\x03\x0b\x03\x0b\
-		\x03\x0b\x03\x0c\x06\x0c\u{128}\x0a\x0c\x0d\x0c\x0e\x0c\u{129}\x03\x0d\
-		\x03\x0d\x03\x0d\x03\x0d\x03\x0d\x03\x0d\x03\x0e\x03\x0e\x03\x0e\x03\x0e\
-		\x03\x0f\x03\x0f\x03\x0f\x03\x0f\x03\x0f\x03\x10\x03\x10\x03\x10\x03\x10\
-		\x03\x10\x03\x11\x03\x11\x03\x11\x03\x11\x03\x11\x03\x11\x03\x12\x03\x12\
-		\x03\x12\x03\x13\x03\x13\x03\x13\x03\x13\x03\x13\x03\x14\x03\x14\x03\x14\
-		\x03\x14\x03\x14\x03\x14\x03\x15\x03\x15\x03\x15\x03\x15\x03\x15\x03\x16\
-		\x03\x16\x03\x16\x03\x16\x03\x16\x03\x16\x03\x17\x03\x17\x03\x17\x03\x17\
-		\x03\x17\x03\x18\x03\x18\x03\x18\x03\x18\x03\x18\x03\x19\x03\x19\x03\x19\
-		\x03\x19\x05\x19\u{16d}\x0a\x19\x03\x19\x03\x19\x03\x1a\x03\x1a\x03\x1a\
-		\x03\x1a\x03\x1a\x03\x1b\x03\x1b\x03\x1b\x03\x1b\x03\x1b\x03\x1b\x03\x1c\
-		\x03\x1c\x03\x1c\x03\x1c\x03\x1c\x03\x1d\x03\x1d\x03\x1d\x03\x1d\x03\x1d\
-		\x03\x1d\x03\x1d\x03\x1e\x03\x1e\x03\x1e\x03\x1e\x03\x1f\x06\x1f\u{18d}\
-		\x0a\x1f\x0d\x1f\x0e\x1f\u{18e}\x03\x1f\x05\x1f\u{192}\x0a\x1f\x03\x20\
-		\x03\x20\x03\x21\x03\x21\x03\x21\x03\x21\x03\x21\x03\x22\x03\x22\x03\x22\
-		\x03\x22\x03\x23\x03\x23\x03\x23\x03\x23\x03\x24\x03\x24\x03\x24\x03\x24\
-		\x03\x25\x03\x25\x03\x25\x03\x25\x03\x25\x03\x25\x03\x26\x03\x26\x03\x26\
-		\x03\x26\x03\x26\x03\x27\x03\x27\x03\x27\x03\x27\x03\x27\x03\x28\x03\x28\
-		\x03\x29\x03\x29\x03\x29\x03\x29\x03\x2a\x03\x2a\x03\x2a\x03\x2a\x03\x2b\
-		\x06\x2b\u{1c2}\x0a\x2b\x0d\x2b\x0e\x2b\u{1c3}\x03\x2b\x03\x2b\x03\x2c\
-		\x03\x2c\x03\x2c\x03\x2c\x03\x2d\x03\x2d\x03\x2d\x03\x2d\x03\x2d\x03\x2e\
-		\x03\x2e\x03\x2e\x03\x2e\x03\x2e\x03\x2e\x03\x2f\x03\x2f\x03\x2f\x03\x2f\
-		\x03\x2f\x03\x30\x03\x30\x03\x30\x05\x30\u{1df}\x0a\x30\x03\x31\x03\x31\
-		\x03\x31\x03\x31\x03\x31\x05\x31\u{1e6}\x0a\x31\x03\x32\x03\x32\x03\x32\
-		\x03\x32\x03\x32\x05\x32\u{1ed}\x0a\x32\x03\x33\x03\x33\x03\x33\x03\x33\
-		\x03\x33\x03\x33\x05\x33\u{1f5}\x0a\x33\x03\x34\x03\x34\x03\x34\x05\x34\
-		\u{1fa}\x0a\x34\x03\x35\x03\x35\x03\x35\x05\x35\u{1ff}\x0a\x35\x03\x36\
-		\x03\x36\x03\x36\x03\x36\x03\x36\x05\x36\u{206}\x0a\x36\x03\x37\x03\x37\
-		\x03\x37\x03\x37\x03\x37\x05\x37\u{20d}\x0a\x37\x03\x38\x03\x38\x03\x38\
-		\x03\x38\x05\x38\u{213}\x0a\x38\x03\x39\x03\x39\x03\x39\x03\x39\x05\x39\
-		\u{219}\x0a\x39\x03\x3a\x03\x3a\x03\x3a\x03\x3a\x05\x3a\u{21f}\x0a\x3a\
-		\x03\x3b\x03\x3b\x03\x3b\x03\x3c\x03\x3c\x03\x3c\x03\x3d\x03\x3d\x03\x3d\
-		\x03\x3e\x03\x3e\x03\x3e\x03\x3f\x03\x3f\x03\x3f\x03\x40\x03\x40\x03\x41\
-		\x03\x41\x03\x42\x03\x42\x03\x43\x03\x43\x03\x44\x03\x44\x03\x45\x03\x45\
-		\x03\x46\x03\x46\x03\x47\x03\x47\x03\x48\x03\x48\x03\x48\x03\x49\x03\x49\
-		\x03\x49\x03\x49\x03\x49\x03\x49\x03\x49\x03\x49\x03\x49\x03\x4a\x03\x4a\
-		\x03\x4a\x03\x4a\x03\x4a\x03\x4a\x03\x4a\x03\x4a\x03\x4a\x03\x4b\x03\x4b\
-		\x03\x4b\x03\x4b\x03\x4b\x03\x4b\x03\x4b\x03\x4c\x03\x4c\x03\x4c\x03\x4c\
-		\x07\x4c\u{260}\x0a\x4c\x0c\x4c\x0e\x4c\u{263}\x0b\x4c\x03\x4c\x03\x4c\
-		\x03\x4d\x03\x4d\x03\x4e\x03\x4e\x03\x4e\x03\x4e\x03\x4f\x03\x4f\x03\x4f\
-		\x03\x4f\x03\x4f\x03\x4f\x03\x4f\x03\x50\x03\x50\x03\x50\x03\x51\x03\x51\
-		\x03\x52\x03\x52\x03\x52\x03\x52\x03\x52\x05\x52\u{27e}\x0a\x52\x03\x53\
-		\x06\x53\u{281}\x0a\x53\x0d\x53\x0e\x53\u{282}\x03\x54\x03\x54\x03\x55\
-		\x03\x55\x03\x55\x03\x55\x03\x56\x03\x56\x03\x56\x03\x56\x03\x56\x03\x56\
-		\x03\x56\x03\x57\x03\x57\x03\x57\x03\x57\x03\x57\x03\x57\x03\x57\x03\x57\
-		\x03\x57\x03\x57\x03\x57\x03\x58\x03\x58\x03\x58\x03\x58\x03\x58\x03\x58\
-		\x05\x58\u{2a3}\x0a\x58\x03\x59\x03\x59\x03\x59\x03\x59\x03\x59\x03\x59\
-		\x03\x59\x03\x59\x03\x5a\x03\x5a\x03\x5a\x03\x5a\x03\x5a\x03\x5a\x03\x5b\
-		\x03\x5b\x03\x5b\x03\x5b\x03\x5b\x03\x5b\x03\x5b\x03\x5b\x03\x5b\x03\x5c\
-		\x03\x5c\x03\x5c\x03\x5c\x03\x5c\x03\x5c\x03\x5c\x03\x5c\x03\x5c\x03\x5c\
-		\x03\x5c\x03\x5c\x03\x5d\x03\x5d\x03\x5d\x03\x5d\x03\x5d\x03\x5d\x03\x5d\
-		\x03\x5d\x03\x5d\x03\x5e\x03\x5e\x03\x5e\x03\x5e\x03\x5e\x03\x5e\x03\x5e\
-		\x03\x5e\x03\x5e\x03\x5f\x03\x5f\x03\x5f\x03\x5f\x03\x5f\x03\x5f\x03\x5f\
-		\x03\x5f\x03\x5f\x03\x60\x03\x60\x03\x60\x03\x60\x03\x60\x03\x60\x03\x60\
-		\x03\x60\x03\x60\x05\x60\u{2ec}\x0a\x60\x03\x61\x03\x61\x03\x61\x03\x61\
-		\x03\x61\x03\x61\x03\x61\x03\x61\x03\x62\x03\x62\x03\x62\x03\x62\x03\x62\
-		\x03\x63\x03\x63\x03\x63\x03\x63\x03\x63\x03\x64\x03\x64\x03\x64\x03\x64\
-		\x03\x64\x03\x65\x03\x65\x03\x65\x03\x65\x03\x66\x06\x66\u{30a}\x0a\x66\
-		\x0d\x66\x0e\x66\u{30b}\x03\x67\x03\x67\x03\x67\x03\x67\x03\x67\x03\x68\
-		\x03\x68\x03\x68\x03\x68\x03\x68\x03\x68\x03\x69\x03\x69\x03\x69\x03\x69\
-		\x03\x69\x03\x6a\x03\x6a\x03\x6a\x03\x6a\x03\x6a\x03\x6b\x03\x6b\x03\x6b\
-		\x03\x6b\x03\x6b\x03\x6b\x02\x02\x6c\x0e\x06\x10\x07\x12\x08\x14\x09\x16\
-		\x02\x18\x02\x1a\x02\x1c\x0a\x1e\x0b\x20\x0c\x22\x0d\x24\x02\x26\x0e\x28\
-		\x02\x2a\x02\x2c\x0f\x2e\x10\x30\x11\x32\x02\x34\x12\x36\x13\x38\x02\x3a\
-		\x02\x3c\x02\x3e\x14\x40\x02\x42\x02\x44\x02\x46\x15\x48\x16\x4a\x02\x4c\
-		\x02\x4e\x17\x50\x18\x52\x19\x54\x02\x56\x02\x58\x02\x5a\x1a\x5c\x1b\x5e\
-		\x02\x60\x1c\x62\x1d\x64\x1e\x66\x1f\x68\x20\x6a\x21\x6c\x22\x6e\x23\x70\
-		\x24\x72\x25\x74\x26\x76\x27\x78\x28\x7a\x29\x7c\x2a\x7e\x2b\u{80}\x2c\
-		\u{82}\x2d\u{84}\x2e\u{86}\x2f\u{88}\x30\u{8a}\x31\u{8c}\x32\u{8e}\x33\
-		\u{90}\x34\u{92}\x35\u{94}\x36\u{96}\x37\u{98}\x38\u{9a}\x39\u{9c}\x51\
-		\u{9e}\x52\u{a0}\x53\u{a2}\x3a\u{a4}\x3b\u{a6}\x3c\u{a8}\x02\u{aa}\x3d\
-		\u{ac}\x3e\u{ae}\x3f\u{b0}\x02\u{b2}\x02\u{b4}\x40\u{b6}\x41\u{b8}\x42\
-		\u{ba}\x43\u{bc}\x44\u{be}\x45\u{c0}\x46\u{c2}\x47\u{c4}\x48\u{c6}\x49\
-		\u{c8}\x4a\u{ca}\x4b\u{cc}\x4c\u{ce}\x4d\u{d0}\x02\u{d2}\x4e\u{d4}\x4f\
-		\u{d6}\x50\u{d8}\x02\u{da}\x02\u{dc}\x02\u{de}\x02\u{e0}\x02\x0e\x02\x03\
-		\x04\x05\x06\x07\x08\x09\x0a\x0b\x0c\x0d\x0f\x04\x02\x0b\x0b\x22\x22\x04\
-		\x02\x0c\x0c\x0f\x0f\x07\x02\x32\x3b\u{302}\u{371}\u{1dc2}\u{1e01}\u{20d2}\
-		\u{2101}\u{fe22}\u{fe31}\x03\x02\x22\x22\x04\x02\x31\x31\x3e\x3e\x09\x02\
-		\x0c\x0c\x0f\x0f\x25\x25\x31\x31\x3e\x3e\x5e\x5e\x7d\x7d\x09\x02\x25\x25\
-		\x31\x31\x3e\x3e\x40\x40\x5e\x5e\x7d\x7d\x7f\x7f\x07\x02\x0b\x0c\x0f\x0f\
-		\x22\x22\x25\x26\x3e\x3e\x06\x02\x0c\x0c\x0f\x0f\x24\x24\x5e\x5e\x04\x02\
-		\x24\x24\x5e\x5e\x03\x02\x32\x3b\x0c\x02\x0b\x0f\x22\x22\u{87}\u{87}\u{a2}\
-		\u{a2}\u{1682}\u{1682}\u{2002}\u{200c}\u{202a}\u{202b}\u{2031}\u{2031}\
-		\u{2061}\u{2061}\u{3002}\u{3002}\x04\x02\x40\x40\x7d\x7d\x03\x33\x02\x43\
-		\x02\x5c\x02\x61\x02\x61\x02\x63\x02\x7c\x02\u{aa}\x02\u{aa}\x02\u{ac}\
-		\x02\u{ac}\x02\u{af}\x02\u{af}\x02\u{b1}\x02\u{b1}\x02\u{b4}\x02\u{b7}\
-		\x02\u{b9}\x02\u{bc}\x02\u{be}\x02\u{c0}\x02\u{c2}\x02\u{d8}\x02\u{da}\
-		\x02\u{f8}\x02\u{fa}\x02\u{301}\x02\u{372}\x02\u{1681}\x02\u{1683}\x02\
-		\u{180f}\x02\u{1811}\x02\u{1dc1}\x02\u{1e02}\x02\u{2001}\x02\u{200d}\x02\
-		\u{200f}\x02\u{202c}\x02\u{2030}\x02\u{2041}\x02\u{2042}\x02\u{2056}\x02\
-		\u{2056}\x02\u{2062}\x02\u{20d1}\x02\u{2102}\x02\u{2191}\x02\u{2462}\x02\
-		\u{2501}\x02\u{2778}\x02\u{2795}\x02\u{2c02}\x02\u{2e01}\x02\u{2e82}\x02\
-		\u{3001}\x02\u{3006}\x02\u{3009}\x02\u{3023}\x02\u{3031}\x02\u{3033}\x02\
-		\u{10801}\x02\u{f902}\x02\u{fd3f}\x02\u{fd42}\x02\u{fdd1}\x02\u{fdf2}\x02\
-		\u{fe21}\x02\u{fe32}\x02\u{fe46}\x02\u{fe49}\x02\u{ffff}\x02\x02\x03\u{ffff}\
-		\x03\x02\x04\u{ffff}\x04\x02\x05\u{ffff}\x05\x02\x06\u{ffff}\x06\x02\x07\
-		\u{ffff}\x07\x02\x08\u{ffff}\x08\x02\x09\u{ffff}\x09\x02\x0a\u{ffff}\x0a\
-		\x02\x0b\u{ffff}\x0b\x02\x0c\u{ffff}\x0c\x02\x0d\u{ffff}\x0d\x02\x0e\u{ffff}\
-		\x0e\x02\x0f\u{ffff}\x0f\x02\x10\u{ffff}\x10\u{337}\x02\x0e\x03\x02\x02\
-		\x02\x02\x10\x03\x02\x02\x02\x02\x12\x03\x02\x02\x02\x02\x14\x03\x02\x02\
-		\x02\x02\x1c\x03\x02\x02\x02\x02\x1e\x03\x02\x02\x02\x02\x20\x03\x02\x02\
-		\x02\x03\x22\x03\x02\x02\x02\x03\x24\x03\x02\x02\x02\x04\x26\x03\x02\x02\
-		\x02\x04\x28\x03\x02\x02\x02\x04\x2a\x03\x02\x02\x02\x04\x2c\x03\x02\x02\
-		\x02\x04\x2e\x03\x02\x02\x02\x04\x30\x03\x02\x02\x02\x04\x32\x03\x02\x02\
-		\x02\x04\x34\x03\x02\x02\x02\x04\x36\x03\x02\x02\x02\x04\x38\x03\x02\x02\
-		\x02\x05\x3a\x03\x02\x02\x02\x05\x3c\x03\x02\x02\x02\x05\x3e\x03\x02\x02\
-		\x02\x05\x40\x03\x02\x02\x02\x05\x42\x03\x02\x02\x02\x05\x44\x03\x02\x02\
-		\x02\x05\x46\x03\x02\x02\x02\x05\x48\x03\x02\x02\x02\x06\x4c\x03\x02\x02\
-		\x02\x06\x4e\x03\x02\x02\x02\x07\x50\x03\x02\x02\x02\x07\x52\x03\x02\x02\
-		\x02\x07\x54\x03\x02\x02\x02\x07\x56\x03\x02\x02\x02\x07\x58\x03\x02\x02\
-		\x02\x07\x5a\x03\x02\x02\x02\x08\x5c\x03\x02\x02\x02\x08\x5e\x03\x02\x02\
-		\x02\x08\x60\x03\x02\x02\x02\x09\x62\x03\x02\x02\x02\x09\x64\x03\x02\x02\
-		\x02\x09\x66\x03\x02\x02\x02\x09\x68\x03\x02\x02\x02\x09\x6a\x03\x02\x02\
-		\x02\x09\x6c\x03\x02\x02\x02\x09\x6e\x03\x02\x02\x02\x09\x70\x03\x02\x02\
-		\x02\x09\x72\x03\x02\x02\x02\x09\x74\x03\x02\x02\x02\x09\x76\x03\x02\x02\
-		\x02\x09\x78\x03\x02\x02\x02\x09\x7a\x03\x02\x02\x02\x09\x7c\x03\x02\x02\
-		\x02\x09\x7e\x03\x02\x02\x02\x09\u{80}\x03\x02\x02\x02\x09\u{82}\x03\x02\
-		\x02\x02\x09\u{84}\x03\x02\x02\x02\x09\u{86}\x03\x02\x02\x02\x09\u{88}\
-		\x03\x02\x02\x02\x09\u{8a}\x03\x02\x02\x02\x09\u{8c}\x03\x02\x02\x02\x09\
-		\u{8e}\x03\x02\x02\x02\x09\u{90}\x03\x02\x02\x02\x09\u{92}\x03\x02\x02\
-		\x02\x09\u{94}\x03\x02\x02\x02\x09\u{96}\x03\x02\x02\x02\x09\u{98}\x03\
-		\x02\x02\x02\x09\u{9a}\x03\x02\x02\x02\x09\u{9c}\x03\x02\x02\x02\x09\u{9e}\
-		\x03\x02\x02\x02\x09\u{a0}\x03\x02\x02\x02\x09\u{a2}\x03\x02\x02\x02\x09\
-		\u{a4}\x03\x02\x02\x02\x09\u{a6}\x03\x02\x02\x02\x09\u{a8}\x03\x02\x02\
-		\x02\x09\u{aa}\x03\x02\x02\x02\x09\u{ac}\x03\x02\x02\x02\x09\u{ae}\x03\
-		\x02\x02\x02\x0a\u{b4}\x03\x02\x02\x02\x0a\u{b6}\x03\x02\x02\x02\x0a\u{b8}\
-		\x03\x02\x02\x02\x0a\u{ba}\x03\x02\x02\x02\x0a\u{bc}\x03\x02\x02\x02\x0a\
+    "\x03\u{608b}\u{a72a}\u{8133}\u{b9ed}\u{417c}\u{3be7}\u{7786}\u{5964}\
+		\x02\x56\u{340}\x08\x01\x08\x01\x08\x01\x08\x01\x08\x01\x08\x01\x08\x01\
+		\x08\x01\x08\x01\x08\x01\x08\x01\x08\x01\x04\x02\x09\x02\x04\x03\x09\
+		\x03\x04\x04\x09\x04\x04\x05\x09\x05\x04\x06\x09\x06\x04\x07\x09\x07\
+		\x04\x08\x09\x08\x04\x09\x09\x09\x04\x0a\x09\x0a\x04\x0b\x09\x0b\x04\
+		\x0c\x09\x0c\x04\x0d\x09\x0d\x04\x0e\x09\x0e\x04\x0f\x09\x0f\x04\x10\
+		\x09\x10\x04\x11\x09\x11\x04\x12\x09\x12\x04\x13\x09\x13\x04\x14\x09\
+		\x14\x04\x15\x09\x15\x04\x16\x09\x16\x04\x17\x09\x17\x04\x18\x09\x18\
+		\x04\x19\x09\x19\x04\x1a\x09\x1a\x04\x1b\x09\x1b\x04\x1c\x09\x1c\x04\
+		\x1d\x09\x1d\x04\x1e\x09\x1e\x04\x1f\x09\x1f\x04\x20\x09\x20\x04\x21\
+		\x09\x21\x04\x22\x09\x22\x04\x23\x09\x23\x04\x24\x09\x24\x04\x25\x09\
+		\x25\x04\x26\x09\x26\x04\x27\x09\x27\x04\x28\x09\x28\x04\x29\x09\x29\
+		\x04\x2a\x09\x2a\x04\x2b\x09\x2b\x04\x2c\x09\x2c\x04\x2d\x09\x2d\x04\
+		\x2e\x09\x2e\x04\x2f\x09\x2f\x04\x30\x09\x30\x04\x31\x09\x31\x04\x32\
+		\x09\x32\x04\x33\x09\x33\x04\x34\x09\x34\x04\x35\x09\x35\x04\x36\x09\
+		\x36\x04\x37\x09\x37\x04\x38\x09\x38\x04\x39\x09\x39\x04\x3a\x09\x3a\
+		\x04\x3b\x09\x3b\x04\x3c\x09\x3c\x04\x3d\x09\x3d\x04\x3e\x09\x3e\x04\
+		\x3f\x09\x3f\x04\x40\x09\x40\x04\x41\x09\x41\x04\x42\x09\x42\x04\x43\
+		\x09\x43\x04\x44\x09\x44\x04\x45\x09\x45\x04\x46\x09\x46\x04\x47\x09\
+		\x47\x04\x48\x09\x48\x04\x49\x09\x49\x04\x4a\x09\x4a\x04\x4b\x09\x4b\
+		\x04\x4c\x09\x4c\x04\x4d\x09\x4d\x04\x4e\x09\x4e\x04\x4f\x09\x4f\x04\
+		\x50\x09\x50\x04\x51\x09\x51\x04\x52\x09\x52\x04\x53\x09\x53\x04\x54\
+		\x09\x54\x04\x55\x09\x55\x04\x56\x09\x56\x04\x57\x09\x57\x04\x58\x09\
+		\x58\x04\x59\x09\x59\x04\x5a\x09\x5a\x04\x5b\x09\x5b\x04\x5c\x09\x5c\
+		\x04\x5d\x09\x5d\x04\x5e\x09\x5e\x04\x5f\x09\x5f\x04\x60\x09\x60\x04\
+		\x61\x09\x61\x04\x62\x09\x62\x04\x63\x09\x63\x04\x64\x09\x64\x04\x65\
+		\x09\x65\x04\x66\x09\x66\x04\x67\x09\x67\x04\x68\x09\x68\x04\x69\x09\
+		\x69\x04\x6a\x09\x6a\x04\x6b\x09\x6b\x03\x02\x06\x02\u{e4}\x0a\x02\x0d\
+		\x02\x0e\x02\u{e5}\x03\x02\x03\x02\x03\x03\x03\x03\x03\x03\x03\x03\x07\
+		\x03\u{ee}\x0a\x03\x0c\x03\x0e\x03\u{f1}\x0b\x03\x03\x03\x03\x03\x03\
+		\x04\x05\x04\u{f6}\x0a\x04\x03\x04\x03\x04\x05\x04\u{fa}\x0a\x04\x03\
+		\x04\x07\x04\u{fd}\x0a\x04\x0c\x04\x0e\x04\u{100}\x0b\x04\x03\x04\x03\
+		\x04\x03\x05\x03\x05\x05\x05\u{106}\x0a\x05\x03\x06\x05\x06\u{109}\x0a\
+		\x06\x03\x07\x03\x07\x05\x07\u{10d}\x0a\x07\x03\x08\x06\x08\u{110}\x0a\
+		\x08\x0d\x08\x0e\x08\u{111}\x03\x09\x03\x09\x03\x09\x03\x09\x03\x09\x03\
+		\x09\x03\x0a\x03\x0a\x07\x0a\u{11c}\x0a\x0a\x0c\x0a\x0e\x0a\u{11f}\x0b\
+		\x0a\x03\x0a\x03\x0a\x03\x0b\x03\x0b\x03\x0b\x03\x0b\x03\x0c\x06\x0c\
+		\u{128}\x0a\x0c\x0d\x0c\x0e\x0c\u{129}\x03\x0d\x03\x0d\x03\x0d\x03\x0d\
+		\x03\x0d\x03\x0d\x03\x0e\x03\x0e\x03\x0e\x03\x0e\x03\x0f\x03\x0f\x03\
+		\x0f\x03\x0f\x03\x0f\x03\x10\x03\x10\x03\x10\x03\x10\x03\x10\x03\x11\
+		\x03\x11\x03\x11\x03\x11\x03\x11\x03\x11\x03\x12\x03\x12\x03\x12\x03\
+		\x13\x03\x13\x03\x13\x03\x13\x03\x13\x03\x14\x03\x14\x03\x14\x03\x14\
+		\x03\x14\x03\x14\x03\x15\x03\x15\x03\x15\x03\x15\x03\x15\x03\x16\x03\
+		\x16\x03\x16\x03\x16\x03\x16\x03\x16\x03\x17\x03\x17\x03\x17\x03\x17\
+		\x03\x17\x03\x18\x03\x18\x03\x18\x03\x18\x03\x18\x03\x19\x03\x19\x03\
+		\x19\x03\x19\x05\x19\u{16d}\x0a\x19\x03\x19\x03\x19\x03\x1a\x03\x1a\x03\
+		\x1a\x03\x1a\x03\x1a\x03\x1b\x03\x1b\x03\x1b\x03\x1b\x03\x1b\x03\x1b\
+		\x03\x1c\x03\x1c\x03\x1c\x03\x1c\x03\x1c\x03\x1d\x03\x1d\x03\x1d\x03\
+		\x1d\x03\x1d\x03\x1d\x03\x1d\x03\x1e\x03\x1e\x03\x1e\x03\x1e\x03\x1f\
+		\x06\x1f\u{18d}\x0a\x1f\x0d\x1f\x0e\x1f\u{18e}\x03\x1f\x05\x1f\u{192}\
+		\x0a\x1f\x03\x20\x03\x20\x03\x21\x03\x21\x03\x21\x03\x21\x03\x21\x03\
+		\x22\x03\x22\x03\x22\x03\x22\x03\x23\x03\x23\x03\x23\x03\x23\x03\x24\
+		\x03\x24\x03\x24\x03\x24\x03\x25\x03\x25\x03\x25\x03\x25\x03\x25\x03\
+		\x25\x03\x26\x03\x26\x03\x26\x03\x26\x03\x26\x03\x27\x03\x27\x03\x27\
+		\x03\x27\x03\x27\x03\x28\x03\x28\x03\x29\x03\x29\x03\x29\x03\x29\x03\
+		\x2a\x03\x2a\x03\x2a\x03\x2a\x03\x2b\x06\x2b\u{1c2}\x0a\x2b\x0d\x2b\x0e\
+		\x2b\u{1c3}\x03\x2b\x03\x2b\x03\x2c\x03\x2c\x03\x2c\x03\x2c\x03\x2d\x03\
+		\x2d\x03\x2d\x03\x2d\x03\x2d\x03\x2e\x03\x2e\x03\x2e\x03\x2e\x03\x2e\
+		\x03\x2e\x03\x2f\x03\x2f\x03\x2f\x03\x2f\x03\x2f\x03\x30\x03\x30\x03\
+		\x30\x05\x30\u{1df}\x0a\x30\x03\x31\x03\x31\x03\x31\x03\x31\x03\x31\x05\
+		\x31\u{1e6}\x0a\x31\x03\x32\x03\x32\x03\x32\x03\x32\x03\x32\x05\x32\u{1ed}\
+		\x0a\x32\x03\x33\x03\x33\x03\x33\x03\x33\x03\x33\x03\x33\x05\x33\u{1f5}\
+		\x0a\x33\x03\x34\x03\x34\x03\x34\x05\x34\u{1fa}\x0a\x34\x03\x35\x03\x35\
+		\x03\x35\x05\x35\u{1ff}\x0a\x35\x03\x36\x03\x36\x03\x36\x03\x36\x03\x36\
+		\x05\x36\u{206}\x0a\x36\x03\x37\x03\x37\x03\x37\x03\x37\x03\x37\x05\x37\
+		\u{20d}\x0a\x37\x03\x38\x03\x38\x03\x38\x03\x38\x05\x38\u{213}\x0a\x38\
+		\x03\x39\x03\x39\x03\x39\x03\x39\x05\x39\u{219}\x0a\x39\x03\x3a\x03\x3a\
+		\x03\x3a\x03\x3a\x05\x3a\u{21f}\x0a\x3a\x03\x3b\x03\x3b\x03\x3b\x03\x3c\
+		\x03\x3c\x03\x3c\x03\x3d\x03\x3d\x03\x3d\x03\x3e\x03\x3e\x03\x3e\x03\
+		\x3f\x03\x3f\x03\x3f\x03\x40\x03\x40\x03\x41\x03\x41\x03\x42\x03\x42\
+		\x03\x43\x03\x43\x03\x44\x03\x44\x03\x45\x03\x45\x03\x46\x03\x46\x03\
+		\x47\x03\x47\x03\x48\x03\x48\x03\x48\x03\x49\x03\x49\x03\x49\x03\x49\
+		\x03\x49\x03\x49\x03\x49\x03\x49\x03\x49\x03\x4a\x03\x4a\x03\x4a\x03\
+		\x4a\x03\x4a\x03\x4a\x03\x4a\x03\x4a\x03\x4a\x03\x4b\x03\x4b\x03\x4b\
+		\x03\x4b\x03\x4b\x03\x4b\x03\x4b\x03\x4c\x03\x4c\x03\x4c\x03\x4c\x07\
+		\x4c\u{260}\x0a\x4c\x0c\x4c\x0e\x4c\u{263}\x0b\x4c\x03\x4c\x03\x4c\x03\
+		\x4d\x03\x4d\x03\x4e\x03\x4e\x03\x4e\x03\x4e\x03\x4f\x03\x4f\x03\x4f\
+		\x03\x4f\x03\x4f\x03\x4f\x03\x4f\x03\x50\x03\x50\x03\x50\x03\x51\x03\
+		\x51\x03\x52\x03\x52\x03\x52\x03\x52\x03\x52\x05\x52\u{27e}\x0a\x52\x03\
+		\x53\x06\x53\u{281}\x0a\x53\x0d\x53\x0e\x53\u{282}\x03\x54\x03\x54\x03\
+		\x55\x03\x55\x03\x55\x03\x55\x03\x56\x03\x56\x03\x56\x03\x56\x03\x56\
+		\x03\x56\x03\x56\x03\x57\x03\x57\x03\x57\x03\x57\x03\x57\x03\x57\x03\
+		\x57\x03\x57\x03\x57\x03\x57\x03\x57\x03\x58\x03\x58\x03\x58\x03\x58\
+		\x03\x58\x03\x58\x05\x58\u{2a3}\x0a\x58\x03\x59\x03\x59\x03\x59\x03\x59\
+		\x03\x59\x03\x59\x03\x59\x03\x59\x03\x5a\x03\x5a\x03\x5a\x03\x5a\x03\
+		\x5a\x03\x5a\x03\x5b\x03\x5b\x03\x5b\x03\x5b\x03\x5b\x03\x5b\x03\x5b\
+		\x03\x5b\x03\x5b\x03\x5c\x03\x5c\x03\x5c\x03\x5c\x03\x5c\x03\x5c\x03\
+		\x5c\x03\x5c\x03\x5c\x03\x5c\x03\x5c\x03\x5c\x03\x5d\x03\x5d\x03\x5d\
+		\x03\x5d\x03\x5d\x03\x5d\x03\x5d\x03\x5d\x03\x5d\x03\x5e\x03\x5e\x03\
+		\x5e\x03\x5e\x03\x5e\x03\x5e\x03\x5e\x03\x5e\x03\x5e\x03\x5f\x03\x5f\
+		\x03\x5f\x03\x5f\x03\x5f\x03\x5f\x03\x5f\x03\x5f\x03\x5f\x03\x60\x03\
+		\x60\x03\x60\x03\x60\x03\x60\x03\x60\x03\x60\x03\x60\x03\x60\x05\x60\
+		\u{2ec}\x0a\x60\x03\x61\x03\x61\x03\x61\x03\x61\x03\x61\x03\x61\x03\x61\
+		\x03\x61\x03\x62\x03\x62\x03\x62\x03\x62\x03\x62\x03\x63\x03\x63\x03\
+		\x63\x03\x63\x03\x63\x03\x64\x03\x64\x03\x64\x03\x64\x03\x64\x03\x65\
+		\x03\x65\x03\x65\x03\x65\x03\x66\x06\x66\u{30a}\x0a\x66\x0d\x66\x0e\x66\
+		\u{30b}\x03\x67\x03\x67\x03\x67\x03\x67\x03\x67\x03\x68\x03\x68\x03\x68\
+		\x03\x68\x03\x68\x03\x68\x03\x69\x03\x69\x03\x69\x03\x69\x03\x69\x03\
+		\x6a\x03\x6a\x03\x6a\x03\x6a\x03\x6a\x03\x6b\x03\x6b\x03\x6b\x03\x6b\
+		\x03\x6b\x03\x6b\x03\x61\x03\x61\x04\x6c\x09\x6c\x03\x6c\x03\x6c\x03\
+		\x6c\x04\x6d\x09\x6d\x03\x6d\x03\x6d\x03\x6d\x03\x6d\x03\x6d\x04\x6e\
+		\x09\x6e\x03\x6e\x03\x6e\x03\x6e\x03\x6e\x03\x6e\x03\x6e\x03\x6e\x03\
+		\x6e\x02\x02\x6f\x0e\x06\x10\x07\x12\x08\x14\x09\x16\x02\x18\x02\x1a\
+		\x02\x1c\x0a\x1e\x0b\x20\x0c\x22\x0d\x24\x02\x26\x0e\x28\x02\x2a\x02\
+		\x2c\x0f\x2e\x10\x30\x11\x32\x02\x34\x12\x36\x13\x38\x02\x3a\x02\x3c\
+		\x02\x3e\x14\x40\x02\x42\x02\x44\x02\x46\x15\x48\x16\x4a\x02\x4c\x02\
+		\x4e\x17\x50\x18\x52\x19\x54\x02\x56\x02\x58\x02\x5a\x1a\x5c\x1b\x5e\
+		\x02\x60\x1c\x62\x1d\x64\x1e\x66\x1f\x68\x20\x6a\x21\x6c\x22\x6e\x23\
+		\x70\x24\x72\x25\x74\x26\x76\x27\x78\x28\x7a\x29\x7c\x2a\x7e\x2b\u{80}\
+		\x2c\u{82}\x2d\u{84}\x2e\u{86}\x2f\u{88}\x30\u{8a}\x31\u{8c}\x32\u{8e}\
+		\x33\u{90}\x34\u{92}\x35\u{94}\x36\u{96}\x37\u{98}\x38\u{9a}\x39\u{9c}\
+		\x51\u{9e}\x52\u{a0}\x53\u{a2}\x3a\u{a4}\x3b\u{a6}\x3c\u{a8}\x02\u{aa}\
+		\x3d\u{ac}\x3e\u{ae}\x3f\u{b0}\x02\u{b2}\x02\u{b4}\x40\u{b6}\x41\u{b8}\
+		\x42\u{ba}\x43\u{bc}\x44\u{be}\x45\u{c0}\x46\u{c2}\x47\u{c4}\x48\u{c6}\
+		\x49\u{c8}\x4a\u{ca}\x4b\u{cc}\x4c\u{ce}\x4d\u{d0}\x02\u{d2}\x4e\u{d4}\
+		\x4f\u{d6}\x50\u{d8}\x02\u{da}\x02\u{dc}\x02\u{de}\x02\u{e0}\x02\u{32a}\
+		\x54\u{32f}\x55\u{336}\x56\x0e\x02\x03\x04\x05\x06\x07\x08\x09\x0a\x0b\
+		\x0c\x0d\x0f\x04\x02\x0b\x0b\x22\x22\x04\x02\x0c\x0c\x0f\x0f\x07\x02\
+		\x32\x3b\u{302}\u{371}\u{1dc2}\u{1e01}\u{20d2}\u{2101}\u{12e22}\u{12e31}\
+		\x03\x02\x22\x22\x04\x02\x31\x31\x3e\x3e\x09\x02\x0c\x0c\x0f\x0f\x25\
+		\x25\x31\x31\x3e\x3e\x5e\x5e\x7d\x7d\x09\x02\x25\x25\x31\x31\x3e\x3e\
+		\x40\x40\x5e\x5e\x7d\x7d\x7f\x7f\x07\x02\x0b\x0c\x0f\x0f\x22\x22\x25\
+		\x26\x3e\x3e\x06\x02\x0c\x0c\x0f\x0f\x24\x24\x5e\x5e\x04\x02\x24\x24\
+		\x5e\x5e\x03\x02\x32\x3b\x0c\x02\x0b\x0f\x22\x22\u{87}\u{87}\u{a2}\u{a2}\
+		\u{1682}\u{1682}\u{2002}\u{200c}\u{202a}\u{202b}\u{2031}\u{2031}\u{2061}\
+		\u{2061}\u{3002}\u{3002}\x04\x02\x40\x40\x7d\x7d\x03\x33\x02\x43\x02\
+		\x5c\x02\x61\x02\x61\x02\x63\x02\x7c\x02\u{aa}\x02\u{aa}\x02\u{ac}\x02\
+		\u{ac}\x02\u{af}\x02\u{af}\x02\u{b1}\x02\u{b1}\x02\u{b4}\x02\u{b7}\x02\
+		\u{b9}\x02\u{bc}\x02\u{be}\x02\u{c0}\x02\u{c2}\x02\u{d8}\x02\u{da}\x02\
+		\u{f8}\x02\u{fa}\x02\u{301}\x02\u{372}\x02\u{1681}\x02\u{1683}\x02\u{180f}\
+		\x02\u{1811}\x02\u{1dc1}\x02\u{1e02}\x02\u{2001}\x02\u{200d}\x02\u{200f}\
+		\x02\u{202c}\x02\u{2030}\x02\u{2041}\x02\u{2042}\x02\u{2056}\x02\u{2056}\
+		\x02\u{2062}\x02\u{20d1}\x02\u{2102}\x02\u{2191}\x02\u{2462}\x02\u{2501}\
+		\x02\u{2778}\x02\u{2795}\x02\u{2c02}\x02\u{2e01}\x02\u{2e82}\x02\u{3001}\
+		\x02\u{3006}\x02\u{3009}\x02\u{3023}\x02\u{3031}\x02\u{3033}\x02\u{10801}\
+		\x02\u{12902}\x02\u{12d3f}\x02\u{12d42}\x02\u{12dd1}\x02\u{12df2}\x02\
+		\u{12e21}\x02\u{12e32}\x02\u{12e46}\x02\u{12e49}\x02\u{12fff}\x02\x02\
+		\x03\u{12fff}\x03\x02\x04\u{12fff}\x04\x02\x05\u{12fff}\x05\x02\x06\u{12fff}\
+		\x06\x02\x07\u{12fff}\x07\x02\x08\u{12fff}\x08\x02\x09\u{12fff}\x09\x02\
+		\x0a\u{12fff}\x0a\x02\x0b\u{12fff}\x0b\x02\x0c\u{12fff}\x0c\x02\x0d\u{12fff}\
+		\x0d\x02\x0e\u{12fff}\x0e\x02\x0f\u{12fff}\x0f\x02\x10\u{12fff}\x10\u{34f}\
+		\x02\x0e\x03\x02\x02\x02\x02\x10\x03\x02\x02\x02\x02\x12\x03\x02\x02\
+		\x02\x02\x14\x03\x02\x02\x02\x02\x1c\x03\x02\x02\x02\x02\x1e\x03\x02\
+		\x02\x02\x02\x20\x03\x02\x02\x02\x03\x22\x03\x02\x02\x02\x03\x24\x03\
+		\x02\x02\x02\x04\x26\x03\x02\x02\x02\x04\x28\x03\x02\x02\x02\x04\x2a\
+		\x03\x02\x02\x02\x04\x2c\x03\x02\x02\x02\x04\x2e\x03\x02\x02\x02\x04\
+		\u{32a}\x03\x02\x02\x02\x04\x30\x03\x02\x02\x02\x04\x32\x03\x02\x02\x02\
+		\x04\x34\x03\x02\x02\x02\x04\x36\x03\x02\x02\x02\x04\x38\x03\x02\x02\
+		\x02\x05\x3a\x03\x02\x02\x02\x05\x3c\x03\x02\x02\x02\x05\x3e\x03\x02\
+		\x02\x02\x05\x40\x03\x02\x02\x02\x05\x42\x03\x02\x02\x02\x05\x44\x03\
+		\x02\x02\x02\x05\x46\x03\x02\x02\x02\x05\x48\x03\x02\x02\x02\x06\x4c\
+		\x03\x02\x02\x02\x06\x4e\x03\x02\x02\x02\x07\x50\x03\x02\x02\x02\x07\
+		\x52\x03\x02\x02\x02\x07\x54\x03\x02\x02\x02\x07\x56\x03\x02\x02\x02\
+		\x07\x58\x03\x02\x02\x02\x07\x5a\x03\x02\x02\x02\x08\x5c\x03\x02\x02\
+		\x02\x08\x5e\x03\x02\x02\x02\x08\x60\x03\x02\x02\x02\x09\x62\x03\x02\
+		\x02\x02\x09\x64\x03\x02\x02\x02\x09\x66\x03\x02\x02\x02\x09\x68\x03\
+		\x02\x02\x02\x09\x6a\x03\x02\x02\x02\x09\x6c\x03\x02\x02\x02\x09\x6e\
+		\x03\x02\x02\x02\x09\x70\x03\x02\x02\x02\x09\x72\x03\x02\x02\x02\x09\
+		\x74\x03\x02\x02\x02\x09\x76\x03\x02\x02\x02\x09\x78\x03\x02\x02\x02\
+		\x09\x7a\x03\x02\x02\x02\x09\x7c\x03\x02\x02\x02\x09\x7e\x03\x02\x02\
+		\x02\x09\u{80}\x03\x02\x02\x02\x09\u{82}\x03\x02\x02\x02\x09\u{84}\x03\
+		\x02\x02\x02\x09\u{86}\x03\x02\x02\x02\x09\u{88}\x03\x02\x02\x02\x09\
+		\u{8a}\x03\x02\x02\x02\x09\u{8c}\x03\x02\x02\x02\x09\u{8e}\x03\x02\x02\
+		\x02\x09\u{90}\x03\x02\x02\x02\x09\u{92}\x03\x02\x02\x02\x09\u{94}\x03\
+		\x02\x02\x02\x09\u{96}\x03\x02\x02\x02\x09\u{98}\x03\x02\x02\x02\x09\
+		\u{9a}\x03\x02\x02\x02\x09\u{9c}\x03\x02\x02\x02\x09\u{9e}\x03\x02\x02\
+		\x02\x09\u{a0}\x03\x02\x02\x02\x09\u{a2}\x03\x02\x02\x02\x09\u{a4}\x03\
+		\x02\x02\x02\x09\u{a6}\x03\x02\x02\x02\x09\u{a8}\x03\x02\x02\x02\x09\
+		\u{aa}\x03\x02\x02\x02\x09\u{ac}\x03\x02\x02\x02\x09\u{ae}\x03\x02\x02\
+		\x02\x0a\u{b4}\x03\x02\x02\x02\x0a\u{b6}\x03\x02\x02\x02\x0a\u{b8}\x03\
+		\x02\x02\x02\x0a\u{ba}\x03\x02\x02\x02\x0a\u{bc}\x03\x02\x02\x02\x0a\
 		\u{be}\x03\x02\x02\x02\x0a\u{c0}\x03\x02\x02\x02\x0a\u{c2}\x03\x02\x02\
 		\x02\x0a\u{c4}\x03\x02\x02\x02\x0a\u{c6}\x03\x02\x02\x02\x0a\u{c8}\x03\
-		\x02\x02\x02\x0a\u{ca}\x03\x02\x02\x02\x0a\u{cc}\x03\x02\x02\x02\x0a\u{ce}\
-		\x03\x02\x02\x02\x0a\u{d0}\x03\x02\x02\x02\x0b\u{d2}\x03\x02\x02\x02\x0b\
-		\u{d4}\x03\x02\x02\x02\x0b\u{d6}\x03\x02\x02\x02\x0c\u{d8}\x03\x02\x02\
-		\x02\x0c\u{da}\x03\x02\x02\x02\x0d\u{dc}\x03\x02\x02\x02\x0d\u{de}\x03\
-		\x02\x02\x02\x0d\u{e0}\x03\x02\x02\x02\x0e\u{e3}\x03\x02\x02\x02\x10\u{e9}\
-		\x03\x02\x02\x02\x12\u{f9}\x03\x02\x02\x02\x14\u{103}\x03\x02\x02\x02\x16\
-		\u{108}\x03\x02\x02\x02\x18\u{10c}\x03\x02\x02\x02\x1a\u{10f}\x03\x02\x02\
-		\x02\x1c\u{113}\x03\x02\x02\x02\x1e\u{119}\x03\x02\x02\x02\x20\u{122}\x03\
-		\x02\x02\x02\x22\u{127}\x03\x02\x02\x02\x24\u{12b}\x03\x02\x02\x02\x26\
-		\u{131}\x03\x02\x02\x02\x28\u{135}\x03\x02\x02\x02\x2a\u{13a}\x03\x02\x02\
-		\x02\x2c\u{13f}\x03\x02\x02\x02\x2e\u{145}\x03\x02\x02\x02\x30\u{148}\x03\
-		\x02\x02\x02\x32\u{14d}\x03\x02\x02\x02\x34\u{153}\x03\x02\x02\x02\x36\
-		\u{158}\x03\x02\x02\x02\x38\u{15e}\x03\x02\x02\x02\x3a\u{163}\x03\x02\x02\
-		\x02\x3c\u{16c}\x03\x02\x02\x02\x3e\u{170}\x03\x02\x02\x02\x40\u{175}\x03\
-		\x02\x02\x02\x42\u{17b}\x03\x02\x02\x02\x44\u{180}\x03\x02\x02\x02\x46\
-		\u{187}\x03\x02\x02\x02\x48\u{191}\x03\x02\x02\x02\x4a\u{193}\x03\x02\x02\
-		\x02\x4c\u{195}\x03\x02\x02\x02\x4e\u{19a}\x03\x02\x02\x02\x50\u{19e}\x03\
-		\x02\x02\x02\x52\u{1a2}\x03\x02\x02\x02\x54\u{1a6}\x03\x02\x02\x02\x56\
-		\u{1ac}\x03\x02\x02\x02\x58\u{1b1}\x03\x02\x02\x02\x5a\u{1b6}\x03\x02\x02\
-		\x02\x5c\u{1b8}\x03\x02\x02\x02\x5e\u{1bc}\x03\x02\x02\x02\x60\u{1c1}\x03\
-		\x02\x02\x02\x62\u{1c7}\x03\x02\x02\x02\x64\u{1cb}\x03\x02\x02\x02\x66\
-		\u{1d0}\x03\x02\x02\x02\x68\u{1d6}\x03\x02\x02\x02\x6a\u{1de}\x03\x02\x02\
-		\x02\x6c\u{1e5}\x03\x02\x02\x02\x6e\u{1ec}\x03\x02\x02\x02\x70\u{1f4}\x03\
-		\x02\x02\x02\x72\u{1f9}\x03\x02\x02\x02\x74\u{1fe}\x03\x02\x02\x02\x76\
-		\u{205}\x03\x02\x02\x02\x78\u{20c}\x03\x02\x02\x02\x7a\u{212}\x03\x02\x02\
-		\x02\x7c\u{218}\x03\x02\x02\x02\x7e\u{21e}\x03\x02\x02\x02\u{80}\u{220}\
-		\x03\x02\x02\x02\u{82}\u{223}\x03\x02\x02\x02\u{84}\u{226}\x03\x02\x02\
-		\x02\u{86}\u{229}\x03\x02\x02\x02\u{88}\u{22c}\x03\x02\x02\x02\u{8a}\u{22f}\
-		\x03\x02\x02\x02\u{8c}\u{231}\x03\x02\x02\x02\u{8e}\u{233}\x03\x02\x02\
-		\x02\u{90}\u{235}\x03\x02\x02\x02\u{92}\u{237}\x03\x02\x02\x02\u{94}\u{239}\
-		\x03\x02\x02\x02\u{96}\u{23b}\x03\x02\x02\x02\u{98}\u{23d}\x03\x02\x02\
-		\x02\u{9a}\u{23f}\x03\x02\x02\x02\u{9c}\u{242}\x03\x02\x02\x02\u{9e}\u{24b}\
-		\x03\x02\x02\x02\u{a0}\u{254}\x03\x02\x02\x02\u{a2}\u{25b}\x03\x02\x02\
-		\x02\u{a4}\u{266}\x03\x02\x02\x02\u{a6}\u{268}\x03\x02\x02\x02\u{a8}\u{26c}\
-		\x03\x02\x02\x02\u{aa}\u{273}\x03\x02\x02\x02\u{ac}\u{276}\x03\x02\x02\
-		\x02\u{ae}\u{27d}\x03\x02\x02\x02\u{b0}\u{280}\x03\x02\x02\x02\u{b2}\u{284}\
+		\x02\x02\x02\x0a\u{ca}\x03\x02\x02\x02\x0a\u{cc}\x03\x02\x02\x02\x0a\
+		\u{32f}\x03\x02\x02\x02\x0a\u{336}\x03\x02\x02\x02\x0a\u{ce}\x03\x02\
+		\x02\x02\x0a\u{d0}\x03\x02\x02\x02\x0b\u{d2}\x03\x02\x02\x02\x0b\u{d4}\
+		\x03\x02\x02\x02\x0b\u{d6}\x03\x02\x02\x02\x0c\u{d8}\x03\x02\x02\x02\
+		\x0c\u{da}\x03\x02\x02\x02\x0d\u{dc}\x03\x02\x02\x02\x0d\u{de}\x03\x02\
+		\x02\x02\x0d\u{e0}\x03\x02\x02\x02\x0e\u{e3}\x03\x02\x02\x02\x10\u{e9}\
+		\x03\x02\x02\x02\x12\u{f9}\x03\x02\x02\x02\x14\u{103}\x03\x02\x02\x02\
+		\x16\u{108}\x03\x02\x02\x02\x18\u{10c}\x03\x02\x02\x02\x1a\u{10f}\x03\
+		\x02\x02\x02\x1c\u{113}\x03\x02\x02\x02\x1e\u{119}\x03\x02\x02\x02\x20\
+		\u{122}\x03\x02\x02\x02\x22\u{127}\x03\x02\x02\x02\x24\u{12b}\x03\x02\
+		\x02\x02\x26\u{131}\x03\x02\x02\x02\x28\u{135}\x03\x02\x02\x02\x2a\u{13a}\
+		\x03\x02\x02\x02\x2c\u{13f}\x03\x02\x02\x02\x2e\u{145}\x03\x02\x02\x02\
+		\x30\u{148}\x03\x02\x02\x02\x32\u{14d}\x03\x02\x02\x02\x34\u{153}\x03\
+		\x02\x02\x02\x36\u{158}\x03\x02\x02\x02\x38\u{15e}\x03\x02\x02\x02\x3a\
+		\u{163}\x03\x02\x02\x02\x3c\u{16c}\x03\x02\x02\x02\x3e\u{170}\x03\x02\
+		\x02\x02\x40\u{175}\x03\x02\x02\x02\x42\u{17b}\x03\x02\x02\x02\x44\u{180}\
+		\x03\x02\x02\x02\x46\u{187}\x03\x02\x02\x02\x48\u{191}\x03\x02\x02\x02\
+		\x4a\u{193}\x03\x02\x02\x02\x4c\u{195}\x03\x02\x02\x02\x4e\u{19a}\x03\
+		\x02\x02\x02\x50\u{19e}\x03\x02\x02\x02\x52\u{1a2}\x03\x02\x02\x02\x54\
+		\u{1a6}\x03\x02\x02\x02\x56\u{1ac}\x03\x02\x02\x02\x58\u{1b1}\x03\x02\
+		\x02\x02\x5a\u{1b6}\x03\x02\x02\x02\x5c\u{1b8}\x03\x02\x02\x02\x5e\u{1bc}\
+		\x03\x02\x02\x02\x60\u{1c1}\x03\x02\x02\x02\x62\u{1c7}\x03\x02\x02\x02\
+		\x64\u{1cb}\x03\x02\x02\x02\x66\u{1d0}\x03\x02\x02\x02\x68\u{1d6}\x03\
+		\x02\x02\x02\x6a\u{1de}\x03\x02\x02\x02\x6c\u{1e5}\x03\x02\x02\x02\x6e\
+		\u{1ec}\x03\x02\x02\x02\x70\u{1f4}\x03\x02\x02\x02\x72\u{1f9}\x03\x02\
+		\x02\x02\x74\u{1fe}\x03\x02\x02\x02\x76\u{205}\x03\x02\x02\x02\x78\u{20c}\
+		\x03\x02\x02\x02\x7a\u{212}\x03\x02\x02\x02\x7c\u{218}\x03\x02\x02\x02\
+		\x7e\u{21e}\x03\x02\x02\x02\u{80}\u{220}\x03\x02\x02\x02\u{82}\u{223}\
+		\x03\x02\x02\x02\u{84}\u{226}\x03\x02\x02\x02\u{86}\u{229}\x03\x02\x02\
+		\x02\u{88}\u{22c}\x03\x02\x02\x02\u{8a}\u{22f}\x03\x02\x02\x02\u{8c}\
+		\u{231}\x03\x02\x02\x02\u{8e}\u{233}\x03\x02\x02\x02\u{90}\u{235}\x03\
+		\x02\x02\x02\u{92}\u{237}\x03\x02\x02\x02\u{94}\u{239}\x03\x02\x02\x02\
+		\u{96}\u{23b}\x03\x02\x02\x02\u{98}\u{23d}\x03\x02\x02\x02\u{9a}\u{23f}\
+		\x03\x02\x02\x02\u{9c}\u{242}\x03\x02\x02\x02\u{9e}\u{24b}\x03\x02\x02\
+		\x02\u{a0}\u{254}\x03\x02\x02\x02\u{a2}\u{25b}\x03\x02\x02\x02\u{a4}\
+		\u{266}\x03\x02\x02\x02\u{a6}\u{268}\x03\x02\x02\x02\u{a8}\u{26c}\x03\
+		\x02\x02\x02\u{aa}\u{273}\x03\x02\x02\x02\u{ac}\u{276}\x03\x02\x02\x02\
+		\u{ae}\u{27d}\x03\x02\x02\x02\u{b0}\u{280}\x03\x02\x02\x02\u{b2}\u{284}\
 		\x03\x02\x02\x02\u{b4}\u{286}\x03\x02\x02\x02\u{b6}\u{28a}\x03\x02\x02\
-		\x02\u{b8}\u{291}\x03\x02\x02\x02\u{ba}\u{29c}\x03\x02\x02\x02\u{bc}\u{2a4}\
-		\x03\x02\x02\x02\u{be}\u{2ac}\x03\x02\x02\x02\u{c0}\u{2b2}\x03\x02\x02\
-		\x02\u{c2}\u{2bb}\x03\x02\x02\x02\u{c4}\u{2c7}\x03\x02\x02\x02\u{c6}\u{2d0}\
-		\x03\x02\x02\x02\u{c8}\u{2d9}\x03\x02\x02\x02\u{ca}\u{2e2}\x03\x02\x02\
-		\x02\u{cc}\u{2ed}\x03\x02\x02\x02\u{ce}\u{2f5}\x03\x02\x02\x02\u{d0}\u{2fa}\
-		\x03\x02\x02\x02\u{d2}\u{2ff}\x03\x02\x02\x02\u{d4}\u{304}\x03\x02\x02\
-		\x02\u{d6}\u{309}\x03\x02\x02\x02\u{d8}\u{30d}\x03\x02\x02\x02\u{da}\u{312}\
-		\x03\x02\x02\x02\u{dc}\u{318}\x03\x02\x02\x02\u{de}\u{31d}\x03\x02\x02\
-		\x02\u{e0}\u{322}\x03\x02\x02\x02\u{e2}\u{e4}\x09\x02\x02\x02\u{e3}\u{e2}\
-		\x03\x02\x02\x02\u{e4}\u{e5}\x03\x02\x02\x02\u{e5}\u{e3}\x03\x02\x02\x02\
-		\u{e5}\u{e6}\x03\x02\x02\x02\u{e6}\u{e7}\x03\x02\x02\x02\u{e7}\u{e8}\x08\
-		\x02\x02\x02\u{e8}\x0f\x03\x02\x02\x02\u{e9}\u{ea}\x07\x31\x02\x02\u{ea}\
-		\u{eb}\x07\x31\x02\x02\u{eb}\u{ef}\x03\x02\x02\x02\u{ec}\u{ee}\x0a\x03\
-		\x02\x02\u{ed}\u{ec}\x03\x02\x02\x02\u{ee}\u{f1}\x03\x02\x02\x02\u{ef}\
-		\u{ed}\x03\x02\x02\x02\u{ef}\u{f0}\x03\x02\x02\x02\u{f0}\u{f2}\x03\x02\
-		\x02\x02\u{f1}\u{ef}\x03\x02\x02\x02\u{f2}\u{f3}\x08\x03\x03\x02\u{f3}\
-		\x11\x03\x02\x02\x02\u{f4}\u{f6}\x07\x0f\x02\x02\u{f5}\u{f4}\x03\x02\x02\
-		\x02\u{f5}\u{f6}\x03\x02\x02\x02\u{f6}\u{f7}\x03\x02\x02\x02\u{f7}\u{fa}\
-		\x07\x0c\x02\x02\u{f8}\u{fa}\x07\x0f\x02\x02\u{f9}\u{f5}\x03\x02\x02\x02\
-		\u{f9}\u{f8}\x03\x02\x02\x02\u{fa}\u{fe}\x03\x02\x02\x02\u{fb}\u{fd}\x09\
-		\x02\x02\x02\u{fc}\u{fb}\x03\x02\x02\x02\u{fd}\u{100}\x03\x02\x02\x02\u{fe}\
-		\u{fc}\x03\x02\x02\x02\u{fe}\u{ff}\x03\x02\x02\x02\u{ff}\u{101}\x03\x02\
-		\x02\x02\u{100}\u{fe}\x03\x02\x02\x02\u{101}\u{102}\x08\x04\x04\x02\u{102}\
-		\x13\x03\x02\x02\x02\u{103}\u{105}\x05\x16\x06\x02\u{104}\u{106}\x05\x1a\
-		\x08\x02\u{105}\u{104}\x03\x02\x02\x02\u{105}\u{106}\x03\x02\x02\x02\u{106}\
-		\x15\x03\x02\x02\x02\u{107}\u{109}\x09\x0f\x02\x02\u{108}\u{107}\x03\x02\
+		\x02\u{b8}\u{291}\x03\x02\x02\x02\u{ba}\u{29c}\x03\x02\x02\x02\u{bc}\
+		\u{2a4}\x03\x02\x02\x02\u{be}\u{2ac}\x03\x02\x02\x02\u{c0}\u{2b2}\x03\
+		\x02\x02\x02\u{c2}\u{2bb}\x03\x02\x02\x02\u{c4}\u{2c7}\x03\x02\x02\x02\
+		\u{c6}\u{2d0}\x03\x02\x02\x02\u{c8}\u{2d9}\x03\x02\x02\x02\u{ca}\u{2e2}\
+		\x03\x02\x02\x02\u{cc}\u{2ed}\x03\x02\x02\x02\u{ce}\u{2f5}\x03\x02\x02\
+		\x02\u{d0}\u{2fa}\x03\x02\x02\x02\u{d2}\u{2ff}\x03\x02\x02\x02\u{d4}\
+		\u{304}\x03\x02\x02\x02\u{d6}\u{309}\x03\x02\x02\x02\u{d8}\u{30d}\x03\
+		\x02\x02\x02\u{da}\u{312}\x03\x02\x02\x02\u{dc}\u{318}\x03\x02\x02\x02\
+		\u{de}\u{31d}\x03\x02\x02\x02\u{e0}\u{322}\x03\x02\x02\x02\u{e2}\u{e4}\
+		\x09\x02\x02\x02\u{e3}\u{e2}\x03\x02\x02\x02\u{e4}\u{e5}\x03\x02\x02\
+		\x02\u{e5}\u{e3}\x03\x02\x02\x02\u{e5}\u{e6}\x03\x02\x02\x02\u{e6}\u{e7}\
+		\x03\x02\x02\x02\u{e7}\u{e8}\x08\x02\x02\x02\u{e8}\x0f\x03\x02\x02\x02\
+		\u{e9}\u{ea}\x07\x31\x02\x02\u{ea}\u{eb}\x07\x31\x02\x02\u{eb}\u{ef}\
+		\x03\x02\x02\x02\u{ec}\u{ee}\x0a\x03\x02\x02\u{ed}\u{ec}\x03\x02\x02\
+		\x02\u{ee}\u{f1}\x03\x02\x02\x02\u{ef}\u{ed}\x03\x02\x02\x02\u{ef}\u{f0}\
+		\x03\x02\x02\x02\u{f0}\u{f2}\x03\x02\x02\x02\u{f1}\u{ef}\x03\x02\x02\
+		\x02\u{f2}\u{f3}\x08\x03\x03\x02\u{f3}\x11\x03\x02\x02\x02\u{f4}\u{f6}\
+		\x07\x0f\x02\x02\u{f5}\u{f4}\x03\x02\x02\x02\u{f5}\u{f6}\x03\x02\x02\
+		\x02\u{f6}\u{f7}\x03\x02\x02\x02\u{f7}\u{fa}\x07\x0c\x02\x02\u{f8}\u{fa}\
+		\x07\x0f\x02\x02\u{f9}\u{f5}\x03\x02\x02\x02\u{f9}\u{f8}\x03\x02\x02\
+		\x02\u{fa}\u{fe}\x03\x02\x02\x02\u{fb}\u{fd}\x09\x02\x02\x02\u{fc}\u{fb}\
+		\x03\x02\x02\x02\u{fd}\u{100}\x03\x02\x02\x02\u{fe}\u{fc}\x03\x02\x02\
+		\x02\u{fe}\u{ff}\x03\x02\x02\x02\u{ff}\u{101}\x03\x02\x02\x02\u{100}\
+		\u{fe}\x03\x02\x02\x02\u{101}\u{102}\x08\x04\x04\x02\u{102}\x13\x03\x02\
+		\x02\x02\u{103}\u{105}\x05\x16\x06\x02\u{104}\u{106}\x05\x1a\x08\x02\
+		\u{105}\u{104}\x03\x02\x02\x02\u{105}\u{106}\x03\x02\x02\x02\u{106}\x15\
+		\x03\x02\x02\x02\u{107}\u{109}\x09\x0f\x02\x02\u{108}\u{107}\x03\x02\
 		\x02\x02\u{109}\x17\x03\x02\x02\x02\u{10a}\u{10d}\x09\x04\x02\x02\u{10b}\
 		\u{10d}\x05\x16\x06\x02\u{10c}\u{10a}\x03\x02\x02\x02\u{10c}\u{10b}\x03\
-		\x02\x02\x02\u{10d}\x19\x03\x02\x02\x02\u{10e}\u{110}\x05\x18\x07\x02\u{10f}\
-		\u{10e}\x03\x02\x02\x02\u{110}\u{111}\x03\x02\x02\x02\u{111}\u{10f}\x03\
-		\x02\x02\x02\u{111}\u{112}\x03\x02\x02\x02\u{112}\x1b\x03\x02\x02\x02\u{113}\
-		\u{114}\x07\x2f\x02\x02\u{114}\u{115}\x07\x2f\x02\x02\u{115}\u{116}\x07\
-		\x2f\x02\x02\u{116}\u{117}\x03\x02\x02\x02\u{117}\u{118}\x08\x09\x05\x02\
-		\u{118}\x1d\x03\x02\x02\x02\u{119}\u{11d}\x07\x3c\x02\x02\u{11a}\u{11c}\
-		\x09\x05\x02\x02\u{11b}\u{11a}\x03\x02\x02\x02\u{11c}\u{11f}\x03\x02\x02\
-		\x02\u{11d}\u{11b}\x03\x02\x02\x02\u{11d}\u{11e}\x03\x02\x02\x02\u{11e}\
-		\u{120}\x03\x02\x02\x02\u{11f}\u{11d}\x03\x02\x02\x02\u{120}\u{121}\x08\
-		\x0a\x06\x02\u{121}\x1f\x03\x02\x02\x02\u{122}\u{123}\x07\x25\x02\x02\u{123}\
-		\u{124}\x03\x02\x02\x02\u{124}\u{125}\x08\x0b\x07\x02\u{125}\x21\x03\x02\
-		\x02\x02\u{126}\u{128}\x0a\x03\x02\x02\u{127}\u{126}\x03\x02\x02\x02\u{128}\
-		\u{129}\x03\x02\x02\x02\u{129}\u{127}\x03\x02\x02\x02\u{129}\u{12a}\x03\
-		\x02\x02\x02\u{12a}\x23\x03\x02\x02\x02\u{12b}\u{12c}\x05\x12\x04\x02\u{12c}\
-		\u{12d}\x03\x02\x02\x02\u{12d}\u{12e}\x08\x0d\x08\x02\u{12e}\u{12f}\x08\
-		\x0d\x04\x02\u{12f}\u{130}\x08\x0d\x09\x02\u{130}\x25\x03\x02\x02\x02\u{131}\
-		\u{132}\x05\x0e\x02\x02\u{132}\u{133}\x03\x02\x02\x02\u{133}\u{134}\x08\
-		\x0e\x02\x02\u{134}\x27\x03\x02\x02\x02\u{135}\u{136}\x05\x12\x04\x02\u{136}\
-		\u{137}\x03\x02\x02\x02\u{137}\u{138}\x08\x0f\x08\x02\u{138}\u{139}\x08\
-		\x0f\x04\x02\u{139}\x29\x03\x02\x02\x02\u{13a}\u{13b}\x05\x10\x03\x02\u{13b}\
-		\u{13c}\x03\x02\x02\x02\u{13c}\u{13d}\x08\x10\x0a\x02\u{13d}\u{13e}\x08\
-		\x10\x03\x02\u{13e}\x2b\x03\x02\x02\x02\u{13f}\u{140}\x07\x3f\x02\x02\u{140}\
+		\x02\x02\x02\u{10d}\x19\x03\x02\x02\x02\u{10e}\u{110}\x05\x18\x07\x02\
+		\u{10f}\u{10e}\x03\x02\x02\x02\u{110}\u{111}\x03\x02\x02\x02\u{111}\u{10f}\
+		\x03\x02\x02\x02\u{111}\u{112}\x03\x02\x02\x02\u{112}\x1b\x03\x02\x02\
+		\x02\u{113}\u{114}\x07\x2f\x02\x02\u{114}\u{115}\x07\x2f\x02\x02\u{115}\
+		\u{116}\x07\x2f\x02\x02\u{116}\u{117}\x03\x02\x02\x02\u{117}\u{118}\x08\
+		\x09\x05\x02\u{118}\x1d\x03\x02\x02\x02\u{119}\u{11d}\x07\x3c\x02\x02\
+		\u{11a}\u{11c}\x09\x05\x02\x02\u{11b}\u{11a}\x03\x02\x02\x02\u{11c}\u{11f}\
+		\x03\x02\x02\x02\u{11d}\u{11b}\x03\x02\x02\x02\u{11d}\u{11e}\x03\x02\
+		\x02\x02\u{11e}\u{120}\x03\x02\x02\x02\u{11f}\u{11d}\x03\x02\x02\x02\
+		\u{120}\u{121}\x08\x0a\x06\x02\u{121}\x1f\x03\x02\x02\x02\u{122}\u{123}\
+		\x07\x25\x02\x02\u{123}\u{124}\x03\x02\x02\x02\u{124}\u{125}\x08\x0b\
+		\x07\x02\u{125}\x21\x03\x02\x02\x02\u{126}\u{128}\x0a\x03\x02\x02\u{127}\
+		\u{126}\x03\x02\x02\x02\u{128}\u{129}\x03\x02\x02\x02\u{129}\u{127}\x03\
+		\x02\x02\x02\u{129}\u{12a}\x03\x02\x02\x02\u{12a}\x23\x03\x02\x02\x02\
+		\u{12b}\u{12c}\x05\x12\x04\x02\u{12c}\u{12d}\x03\x02\x02\x02\u{12d}\u{12e}\
+		\x08\x0d\x08\x02\u{12e}\u{12f}\x08\x0d\x04\x02\u{12f}\u{130}\x08\x0d\
+		\x09\x02\u{130}\x25\x03\x02\x02\x02\u{131}\u{132}\x05\x0e\x02\x02\u{132}\
+		\u{133}\x03\x02\x02\x02\u{133}\u{134}\x08\x0e\x02\x02\u{134}\x27\x03\
+		\x02\x02\x02\u{135}\u{136}\x05\x12\x04\x02\u{136}\u{137}\x03\x02\x02\
+		\x02\u{137}\u{138}\x08\x0f\x08\x02\u{138}\u{139}\x08\x0f\x04\x02\u{139}\
+		\x29\x03\x02\x02\x02\u{13a}\u{13b}\x05\x10\x03\x02\u{13b}\u{13c}\x03\
+		\x02\x02\x02\u{13c}\u{13d}\x08\x10\x0a\x02\u{13d}\u{13e}\x08\x10\x03\
+		\x02\u{13e}\x2b\x03\x02\x02\x02\u{13f}\u{140}\x07\x3f\x02\x02\u{140}\
 		\u{141}\x07\x3f\x02\x02\u{141}\u{142}\x07\x3f\x02\x02\u{142}\u{143}\x03\
-		\x02\x02\x02\u{143}\u{144}\x08\x11\x09\x02\u{144}\x2d\x03\x02\x02\x02\u{145}\
-		\u{146}\x07\x2f\x02\x02\u{146}\u{147}\x07\x40\x02\x02\u{147}\x2f\x03\x02\
-		\x02\x02\u{148}\u{149}\x07\x3e\x02\x02\u{149}\u{14a}\x07\x3e\x02\x02\u{14a}\
-		\u{14b}\x03\x02\x02\x02\u{14b}\u{14c}\x08\x13\x0b\x02\u{14c}\x31\x03\x02\
-		\x02\x02\u{14d}\u{14e}\x07\x25\x02\x02\u{14e}\u{14f}\x03\x02\x02\x02\u{14f}\
-		\u{150}\x08\x14\x0c\x02\u{150}\u{151}\x08\x14\x0d\x02\u{151}\u{152}\x08\
-		\x14\x07\x02\u{152}\x33\x03\x02\x02\x02\u{153}\u{154}\x07\x7d\x02\x02\u{154}\
-		\u{155}\x03\x02\x02\x02\u{155}\u{156}\x08\x15\x0e\x02\u{156}\u{157}\x08\
-		\x15\x0f\x02\u{157}\x35\x03\x02\x02\x02\u{158}\u{159}\x07\x5e\x02\x02\u{159}\
-		\u{15a}\x03\x02\x02\x02\u{15a}\u{15b}\x08\x16\x10\x02\u{15b}\u{15c}\x08\
-		\x16\x0e\x02\u{15c}\u{15d}\x08\x16\x11\x02\u{15d}\x37\x03\x02\x02\x02\u{15e}\
-		\u{15f}\x0b\x02\x02\x02\u{15f}\u{160}\x03\x02\x02\x02\u{160}\u{161}\x08\
-		\x17\x12\x02\u{161}\u{162}\x08\x17\x0e\x02\u{162}\x39\x03\x02\x02\x02\u{163}\
-		\u{164}\x05\x12\x04\x02\u{164}\u{165}\x03\x02\x02\x02\u{165}\u{166}\x08\
-		\x18\x08\x02\u{166}\u{167}\x08\x18\x09\x02\u{167}\x3b\x03\x02\x02\x02\u{168}\
-		\u{169}\x07\x5e\x02\x02\u{169}\u{16d}\x07\x5d\x02\x02\u{16a}\u{16b}\x07\
-		\x5e\x02\x02\u{16b}\u{16d}\x07\x5f\x02\x02\u{16c}\u{168}\x03\x02\x02\x02\
-		\u{16c}\u{16a}\x03\x02\x02\x02\u{16d}\u{16e}\x03\x02\x02\x02\u{16e}\u{16f}\
-		\x08\x19\x12\x02\u{16f}\x3d\x03\x02\x02\x02\u{170}\u{171}\x07\x5e\x02\x02\
-		\u{171}\u{172}\x03\x02\x02\x02\u{172}\u{173}\x08\x1a\x10\x02\u{173}\u{174}\
-		\x08\x1a\x11\x02\u{174}\x3f\x03\x02\x02\x02\u{175}\u{176}\x05\x20\x0b\x02\
+		\x02\x02\x02\u{143}\u{144}\x08\x11\x09\x02\u{144}\x2d\x03\x02\x02\x02\
+		\u{145}\u{146}\x07\x2f\x02\x02\u{146}\u{147}\x07\x40\x02\x02\u{147}\x2f\
+		\x03\x02\x02\x02\u{148}\u{149}\x07\x3e\x02\x02\u{149}\u{14a}\x07\x3e\
+		\x02\x02\u{14a}\u{14b}\x03\x02\x02\x02\u{14b}\u{14c}\x08\x13\x0b\x02\
+		\u{14c}\x31\x03\x02\x02\x02\u{14d}\u{14e}\x07\x25\x02\x02\u{14e}\u{14f}\
+		\x03\x02\x02\x02\u{14f}\u{150}\x08\x14\x0c\x02\u{150}\u{151}\x08\x14\
+		\x0d\x02\u{151}\u{152}\x08\x14\x07\x02\u{152}\x33\x03\x02\x02\x02\u{153}\
+		\u{154}\x07\x7d\x02\x02\u{154}\u{155}\x03\x02\x02\x02\u{155}\u{156}\x08\
+		\x15\x0e\x02\u{156}\u{157}\x08\x15\x0f\x02\u{157}\x35\x03\x02\x02\x02\
+		\u{158}\u{159}\x07\x5e\x02\x02\u{159}\u{15a}\x03\x02\x02\x02\u{15a}\u{15b}\
+		\x08\x16\x10\x02\u{15b}\u{15c}\x08\x16\x0e\x02\u{15c}\u{15d}\x08\x16\
+		\x11\x02\u{15d}\x37\x03\x02\x02\x02\u{15e}\u{15f}\x0b\x02\x02\x02\u{15f}\
+		\u{160}\x03\x02\x02\x02\u{160}\u{161}\x08\x17\x12\x02\u{161}\u{162}\x08\
+		\x17\x0e\x02\u{162}\x39\x03\x02\x02\x02\u{163}\u{164}\x05\x12\x04\x02\
+		\u{164}\u{165}\x03\x02\x02\x02\u{165}\u{166}\x08\x18\x08\x02\u{166}\u{167}\
+		\x08\x18\x09\x02\u{167}\x3b\x03\x02\x02\x02\u{168}\u{169}\x07\x5e\x02\
+		\x02\u{169}\u{16d}\x07\x5d\x02\x02\u{16a}\u{16b}\x07\x5e\x02\x02\u{16b}\
+		\u{16d}\x07\x5f\x02\x02\u{16c}\u{168}\x03\x02\x02\x02\u{16c}\u{16a}\x03\
+		\x02\x02\x02\u{16d}\u{16e}\x03\x02\x02\x02\u{16e}\u{16f}\x08\x19\x12\
+		\x02\u{16f}\x3d\x03\x02\x02\x02\u{170}\u{171}\x07\x5e\x02\x02\u{171}\
+		\u{172}\x03\x02\x02\x02\u{172}\u{173}\x08\x1a\x10\x02\u{173}\u{174}\x08\
+		\x1a\x11\x02\u{174}\x3f\x03\x02\x02\x02\u{175}\u{176}\x05\x20\x0b\x02\
 		\u{176}\u{177}\x03\x02\x02\x02\u{177}\u{178}\x08\x1b\x0c\x02\u{178}\u{179}\
-		\x08\x1b\x13\x02\u{179}\u{17a}\x08\x1b\x07\x02\u{17a}\x41\x03\x02\x02\x02\
-		\u{17b}\u{17c}\x07\x7d\x02\x02\u{17c}\u{17d}\x03\x02\x02\x02\u{17d}\u{17e}\
-		\x08\x1c\x14\x02\u{17e}\u{17f}\x08\x1c\x0f\x02\u{17f}\x43\x03\x02\x02\x02\
-		\u{180}\u{181}\x07\x3e\x02\x02\u{181}\u{182}\x07\x3e\x02\x02\u{182}\u{183}\
-		\x03\x02\x02\x02\u{183}\u{184}\x08\x1d\x15\x02\u{184}\u{185}\x08\x1d\x13\
-		\x02\u{185}\u{186}\x08\x1d\x0b\x02\u{186}\x45\x03\x02\x02\x02\u{187}\u{188}\
-		\x05\x10\x03\x02\u{188}\u{189}\x03\x02\x02\x02\u{189}\u{18a}\x08\x1e\x03\
-		\x02\u{18a}\x47\x03\x02\x02\x02\u{18b}\u{18d}\x05\x4a\x20\x02\u{18c}\u{18b}\
-		\x03\x02\x02\x02\u{18d}\u{18e}\x03\x02\x02\x02\u{18e}\u{18c}\x03\x02\x02\
-		\x02\u{18e}\u{18f}\x03\x02\x02\x02\u{18f}\u{192}\x03\x02\x02\x02\u{190}\
-		\u{192}\x09\x06\x02\x02\u{191}\u{18c}\x03\x02\x02\x02\u{191}\u{190}\x03\
-		\x02\x02\x02\u{192}\x49\x03\x02\x02\x02\u{193}\u{194}\x0a\x07\x02\x02\u{194}\
-		\x4b\x03\x02\x02\x02\u{195}\u{196}\x09\x08\x02\x02\u{196}\u{197}\x03\x02\
-		\x02\x02\u{197}\u{198}\x08\x21\x12\x02\u{198}\u{199}\x08\x21\x09\x02\u{199}\
-		\x4d\x03\x02\x02\x02\u{19a}\u{19b}\x0b\x02\x02\x02\u{19b}\u{19c}\x03\x02\
-		\x02\x02\u{19c}\u{19d}\x08\x22\x09\x02\u{19d}\x4f\x03\x02\x02\x02\u{19e}\
-		\u{19f}\x05\x0e\x02\x02\u{19f}\u{1a0}\x03\x02\x02\x02\u{1a0}\u{1a1}\x08\
-		\x23\x02\x02\u{1a1}\x51\x03\x02\x02\x02\u{1a2}\u{1a3}\x05\x10\x03\x02\u{1a3}\
-		\u{1a4}\x03\x02\x02\x02\u{1a4}\u{1a5}\x08\x24\x03\x02\u{1a5}\x53\x03\x02\
-		\x02\x02\u{1a6}\u{1a7}\x07\x3e\x02\x02\u{1a7}\u{1a8}\x07\x3e\x02\x02\u{1a8}\
-		\u{1a9}\x03\x02\x02\x02\u{1a9}\u{1aa}\x08\x25\x15\x02\u{1aa}\u{1ab}\x08\
-		\x25\x0b\x02\u{1ab}\x55\x03\x02\x02\x02\u{1ac}\u{1ad}\x07\x25\x02\x02\u{1ad}\
-		\u{1ae}\x03\x02\x02\x02\u{1ae}\u{1af}\x08\x26\x0c\x02\u{1af}\u{1b0}\x08\
-		\x26\x07\x02\u{1b0}\x57\x03\x02\x02\x02\u{1b1}\u{1b2}\x05\x12\x04\x02\u{1b2}\
-		\u{1b3}\x03\x02\x02\x02\u{1b3}\u{1b4}\x08\x27\x08\x02\u{1b4}\u{1b5}\x08\
-		\x27\x09\x02\u{1b5}\x59\x03\x02\x02\x02\u{1b6}\u{1b7}\x0b\x02\x02\x02\u{1b7}\
-		\x5b\x03\x02\x02\x02\u{1b8}\u{1b9}\x05\x0e\x02\x02\u{1b9}\u{1ba}\x03\x02\
-		\x02\x02\u{1ba}\u{1bb}\x08\x29\x02\x02\u{1bb}\x5d\x03\x02\x02\x02\u{1bc}\
-		\u{1bd}\x05\x20\x0b\x02\u{1bd}\u{1be}\x03\x02\x02\x02\u{1be}\u{1bf}\x08\
-		\x2a\x0c\x02\u{1bf}\x5f\x03\x02\x02\x02\u{1c0}\u{1c2}\x0a\x09\x02\x02\u{1c1}\
-		\u{1c0}\x03\x02\x02\x02\u{1c2}\u{1c3}\x03\x02\x02\x02\u{1c3}\u{1c1}\x03\
-		\x02\x02\x02\u{1c3}\u{1c4}\x03\x02\x02\x02\u{1c4}\u{1c5}\x03\x02\x02\x02\
-		\u{1c5}\u{1c6}\x08\x2b\x09\x02\u{1c6}\x61\x03\x02\x02\x02\u{1c7}\u{1c8}\
-		\x05\x0e\x02\x02\u{1c8}\u{1c9}\x03\x02\x02\x02\u{1c9}\u{1ca}\x08\x2c\x02\
-		\x02\u{1ca}\x63\x03\x02\x02\x02\u{1cb}\u{1cc}\x07\x76\x02\x02\u{1cc}\u{1cd}\
-		\x07\x74\x02\x02\u{1cd}\u{1ce}\x07\x77\x02\x02\u{1ce}\u{1cf}\x07\x67\x02\
-		\x02\u{1cf}\x65\x03\x02\x02\x02\u{1d0}\u{1d1}\x07\x68\x02\x02\u{1d1}\u{1d2}\
-		\x07\x63\x02\x02\u{1d2}\u{1d3}\x07\x6e\x02\x02\u{1d3}\u{1d4}\x07\x75\x02\
-		\x02\u{1d4}\u{1d5}\x07\x67\x02\x02\u{1d5}\x67\x03\x02\x02\x02\u{1d6}\u{1d7}\
-		\x07\x70\x02\x02\u{1d7}\u{1d8}\x07\x77\x02\x02\u{1d8}\u{1d9}\x07\x6e\x02\
-		\x02\u{1d9}\u{1da}\x07\x6e\x02\x02\u{1da}\x69\x03\x02\x02\x02\u{1db}\u{1df}\
-		\x07\x3f\x02\x02\u{1dc}\u{1dd}\x07\x76\x02\x02\u{1dd}\u{1df}\x07\x71\x02\
-		\x02\u{1de}\u{1db}\x03\x02\x02\x02\u{1de}\u{1dc}\x03\x02\x02\x02\u{1df}\
-		\x6b\x03\x02\x02\x02\u{1e0}\u{1e1}\x07\x3e\x02\x02\u{1e1}\u{1e6}\x07\x3f\
-		\x02\x02\u{1e2}\u{1e3}\x07\x6e\x02\x02\u{1e3}\u{1e4}\x07\x76\x02\x02\u{1e4}\
-		\u{1e6}\x07\x67\x02\x02\u{1e5}\u{1e0}\x03\x02\x02\x02\u{1e5}\u{1e2}\x03\
-		\x02\x02\x02\u{1e6}\x6d\x03\x02\x02\x02\u{1e7}\u{1e8}\x07\x40\x02\x02\u{1e8}\
-		\u{1ed}\x07\x3f\x02\x02\u{1e9}\u{1ea}\x07\x69\x02\x02\u{1ea}\u{1eb}\x07\
-		\x76\x02\x02\u{1eb}\u{1ed}\x07\x67\x02\x02\u{1ec}\u{1e7}\x03\x02\x02\x02\
-		\u{1ec}\u{1e9}\x03\x02\x02\x02\u{1ed}\x6f\x03\x02\x02\x02\u{1ee}\u{1ef}\
-		\x07\x3f\x02\x02\u{1ef}\u{1f5}\x07\x3f\x02\x02\u{1f0}\u{1f1}\x07\x6b\x02\
-		\x02\u{1f1}\u{1f5}\x07\x75\x02\x02\u{1f2}\u{1f3}\x07\x67\x02\x02\u{1f3}\
-		\u{1f5}\x07\x73\x02\x02\u{1f4}\u{1ee}\x03\x02\x02\x02\u{1f4}\u{1f0}\x03\
-		\x02\x02\x02\u{1f4}\u{1f2}\x03\x02\x02\x02\u{1f5}\x71\x03\x02\x02\x02\u{1f6}\
-		\u{1fa}\x07\x3e\x02\x02\u{1f7}\u{1f8}\x07\x6e\x02\x02\u{1f8}\u{1fa}\x07\
-		\x76\x02\x02\u{1f9}\u{1f6}\x03\x02\x02\x02\u{1f9}\u{1f7}\x03\x02\x02\x02\
-		\u{1fa}\x73\x03\x02\x02\x02\u{1fb}\u{1ff}\x07\x40\x02\x02\u{1fc}\u{1fd}\
-		\x07\x69\x02\x02\u{1fd}\u{1ff}\x07\x76\x02\x02\u{1fe}\u{1fb}\x03\x02\x02\
-		\x02\u{1fe}\u{1fc}\x03\x02\x02\x02\u{1ff}\x75\x03\x02\x02\x02\u{200}\u{201}\
-		\x07\x23\x02\x02\u{201}\u{206}\x07\x3f\x02\x02\u{202}\u{203}\x07\x70\x02\
-		\x02\u{203}\u{204}\x07\x67\x02\x02\u{204}\u{206}\x07\x73\x02\x02\u{205}\
-		\u{200}\x03\x02\x02\x02\u{205}\u{202}\x03\x02\x02\x02\u{206}\x77\x03\x02\
-		\x02\x02\u{207}\u{208}\x07\x63\x02\x02\u{208}\u{209}\x07\x70\x02\x02\u{209}\
-		\u{20d}\x07\x66\x02\x02\u{20a}\u{20b}\x07\x28\x02\x02\u{20b}\u{20d}\x07\
-		\x28\x02\x02\u{20c}\u{207}\x03\x02\x02\x02\u{20c}\u{20a}\x03\x02\x02\x02\
-		\u{20d}\x79\x03\x02\x02\x02\u{20e}\u{20f}\x07\x71\x02\x02\u{20f}\u{213}\
-		\x07\x74\x02\x02\u{210}\u{211}\x07\x7e\x02\x02\u{211}\u{213}\x07\x7e\x02\
+		\x08\x1b\x13\x02\u{179}\u{17a}\x08\x1b\x07\x02\u{17a}\x41\x03\x02\x02\
+		\x02\u{17b}\u{17c}\x07\x7d\x02\x02\u{17c}\u{17d}\x03\x02\x02\x02\u{17d}\
+		\u{17e}\x08\x1c\x14\x02\u{17e}\u{17f}\x08\x1c\x0f\x02\u{17f}\x43\x03\
+		\x02\x02\x02\u{180}\u{181}\x07\x3e\x02\x02\u{181}\u{182}\x07\x3e\x02\
+		\x02\u{182}\u{183}\x03\x02\x02\x02\u{183}\u{184}\x08\x1d\x15\x02\u{184}\
+		\u{185}\x08\x1d\x13\x02\u{185}\u{186}\x08\x1d\x0b\x02\u{186}\x45\x03\
+		\x02\x02\x02\u{187}\u{188}\x05\x10\x03\x02\u{188}\u{189}\x03\x02\x02\
+		\x02\u{189}\u{18a}\x08\x1e\x03\x02\u{18a}\x47\x03\x02\x02\x02\u{18b}\
+		\u{18d}\x05\x4a\x20\x02\u{18c}\u{18b}\x03\x02\x02\x02\u{18d}\u{18e}\x03\
+		\x02\x02\x02\u{18e}\u{18c}\x03\x02\x02\x02\u{18e}\u{18f}\x03\x02\x02\
+		\x02\u{18f}\u{192}\x03\x02\x02\x02\u{190}\u{192}\x09\x06\x02\x02\u{191}\
+		\u{18c}\x03\x02\x02\x02\u{191}\u{190}\x03\x02\x02\x02\u{192}\x49\x03\
+		\x02\x02\x02\u{193}\u{194}\x0a\x07\x02\x02\u{194}\x4b\x03\x02\x02\x02\
+		\u{195}\u{196}\x09\x08\x02\x02\u{196}\u{197}\x03\x02\x02\x02\u{197}\u{198}\
+		\x08\x21\x12\x02\u{198}\u{199}\x08\x21\x09\x02\u{199}\x4d\x03\x02\x02\
+		\x02\u{19a}\u{19b}\x0b\x02\x02\x02\u{19b}\u{19c}\x03\x02\x02\x02\u{19c}\
+		\u{19d}\x08\x22\x09\x02\u{19d}\x4f\x03\x02\x02\x02\u{19e}\u{19f}\x05\
+		\x0e\x02\x02\u{19f}\u{1a0}\x03\x02\x02\x02\u{1a0}\u{1a1}\x08\x23\x02\
+		\x02\u{1a1}\x51\x03\x02\x02\x02\u{1a2}\u{1a3}\x05\x10\x03\x02\u{1a3}\
+		\u{1a4}\x03\x02\x02\x02\u{1a4}\u{1a5}\x08\x24\x03\x02\u{1a5}\x53\x03\
+		\x02\x02\x02\u{1a6}\u{1a7}\x07\x3e\x02\x02\u{1a7}\u{1a8}\x07\x3e\x02\
+		\x02\u{1a8}\u{1a9}\x03\x02\x02\x02\u{1a9}\u{1aa}\x08\x25\x15\x02\u{1aa}\
+		\u{1ab}\x08\x25\x0b\x02\u{1ab}\x55\x03\x02\x02\x02\u{1ac}\u{1ad}\x07\
+		\x25\x02\x02\u{1ad}\u{1ae}\x03\x02\x02\x02\u{1ae}\u{1af}\x08\x26\x0c\
+		\x02\u{1af}\u{1b0}\x08\x26\x07\x02\u{1b0}\x57\x03\x02\x02\x02\u{1b1}\
+		\u{1b2}\x05\x12\x04\x02\u{1b2}\u{1b3}\x03\x02\x02\x02\u{1b3}\u{1b4}\x08\
+		\x27\x08\x02\u{1b4}\u{1b5}\x08\x27\x09\x02\u{1b5}\x59\x03\x02\x02\x02\
+		\u{1b6}\u{1b7}\x0b\x02\x02\x02\u{1b7}\x5b\x03\x02\x02\x02\u{1b8}\u{1b9}\
+		\x05\x0e\x02\x02\u{1b9}\u{1ba}\x03\x02\x02\x02\u{1ba}\u{1bb}\x08\x29\
+		\x02\x02\u{1bb}\x5d\x03\x02\x02\x02\u{1bc}\u{1bd}\x05\x20\x0b\x02\u{1bd}\
+		\u{1be}\x03\x02\x02\x02\u{1be}\u{1bf}\x08\x2a\x0c\x02\u{1bf}\x5f\x03\
+		\x02\x02\x02\u{1c0}\u{1c2}\x0a\x09\x02\x02\u{1c1}\u{1c0}\x03\x02\x02\
+		\x02\u{1c2}\u{1c3}\x03\x02\x02\x02\u{1c3}\u{1c1}\x03\x02\x02\x02\u{1c3}\
+		\u{1c4}\x03\x02\x02\x02\u{1c4}\u{1c5}\x03\x02\x02\x02\u{1c5}\u{1c6}\x08\
+		\x2b\x09\x02\u{1c6}\x61\x03\x02\x02\x02\u{1c7}\u{1c8}\x05\x0e\x02\x02\
+		\u{1c8}\u{1c9}\x03\x02\x02\x02\u{1c9}\u{1ca}\x08\x2c\x02\x02\u{1ca}\x63\
+		\x03\x02\x02\x02\u{1cb}\u{1cc}\x07\x76\x02\x02\u{1cc}\u{1cd}\x07\x74\
+		\x02\x02\u{1cd}\u{1ce}\x07\x77\x02\x02\u{1ce}\u{1cf}\x07\x67\x02\x02\
+		\u{1cf}\x65\x03\x02\x02\x02\u{1d0}\u{1d1}\x07\x68\x02\x02\u{1d1}\u{1d2}\
+		\x07\x63\x02\x02\u{1d2}\u{1d3}\x07\x6e\x02\x02\u{1d3}\u{1d4}\x07\x75\
+		\x02\x02\u{1d4}\u{1d5}\x07\x67\x02\x02\u{1d5}\x67\x03\x02\x02\x02\u{1d6}\
+		\u{1d7}\x07\x70\x02\x02\u{1d7}\u{1d8}\x07\x77\x02\x02\u{1d8}\u{1d9}\x07\
+		\x6e\x02\x02\u{1d9}\u{1da}\x07\x6e\x02\x02\u{1da}\x69\x03\x02\x02\x02\
+		\u{1db}\u{1df}\x07\x3f\x02\x02\u{1dc}\u{1dd}\x07\x76\x02\x02\u{1dd}\u{1df}\
+		\x07\x71\x02\x02\u{1de}\u{1db}\x03\x02\x02\x02\u{1de}\u{1dc}\x03\x02\
+		\x02\x02\u{1df}\x6b\x03\x02\x02\x02\u{1e0}\u{1e1}\x07\x3e\x02\x02\u{1e1}\
+		\u{1e6}\x07\x3f\x02\x02\u{1e2}\u{1e3}\x07\x6e\x02\x02\u{1e3}\u{1e4}\x07\
+		\x76\x02\x02\u{1e4}\u{1e6}\x07\x67\x02\x02\u{1e5}\u{1e0}\x03\x02\x02\
+		\x02\u{1e5}\u{1e2}\x03\x02\x02\x02\u{1e6}\x6d\x03\x02\x02\x02\u{1e7}\
+		\u{1e8}\x07\x40\x02\x02\u{1e8}\u{1ed}\x07\x3f\x02\x02\u{1e9}\u{1ea}\x07\
+		\x69\x02\x02\u{1ea}\u{1eb}\x07\x76\x02\x02\u{1eb}\u{1ed}\x07\x67\x02\
+		\x02\u{1ec}\u{1e7}\x03\x02\x02\x02\u{1ec}\u{1e9}\x03\x02\x02\x02\u{1ed}\
+		\x6f\x03\x02\x02\x02\u{1ee}\u{1ef}\x07\x3f\x02\x02\u{1ef}\u{1f5}\x07\
+		\x3f\x02\x02\u{1f0}\u{1f1}\x07\x6b\x02\x02\u{1f1}\u{1f5}\x07\x75\x02\
+		\x02\u{1f2}\u{1f3}\x07\x67\x02\x02\u{1f3}\u{1f5}\x07\x73\x02\x02\u{1f4}\
+		\u{1ee}\x03\x02\x02\x02\u{1f4}\u{1f0}\x03\x02\x02\x02\u{1f4}\u{1f2}\x03\
+		\x02\x02\x02\u{1f5}\x71\x03\x02\x02\x02\u{1f6}\u{1fa}\x07\x3e\x02\x02\
+		\u{1f7}\u{1f8}\x07\x6e\x02\x02\u{1f8}\u{1fa}\x07\x76\x02\x02\u{1f9}\u{1f6}\
+		\x03\x02\x02\x02\u{1f9}\u{1f7}\x03\x02\x02\x02\u{1fa}\x73\x03\x02\x02\
+		\x02\u{1fb}\u{1ff}\x07\x40\x02\x02\u{1fc}\u{1fd}\x07\x69\x02\x02\u{1fd}\
+		\u{1ff}\x07\x76\x02\x02\u{1fe}\u{1fb}\x03\x02\x02\x02\u{1fe}\u{1fc}\x03\
+		\x02\x02\x02\u{1ff}\x75\x03\x02\x02\x02\u{200}\u{201}\x07\x23\x02\x02\
+		\u{201}\u{206}\x07\x3f\x02\x02\u{202}\u{203}\x07\x70\x02\x02\u{203}\u{204}\
+		\x07\x67\x02\x02\u{204}\u{206}\x07\x73\x02\x02\u{205}\u{200}\x03\x02\
+		\x02\x02\u{205}\u{202}\x03\x02\x02\x02\u{206}\x77\x03\x02\x02\x02\u{207}\
+		\u{208}\x07\x63\x02\x02\u{208}\u{209}\x07\x70\x02\x02\u{209}\u{20d}\x07\
+		\x66\x02\x02\u{20a}\u{20b}\x07\x28\x02\x02\u{20b}\u{20d}\x07\x28\x02\
+		\x02\u{20c}\u{207}\x03\x02\x02\x02\u{20c}\u{20a}\x03\x02\x02\x02\u{20d}\
+		\x79\x03\x02\x02\x02\u{20e}\u{20f}\x07\x71\x02\x02\u{20f}\u{213}\x07\
+		\x74\x02\x02\u{210}\u{211}\x07\x7e\x02\x02\u{211}\u{213}\x07\x7e\x02\
 		\x02\u{212}\u{20e}\x03\x02\x02\x02\u{212}\u{210}\x03\x02\x02\x02\u{213}\
-		\x7b\x03\x02\x02\x02\u{214}\u{215}\x07\x7a\x02\x02\u{215}\u{216}\x07\x71\
-		\x02\x02\u{216}\u{219}\x07\x74\x02\x02\u{217}\u{219}\x07\x60\x02\x02\u{218}\
-		\u{214}\x03\x02\x02\x02\u{218}\u{217}\x03\x02\x02\x02\u{219}\x7d\x03\x02\
-		\x02\x02\u{21a}\u{21b}\x07\x70\x02\x02\u{21b}\u{21c}\x07\x71\x02\x02\u{21c}\
-		\u{21f}\x07\x76\x02\x02\u{21d}\u{21f}\x07\x23\x02\x02\u{21e}\u{21a}\x03\
-		\x02\x02\x02\u{21e}\u{21d}\x03\x02\x02\x02\u{21f}\x7f\x03\x02\x02\x02\u{220}\
-		\u{221}\x07\x2d\x02\x02\u{221}\u{222}\x07\x3f\x02\x02\u{222}\u{81}\x03\
-		\x02\x02\x02\u{223}\u{224}\x07\x2f\x02\x02\u{224}\u{225}\x07\x3f\x02\x02\
-		\u{225}\u{83}\x03\x02\x02\x02\u{226}\u{227}\x07\x2c\x02\x02\u{227}\u{228}\
-		\x07\x3f\x02\x02\u{228}\u{85}\x03\x02\x02\x02\u{229}\u{22a}\x07\x27\x02\
-		\x02\u{22a}\u{22b}\x07\x3f\x02\x02\u{22b}\u{87}\x03\x02\x02\x02\u{22c}\
-		\u{22d}\x07\x31\x02\x02\u{22d}\u{22e}\x07\x3f\x02\x02\u{22e}\u{89}\x03\
-		\x02\x02\x02\u{22f}\u{230}\x07\x2d\x02\x02\u{230}\u{8b}\x03\x02\x02\x02\
-		\u{231}\u{232}\x07\x2f\x02\x02\u{232}\u{8d}\x03\x02\x02\x02\u{233}\u{234}\
-		\x07\x2c\x02\x02\u{234}\u{8f}\x03\x02\x02\x02\u{235}\u{236}\x07\x31\x02\
-		\x02\u{236}\u{91}\x03\x02\x02\x02\u{237}\u{238}\x07\x27\x02\x02\u{238}\
-		\u{93}\x03\x02\x02\x02\u{239}\u{23a}\x07\x2a\x02\x02\u{23a}\u{95}\x03\x02\
-		\x02\x02\u{23b}\u{23c}\x07\x2b\x02\x02\u{23c}\u{97}\x03\x02\x02\x02\u{23d}\
-		\u{23e}\x07\x2e\x02\x02\u{23e}\u{99}\x03\x02\x02\x02\u{23f}\u{240}\x07\
-		\x63\x02\x02\u{240}\u{241}\x07\x75\x02\x02\u{241}\u{9b}\x03\x02\x02\x02\
-		\u{242}\u{243}\x07\x75\x02\x02\u{243}\u{244}\x07\x76\x02\x02\u{244}\u{245}\
-		\x07\x74\x02\x02\u{245}\u{246}\x07\x6b\x02\x02\u{246}\u{247}\x07\x70\x02\
-		\x02\u{247}\u{248}\x07\x69\x02\x02\u{248}\u{249}\x03\x02\x02\x02\u{249}\
-		\u{24a}\x08\x49\x16\x02\u{24a}\u{9d}\x03\x02\x02\x02\u{24b}\u{24c}\x07\
-		\x70\x02\x02\u{24c}\u{24d}\x07\x77\x02\x02\u{24d}\u{24e}\x07\x6f\x02\x02\
-		\u{24e}\u{24f}\x07\x64\x02\x02\u{24f}\u{250}\x07\x67\x02\x02\u{250}\u{251}\
-		\x07\x74\x02\x02\u{251}\u{252}\x03\x02\x02\x02\u{252}\u{253}\x08\x4a\x16\
-		\x02\u{253}\u{9f}\x03\x02\x02\x02\u{254}\u{255}\x07\x64\x02\x02\u{255}\
-		\u{256}\x07\x71\x02\x02\u{256}\u{257}\x07\x71\x02\x02\u{257}\u{258}\x07\
-		\x6e\x02\x02\u{258}\u{259}\x03\x02\x02\x02\u{259}\u{25a}\x08\x4b\x16\x02\
-		\u{25a}\u{a1}\x03\x02\x02\x02\u{25b}\u{261}\x07\x24\x02\x02\u{25c}\u{260}\
-		\x0a\x0a\x02\x02\u{25d}\u{25e}\x07\x5e\x02\x02\u{25e}\u{260}\x09\x0b\x02\
-		\x02\u{25f}\u{25c}\x03\x02\x02\x02\u{25f}\u{25d}\x03\x02\x02\x02\u{260}\
-		\u{263}\x03\x02\x02\x02\u{261}\u{25f}\x03\x02\x02\x02\u{261}\u{262}\x03\
-		\x02\x02\x02\u{262}\u{264}\x03\x02\x02\x02\u{263}\u{261}\x03\x02\x02\x02\
-		\u{264}\u{265}\x07\x24\x02\x02\u{265}\u{a3}\x03\x02\x02\x02\u{266}\u{267}\
-		\x05\x14\x05\x02\u{267}\u{a5}\x03\x02\x02\x02\u{268}\u{269}\x07\x7f\x02\
-		\x02\u{269}\u{26a}\x03\x02\x02\x02\u{26a}\u{26b}\x08\x4e\x09\x02\u{26b}\
-		\u{a7}\x03\x02\x02\x02\u{26c}\u{26d}\x07\x40\x02\x02\u{26d}\u{26e}\x07\
-		\x40\x02\x02\u{26e}\u{26f}\x03\x02\x02\x02\u{26f}\u{270}\x08\x4f\x17\x02\
+		\x7b\x03\x02\x02\x02\u{214}\u{215}\x07\x7a\x02\x02\u{215}\u{216}\x07\
+		\x71\x02\x02\u{216}\u{219}\x07\x74\x02\x02\u{217}\u{219}\x07\x60\x02\
+		\x02\u{218}\u{214}\x03\x02\x02\x02\u{218}\u{217}\x03\x02\x02\x02\u{219}\
+		\x7d\x03\x02\x02\x02\u{21a}\u{21b}\x07\x70\x02\x02\u{21b}\u{21c}\x07\
+		\x71\x02\x02\u{21c}\u{21f}\x07\x76\x02\x02\u{21d}\u{21f}\x07\x23\x02\
+		\x02\u{21e}\u{21a}\x03\x02\x02\x02\u{21e}\u{21d}\x03\x02\x02\x02\u{21f}\
+		\x7f\x03\x02\x02\x02\u{220}\u{221}\x07\x2d\x02\x02\u{221}\u{222}\x07\
+		\x3f\x02\x02\u{222}\u{81}\x03\x02\x02\x02\u{223}\u{224}\x07\x2f\x02\x02\
+		\u{224}\u{225}\x07\x3f\x02\x02\u{225}\u{83}\x03\x02\x02\x02\u{226}\u{227}\
+		\x07\x2c\x02\x02\u{227}\u{228}\x07\x3f\x02\x02\u{228}\u{85}\x03\x02\x02\
+		\x02\u{229}\u{22a}\x07\x27\x02\x02\u{22a}\u{22b}\x07\x3f\x02\x02\u{22b}\
+		\u{87}\x03\x02\x02\x02\u{22c}\u{22d}\x07\x31\x02\x02\u{22d}\u{22e}\x07\
+		\x3f\x02\x02\u{22e}\u{89}\x03\x02\x02\x02\u{22f}\u{230}\x07\x2d\x02\x02\
+		\u{230}\u{8b}\x03\x02\x02\x02\u{231}\u{232}\x07\x2f\x02\x02\u{232}\u{8d}\
+		\x03\x02\x02\x02\u{233}\u{234}\x07\x2c\x02\x02\u{234}\u{8f}\x03\x02\x02\
+		\x02\u{235}\u{236}\x07\x31\x02\x02\u{236}\u{91}\x03\x02\x02\x02\u{237}\
+		\u{238}\x07\x27\x02\x02\u{238}\u{93}\x03\x02\x02\x02\u{239}\u{23a}\x07\
+		\x2a\x02\x02\u{23a}\u{95}\x03\x02\x02\x02\u{23b}\u{23c}\x07\x2b\x02\x02\
+		\u{23c}\u{97}\x03\x02\x02\x02\u{23d}\u{23e}\x07\x2e\x02\x02\u{23e}\u{99}\
+		\x03\x02\x02\x02\u{23f}\u{240}\x07\x63\x02\x02\u{240}\u{241}\x07\x75\
+		\x02\x02\u{241}\u{9b}\x03\x02\x02\x02\u{242}\u{243}\x07\x75\x02\x02\u{243}\
+		\u{244}\x07\x76\x02\x02\u{244}\u{245}\x07\x74\x02\x02\u{245}\u{246}\x07\
+		\x6b\x02\x02\u{246}\u{247}\x07\x70\x02\x02\u{247}\u{248}\x07\x69\x02\
+		\x02\u{248}\u{249}\x03\x02\x02\x02\u{249}\u{24a}\x08\x49\x16\x02\u{24a}\
+		\u{9d}\x03\x02\x02\x02\u{24b}\u{24c}\x07\x70\x02\x02\u{24c}\u{24d}\x07\
+		\x77\x02\x02\u{24d}\u{24e}\x07\x6f\x02\x02\u{24e}\u{24f}\x07\x64\x02\
+		\x02\u{24f}\u{250}\x07\x67\x02\x02\u{250}\u{251}\x07\x74\x02\x02\u{251}\
+		\u{252}\x03\x02\x02\x02\u{252}\u{253}\x08\x4a\x16\x02\u{253}\u{9f}\x03\
+		\x02\x02\x02\u{254}\u{255}\x07\x64\x02\x02\u{255}\u{256}\x07\x71\x02\
+		\x02\u{256}\u{257}\x07\x71\x02\x02\u{257}\u{258}\x07\x6e\x02\x02\u{258}\
+		\u{259}\x03\x02\x02\x02\u{259}\u{25a}\x08\x4b\x16\x02\u{25a}\u{a1}\x03\
+		\x02\x02\x02\u{25b}\u{261}\x07\x24\x02\x02\u{25c}\u{260}\x0a\x0a\x02\
+		\x02\u{25d}\u{25e}\x07\x5e\x02\x02\u{25e}\u{260}\x09\x0b\x02\x02\u{25f}\
+		\u{25c}\x03\x02\x02\x02\u{25f}\u{25d}\x03\x02\x02\x02\u{260}\u{263}\x03\
+		\x02\x02\x02\u{261}\u{25f}\x03\x02\x02\x02\u{261}\u{262}\x03\x02\x02\
+		\x02\u{262}\u{264}\x03\x02\x02\x02\u{263}\u{261}\x03\x02\x02\x02\u{264}\
+		\u{265}\x07\x24\x02\x02\u{265}\u{a3}\x03\x02\x02\x02\u{266}\u{267}\x05\
+		\x14\x05\x02\u{267}\u{a5}\x03\x02\x02\x02\u{268}\u{269}\x07\x7f\x02\x02\
+		\u{269}\u{26a}\x03\x02\x02\x02\u{26a}\u{26b}\x08\x4e\x09\x02\u{26b}\u{a7}\
+		\x03\x02\x02\x02\u{26c}\u{26d}\x07\x40\x02\x02\u{26d}\u{26e}\x07\x40\
+		\x02\x02\u{26e}\u{26f}\x03\x02\x02\x02\u{26f}\u{270}\x08\x4f\x17\x02\
 		\u{270}\u{271}\x08\x4f\x09\x02\u{271}\u{272}\x08\x4f\x09\x02\u{272}\u{a9}\
-		\x03\x02\x02\x02\u{273}\u{274}\x07\x26\x02\x02\u{274}\u{275}\x05\x14\x05\
-		\x02\u{275}\u{ab}\x03\x02\x02\x02\u{276}\u{277}\x07\x30\x02\x02\u{277}\
-		\u{ad}\x03\x02\x02\x02\u{278}\u{27e}\x05\u{b0}\x53\x02\u{279}\u{27a}\x05\
-		\u{b0}\x53\x02\u{27a}\u{27b}\x07\x30\x02\x02\u{27b}\u{27c}\x05\u{b0}\x53\
-		\x02\u{27c}\u{27e}\x03\x02\x02\x02\u{27d}\u{278}\x03\x02\x02\x02\u{27d}\
-		\u{279}\x03\x02\x02\x02\u{27e}\u{af}\x03\x02\x02\x02\u{27f}\u{281}\x05\
-		\u{b2}\x54\x02\u{280}\u{27f}\x03\x02\x02\x02\u{281}\u{282}\x03\x02\x02\
-		\x02\u{282}\u{280}\x03\x02\x02\x02\u{282}\u{283}\x03\x02\x02\x02\u{283}\
-		\u{b1}\x03\x02\x02\x02\u{284}\u{285}\x09\x0c\x02\x02\u{285}\u{b3}\x03\x02\
-		\x02\x02\u{286}\u{287}\x05\x0e\x02\x02\u{287}\u{288}\x03\x02\x02\x02\u{288}\
-		\u{289}\x08\x55\x02\x02\u{289}\u{b5}\x03\x02\x02\x02\u{28a}\u{28b}\x07\
-		\x6b\x02\x02\u{28b}\u{28c}\x07\x68\x02\x02\u{28c}\u{28d}\x03\x02\x02\x02\
-		\u{28d}\u{28e}\x09\x0d\x02\x02\u{28e}\u{28f}\x03\x02\x02\x02\u{28f}\u{290}\
-		\x08\x56\x0f\x02\u{290}\u{b7}\x03\x02\x02\x02\u{291}\u{292}\x07\x67\x02\
-		\x02\u{292}\u{293}\x07\x6e\x02\x02\u{293}\u{294}\x07\x75\x02\x02\u{294}\
-		\u{295}\x07\x67\x02\x02\u{295}\u{296}\x07\x6b\x02\x02\u{296}\u{297}\x07\
-		\x68\x02\x02\u{297}\u{298}\x03\x02\x02\x02\u{298}\u{299}\x09\x0d\x02\x02\
-		\u{299}\u{29a}\x03\x02\x02\x02\u{29a}\u{29b}\x08\x57\x0f\x02\u{29b}\u{b9}\
-		\x03\x02\x02\x02\u{29c}\u{29d}\x07\x67\x02\x02\u{29d}\u{29e}\x07\x6e\x02\
-		\x02\u{29e}\u{29f}\x07\x75\x02\x02\u{29f}\u{2a0}\x07\x67\x02\x02\u{2a0}\
-		\u{2a2}\x03\x02\x02\x02\u{2a1}\u{2a3}\x09\x0d\x02\x02\u{2a2}\u{2a1}\x03\
-		\x02\x02\x02\u{2a2}\u{2a3}\x03\x02\x02\x02\u{2a3}\u{bb}\x03\x02\x02\x02\
-		\u{2a4}\u{2a5}\x07\x75\x02\x02\u{2a5}\u{2a6}\x07\x67\x02\x02\u{2a6}\u{2a7}\
-		\x07\x76\x02\x02\u{2a7}\u{2a8}\x03\x02\x02\x02\u{2a8}\u{2a9}\x09\x0d\x02\
-		\x02\u{2a9}\u{2aa}\x03\x02\x02\x02\u{2aa}\u{2ab}\x08\x59\x0f\x02\u{2ab}\
-		\u{bd}\x03\x02\x02\x02\u{2ac}\u{2ad}\x07\x67\x02\x02\u{2ad}\u{2ae}\x07\
-		\x70\x02\x02\u{2ae}\u{2af}\x07\x66\x02\x02\u{2af}\u{2b0}\x07\x6b\x02\x02\
-		\u{2b0}\u{2b1}\x07\x68\x02\x02\u{2b1}\u{bf}\x03\x02\x02\x02\u{2b2}\u{2b3}\
-		\x07\x65\x02\x02\u{2b3}\u{2b4}\x07\x63\x02\x02\u{2b4}\u{2b5}\x07\x6e\x02\
-		\x02\u{2b5}\u{2b6}\x07\x6e\x02\x02\u{2b6}\u{2b7}\x03\x02\x02\x02\u{2b7}\
-		\u{2b8}\x09\x0d\x02\x02\u{2b8}\u{2b9}\x03\x02\x02\x02\u{2b9}\u{2ba}\x08\
-		\x5b\x0f\x02\u{2ba}\u{c1}\x03\x02\x02\x02\u{2bb}\u{2bc}\x07\x66\x02\x02\
-		\u{2bc}\u{2bd}\x07\x67\x02\x02\u{2bd}\u{2be}\x07\x65\x02\x02\u{2be}\u{2bf}\
-		\x07\x6e\x02\x02\u{2bf}\u{2c0}\x07\x63\x02\x02\u{2c0}\u{2c1}\x07\x74\x02\
-		\x02\u{2c1}\u{2c2}\x07\x67\x02\x02\u{2c2}\u{2c3}\x03\x02\x02\x02\u{2c3}\
-		\u{2c4}\x09\x0d\x02\x02\u{2c4}\u{2c5}\x03\x02\x02\x02\u{2c5}\u{2c6}\x08\
-		\x5c\x0f\x02\u{2c6}\u{c3}\x03\x02\x02\x02\u{2c7}\u{2c8}\x07\x6c\x02\x02\
-		\u{2c8}\u{2c9}\x07\x77\x02\x02\u{2c9}\u{2ca}\x07\x6f\x02\x02\u{2ca}\u{2cb}\
-		\x07\x72\x02\x02\u{2cb}\u{2cc}\x03\x02\x02\x02\u{2cc}\u{2cd}\x09\x0d\x02\
-		\x02\u{2cd}\u{2ce}\x03\x02\x02\x02\u{2ce}\u{2cf}\x08\x5d\x18\x02\u{2cf}\
-		\u{c5}\x03\x02\x02\x02\u{2d0}\u{2d1}\x07\x67\x02\x02\u{2d1}\u{2d2}\x07\
-		\x70\x02\x02\u{2d2}\u{2d3}\x07\x77\x02\x02\u{2d3}\u{2d4}\x07\x6f\x02\x02\
+		\x03\x02\x02\x02\u{273}\u{274}\x07\x26\x02\x02\u{274}\u{275}\x05\x14\
+		\x05\x02\u{275}\u{ab}\x03\x02\x02\x02\u{276}\u{277}\x07\x30\x02\x02\u{277}\
+		\u{ad}\x03\x02\x02\x02\u{278}\u{27e}\x05\u{b0}\x53\x02\u{279}\u{27a}\
+		\x05\u{b0}\x53\x02\u{27a}\u{27b}\x07\x30\x02\x02\u{27b}\u{27c}\x05\u{b0}\
+		\x53\x02\u{27c}\u{27e}\x03\x02\x02\x02\u{27d}\u{278}\x03\x02\x02\x02\
+		\u{27d}\u{279}\x03\x02\x02\x02\u{27e}\u{af}\x03\x02\x02\x02\u{27f}\u{281}\
+		\x05\u{b2}\x54\x02\u{280}\u{27f}\x03\x02\x02\x02\u{281}\u{282}\x03\x02\
+		\x02\x02\u{282}\u{280}\x03\x02\x02\x02\u{282}\u{283}\x03\x02\x02\x02\
+		\u{283}\u{b1}\x03\x02\x02\x02\u{284}\u{285}\x09\x0c\x02\x02\u{285}\u{b3}\
+		\x03\x02\x02\x02\u{286}\u{287}\x05\x0e\x02\x02\u{287}\u{288}\x03\x02\
+		\x02\x02\u{288}\u{289}\x08\x55\x02\x02\u{289}\u{b5}\x03\x02\x02\x02\u{28a}\
+		\u{28b}\x07\x6b\x02\x02\u{28b}\u{28c}\x07\x68\x02\x02\u{28c}\u{28d}\x03\
+		\x02\x02\x02\u{28d}\u{28e}\x09\x0d\x02\x02\u{28e}\u{28f}\x03\x02\x02\
+		\x02\u{28f}\u{290}\x08\x56\x0f\x02\u{290}\u{b7}\x03\x02\x02\x02\u{291}\
+		\u{292}\x07\x67\x02\x02\u{292}\u{293}\x07\x6e\x02\x02\u{293}\u{294}\x07\
+		\x75\x02\x02\u{294}\u{295}\x07\x67\x02\x02\u{295}\u{296}\x07\x6b\x02\
+		\x02\u{296}\u{297}\x07\x68\x02\x02\u{297}\u{298}\x03\x02\x02\x02\u{298}\
+		\u{299}\x09\x0d\x02\x02\u{299}\u{29a}\x03\x02\x02\x02\u{29a}\u{29b}\x08\
+		\x57\x0f\x02\u{29b}\u{b9}\x03\x02\x02\x02\u{29c}\u{29d}\x07\x67\x02\x02\
+		\u{29d}\u{29e}\x07\x6e\x02\x02\u{29e}\u{29f}\x07\x75\x02\x02\u{29f}\u{2a0}\
+		\x07\x67\x02\x02\u{2a0}\u{2a2}\x03\x02\x02\x02\u{2a1}\u{2a3}\x09\x0d\
+		\x02\x02\u{2a2}\u{2a1}\x03\x02\x02\x02\u{2a2}\u{2a3}\x03\x02\x02\x02\
+		\u{2a3}\u{bb}\x03\x02\x02\x02\u{2a4}\u{2a5}\x07\x75\x02\x02\u{2a5}\u{2a6}\
+		\x07\x67\x02\x02\u{2a6}\u{2a7}\x07\x76\x02\x02\u{2a7}\u{2a8}\x03\x02\
+		\x02\x02\u{2a8}\u{2a9}\x09\x0d\x02\x02\u{2a9}\u{2aa}\x03\x02\x02\x02\
+		\u{2aa}\u{2ab}\x08\x59\x0f\x02\u{2ab}\u{bd}\x03\x02\x02\x02\u{2ac}\u{2ad}\
+		\x07\x67\x02\x02\u{2ad}\u{2ae}\x07\x70\x02\x02\u{2ae}\u{2af}\x07\x66\
+		\x02\x02\u{2af}\u{2b0}\x07\x6b\x02\x02\u{2b0}\u{2b1}\x07\x68\x02\x02\
+		\u{2b1}\u{bf}\x03\x02\x02\x02\u{2b2}\u{2b3}\x07\x65\x02\x02\u{2b3}\u{2b4}\
+		\x07\x63\x02\x02\u{2b4}\u{2b5}\x07\x6e\x02\x02\u{2b5}\u{2b6}\x07\x6e\
+		\x02\x02\u{2b6}\u{2b7}\x03\x02\x02\x02\u{2b7}\u{2b8}\x09\x0d\x02\x02\
+		\u{2b8}\u{2b9}\x03\x02\x02\x02\u{2b9}\u{2ba}\x08\x5b\x0f\x02\u{2ba}\u{c1}\
+		\x03\x02\x02\x02\u{2bb}\u{2bc}\x07\x66\x02\x02\u{2bc}\u{2bd}\x07\x67\
+		\x02\x02\u{2bd}\u{2be}\x07\x65\x02\x02\u{2be}\u{2bf}\x07\x6e\x02\x02\
+		\u{2bf}\u{2c0}\x07\x63\x02\x02\u{2c0}\u{2c1}\x07\x74\x02\x02\u{2c1}\u{2c2}\
+		\x07\x67\x02\x02\u{2c2}\u{2c3}\x03\x02\x02\x02\u{2c3}\u{2c4}\x09\x0d\
+		\x02\x02\u{2c4}\u{2c5}\x03\x02\x02\x02\u{2c5}\u{2c6}\x08\x5c\x0f\x02\
+		\u{2c6}\u{c3}\x03\x02\x02\x02\u{2c7}\u{2c8}\x07\x6c\x02\x02\u{2c8}\u{2c9}\
+		\x07\x77\x02\x02\u{2c9}\u{2ca}\x07\x6f\x02\x02\u{2ca}\u{2cb}\x07\x72\
+		\x02\x02\u{2cb}\u{2cc}\x03\x02\x02\x02\u{2cc}\u{2cd}\x09\x0d\x02\x02\
+		\u{2cd}\u{2ce}\x03\x02\x02\x02\u{2ce}\u{2cf}\x08\x5d\x18\x02\u{2cf}\u{c5}\
+		\x03\x02\x02\x02\u{2d0}\u{2d1}\x07\x67\x02\x02\u{2d1}\u{2d2}\x07\x70\
+		\x02\x02\u{2d2}\u{2d3}\x07\x77\x02\x02\u{2d3}\u{2d4}\x07\x6f\x02\x02\
 		\u{2d4}\u{2d5}\x03\x02\x02\x02\u{2d5}\u{2d6}\x09\x0d\x02\x02\u{2d6}\u{2d7}\
 		\x03\x02\x02\x02\u{2d7}\u{2d8}\x08\x5e\x19\x02\u{2d8}\u{c7}\x03\x02\x02\
 		\x02\u{2d9}\u{2da}\x07\x65\x02\x02\u{2da}\u{2db}\x07\x63\x02\x02\u{2db}\
 		\u{2dc}\x07\x75\x02\x02\u{2dc}\u{2dd}\x07\x67\x02\x02\u{2dd}\u{2de}\x03\
-		\x02\x02\x02\u{2de}\u{2df}\x09\x0d\x02\x02\u{2df}\u{2e0}\x03\x02\x02\x02\
-		\u{2e0}\u{2e1}\x08\x5f\x19\x02\u{2e1}\u{c9}\x03\x02\x02\x02\u{2e2}\u{2e3}\
-		\x07\x67\x02\x02\u{2e3}\u{2e4}\x07\x70\x02\x02\u{2e4}\u{2e5}\x07\x66\x02\
-		\x02\u{2e5}\u{2e6}\x07\x67\x02\x02\u{2e6}\u{2e7}\x07\x70\x02\x02\u{2e7}\
-		\u{2e8}\x07\x77\x02\x02\u{2e8}\u{2e9}\x07\x6f\x02\x02\u{2e9}\u{2eb}\x03\
-		\x02\x02\x02\u{2ea}\u{2ec}\x09\x0d\x02\x02\u{2eb}\u{2ea}\x03\x02\x02\x02\
-		\u{2eb}\u{2ec}\x03\x02\x02\x02\u{2ec}\u{cb}\x03\x02\x02\x02\u{2ed}\u{2ee}\
-		\x07\x6e\x02\x02\u{2ee}\u{2ef}\x07\x71\x02\x02\u{2ef}\u{2f0}\x07\x65\x02\
-		\x02\u{2f0}\u{2f1}\x07\x63\x02\x02\u{2f1}\u{2f2}\x07\x6e\x02\x02\u{2f2}\
-		\u{2f3}\x03\x02\x02\x02\u{2f3}\u{2f4}\x09\x0d\x02\x02\u{2f4}\u{cd}\x03\
-		\x02\x02\x02\u{2f5}\u{2f6}\x07\x40\x02\x02\u{2f6}\u{2f7}\x07\x40\x02\x02\
-		\u{2f7}\u{2f8}\x03\x02\x02\x02\u{2f8}\u{2f9}\x08\x62\x09\x02\u{2f9}\u{cf}\
-		\x03\x02\x02\x02\u{2fa}\u{2fb}\x0b\x02\x02\x02\u{2fb}\u{2fc}\x03\x02\x02\
-		\x02\u{2fc}\u{2fd}\x08\x63\x1a\x02\u{2fd}\u{2fe}\x08\x63\x1b\x02\u{2fe}\
-		\u{d1}\x03\x02\x02\x02\u{2ff}\u{300}\x07\x40\x02\x02\u{300}\u{301}\x07\
-		\x40\x02\x02\u{301}\u{302}\x03\x02\x02\x02\u{302}\u{303}\x08\x64\x09\x02\
-		\u{303}\u{d3}\x03\x02\x02\x02\u{304}\u{305}\x07\x7d\x02\x02\u{305}\u{306}\
-		\x03\x02\x02\x02\u{306}\u{307}\x08\x65\x0f\x02\u{307}\u{d5}\x03\x02\x02\
-		\x02\u{308}\u{30a}\x0a\x0e\x02\x02\u{309}\u{308}\x03\x02\x02\x02\u{30a}\
-		\u{30b}\x03\x02\x02\x02\u{30b}\u{309}\x03\x02\x02\x02\u{30b}\u{30c}\x03\
-		\x02\x02\x02\u{30c}\u{d7}\x03\x02\x02\x02\u{30d}\u{30e}\x05\x14\x05\x02\
-		\u{30e}\u{30f}\x03\x02\x02\x02\u{30f}\u{310}\x08\x67\x1c\x02\u{310}\u{311}\
-		\x08\x67\x09\x02\u{311}\u{d9}\x03\x02\x02\x02\u{312}\u{313}\x07\x40\x02\
-		\x02\u{313}\u{314}\x07\x40\x02\x02\u{314}\u{315}\x03\x02\x02\x02\u{315}\
-		\u{316}\x08\x68\x17\x02\u{316}\u{317}\x08\x68\x09\x02\u{317}\u{db}\x03\
-		\x02\x02\x02\u{318}\u{319}\x05\x14\x05\x02\u{319}\u{31a}\x03\x02\x02\x02\
-		\u{31a}\u{31b}\x08\x69\x1c\x02\u{31b}\u{31c}\x08\x69\x09\x02\u{31c}\u{dd}\
-		\x03\x02\x02\x02\u{31d}\u{31e}\x05\x34\x15\x02\u{31e}\u{31f}\x03\x02\x02\
-		\x02\u{31f}\u{320}\x08\x6a\x14\x02\u{320}\u{321}\x08\x6a\x1d\x02\u{321}\
-		\u{df}\x03\x02\x02\x02\u{322}\u{323}\x07\x40\x02\x02\u{323}\u{324}\x07\
-		\x40\x02\x02\u{324}\u{325}\x03\x02\x02\x02\u{325}\u{326}\x08\x6b\x17\x02\
-		\u{326}\u{327}\x08\x6b\x09\x02\u{327}\u{e1}\x03\x02\x02\x02\x2f\x02\x03\
-		\x04\x05\x06\x07\x08\x09\x0a\x0b\x0c\x0d\u{e5}\u{ef}\u{f5}\u{f9}\u{fe}\
-		\u{105}\u{108}\u{10c}\u{111}\u{11d}\u{129}\u{16c}\u{18e}\u{191}\u{1c3}\
-		\u{1de}\u{1e5}\u{1ec}\u{1f4}\u{1f9}\u{1fe}\u{205}\u{20c}\u{212}\u{218}\
-		\u{21e}\u{25f}\u{261}\u{27d}\u{282}\u{2a2}\u{2eb}\u{30b}\x1e\x02\x03\x02\
-		\x02\x05\x02\x02\x04\x02\x07\x04\x02\x07\x03\x02\x07\x08\x02\x09\x08\x02\
-		\x06\x02\x02\x09\x07\x02\x07\x0a\x02\x09\x0c\x02\x07\x07\x02\x07\x05\x02\
-		\x07\x09\x02\x08\x02\x02\x07\x06\x02\x09\x16\x02\x04\x07\x02\x09\x12\x02\
-		\x09\x11\x02\x09\x3b\x02\x09\x4d\x02\x07\x0d\x02\x07\x0c\x02\x09\x50\x02\
-		\x04\x0b\x02\x09\x09\x02\x04\x09\x02";
+		\x02\x02\x02\u{2de}\u{2df}\x09\x0d\x02\x02\u{2df}\u{2e0}\x03\x02\x02\
+		\x02\u{2e0}\u{2e1}\x08\x5f\x19\x02\u{2e1}\u{c9}\x03\x02\x02\x02\u{2e2}\
+		\u{2e3}\x07\x67\x02\x02\u{2e3}\u{2e4}\x07\x70\x02\x02\u{2e4}\u{2e5}\x07\
+		\x66\x02\x02\u{2e5}\u{2e6}\x07\x67\x02\x02\u{2e6}\u{2e7}\x07\x70\x02\
+		\x02\u{2e7}\u{2e8}\x07\x77\x02\x02\u{2e8}\u{2e9}\x07\x6f\x02\x02\u{2e9}\
+		\u{2eb}\x03\x02\x02\x02\u{2ea}\u{2ec}\x09\x0d\x02\x02\u{2eb}\u{2ea}\x03\
+		\x02\x02\x02\u{2eb}\u{2ec}\x03\x02\x02\x02\u{2ec}\u{cb}\x03\x02\x02\x02\
+		\u{2ed}\u{2ee}\x07\x6e\x02\x02\u{2ee}\u{2ef}\x07\x71\x02\x02\u{2ef}\u{2f0}\
+		\x07\x65\x02\x02\u{2f0}\u{2f1}\x07\x63\x02\x02\u{2f1}\u{2f2}\x07\x6e\
+		\x02\x02\u{2f2}\u{2f3}\x03\x02\x02\x02\u{2f3}\u{2f4}\x09\x0d\x02\x02\
+		\u{2f4}\u{328}\x03\x02\x02\x02\u{2f5}\u{2f6}\x07\x40\x02\x02\u{2f6}\u{2f7}\
+		\x07\x40\x02\x02\u{2f7}\u{2f8}\x03\x02\x02\x02\u{2f8}\u{2f9}\x08\x62\
+		\x09\x02\u{2f9}\u{cf}\x03\x02\x02\x02\u{2fa}\u{2fb}\x0b\x02\x02\x02\u{2fb}\
+		\u{2fc}\x03\x02\x02\x02\u{2fc}\u{2fd}\x08\x63\x1a\x02\u{2fd}\u{2fe}\x08\
+		\x63\x1b\x02\u{2fe}\u{d1}\x03\x02\x02\x02\u{2ff}\u{300}\x07\x40\x02\x02\
+		\u{300}\u{301}\x07\x40\x02\x02\u{301}\u{302}\x03\x02\x02\x02\u{302}\u{303}\
+		\x08\x64\x09\x02\u{303}\u{d3}\x03\x02\x02\x02\u{304}\u{305}\x07\x7d\x02\
+		\x02\u{305}\u{306}\x03\x02\x02\x02\u{306}\u{307}\x08\x65\x0f\x02\u{307}\
+		\u{d5}\x03\x02\x02\x02\u{308}\u{30a}\x0a\x0e\x02\x02\u{309}\u{308}\x03\
+		\x02\x02\x02\u{30a}\u{30b}\x03\x02\x02\x02\u{30b}\u{309}\x03\x02\x02\
+		\x02\u{30b}\u{30c}\x03\x02\x02\x02\u{30c}\u{d7}\x03\x02\x02\x02\u{30d}\
+		\u{30e}\x05\x14\x05\x02\u{30e}\u{30f}\x03\x02\x02\x02\u{30f}\u{310}\x08\
+		\x67\x1c\x02\u{310}\u{311}\x08\x67\x09\x02\u{311}\u{d9}\x03\x02\x02\x02\
+		\u{312}\u{313}\x07\x40\x02\x02\u{313}\u{314}\x07\x40\x02\x02\u{314}\u{315}\
+		\x03\x02\x02\x02\u{315}\u{316}\x08\x68\x17\x02\u{316}\u{317}\x08\x68\
+		\x09\x02\u{317}\u{db}\x03\x02\x02\x02\u{318}\u{319}\x05\x14\x05\x02\u{319}\
+		\u{31a}\x03\x02\x02\x02\u{31a}\u{31b}\x08\x69\x1c\x02\u{31b}\u{31c}\x08\
+		\x69\x09\x02\u{31c}\u{dd}\x03\x02\x02\x02\u{31d}\u{31e}\x05\x34\x15\x02\
+		\u{31e}\u{31f}\x03\x02\x02\x02\u{31f}\u{320}\x08\x6a\x14\x02\u{320}\u{321}\
+		\x08\x6a\x1d\x02\u{321}\u{df}\x03\x02\x02\x02\u{322}\u{323}\x07\x40\x02\
+		\x02\u{323}\u{324}\x07\x40\x02\x02\u{324}\u{325}\x03\x02\x02\x02\u{325}\
+		\u{326}\x08\x6b\x17\x02\u{326}\u{327}\x08\x6b\x09\x02\u{327}\u{e1}\x03\
+		\x02\x02\x02\u{328}\u{329}\x08\x61\x0f\x02\u{329}\u{cd}\x03\x02\x02\x02\
+		\u{32a}\u{32c}\x03\x02\x02\x02\u{32c}\u{32d}\x07\x3f\x02\x02\u{32d}\u{32e}\
+		\x07\x40\x02\x02\u{32e}\u{32b}\x03\x02\x02\x02\u{32f}\u{331}\x03\x02\
+		\x02\x02\u{331}\u{332}\x07\x71\x02\x02\u{332}\u{333}\x07\x70\x02\x02\
+		\u{333}\u{334}\x07\x65\x02\x02\u{334}\u{335}\x07\x67\x02\x02\u{335}\u{330}\
+		\x03\x02\x02\x02\u{336}\u{338}\x03\x02\x02\x02\u{338}\u{339}\x07\x67\
+		\x02\x02\u{339}\u{33a}\x07\x70\x02\x02\u{33a}\u{33b}\x07\x66\x02\x02\
+		\u{33b}\u{33c}\x07\x71\x02\x02\u{33c}\u{33d}\x07\x70\x02\x02\u{33d}\u{33e}\
+		\x07\x65\x02\x02\u{33e}\u{33f}\x07\x67\x02\x02\u{33f}\u{337}\x03\x02\
+		\x02\x02\x2f\x02\x03\x04\x05\x06\x07\x08\x09\x0a\x0b\x0c\x0d\u{e5}\u{ef}\
+		\u{f5}\u{f9}\u{fe}\u{105}\u{108}\u{10c}\u{111}\u{11d}\u{129}\u{16c}\u{18e}\
+		\u{191}\u{1c3}\u{1de}\u{1e5}\u{1ec}\u{1f4}\u{1f9}\u{1fe}\u{205}\u{20c}\
+		\u{212}\u{218}\u{21e}\u{25f}\u{261}\u{27d}\u{282}\u{2a2}\u{2eb}\u{30b}\
+		\x1e\x02\x03\x02\x02\x05\x02\x02\x04\x02\x07\x04\x02\x07\x03\x02\x07\
+		\x08\x02\x09\x08\x02\x06\x02\x02\x09\x07\x02\x07\x0a\x02\x09\x0c\x02\
+		\x07\x07\x02\x07\x05\x02\x07\x09\x02\x08\x02\x02\x07\x06\x02\x09\x16\
+		\x02\x04\x07\x02\x09\x12\x02\x09\x11\x02\x09\x3b\x02\x09\x4d\x02\x07\
+		\x0d\x02\x07\x0c\x02\x09\x50\x02\x04\x0b\x02\x09\x09\x02\x04\x09\x02";
@@ -180,6 +180,13 @@ pub trait YarnSpinnerParserVisitor<'input>: ParseTreeVisitor<'input,YarnSpinnerP
 	 */
 	fn visit_valueFunc(&mut self, ctx: &ValueFuncContext<'input>) { self.visit_children(ctx) }
 
+	/**
+	 * Visit a parse tree produced by the {@code valueTypeMemberReference}
+	 * labeled alternative in {@link YarnSpinnerParser#value}.
+	 * @param ctx the parse tree
+	 */
+	fn visit_valueTypeMemberReference(&mut self, ctx: &ValueTypeMemberReferenceContext<'input>) { self.visit_children(ctx) }
+
 	/**
 	 * Visit a parse tree produced by {@link YarnSpinnerParser#variable}.
 	 * @param ctx the parse tree
@@ -272,6 +279,48 @@ pub trait YarnSpinnerParserVisitor<'input>: ParseTreeVisitor<'input,YarnSpinnerP
 	 */
 	fn visit_jumpToExpression(&mut self, ctx: &JumpToExpressionContext<'input>) { self.visit_children(ctx) }
 
+	/**
+	 * Visit a parse tree produced by {@link YarnSpinnerParser#enum_statement}.
+	 * @param ctx the parse tree
+	 */
+	fn visit_enum_statement(&mut self, ctx: &Enum_statementContext<'input>) { self.visit_children(ctx) }
+
+	/**
+	 * Visit a parse tree produced by {@link YarnSpinnerParser#enum_case_statement}.
+	 * @param ctx the parse tree
+	 */
+	fn visit_enum_case_statement(&mut self, ctx: &Enum_case_statementContext<'input>) { self.visit_children(ctx) }
+
+	/**
+	 * Visit a parse tree produced by {@link YarnSpinnerParser#typeMemberReference}.
+	 * @param ctx the parse tree
+	 */
+	fn visit_typeMemberReference(&mut self, ctx: &TypeMemberReferenceContext<'input>) { self.visit_children(ctx) }
+
+	/**
+	 * Visit a parse tree produced by {@link YarnSpinnerParser#local_statement}.
+	 * @param ctx the parse tree
+	 */
+	fn visit_local_statement(&mut self, ctx: &Local_statementContext<'input>) { self.visit_children(ctx) }
+
+	/**
+	 * Visit a parse tree produced by {@link YarnSpinnerParser#line_group_statement}.
+	 * @param ctx the parse tree
+	 */
+	fn visit_line_group_statement(&mut self, ctx: &Line_group_statementContext<'input>) { self.visit_children(ctx) }
+
+	/**
+	 * Visit a parse tree produced by {@link YarnSpinnerParser#line_group_item}.
+	 * @param ctx the parse tree
+	 */
+	fn visit_line_group_item(&mut self, ctx: &Line_group_itemContext<'input>) { self.visit_children(ctx) }
+
+	/**
+	 * Visit a parse tree produced by {@link YarnSpinnerParser#once_statement}.
+	 * @param ctx the parse tree
+	 */
+	fn visit_once_statement(&mut self, ctx: &Once_statementContext<'input>) { self.visit_children(ctx) }
+
 }
 
 pub trait YarnSpinnerParserVisitorCompat<'input>:ParseTreeVisitorCompat<'input, Node= YarnSpinnerParserContextType>{
@@ -499,6 +548,15 @@ pub trait YarnSpinnerParserVisitorCompat<'input>:ParseTreeVisitorCompat<'input,
 			self.visit_children(ctx)
 		}
 
+	/**
+	 * Visit a parse tree produced by the {@code valueTypeMemberReference}
+	 * labeled alternative in {@link YarnSpinnerParser#value}.
+	 * @param ctx the parse tree
+	 */
+		fn visit_valueTypeMemberReference(&mut self, ctx: &ValueTypeMemberReferenceContext<'input>) -> Self::Return {
+			self.visit_children(ctx)
+		}
+
 	/**
 	 * Visit a parse tree produced by {@link YarnSpinnerParser#variable}.
 	 * @param ctx the parse tree
@@ -621,6 +679,62 @@ pub trait YarnSpinnerParserVisitorCompat<'input>:ParseTreeVisitorCompat<'input,
 			self.visit_children(ctx)
 		}
 
+	/**
+	 * Visit a parse tree produced by {@link YarnSpinnerParser#enum_statement}.
+	 * @param ctx the parse tree
+	 */
+		fn visit_enum_statement(&mut self, ctx: &Enum_statementContext<'input>) -> Self::Return {
+			self.visit_children(ctx)
+		}
+
+	/**
+	 * Visit a parse tree produced by {@link YarnSpinnerParser#enum_case_statement}.
+	 * @param ctx the parse tree
+	 */
+		fn visit_enum_case_statement(&mut self, ctx: &Enum_case_statementContext<'input>) -> Self::Return {
+			self.visit_children(ctx)
+		}
+
+	/**
+	 * Visit a parse tree produced by {@link YarnSpinnerParser#typeMemberReference}.
+	 * @param ctx the parse tree
+	 */
+		fn visit_typeMemberReference(&mut self, ctx: &TypeMemberReferenceContext<'input>) -> Self::Return {
+			self.visit_children(ctx)
+		}
+
+	/**
+	 * Visit a parse tree produced by {@link YarnSpinnerParser#local_statement}.
+	 * @param ctx the parse tree
+	 */
+		fn visit_local_statement(&mut self, ctx: &Local_statementContext<'input>) -> Self::Return {
+			self.visit_children(ctx)
+		}
+
+	/**
+	 * Visit a parse tree produced by {@link YarnSpinnerParser#line_group_statement}.
+	 * @param ctx the parse tree
+	 */
+		fn visit_line_group_statement(&mut self, ctx: &Line_group_statementContext<'input>) -> Self::Return {
+			self.visit_children(ctx)
+		}
+
+	/**
+	 * Visit a parse tree produced by {@link YarnSpinnerParser#line_group_item}.
+	 * @param ctx the parse tree
+	 */
+		fn visit_line_group_item(&mut self, ctx: &Line_group_itemContext<'input>) -> Self::Return {
+			self.visit_children(ctx)
+		}
+
+	/**
+	 * Visit a parse tree produced by {@link YarnSpinnerParser#once_statement}.
+	 * @param ctx the parse tree
+	 */
+		fn visit_once_statement(&mut self, ctx: &Once_statementContext<'input>) -> Self::Return {
+			self.visit_children(ctx)
+		}
+
 }
 
 impl<'input,T> YarnSpinnerParserVisitor<'input> for T
@@ -757,6 +871,11 @@ where
         *<Self as ParseTreeVisitorCompat>::temp_result(self) = result;
 	}
 
+	fn visit_valueTypeMemberReference(&mut self, ctx: &ValueTypeMemberReferenceContext<'input>){
+		let result = <Self as YarnSpinnerParserVisitorCompat>::visit_valueTypeMemberReference(self, ctx);
+        *<Self as ParseTreeVisitorCompat>::temp_result(self) = result;
+	}
+
 	fn visit_variable(&mut self, ctx: &VariableContext<'input>){
 		let result = <Self as YarnSpinnerParserVisitorCompat>::visit_variable(self, ctx);
         *<Self as ParseTreeVisitorCompat>::temp_result(self) = result;
@@ -832,4 +951,39 @@ where
         *<Self as ParseTreeVisitorCompat>::temp_result(self) = result;
 	}
 
+	fn visit_enum_statement(&mut self, ctx: &Enum_statementContext<'input>){
+		let result = <Self as YarnSpinnerParserVisitorCompat>::visit_enum_statement(self, ctx);
+        *<Self as ParseTreeVisitorCompat>::temp_result(self) = result;
+	}
+
+	fn visit_enum_case_statement(&mut self, ctx: &Enum_case_statementContext<'input>){
+		let result = <Self as YarnSpinnerParserVisitorCompat>::visit_enum_case_statement(self, ctx);
+        *<Self as ParseTreeVisitorCompat>::temp_result(self) = result;
+	}
+
+	fn visit_typeMemberReference(&mut self, ctx: &TypeMemberReferenceContext<'input>){
+		let result = <Self as YarnSpinnerParserVisitorCompat>::visit_typeMemberReference(self, ctx);
+        *<Self as ParseTreeVisitorCompat>::temp_result(self) = result;
+	}
+
+	fn visit_local_statement(&mut self, ctx: &Local_statementContext<'input>){
+		let result = <Self as YarnSpinnerParserVisitorCompat>::visit_local_statement(self, ctx);
+        *<Self as ParseTreeVisitorCompat>::temp_result(self) = result;
+	}
+
+	fn visit_line_group_statement(&mut self, ctx: &Line_group_statementContext<'input>){
+		let result = <Self as YarnSpinnerParserVisitorCompat>::visit_line_group_statement(self, ctx);
+        *<Self as ParseTreeVisitorCompat>::temp_result(self) = result;
+	}
+
+	fn visit_line_group_item(&mut self, ctx: &Line_group_itemContext<'input>){
+		let result = <Self as YarnSpinnerParserVisitorCompat>::visit_line_group_item(self, ctx);
+        *<Self as ParseTreeVisitorCompat>::temp_result(self) = result;
+	}
+
+	fn visit_once_statement(&mut self, ctx: &Once_statementContext<'input>){
+		let result = <Self as YarnSpinnerParserVisitorCompat>::visit_once_statement(self, ctx);
+        *<Self as ParseTreeVisitorCompat>::temp_result(self) = result;
+	}
+
 }
\ No newline at end of file
@@ -9,7 +9,7 @@ use super::generated::yarnspinnerlexer::{
     self, LocalTokenFactory, YarnSpinnerLexer as GeneratedYarnSpinnerLexer,
 };
 use crate::collections::*;
-use crate::listeners::Diagnostic;
+use crate::listeners::{Diagnostic, DiagnosticCode};
 use crate::prelude::{create_common_token, DiagnosticSeverity, TokenExt};
 use antlr_rust::token::CommonToken;
 use antlr_rust::{
@@ -61,6 +61,8 @@ pub(crate) struct IndentAwareYarnSpinnerLexer<
     /// holds the line number of the last seen option.
     /// Lets us work out if the blank line needs to end the option.
     last_seen_option_content: Option<isize>,
+    /// Whether the value of a `when` header is currently being lexed as an expression.
+    lexing_when_header: bool,
     file_name: String,
     pub(crate) diagnostics: Rc<RefCell<Vec<Diagnostic>>>,
 }
@@ -136,11 +138,19 @@ where
             last_indent: Default::default(),
             unbalanced_indents: Default::default(),
             last_seen_option_content: None,
+            lexing_when_header: false,
             diagnostics: Default::default(),
         }
     }
 
     fn check_next_token(&mut self) {
+        if self.lexing_when_header && self.is_at_end_of_line() {
+            // The expression ends with the line. Let the header mode lex the newline,
+            // which also takes us back to the default mode.
+            self.base.set_mode(yarnspinnerlexer::HeaderMode);
+            self.lexing_when_header = false;
+        }
+
         let current = self.base.next_token();
 
         match current.token_type {
@@ -150,13 +160,19 @@ where
             // Insert dedents before the end of the file, and then
             // enqueues the EOF.
             antlr_rust::token::TOKEN_EOF => self.handle_eof_token(current.clone()),
-            yarnspinnerlexer::SHORTCUT_ARROW => {
+            yarnspinnerlexer::SHORTCUT_ARROW | yarnspinnerlexer::LINE_GROUP_ARROW => {
                 self.pending_tokens.enqueue(current.clone());
                 self.line_contains_shortcut = true;
             }
             // we are at the end of the node
             // depth no longer matters
             // clear the stack
+            // The value of a `when` header is a condition, so lex it like the inside of a command
+            yarnspinnerlexer::HEADER_DELIMITER if self.is_after_when_header_key() => {
+                self.base.set_mode(yarnspinnerlexer::ExpressionMode);
+                self.lexing_when_header = true;
+                self.pending_tokens.enqueue(current.clone());
+            }
             yarnspinnerlexer::COMMAND_TEXT => {
                 self.diagnose_newlines_in_commands(&current);
                 self.pending_tokens.enqueue(current.clone());
@@ -176,6 +192,17 @@ where
         self.last_token = Some(current);
     }
 
+    fn is_after_when_header_key(&self) -> bool {
+        self.last_token.as_ref().is_some_and(|token| {
+            token.token_type == yarnspinnerlexer::ID && token.get_text() == "when"
+        })
+    }
+
+    fn is_at_end_of_line(&mut self) -> bool {
+        let next = self.base.input().la(1);
+        next == antlr_rust::int_stream::EOF || next == '\n' as isize || next == '\r' as isize
+    }
+
     fn handle_newline_token(
         &mut self,
         current_token: Box<antlr_rust::token::GenericToken<std::borrow::Cow<'input, str>>>,
@@ -316,12 +343,15 @@ where
 
         if saw_spaces && saw_tabs {
             self.diagnostics.borrow_mut().push(
-                Diagnostic::from_message("Indentation contains tabs and spaces")
-                    .with_range(get_newline_indentation_range(current_token))
-                    .with_context(get_newline_indentation_text(current_token))
-                    .with_start_line(current_token.line as usize)
-                    .with_file_name(self.file_name.clone())
-                    .with_severity(DiagnosticSeverity::Warning),
+                Diagnostic::new(
+                    DiagnosticCode::MixedIndentation,
+                    "Indentation contains tabs and spaces",
+                )
+                .with_range(get_newline_indentation_range(current_token))
+                .with_context(get_newline_indentation_text(current_token))
+                .with_start_line(current_token.line as usize)
+                .with_file_name(self.file_name.clone())
+                .with_severity(DiagnosticSeverity::Warning),
             );
         }
 
@@ -356,20 +386,23 @@ where
             let line_len = token.get_text().lines().count();
             let last_line_len = token.get_text().lines().last().unwrap().len();
             self.diagnostics.borrow_mut().push(
-                Diagnostic::from_message("Newlines are not allowed in commands")
-                    .with_range(
-                        Position {
-                            line: token.get_line_as_usize() - 1,
-                            character: token.get_column_as_usize(),
-                        }..Position {
-                            line: token.get_line_as_usize() - 1 + line_len,
-                            character: last_line_len,
-                        },
-                    )
-                    .with_context(token.get_text().to_string())
-                    .with_start_line(token.get_line_as_usize() - 1)
-                    .with_file_name(self.file_name.clone())
-                    .with_severity(DiagnosticSeverity::Error),
+                Diagnostic::new(
+                    DiagnosticCode::NewlineInCommand,
+                    "Newlines are not allowed in commands",
+                )
+                .with_range(
+                    Position {
+                        line: token.get_line_as_usize() - 1,
+                        character: token.get_column_as_usize(),
+                    }..Position {
+                        line: token.get_line_as_usize() - 1 + line_len,
+                        character: last_line_len,
+                    },
+                )
+                .with_context(token.get_text().to_string())
+                .with_start_line(token.get_line_as_usize() - 1)
+                .with_file_name(self.file_name.clone())
+                .with_severity(DiagnosticSeverity::Error),
             );
         }
     }
@@ -0,0 +1,64 @@
+//! The input stream for the lexer.
+//!
+//! ANTLR's `CodePoint32BitCharStream` borrows the characters it lexes, which ties the lifetime of every token
+//! and parse tree to the characters of the file. Owning them instead lets parse results outlive a single compilation,
+//! which is what allows [`IncrementalCompiler`](crate::prelude::IncrementalCompiler) to cache them.
+
+use antlr_rust::char_stream::{CharStream, InputData};
+use antlr_rust::int_stream::IntStream;
+use antlr_rust::InputStream;
+use std::borrow::Cow;
+
+/// An input stream over Unicode code points that owns its data.
+#[derive(Debug)]
+pub(crate) struct OwnedInputStream(InputStream<Box<[u32]>>);
+
+antlr_rust::tid! { OwnedInputStream }
+
+impl OwnedInputStream {
+    pub(crate) fn new(source: &str) -> Self {
+        let chars: Box<[u32]> = source.chars().map(|c| c as u32).collect();
+        Self(InputStream::new_owned(chars))
+    }
+}
+
+impl<'a> CharStream<Cow<'a, str>> for OwnedInputStream {
+    fn get_text(&self, a: isize, b: isize) -> Cow<'a, str> {
+        let chars: Vec<u32> = self.0.get_text(a, b);
+        chars.as_slice().to_display().into()
+    }
+}
+
+impl IntStream for OwnedInputStream {
+    fn consume(&mut self) {
+        self.0.consume()
+    }
+
+    fn la(&mut self, i: isize) -> isize {
+        self.0.la(i)
+    }
+
+    fn mark(&mut self) -> isize {
+        self.0.mark()
+    }
+
+    fn release(&mut self, marker: isize) {
+        self.0.release(marker)
+    }
+
+    fn index(&self) -> isize {
+        self.0.index()
+    }
+
+    fn seek(&mut self, index: isize) {
+        self.0.seek(index)
+    }
+
+    fn size(&self) -> isize {
+        self.0.size()
+    }
+
+    fn get_source_name(&self) -> String {
+        self.0.get_source_name()
+    }
+}
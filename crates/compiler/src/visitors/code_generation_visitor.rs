@@ -262,6 +262,23 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
         self.visit(ctx.function_call().unwrap().as_ref())
     }
 
+    /// enum cases are represented by their name at runtime
+    fn visit_valueTypeMemberReference(
+        &mut self,
+        ctx: &ValueTypeMemberReferenceContext<'input>,
+    ) -> Self::Return {
+        let reference = ctx.typeMemberReference().unwrap();
+        let case_name = reference.memberName.as_ref().unwrap().get_text();
+        self.compiler_listener.emit(
+            Emit::from_op_code(OpCode::PushString)
+                .with_token(ctx.start().deref())
+                .with_operand(case_name.to_owned()),
+        )
+    }
+
+    /// enum declarations only matter for type checking
+    fn visit_enum_statement(&mut self, _ctx: &Enum_statementContext<'input>) -> Self::Return {}
+
     fn visit_variable(&mut self, ctx: &VariableContext<'input>) -> Self::Return {
         let variable_name = ctx.VAR_ID().unwrap().get_text();
         self.compiler_listener.emit(
@@ -431,7 +448,8 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
         );
 
         // [sic] TODO: look into replacing this as it seems a bit odd
-        match composed_string.as_str() {
+        // Whitespace before `>>` is part of the text, but not of the command's name
+        match composed_string.trim_end() {
             "stop" => {
                 // "stop" is a special command that immediately stops
                 // execution
@@ -439,6 +457,33 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
                     Emit::from_op_code(OpCode::Stop).with_token(formatted_text.start().deref()),
                 );
             }
+            "return" => {
+                // "return" goes back to the node that detoured into this
+                // one, so this node is done just like with a jump
+                if let Some(tracking_enabled) = self.tracking_enabled.clone() {
+                    Self::generate_tracking_code(self.compiler_listener, tracking_enabled);
+                }
+                self.compiler_listener.emit(
+                    Emit::from_op_code(OpCode::Return).with_token(formatted_text.start().deref()),
+                );
+            }
+            // "detour" runs another node and then comes back here. The
+            // destination is either a node name or a single expression,
+            // which has already been pushed onto the stack above.
+            detour if Self::is_detour(detour, expression_count) => {
+                let destination = detour["detour".len()..].trim();
+                if expression_count == 0 {
+                    self.compiler_listener.emit(
+                        Emit::from_op_code(OpCode::PushString)
+                            .with_token(formatted_text.start().deref())
+                            .with_operand(destination.to_owned()),
+                    );
+                }
+                self.compiler_listener.emit(
+                    Emit::from_op_code(OpCode::DetourToNode)
+                        .with_token(formatted_text.start().deref()),
+                );
+            }
             _ => {
                 self.compiler_listener.emit(
                     Emit::from_op_code(OpCode::RunCommand)
@@ -457,6 +502,7 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
     ) -> Self::Return {
         let end_of_group_label = self.compiler_listener.register_label("group_end");
         let mut labels = Vec::new();
+        let mut once_variables = Vec::new();
 
         // For each option, create an internal destination label that, if
         // the user selects the option, control flow jumps to. Then,
@@ -483,17 +529,26 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
             // This line statement may have a condition on it. If it does,
             // emit code that evaluates the condition, and add a flag on the
             // 'Add Option' instruction that indicates that a condition exists.
-            let has_line_condition = if let Some(expression) = shortcut
+            let line_condition = shortcut
                 .line_statement()
-                .and_then(|ctx| ctx.line_condition())
-                .and_then(|ctx| ctx.expression())
+                .and_then(|ctx| ctx.line_condition());
+            let once_variable = line_condition
+                .as_ref()
+                .and_then(|ctx| self.once_variable(ctx.as_ref()));
+            let has_line_condition = if let Some(expression) =
+                line_condition.as_ref().and_then(|ctx| ctx.expression())
             {
                 // Evaluate the condition, and leave it on the stack
                 self.visit(expression.as_ref());
                 true
+            } else if let Some(once_variable) = &once_variable {
+                // A once-only option is available until it is chosen
+                self.generate_code_for_once_condition(once_variable, shortcut.start().deref());
+                true
             } else {
                 false
             };
+            once_variables.push(once_variable);
 
             // We can now prepare and add the option.
 
@@ -537,6 +592,9 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
                 labels[option_count].clone(),
                 current_node.instructions.len() as i32,
             );
+            if let Some(once_variable) = once_variables[option_count].clone() {
+                self.generate_code_for_marking_once(once_variable, shortcut.start().deref());
+            }
 
             // Run through all the children statements of the shortcut option
             for child in shortcut.statement_all() {
@@ -560,10 +618,189 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
             .emit(Emit::from_op_code(OpCode::Pop).with_token(token.deref()));
     }
 
+    /// for the line groups (=> line of text <<if expression>> indent statements dedent)+
+    fn visit_line_group_statement(
+        &mut self,
+        ctx: &Line_group_statementContext<'input>,
+    ) -> Self::Return {
+        let end_of_group_label = self.compiler_listener.register_label("line_group_end");
+        let name = self
+            .compiler_listener
+            .current_node
+            .as_ref()
+            .map(|node| node.name.clone())
+            .unwrap_or_else(|| "node".to_string());
+
+        // Every line whose condition passes becomes a candidate that the
+        // saliency strategy can pick at runtime. Lines without a condition
+        // are always candidates.
+        let mut labels = Vec::new();
+        let mut once_variables = Vec::new();
+        for (item_count, item) in ctx.line_group_item_all().into_iter().enumerate() {
+            let item_destination_label = self
+                .compiler_listener
+                .register_label(format!("linegroupitem_{name}_{}", item_count + 1).as_str());
+            labels.push(item_destination_label.clone());
+
+            let line_statement = item.line_statement().unwrap();
+            let line_condition = line_statement.line_condition();
+            let once_variable = line_condition
+                .as_ref()
+                .and_then(|ctx| self.once_variable(ctx.as_ref()));
+            if let Some(expression) = line_condition.as_ref().and_then(|ctx| ctx.expression()) {
+                self.visit(expression.as_ref());
+            } else if let Some(once_variable) = &once_variable {
+                self.generate_code_for_once_condition(
+                    once_variable,
+                    line_statement.start().deref(),
+                );
+            } else {
+                self.compiler_listener.emit(
+                    Emit::from_op_code(OpCode::PushBool)
+                        .with_token(line_statement.start().deref())
+                        .with_operand(true),
+                );
+            }
+
+            let line_id_tag = get_line_id_tag(&line_statement.hashtag_all())
+                .expect("Internal error: no line ID provided. This is a bug. Please report it at https://github.com/YarnSpinnerTool/YarnSpinner-Rust/issues/new");
+            let line_id = line_id_tag.text.as_ref().unwrap().get_text().to_owned();
+            self.compiler_listener.emit(
+                Emit::from_op_code(OpCode::AddSaliencyCandidate)
+                    .with_token(line_statement.start().deref())
+                    .with_operand(line_id)
+                    .with_operand(item_destination_label),
+            );
+            once_variables.push(once_variable);
+        }
+
+        // The top of the stack now contains the label of the selected line,
+        // or the end of the group if none was selected. Jump to it now.
+        let token = ctx.stop();
+        self.compiler_listener.emit(
+            Emit::from_op_code(OpCode::SelectSaliencyCandidate)
+                .with_token(token.deref())
+                .with_operand(end_of_group_label.clone()),
+        );
+        self.compiler_listener
+            .emit(Emit::from_op_code(OpCode::Jump).with_token(token.deref()));
+
+        for (item_count, item) in ctx.line_group_item_all().into_iter().enumerate() {
+            let current_node = self.compiler_listener.current_node.as_mut().unwrap();
+            current_node.labels.insert(
+                labels[item_count].clone(),
+                current_node.instructions.len() as i32,
+            );
+            if let Some(once_variable) = once_variables[item_count].clone() {
+                self.generate_code_for_marking_once(once_variable, item.start().deref());
+            }
+
+            // Run the line itself, then the statements nested below it
+            self.visit(item.line_statement().unwrap().as_ref());
+            for child in item.statement_all() {
+                self.visit(child.as_ref());
+            }
+
+            self.compiler_listener.emit(
+                Emit::from_op_code(OpCode::JumpTo)
+                    .with_token(item.stop().deref())
+                    .with_operand(end_of_group_label.clone()),
+            );
+        }
+
+        // Mark the end of the group and clean up the label from the stack
+        let current_node = self.compiler_listener.current_node.as_mut().unwrap();
+        current_node
+            .labels
+            .insert(end_of_group_label, current_node.instructions.len() as i32);
+        self.compiler_listener
+            .emit(Emit::from_op_code(OpCode::Pop).with_token(token.deref()));
+    }
+
+    /// A block that only runs the first time it is reached: <<once>> statements <<endonce>>
+    fn visit_once_statement(&mut self, ctx: &Once_statementContext<'input>) -> Self::Return {
+        let once_variable = self.once_variable(ctx).expect(
+            "Internal error: no variable was generated for a <<once>> block. This is a bug. Please report it at https://github.com/YarnSpinnerTool/YarnSpinner-Rust/issues/new",
+        );
+        let first_time_label = self.compiler_listener.register_label("once_first_time");
+        let end_of_once_label = self.compiler_listener.register_label("endonce");
+        let token = ctx.start();
+
+        // The variable is false until the block has run
+        self.compiler_listener.emit(
+            Emit::from_op_code(OpCode::PushVariable)
+                .with_token(token.deref())
+                .with_operand(once_variable.clone()),
+        );
+        self.compiler_listener.emit(
+            Emit::from_op_code(OpCode::JumpIfFalse)
+                .with_token(token.deref())
+                .with_operand(first_time_label.clone()),
+        );
+        self.compiler_listener
+            .emit(Emit::from_op_code(OpCode::Pop).with_token(token.deref()));
+        self.compiler_listener.emit(
+            Emit::from_op_code(OpCode::JumpTo)
+                .with_token(token.deref())
+                .with_operand(end_of_once_label.clone()),
+        );
+
+        let current_node = self.compiler_listener.current_node.as_mut().unwrap();
+        current_node
+            .labels
+            .insert(first_time_label, current_node.instructions.len() as i32);
+        self.compiler_listener
+            .emit(Emit::from_op_code(OpCode::Pop).with_token(token.deref()));
+        self.generate_code_for_marking_once(once_variable, token.deref());
+        for child in ctx.statement_all() {
+            self.visit(child.as_ref());
+        }
+
+        let current_node = self.compiler_listener.current_node.as_mut().unwrap();
+        current_node
+            .labels
+            .insert(end_of_once_label, current_node.instructions.len() as i32);
+    }
+
     fn visit_declare_statement(&mut self, _ctx: &Declare_statementContext<'input>) -> Self::Return {
         // Declare statements do not participate in code generation
     }
 
+    /// A <<local>> command: declares a variable that only exists while this node runs, e.g. <<local $tmp = 3>>
+    fn visit_local_statement(&mut self, ctx: &Local_statementContext<'input>) -> Self::Return {
+        let variable = ctx.variable().unwrap();
+        let variable_name = variable.get_text();
+
+        // Let the VM know that this variable must not be stored in the variable storage.
+        // A single header lists all of the node's local variables.
+        let current_node = self.compiler_listener.current_node.as_mut().unwrap();
+        match current_node
+            .headers
+            .iter_mut()
+            .find(|header| header.key == Node::LOCAL_VARIABLE_HEADER)
+        {
+            Some(header) => {
+                header.value.push(' ');
+                header.value.push_str(&variable_name);
+            }
+            None => current_node.headers.push(Header {
+                key: Node::LOCAL_VARIABLE_HEADER.to_owned(),
+                value: variable_name.clone(),
+            }),
+        }
+
+        // Unlike declared variables, local variables are (re)initialized every time the statement is run
+        self.visit(ctx.value().unwrap().as_ref());
+        let token = variable.start();
+        self.compiler_listener.emit(
+            Emit::from_op_code(OpCode::StoreVariable)
+                .with_token(token.deref())
+                .with_operand(variable_name),
+        );
+        self.compiler_listener
+            .emit(Emit::from_op_code(OpCode::Pop).with_token(token.deref()));
+    }
+
     /// A <<jump>> command, which immediately jumps to another node, given its name.
     fn visit_jumpToNodeName(&mut self, ctx: &JumpToNodeNameContext<'input>) -> Self::Return {
         if let Some(tracking_enabled) = self.tracking_enabled.clone() {
@@ -593,6 +830,45 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
 }
 
 impl<'a, 'input: 'a> CodeGenerationVisitor<'a, 'input> {
+    /// Composes the text of a command the same way [`CodeGenerationVisitor::visit_command_statement`] does,
+    /// i.e. with the expressions replaced by their index, like `detour {0}`. Returns the text and the number of expressions.
+    pub(crate) fn compose_command_text(
+        ctx: &Command_formatted_textContext<'input>,
+    ) -> (String, usize) {
+        ctx.get_children().fold(
+            (String::new(), 0_usize),
+            |(command, expression_count), node| {
+                if node.get_child_count() == 0 {
+                    (command + &node.get_text(), expression_count)
+                } else {
+                    (
+                        command + &expression_count.to_string(),
+                        expression_count + 1,
+                    )
+                }
+            },
+        )
+    }
+
+    /// Whether a command is `<<detour NodeName>>` or `<<detour {expression}>>`.
+    /// Replaced by the `detour_statement` rule once the parser is regenerated, see `parser/generated/readme.md`.
+    pub(crate) fn is_detour(command: &str, expression_count: usize) -> bool {
+        let Some(destination) = command.strip_prefix("detour") else {
+            return false;
+        };
+        if !destination.starts_with(char::is_whitespace) {
+            return false;
+        }
+        match (destination.trim(), expression_count) {
+            ("{0}", 1) => true,
+            (node_name, 0) => {
+                !node_name.is_empty()
+                    && !node_name.contains(|c: char| c.is_whitespace() || c == '{')
+            }
+            _ => false,
+        }
+    }
+
     fn generate_code_for_expressions_in_formatted_text(
         &mut self,
         nodes: impl Iterator<Item = Rc<ActualParserContext<'input>>>,
@@ -614,6 +890,50 @@ impl<'a, 'input: 'a> CodeGenerationVisitor<'a, 'input> {
             .count()
     }
 
+    /// The variable that remembers whether the `<<once>>` block or once-only option starting at this context has run
+    fn once_variable(&self, ctx: &impl ParserRuleContext<'input>) -> Option<String> {
+        self.compiler_listener
+            .once_variables
+            .get(&ctx.start().get_token_index())
+            .cloned()
+    }
+
+    /// Emits code that leaves `true` on the stack if the once-only content has not run yet
+    fn generate_code_for_once_condition(&mut self, once_variable: &str, token: &impl Token) {
+        self.compiler_listener.emit(
+            Emit::from_op_code(OpCode::PushVariable)
+                .with_token(token)
+                .with_operand(once_variable.to_owned()),
+        );
+        self.compiler_listener.emit(
+            Emit::from_op_code(OpCode::PushFloat)
+                .with_token(token)
+                .with_operand(1),
+        );
+        let function_name = Type::Boolean.get_canonical_name_for_method(&Operator::Not.to_string());
+        self.compiler_listener.emit(
+            Emit::from_op_code(OpCode::CallFunc)
+                .with_token(token)
+                .with_operand(function_name),
+        );
+    }
+
+    /// Emits code that remembers that the once-only content has run
+    fn generate_code_for_marking_once(&mut self, once_variable: String, token: &impl Token) {
+        self.compiler_listener.emit(
+            Emit::from_op_code(OpCode::PushBool)
+                .with_token(token)
+                .with_operand(true),
+        );
+        self.compiler_listener.emit(
+            Emit::from_op_code(OpCode::StoreVariable)
+                .with_token(token)
+                .with_operand(once_variable),
+        );
+        self.compiler_listener
+            .emit(Emit::from_op_code(OpCode::Pop).with_token(token));
+    }
+
     /// Emits code that calls a method appropriate for the operator
     fn generate_code_for_operation(
         &mut self,
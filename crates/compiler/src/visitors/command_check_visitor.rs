@@ -0,0 +1,231 @@
+use crate::prelude::generated::yarnspinnerparser::*;
+use crate::prelude::generated::yarnspinnerparservisitor::YarnSpinnerParserVisitorCompat;
+use crate::prelude::*;
+use crate::visitors::KnownTypes;
+use antlr_rust::parser_rule_context::ParserRuleContext;
+use antlr_rust::token::Token;
+use antlr_rust::tree::ParseTreeVisitorCompat;
+use yarnspinner_core::types::*;
+
+/// The commands that the compiler turns into instructions of their own instead of running them as commands.
+pub(crate) const BUILT_IN_COMMANDS: [&str; 3] = ["stop", "return", "detour"];
+
+/// The commands that dialogue runners provide out of the box, like `<<wait 2>>` in Bevy.
+/// Unlike [`BUILT_IN_COMMANDS`], they may be declared, but running them is fine even if they weren't.
+const DIALOGUE_RUNNER_COMMANDS: [&str; 1] = ["wait"];
+
+/// Checks the commands run by a file against the commands declared with [`Compiler::declare_command`].
+pub(crate) struct CommandCheckVisitor<'a, 'input> {
+    pub(crate) diagnostics: Vec<Diagnostic>,
+    compiler: &'a Compiler,
+    known_types: &'a KnownTypes,
+    file: &'a FileParseResult<'input>,
+    _dummy: (),
+}
+
+/// A whitespace separated part of a command, like the runtime splits it.
+#[derive(Debug, Default)]
+struct CommandComponent {
+    text: String,
+    quoted: bool,
+    /// The types of the expressions inside this component. `None` if the type is not known.
+    expression_types: Vec<Option<Type>>,
+}
+
+impl CommandComponent {
+    /// The type of the value this component is passed as. `None` if it can't be known before running the command.
+    fn r#type(&self) -> Option<Type> {
+        match self.expression_types.as_slice() {
+            [expression_type] if self.text.is_empty() && !self.quoted => expression_type.clone(),
+            [] if !self.quoted => {
+                if self.text == "true" || self.text == "false" {
+                    Some(Type::Boolean)
+                } else if self.text.parse::<f32>().is_ok() {
+                    Some(Type::Number)
+                } else {
+                    Some(Type::String)
+                }
+            }
+            _ => Some(Type::String),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.text.is_empty() && !self.quoted && self.expression_types.is_empty()
+    }
+}
+
+impl<'a, 'input> CommandCheckVisitor<'a, 'input> {
+    pub(crate) fn new(
+        compiler: &'a Compiler,
+        known_types: &'a KnownTypes,
+        file: &'a FileParseResult<'input>,
+    ) -> Self {
+        Self {
+            diagnostics: Default::default(),
+            compiler,
+            known_types,
+            file,
+            _dummy: Default::default(),
+        }
+    }
+
+    /// Splits a command into its components, keeping quoted text and expressions together.
+    fn split_command(&self, ctx: &Command_formatted_textContext<'input>) -> Vec<CommandComponent> {
+        enum Part<'p> {
+            Text(&'p str),
+            Expression(Option<Type>),
+        }
+        let texts = ctx.COMMAND_TEXT_all();
+        let expressions = ctx.expression_all();
+        let mut parts: Vec<_> = texts
+            .iter()
+            .map(|text| {
+                (
+                    text.symbol.get_token_index(),
+                    Part::Text(text.symbol.get_text()),
+                )
+            })
+            .chain(expressions.iter().map(|expression| {
+                let r#type = self.known_types.get(expression.as_ref()).cloned();
+                (
+                    expression.start().get_token_index(),
+                    Part::Expression(r#type),
+                )
+            }))
+            .collect();
+        parts.sort_by_key(|(token_index, _)| *token_index);
+
+        let mut components = vec![CommandComponent::default()];
+        let mut in_quotes = false;
+        for (_, part) in parts {
+            match part {
+                Part::Expression(r#type) => {
+                    components.last_mut().unwrap().expression_types.push(r#type)
+                }
+                Part::Text(text) => {
+                    for char in text.chars() {
+                        let component = components.last_mut().unwrap();
+                        match char {
+                            // The braces around expressions
+                            '{' | '}' => {}
+                            '"' => {
+                                in_quotes = !in_quotes;
+                                component.quoted = true;
+                            }
+                            _ if char.is_whitespace() && !in_quotes => {
+                                if !component.is_empty() {
+                                    components.push(CommandComponent::default());
+                                }
+                            }
+                            _ => component.text.push(char),
+                        }
+                    }
+                }
+            }
+        }
+        components.retain(|component| !component.is_empty());
+        components
+    }
+
+    fn report(
+        &mut self,
+        code: DiagnosticCode,
+        message: String,
+        severity: DiagnosticSeverity,
+        ctx: &Command_statementContext<'input>,
+    ) {
+        self.diagnostics.push(
+            Diagnostic::new(code, message)
+                .with_file_name(&self.file.name)
+                .with_parser_context(ctx, self.file.tokens())
+                .with_severity(severity),
+        );
+    }
+}
+
+impl<'input> ParseTreeVisitorCompat<'input> for CommandCheckVisitor<'_, 'input> {
+    type Node = YarnSpinnerParserContextType;
+    type Return = ();
+
+    fn temp_result(&mut self) -> &mut Self::Return {
+        &mut self._dummy
+    }
+}
+
+impl<'input> YarnSpinnerParserVisitorCompat<'input> for CommandCheckVisitor<'_, 'input> {
+    fn visit_command_statement(&mut self, ctx: &Command_statementContext<'input>) -> Self::Return {
+        let formatted_text = ctx.command_formatted_text().unwrap();
+        let mut components = self.split_command(&formatted_text);
+        if components.is_empty() {
+            return;
+        }
+        let name_component = components.remove(0);
+        if !name_component.expression_types.is_empty() || name_component.quoted {
+            // The name is only known when the command runs
+            return;
+        }
+        let name = name_component.text;
+        if BUILT_IN_COMMANDS.contains(&name.as_str()) {
+            // Built into Yarn Spinner itself
+            return;
+        }
+
+        let Some(declaration) = self
+            .compiler
+            .command_declarations
+            .iter()
+            .find(|declaration| declaration.name == name)
+        else {
+            if DIALOGUE_RUNNER_COMMANDS.contains(&name.as_str()) {
+                return;
+            }
+            self.report(
+                DiagnosticCode::UnknownCommand,
+                format!("The command {name} was not declared"),
+                DiagnosticSeverity::Warning,
+                ctx,
+            );
+            return;
+        };
+
+        let parameter_types = declaration.parameter_types.clone();
+        if parameter_types.len() != components.len() {
+            self.report(
+                DiagnosticCode::WrongCommandParameterCount,
+                format!(
+                    "The command {name} expects {} parameters, but was given {}",
+                    parameter_types.len(),
+                    components.len()
+                ),
+                DiagnosticSeverity::Error,
+                ctx,
+            );
+            return;
+        }
+        for (index, (expected_type, component)) in
+            parameter_types.iter().zip(&components).enumerate()
+        {
+            let Some(actual_type) = component.r#type() else {
+                continue;
+            };
+            let matches = match expected_type {
+                Type::Any | Type::String => true,
+                _ => actual_type.is_sub_type_of(expected_type),
+            };
+            if !matches {
+                self.report(
+                    DiagnosticCode::WrongCommandParameterType,
+                    format!(
+                        "Parameter {} of the command {name} expects a {}, but was given a {}",
+                        index + 1,
+                        expected_type.format(),
+                        actual_type.format()
+                    ),
+                    DiagnosticSeverity::Error,
+                    ctx,
+                );
+            }
+        }
+    }
+}
@@ -3,26 +3,39 @@
 use crate::prelude::generated::yarnspinnerparser::*;
 use crate::prelude::generated::yarnspinnerparservisitor::YarnSpinnerParserVisitorCompat;
 use crate::prelude::*;
+use crate::visitors::{resolve_enum_case, unresolved_enum_case_error, validate_enum_case};
 use antlr_rust::parser::ParserNodeType;
+use antlr_rust::token::Token;
 use antlr_rust::tree::{ParseTree, ParseTreeVisitorCompat, VisitChildren};
 use std::mem;
 use std::ops::{Deref, DerefMut};
 use yarnspinner_core::prelude::*;
 
 /// A visitor that visits any valid constant value, and returns a [`InternalValue`].
-/// Currently only supports terminals, not expressions,
-/// even if those expressions would be constant.
+/// Currently only supports terminals, optionally wrapped in parentheses or negated,
+/// not other expressions, even if those expressions would be constant.
 #[derive(Clone)]
 pub(crate) struct ConstantValueVisitor<'input> {
     pub(crate) diagnostics: Vec<Diagnostic>,
+    /// The enums that values like `Mood.Happy` can refer to.
+    enums: Vec<EnumType>,
+    /// The type the value is expected to have, used to resolve shorthand enum cases like `.Happy`.
+    type_hint: Option<Type>,
     _dummy: ConstantValue,
     file: FileParseResult<'input>,
 }
 
 impl<'input> ConstantValueVisitor<'input> {
-    pub(crate) fn new(diagnostics: Vec<Diagnostic>, file: FileParseResult<'input>) -> Self {
+    pub(crate) fn new(
+        diagnostics: Vec<Diagnostic>,
+        enums: Vec<EnumType>,
+        type_hint: Option<Type>,
+        file: FileParseResult<'input>,
+    ) -> Self {
         Self {
             diagnostics,
+            enums,
+            type_hint,
             file,
             _dummy: ConstantValue::non_panicking_default(),
         }
@@ -47,6 +60,23 @@ impl<'input> ParseTreeVisitorCompat<'input> for ConstantValueVisitor<'input> {
 }
 
 impl<'input> YarnSpinnerParserVisitorCompat<'input> for ConstantValueVisitor<'input> {
+    fn visit_expValue(&mut self, ctx: &ExpValueContext<'input>) -> Self::Return {
+        self.visit(ctx.value().unwrap().as_ref())
+    }
+
+    fn visit_expParens(&mut self, ctx: &ExpParensContext<'input>) -> Self::Return {
+        self.visit(ctx.expression().unwrap().as_ref())
+    }
+
+    /// Only negative numbers are constants, see [`is_constant`](crate::visitors::is_constant).
+    fn visit_expNegative(&mut self, ctx: &ExpNegativeContext<'input>) -> Self::Return {
+        let value = self.visit(ctx.expression().unwrap().as_ref());
+        match value.as_ref().map(|value| &value.raw_value) {
+            Some(YarnValue::Number(number)) => ConstantValue(Some(InternalValue::from(-number))),
+            _ => value,
+        }
+    }
+
     fn visit_valueNumber(&mut self, ctx: &ValueNumberContext<'input>) -> Self::Return {
         let text = ctx.get_text();
         if let Ok(number) = text.parse::<f32>() {
@@ -54,7 +84,7 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for ConstantValueVisitor<'in
         } else {
             let message = format!("Failed to parse {text} as a float",);
             self.diagnostics.push(
-                Diagnostic::from_message(message)
+                Diagnostic::new(DiagnosticCode::InvalidNumber, message)
                     .with_file_name(&self.file.name)
                     .with_parser_context(ctx, self.file.tokens()),
             );
@@ -79,7 +109,7 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for ConstantValueVisitor<'in
             "Variable declarations must be constant values, but `{text}` is another variable",
         );
         self.diagnostics.push(
-            Diagnostic::from_message(message)
+            Diagnostic::new(DiagnosticCode::NonConstantDeclaration, message)
                 .with_file_name(&self.file.name)
                 .with_parser_context(ctx, self.file.tokens()),
         );
@@ -94,7 +124,7 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for ConstantValueVisitor<'in
     fn visit_valueNull(&mut self, ctx: &ValueNullContext<'input>) -> Self::Return {
         let message = "Null is not a permitted type in Yarn Spinner 2.0 and later";
         self.diagnostics.push(
-            Diagnostic::from_message(message)
+            Diagnostic::new(DiagnosticCode::NullValue, message)
                 .with_file_name(&self.file.name)
                 .with_parser_context(ctx, self.file.tokens()),
         );
@@ -106,12 +136,44 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for ConstantValueVisitor<'in
         let message =
             format!("Variable declarations must be constant values, but `{text}` is a function",);
         self.diagnostics.push(
-            Diagnostic::from_message(message)
+            Diagnostic::new(DiagnosticCode::NonConstantDeclaration, message)
                 .with_file_name(&self.file.name)
                 .with_parser_context(ctx, self.file.tokens()),
         );
         ConstantValue::non_panicking_default()
     }
+
+    fn visit_valueTypeMemberReference(
+        &mut self,
+        ctx: &ValueTypeMemberReferenceContext<'input>,
+    ) -> Self::Return {
+        let reference = ctx.typeMemberReference().unwrap();
+        let Some(case_name) = reference.memberName.as_ref().map(|t| t.get_text()) else {
+            return ConstantValue::non_panicking_default();
+        };
+        let r#type = match (reference.typeName.as_ref(), self.type_hint.as_ref()) {
+            (Some(type_name), _) => resolve_enum_case(&self.enums, type_name.get_text(), case_name),
+            (None, Some(Type::Enum(enum_type))) => {
+                validate_enum_case(enum_type, case_name).map(|_| Type::Enum(enum_type.clone()))
+            }
+            (None, _) => Err(unresolved_enum_case_error(case_name)),
+        };
+        match r#type {
+            Ok(r#type) => InternalValue {
+                r#type,
+                raw_value: case_name.into(),
+            }
+            .into(),
+            Err(diagnostic) => {
+                self.diagnostics.push(
+                    diagnostic
+                        .with_file_name(&self.file.name)
+                        .with_parser_context(ctx, self.file.tokens()),
+                );
+                ConstantValue::non_panicking_default()
+            }
+        }
+    }
 }
 
 /// Needed because ANTLR needs visitors' return values to have a default.
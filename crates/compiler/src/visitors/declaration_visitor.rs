@@ -1,12 +1,16 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/DeclarationVisitor.cs>
 
+use crate::parser_rule_context_ext::ParserRuleContextExt;
 use crate::prelude::generated::yarnspinnerparser::*;
 use crate::prelude::generated::yarnspinnerparservisitor::YarnSpinnerParserVisitorCompat;
 use crate::prelude::*;
 use crate::visitors::constant_value_visitor::ConstantValueVisitor;
+use antlr_rust::rule_context::CustomRuleContext;
 use antlr_rust::token::Token;
+use antlr_rust::token_factory::TokenFactory;
 use antlr_rust::tree::{ParseTree, ParseTreeVisitorCompat};
 use regex::Regex;
+use std::rc::Rc;
 use yarnspinner_core::prelude::*;
 use yarnspinner_core::types::*;
 
@@ -25,6 +29,10 @@ pub(crate) struct DeclarationVisitor<'input> {
     /// result of using this  [`DeclarationVisitor`] to visit a [`ParserRuleContext`].
     pub(crate) file_tags: Vec<String>,
 
+    /// The smart variables declared in the file, whose types are still unknown
+    /// unless they were declared explicitly.
+    pub(crate) smart_variables: Vec<SmartVariable<'input>>,
+
     pub(crate) diagnostics: Vec<Diagnostic>,
 
     /// The CommonTokenStream derived from the file we're parsing. This
@@ -34,6 +42,9 @@ pub(crate) struct DeclarationVisitor<'input> {
     /// The collection of variable declarations we know about before starting our work
     existing_declarations: Vec<Declaration>,
 
+    /// The enums declared anywhere in the compilation job
+    known_enums: Vec<EnumType>,
+
     /// The name of the node that we're currently visiting.
     current_node_name: Option<String>,
 
@@ -43,17 +54,72 @@ pub(crate) struct DeclarationVisitor<'input> {
     _dummy: (),
 }
 
+/// A variable declared with an expression instead of a constant value, e.g. `<<declare $can_afford = $gold >= $price>>`.
+/// Its value is recomputed from the expression every time it is read.
+#[derive(Clone)]
+pub(crate) struct SmartVariable<'input> {
+    pub(crate) name: String,
+    pub(crate) file_name: String,
+    pub(crate) expression: Rc<ExpressionContextAll<'input>>,
+}
+
+/// Returns the value of an expression if it is written as a constant, i.e. not a variable or a function call.
+/// Parentheses around the value are looked through.
+pub(crate) fn constant_value<'input>(
+    expression: &ExpressionContextAll<'input>,
+) -> Option<Rc<ValueContextAll<'input>>> {
+    match expression {
+        ExpressionContextAll::ExpParensContext(expression) => {
+            let expression = expression.expression()?;
+            constant_value(&expression)
+        }
+        ExpressionContextAll::ExpValueContext(expression) => {
+            let value = expression.value()?;
+            match value.as_ref() {
+                // Values that failed to parse were already reported as syntax errors
+                ValueContextAll::ValueVarContext(_)
+                | ValueContextAll::ValueFuncContext(_)
+                | ValueContextAll::Error(_) => None,
+                _ => Some(value),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Whether the expression of a declaration is a constant like `"text"`, `(1)` or `-1`.
+/// Declarations with any other expression declare smart variables.
+pub(crate) fn is_constant(expression: &ExpressionContextAll) -> bool {
+    match expression {
+        ExpressionContextAll::ExpParensContext(expression) => expression
+            .expression()
+            .is_some_and(|expression| is_constant(&expression)),
+        ExpressionContextAll::ExpNegativeContext(expression) => {
+            expression.expression().is_some_and(|expression| {
+                matches!(
+                    constant_value(&expression).as_deref(),
+                    Some(ValueContextAll::ValueNumberContext(_))
+                )
+            })
+        }
+        _ => constant_value(expression).is_some(),
+    }
+}
+
 impl<'input> DeclarationVisitor<'input> {
     pub(crate) fn new(
         existing_declarations: Vec<Declaration>,
+        known_enums: Vec<EnumType>,
         file: FileParseResult<'input>,
     ) -> Self {
         Self {
             file,
             existing_declarations,
+            known_enums,
             new_declarations: Default::default(),
             regex: Regex::new(r"[\[<>\]{}|:\s#$]").unwrap(),
             file_tags: Default::default(),
+            smart_variables: Default::default(),
             diagnostics: Default::default(),
             current_node_name: None,
             _dummy: Default::default(),
@@ -93,14 +159,17 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for DeclarationVisitor<'inpu
                 continue;
             }
 
-            let header_value = header.header_value.as_ref().unwrap();
+            // A title without a value was already reported as a syntax error
+            let Some(header_value) = header.header_value.as_ref() else {
+                continue;
+            };
             let current_node_name = header_value.get_text();
             self.current_node_name = Some(current_node_name.to_owned());
             if self.regex.is_match(current_node_name) {
                 let message =
                     format!("The node '{current_node_name}' contains illegal characters.");
                 self.diagnostics.push(
-                    Diagnostic::from_message(message)
+                    Diagnostic::new(DiagnosticCode::InvalidNodeName, message)
                         .with_file_name(self.file.name.clone())
                         .with_parser_context(header.as_ref(), self.file.tokens()),
                 );
@@ -112,15 +181,83 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for DeclarationVisitor<'inpu
     }
 
     fn visit_declare_statement(&mut self, ctx: &Declare_statementContext<'input>) -> Self::Return {
+        // Incomplete declarations were already reported as syntax errors
+        let (Some(variable_context), Some(expression_context)) = (ctx.variable(), ctx.expression())
+        else {
+            return;
+        };
+        let declaration_type = ctx.declaration_type.as_ref().map(|t| t.get_text());
+        let is_smart = !is_constant(&expression_context);
+        let value_context =
+            (!is_smart).then(|| expression_context.clone() as Rc<ActualParserContext>);
+        let variable_name = variable_context.get_text();
+        let is_declared = self.declare_variable(
+            ctx,
+            variable_context,
+            value_context,
+            declaration_type,
+            false,
+        );
+        if is_declared && is_smart {
+            self.smart_variables.push(SmartVariable {
+                name: variable_name,
+                file_name: self.file.name.clone(),
+                expression: expression_context,
+            });
+        }
+    }
+
+    fn visit_local_statement(&mut self, ctx: &Local_statementContext<'input>) -> Self::Return {
+        // The parser will already have reported an error for incomplete statements
+        let (Some(variable_context), Some(value_context)) = (ctx.variable(), ctx.value()) else {
+            return;
+        };
+        if let ValueContextAll::Error(_) = value_context.as_ref() {
+            return;
+        }
+        let declaration_type = ctx.declaration_type.as_ref().map(|t| t.get_text());
+        self.declare_variable(
+            ctx,
+            variable_context,
+            Some(value_context as Rc<ActualParserContext>),
+            declaration_type,
+            true,
+        );
+    }
+}
+
+impl<'input> DeclarationVisitor<'input> {
+    /// Shared implementation of `<<declare>>` and `<<local>>`.
+    /// A `value_context` of `None` declares a smart variable.
+    /// Returns whether a declaration was created.
+    fn declare_variable<T>(
+        &mut self,
+        ctx: &T,
+        variable_context: Rc<VariableContextAll<'input>>,
+        value_context: Option<Rc<ActualParserContext<'input>>>,
+        declaration_type: Option<&str>,
+        is_local: bool,
+    ) -> bool
+    where
+        T: ParserRuleContextExt<'input>
+            + YarnSpinnerParserContext<
+                'input,
+                TF = LocalTokenFactory<'input>,
+                Ctx = YarnSpinnerParserContextType,
+            >,
+        <<<<T as CustomRuleContext<'input>>::TF as TokenFactory<'input>>::Inner as Token>::Data as ToOwned>::Owned:
+            Into<String>,
+    {
         // Get the name of the variable we're declaring
-        let variable_context = ctx.variable().unwrap();
         let variable_name = variable_context.get_text();
 
         // Does this variable name already exist in our declarations?
-        let existing_explicit_declaration = self
-            .declarations()
-            .into_iter()
-            .find(|d| !d.is_implicit && d.name == variable_name);
+        // Local variables of other nodes don't get in the way of a new local variable.
+        let existing_explicit_declaration = self.declarations().into_iter().find(|d| {
+            !d.is_implicit
+                && d.name == variable_name
+                && !(is_local && d.is_local && d.source_node_name != self.current_node_name)
+        });
         if let Some(existing_explicit_declaration) = existing_explicit_declaration {
             // Then this is an error, because you can't have two explicit declarations for the same variable.
             let line = existing_explicit_declaration
@@ -132,78 +269,109 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for DeclarationVisitor<'inpu
                 existing_explicit_declaration.name, existing_explicit_declaration.source_file_name,
             );
             self.diagnostics.push(
-                Diagnostic::from_message(msg)
+                Diagnostic::new(DiagnosticCode::DuplicateDeclaration, msg)
                     .with_file_name(&self.file.name)
                     .with_parser_context(ctx, self.file.tokens()),
             );
-            return;
+            return false;
         }
 
-        // Figure out the value and its type
-        let mut constant_value_visitor =
-            ConstantValueVisitor::new(self.diagnostics.clone(), self.file.clone());
-        let value_context = ctx.value().unwrap();
-        let value = constant_value_visitor.visit(value_context.as_ref());
-        self.diagnostics
-            .extend_from_slice(&constant_value_visitor.diagnostics);
-
         // Did the source code name an explicit type?
-        if let Some(declaration_type) = ctx.declaration_type.as_ref() {
-            let explicit_type = match keyword_to_type(declaration_type.get_text()) {
-                Some(builtin_type) => builtin_type,
-
-                // The type name provided didn't map to a built-in
-                // type. Look for the type in our type collection.
-                None => match Type::EXPLICITLY_CONSTRUCTABLE
-                    .iter()
-                    .find(|t| t.to_string() == declaration_type.get_text())
-                {
-                    Some(explicit_type) => explicit_type.clone(),
-                    None => {
-                        // We didn't find a type by this name.
-                        let msg = format!("Unknown type {}", declaration_type.get_text());
-                        self.diagnostics.push(
-                            Diagnostic::from_message(msg)
-                                .with_file_name(&self.file.name)
-                                .with_parser_context(ctx, self.file.tokens()),
-                        );
-                        return;
-                    }
-                },
-            };
-
-            // Check that the type we've found is compatible with the
-            // type of the value that was provided - if it doesn't,
-            // that's a type error
-            if let Some(value) = value.as_ref() {
-                if !value.r#type.is_sub_type_of(&explicit_type) {
-                    let msg = format!(
-                        "Type {} does not match value {} ({})",
-                        declaration_type.get_text(),
-                        value_context.get_text(),
-                        value.r#type.format()
-                    );
+        let explicit_type = match declaration_type {
+            Some(declaration_type) => match self.type_from_name(declaration_type) {
+                Some(explicit_type) => Some(explicit_type),
+                None => {
+                    // We didn't find a type by this name.
+                    let msg = format!("Unknown type {declaration_type}");
                     self.diagnostics.push(
-                        Diagnostic::from_message(msg)
+                        Diagnostic::new(DiagnosticCode::UnknownType, msg)
                             .with_file_name(&self.file.name)
                             .with_parser_context(ctx, self.file.tokens()),
                     );
-                    return;
+                    return false;
                 }
+            },
+            None => None,
+        };
+
+        let description = get_document_comments(self.file.tokens(), ctx);
+        let description_as_option = (!description.is_empty()).then_some(description);
+        let Some(value_context) = value_context else {
+            // The type of a smart variable is the one of its expression, unless it was declared explicitly.
+            // It stays unknown until the expression has been type checked.
+            let declaration = Declaration::new(variable_name, explicit_type.unwrap_or_default())
+                .with_description_optional(description_as_option)
+                .with_source_file_name(self.file.name.clone())
+                .with_source_node_name_optional(self.current_node_name.clone())
+                .with_range(variable_context.range())
+                .with_smart();
+            self.new_declarations.push(declaration);
+            return true;
+        };
+
+        // Figure out the value and its type
+        let mut constant_value_visitor = ConstantValueVisitor::new(
+            self.diagnostics.clone(),
+            self.known_enums.clone(),
+            explicit_type.clone(),
+            self.file.clone(),
+        );
+        let value = constant_value_visitor.visit(value_context.as_ref());
+        self.diagnostics
+            .extend_from_slice(&constant_value_visitor.diagnostics);
+
+        // Check that the type we've found is compatible with the
+        // type of the value that was provided - if it doesn't,
+        // that's a type error
+        if let (Some(explicit_type), Some(value)) = (explicit_type, value.as_ref()) {
+            if !value.r#type.is_sub_type_of(&explicit_type) {
+                let msg = format!(
+                    "Type {} does not match value {} ({})",
+                    declaration_type.unwrap(),
+                    value_context.get_text(),
+                    value.r#type.format()
+                );
+                self.diagnostics.push(
+                    Diagnostic::new(DiagnosticCode::TypeMismatch, msg)
+                        .with_file_name(&self.file.name)
+                        .with_parser_context(ctx, self.file.tokens()),
+                );
+                return false;
             }
         }
         // We're done creating the declaration!
-        let description = get_document_comments(self.file.tokens(), ctx);
-        let description_as_option = (!description.is_empty()).then_some(description);
         if let Some(value) = value.as_ref() {
-            let declaration = Declaration::new(variable_name, value.r#type.clone())
+            let mut declaration = Declaration::new(variable_name, value.r#type.clone())
                 .with_default_value(value.raw_value.clone())
                 .with_description_optional(description_as_option)
                 .with_source_file_name(self.file.name.clone())
                 .with_source_node_name_optional(self.current_node_name.clone())
                 .with_range(variable_context.range());
+            if is_local {
+                declaration = declaration.with_local();
+            }
             self.new_declarations.push(declaration);
         }
+        value.is_some()
+    }
+
+    fn type_from_name(&self, name: &str) -> Option<Type> {
+        keyword_to_type(name)
+            // The type name provided didn't map to a built-in
+            // type. Look for the type in our type collection.
+            .or_else(|| {
+                Type::EXPLICITLY_CONSTRUCTABLE
+                    .iter()
+                    .find(|t| t.to_string() == name)
+                    .cloned()
+            })
+            .or_else(|| {
+                self.known_enums
+                    .iter()
+                    .find(|e| e.name == name)
+                    .cloned()
+                    .map(Type::Enum)
+            })
     }
 }
 
@@ -238,6 +406,7 @@ mod tests {
             library: Default::default(),
             compilation_type: CompilationType::FullCompilation,
             variable_declarations: vec![],
+            ..Default::default()
         }
         .compile()
         .unwrap();
@@ -328,6 +497,7 @@ mod tests {
             library: Default::default(),
             compilation_type: CompilationType::FullCompilation,
             variable_declarations: vec![],
+            ..Default::default()
         }
         .compile();
 
@@ -335,22 +505,25 @@ mod tests {
         assert_eq!(2, diagnostics.len());
         assert_eq!(
             diagnostics[0],
-            Diagnostic::from_message("Type string does not match value 1 (Number)".to_string())
-                .with_file_name("test.yarn".to_string())
-                .with_context(file.source.clone())
-                .with_range(
-                    Position {
-                        line: 2,
-                        character: 0,
-                    }..Position {
-                        line: 2,
-                        character: 31,
-                    }
-                )
+            Diagnostic::new(
+                DiagnosticCode::TypeMismatch,
+                "Type string does not match value 1 (Number)".to_string()
+            )
+            .with_file_name("test.yarn".to_string())
+            .with_context(file.source.clone())
+            .with_range(
+                Position {
+                    line: 2,
+                    character: 0,
+                }..Position {
+                    line: 2,
+                    character: 31,
+                }
+            )
         );
         assert_eq!(
             diagnostics[1],
-            Diagnostic::from_message("Can't figure out the type of variable $foo given its context. Specify its type with a <<declare>> statement.".to_string())
+            Diagnostic::new(DiagnosticCode::UndefinedVariable, "Can't figure out the type of variable $foo given its context. Specify its type with a <<declare>> statement.".to_string())
                 .with_file_name("test.yarn".to_string())
                 .with_context(file.source)
                 .with_range(
@@ -0,0 +1,257 @@
+use crate::prelude::generated::yarnspinnerparser::*;
+use crate::prelude::generated::yarnspinnerparservisitor::YarnSpinnerParserVisitorCompat;
+use crate::prelude::*;
+use antlr_rust::token::Token;
+use antlr_rust::tree::ParseTreeVisitorCompat;
+use yarnspinner_core::types::*;
+
+/// A visitor that collects the enums declared with `<<enum>>` statements in a parse tree.
+/// After visiting an entire parse tree for a file, [`EnumDeclarationVisitor::new_enums`]
+/// will contain all enums that were found.
+pub(crate) struct EnumDeclarationVisitor<'input> {
+    /// The enums declared in the visited file.
+    pub(crate) new_enums: Vec<EnumType>,
+
+    pub(crate) diagnostics: Vec<Diagnostic>,
+
+    /// The enums we know about before starting our work, e.g. from previously visited files.
+    existing_enums: Vec<EnumType>,
+
+    file: FileParseResult<'input>,
+
+    _dummy: (),
+}
+
+impl<'input> EnumDeclarationVisitor<'input> {
+    pub(crate) fn new(existing_enums: Vec<EnumType>, file: FileParseResult<'input>) -> Self {
+        Self {
+            file,
+            existing_enums,
+            new_enums: Default::default(),
+            diagnostics: Default::default(),
+            _dummy: Default::default(),
+        }
+    }
+}
+
+impl<'input> ParseTreeVisitorCompat<'input> for EnumDeclarationVisitor<'input> {
+    type Node = YarnSpinnerParserContextType;
+    type Return = ();
+
+    fn temp_result(&mut self) -> &mut Self::Return {
+        &mut self._dummy
+    }
+}
+
+impl<'input> YarnSpinnerParserVisitorCompat<'input> for EnumDeclarationVisitor<'input> {
+    fn visit_enum_statement(&mut self, ctx: &Enum_statementContext<'input>) -> Self::Return {
+        // The parser will already have reported an error if the name is missing
+        let Some(name) = ctx.name.as_ref() else {
+            return;
+        };
+        let name = name.get_text().to_owned();
+
+        if is_built_in_type_name(&name) {
+            self.diagnostics.push(
+                Diagnostic::new(
+                    DiagnosticCode::InvalidEnumName,
+                    format!("Enum {name} has the same name as a built-in type"),
+                )
+                .with_file_name(&self.file.name)
+                .with_parser_context(ctx, self.file.tokens()),
+            );
+            return;
+        }
+        if self
+            .existing_enums
+            .iter()
+            .chain(self.new_enums.iter())
+            .any(|e| e.name == name)
+        {
+            self.diagnostics.push(
+                Diagnostic::new(
+                    DiagnosticCode::DuplicateEnum,
+                    format!("Enum {name} has already been declared"),
+                )
+                .with_file_name(&self.file.name)
+                .with_parser_context(ctx, self.file.tokens()),
+            );
+            return;
+        }
+
+        let mut enum_type = EnumType::new(name);
+        for case_context in ctx.enum_case_statement_all() {
+            let Some(case) = case_context.name.as_ref() else {
+                continue;
+            };
+            let case = case.get_text();
+            if enum_type.has_case(case) {
+                let message = format!("Enum {} already has a case named {case}", enum_type.name);
+                self.diagnostics.push(
+                    Diagnostic::new(DiagnosticCode::DuplicateEnumCase, message)
+                        .with_file_name(&self.file.name)
+                        .with_parser_context(case_context.as_ref(), self.file.tokens()),
+                );
+                continue;
+            }
+            enum_type.cases.push(case.to_owned());
+        }
+        self.new_enums.push(enum_type);
+    }
+}
+
+fn is_built_in_type_name(name: &str) -> bool {
+    ["string", "number", "bool"].contains(&name)
+        || Type::EXPLICITLY_CONSTRUCTABLE
+            .iter()
+            .any(|t| t.to_string() == name)
+}
+
+/// Looks up the case `case_name` of the enum named `enum_name`.
+/// Returns the type of the enum if it exists and has the case, or a diagnostic describing the problem otherwise.
+pub(crate) fn resolve_enum_case(
+    enums: &[EnumType],
+    enum_name: &str,
+    case_name: &str,
+) -> Result<Type, Box<Diagnostic>> {
+    let enum_type = enums.iter().find(|e| e.name == enum_name).ok_or_else(|| {
+        Box::new(Diagnostic::new(
+            DiagnosticCode::UnknownType,
+            format!("Unknown type {enum_name}"),
+        ))
+    })?;
+    validate_enum_case(enum_type, case_name)?;
+    Ok(Type::Enum(enum_type.clone()))
+}
+
+/// Checks that `case_name` is one of the cases of `enum_type`.
+pub(crate) fn validate_enum_case(
+    enum_type: &EnumType,
+    case_name: &str,
+) -> Result<(), Box<Diagnostic>> {
+    if enum_type.has_case(case_name) {
+        Ok(())
+    } else {
+        Err(Box::new(Diagnostic::new(
+            DiagnosticCode::UnknownEnumCase,
+            format!(
+                "{case_name} is not a case of enum {} (expected one of {})",
+                enum_type.name,
+                enum_type.cases.join(", ")
+            ),
+        )))
+    }
+}
+
+/// {0} = case name
+pub(crate) fn unresolved_enum_case_error(case_name: &str) -> Box<Diagnostic> {
+    Box::new(Diagnostic::new(DiagnosticCode::UnresolvedEnumCase, format!("Can't figure out which enum .{case_name} belongs to given its context. Write it as EnumName.{case_name} instead.")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_enums_and_their_cases() {
+        let result = compile(
+            "title: test
+---
+<<enum Mood>>
+    <<case Happy>>
+    <<case Angry>>
+<<endenum>>
+<<enum Weather>>
+    <<case Sunny>>
+<<endenum>>
+<<declare $mood = Mood.Angry>>
+<<declare $weather = .Sunny as Weather>>
+===",
+        )
+        .unwrap();
+        assert!(result.warnings.is_empty());
+
+        let mood = EnumType::new("Mood").with_case("Happy").with_case("Angry");
+        let weather = EnumType::new("Weather").with_case("Sunny");
+        let declared_types: Vec<_> = result
+            .declarations
+            .iter()
+            .filter(|declaration| ["$mood", "$weather"].contains(&declaration.name.as_str()))
+            .map(|declaration| (declaration.name.as_str(), declaration.r#type.clone()))
+            .collect();
+        assert_eq!(
+            vec![
+                ("$mood", Type::Enum(mood)),
+                ("$weather", Type::Enum(weather))
+            ],
+            declared_types
+        );
+    }
+
+    #[test]
+    fn catches_duplicate_enums_and_cases() {
+        let diagnostics = compile(
+            "title: test
+---
+<<enum Mood>>
+    <<case Happy>>
+    <<case Happy>>
+<<endenum>>
+<<enum Mood>>
+    <<case Sad>>
+<<endenum>>
+<<enum String>>
+    <<case Foo>>
+<<endenum>>
+===",
+        )
+        .unwrap_err()
+        .0;
+        let messages: Vec<_> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "Enum Mood already has a case named Happy",
+                "Enum Mood has already been declared",
+                "Enum String has the same name as a built-in type",
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_enum_cases() {
+        let enums = [EnumType::new("Mood").with_case("Happy")];
+        assert_eq!(
+            Ok(Type::Enum(enums[0].clone())),
+            resolve_enum_case(&enums, "Mood", "Happy")
+        );
+        assert_eq!(
+            Err(Box::new(Diagnostic::new(
+                DiagnosticCode::UnknownType,
+                "Unknown type Weather"
+            ))),
+            resolve_enum_case(&enums, "Weather", "Happy")
+        );
+        assert_eq!(
+            Err(Box::new(Diagnostic::new(
+                DiagnosticCode::UnknownEnumCase,
+                "Sad is not a case of enum Mood (expected one of Happy)"
+            ))),
+            resolve_enum_case(&enums, "Mood", "Sad")
+        );
+    }
+
+    fn compile(source: &str) -> crate::Result<Compilation> {
+        Compiler {
+            files: vec![File {
+                file_name: "test.yarn".to_string(),
+                source: source.to_string(),
+            }],
+            library: Default::default(),
+            compilation_type: CompilationType::FullCompilation,
+            variable_declarations: vec![],
+            ..Default::default()
+        }
+        .compile()
+    }
+}
@@ -62,6 +62,17 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for LastLineBeforeOptionsVis
             self.run_through_statement(&statements);
         }
     }
+
+    // line groups can contain options in the statements below each line, just like options can
+    fn visit_line_group_statement(
+        &mut self,
+        ctx: &Line_group_statementContext<'input>,
+    ) -> Self::Return {
+        for item in ctx.line_group_item_all() {
+            let statements = item.statement_all();
+            self.run_through_statement(&statements);
+        }
+    }
 }
 
 impl LastLineBeforeOptionsVisitor {
@@ -80,6 +91,10 @@ impl LastLineBeforeOptionsVisitor {
                 self.visit(if_statement.as_ref());
                 continue;
             }
+            if let Some(line_group_statement) = statement.line_group_statement() {
+                self.visit(line_group_statement.as_ref());
+                continue;
+            }
 
             let Some(shortcut_option_statement) = statement.shortcut_option_statement() else {
                 // we aren't an option, keep moving
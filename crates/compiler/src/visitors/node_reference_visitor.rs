@@ -0,0 +1,273 @@
+use crate::parser_rule_context_ext::ParserRuleContextExt;
+use crate::prelude::generated::yarnspinnerparser::{self, *};
+use crate::prelude::generated::yarnspinnerparservisitor::YarnSpinnerParserVisitorCompat;
+use crate::prelude::*;
+use crate::visitors::{constant_value, CodeGenerationVisitor};
+use antlr_rust::parser_rule_context::ParserRuleContext;
+use antlr_rust::rule_context::CustomRuleContext;
+use antlr_rust::token::Token;
+use antlr_rust::token_factory::TokenFactory;
+use antlr_rust::tree::{ParseTree, ParseTreeVisitorCompat};
+use std::collections::HashSet;
+use std::ops::Range;
+
+/// Checks that the nodes referred to by `<<jump>>`, `<<detour>>`, `visited` and `visited_count` exist.
+/// Also checks that `<<detour>>` and `<<return>>` are given the right parameters.
+pub(crate) struct NodeReferenceVisitor<'a, 'input> {
+    pub(crate) diagnostics: Vec<Diagnostic>,
+    node_names: &'a HashSet<String>,
+    excluded_nodes: &'a HashSet<String>,
+    file: &'a FileParseResult<'input>,
+    _dummy: (),
+}
+
+impl<'a, 'input> NodeReferenceVisitor<'a, 'input> {
+    pub(crate) fn new(
+        node_names: &'a HashSet<String>,
+        excluded_nodes: &'a HashSet<String>,
+        file: &'a FileParseResult<'input>,
+    ) -> Self {
+        Self {
+            diagnostics: Default::default(),
+            node_names,
+            excluded_nodes,
+            file,
+            _dummy: Default::default(),
+        }
+    }
+
+    /// `name_range` is the range of the node name itself, which is replaced by the suggested name if there is one.
+    fn check_node_name<T>(
+        &mut self,
+        node_name: &str,
+        name_range: Option<Range<Position>>,
+        ctx: &T,
+        message: impl FnOnce() -> String,
+    ) where
+        T: ParserRuleContextExt<'input>,
+        <<<<T as CustomRuleContext<'input>>::TF as TokenFactory<'input>>::Inner as Token>::Data as ToOwned>::Owned:
+            Into<String>,
+    {
+        if self.node_names.contains(node_name) {
+            return;
+        }
+        if self.excluded_nodes.contains(node_name) {
+            // The node exists, but was left out of a partial compilation
+            self.diagnostics.push(
+                Diagnostic::new(
+                    DiagnosticCode::ExcludedNode,
+                    format!("{node_name} was left out of the compilation because it has errors"),
+                )
+                .with_file_name(&self.file.name)
+                .with_parser_context(ctx, self.file.tokens())
+                .with_severity(DiagnosticSeverity::Warning),
+            );
+            return;
+        }
+        let mut message = message();
+        let suggestion = suggest_node_name(node_name, self.node_names);
+        if let Some(suggestion) = suggestion {
+            message += &format!(". Did you mean {suggestion}?");
+        }
+        let mut diagnostic = Diagnostic::new(DiagnosticCode::UnknownNode, message)
+            .with_file_name(&self.file.name)
+            .with_parser_context(ctx, self.file.tokens());
+        if let (Some(suggestion), Some(range)) = (suggestion, name_range) {
+            diagnostic = diagnostic.with_fix(DiagnosticFix {
+                description: format!("Replace with {suggestion}"),
+                edits: vec![DiagnosticEdit {
+                    range,
+                    new_text: suggestion.to_owned(),
+                }],
+            });
+        }
+        self.diagnostics.push(diagnostic);
+    }
+
+    fn warn_about_dynamic_destination<T>(&mut self, ctx: &T, message: &str)
+    where
+        T: ParserRuleContextExt<'input>,
+        <<<<T as CustomRuleContext<'input>>::TF as TokenFactory<'input>>::Inner as Token>::Data as ToOwned>::Owned:
+            Into<String>,
+    {
+        self.diagnostics.push(
+            Diagnostic::new(DiagnosticCode::DynamicNodeReference, message)
+                .with_file_name(&self.file.name)
+                .with_parser_context(ctx, self.file.tokens())
+                .with_severity(DiagnosticSeverity::Warning),
+        );
+    }
+}
+
+impl<'input> ParseTreeVisitorCompat<'input> for NodeReferenceVisitor<'_, 'input> {
+    type Node = YarnSpinnerParserContextType;
+    type Return = ();
+
+    fn temp_result(&mut self) -> &mut Self::Return {
+        &mut self._dummy
+    }
+}
+
+impl<'input> YarnSpinnerParserVisitorCompat<'input> for NodeReferenceVisitor<'_, 'input> {
+    fn visit_jumpToNodeName(&mut self, ctx: &JumpToNodeNameContext<'input>) -> Self::Return {
+        let destination = ctx.destination.as_ref().unwrap();
+        let name_range = token_range(destination.as_ref());
+        let destination = destination.get_text();
+        self.check_node_name(destination, Some(name_range), ctx, || {
+            format!("Can't jump to {destination}, because there is no node with that name")
+        });
+    }
+
+    fn visit_jumpToExpression(&mut self, ctx: &JumpToExpressionContext<'input>) -> Self::Return {
+        self.warn_about_dynamic_destination(
+            ctx,
+            "The destination of this jump is only known when it runs, so it can't be checked to exist",
+        );
+        self.visit_children(ctx)
+    }
+
+    fn visit_command_statement(&mut self, ctx: &Command_statementContext<'input>) -> Self::Return {
+        let formatted_text = ctx.command_formatted_text().unwrap();
+        let (command, expression_count) =
+            CodeGenerationVisitor::compose_command_text(&formatted_text);
+        if CodeGenerationVisitor::is_detour(&command, expression_count) {
+            if expression_count == 0 {
+                let destination = command["detour".len()..].trim();
+                self.check_node_name(destination, None, ctx, || {
+                    format!(
+                        "Can't detour to {destination}, because there is no node with that name"
+                    )
+                });
+            } else {
+                self.warn_about_dynamic_destination(
+                    ctx,
+                    "The destination of this detour is only known when it runs, so it can't be checked to exist",
+                );
+            }
+        } else {
+            // Anything else starting with a built-in command's name would silently be run as a custom command
+            let message = match command.split_whitespace().next() {
+                Some("detour") => Some(
+                    "<<detour>> needs exactly one destination, which is either a node name or an expression in braces",
+                ),
+                Some("return") if command.trim() != "return" => {
+                    Some("<<return>> doesn't take any parameters")
+                }
+                _ => None,
+            };
+            if let Some(message) = message {
+                self.diagnostics.push(
+                    Diagnostic::new(DiagnosticCode::MalformedBuiltInCommand, message)
+                        .with_file_name(&self.file.name)
+                        .with_parser_context(ctx, self.file.tokens()),
+                );
+            }
+        }
+        self.visit_children(ctx)
+    }
+
+    fn visit_function_call(&mut self, ctx: &Function_callContext<'input>) -> Self::Return {
+        let function_name = ctx
+            .get_token(yarnspinnerparser::FUNC_ID, 0)
+            .unwrap()
+            .get_text();
+        if ["visited", "visited_count"].contains(&function_name.as_str()) {
+            // Only node names written as string literals can be checked.
+            // Wrong parameter counts and types are reported by the type checker.
+            let node_name = ctx.expression(0).and_then(|expression| {
+                let value = constant_value(&expression)?;
+                let ValueContextAll::ValueStringContext(value) = value.as_ref() else {
+                    return None;
+                };
+                let string = value.get_token(yarnspinnerparser::STRING, 0)?;
+                let node_name = string.get_text().trim_matches('"').to_owned();
+                // Leave out the quotes
+                let range = token_range(string.symbol.as_ref());
+                let name_range = Position {
+                    character: range.start.character + 1,
+                    ..range.start
+                }..Position {
+                    character: range.end.character - 1,
+                    ..range.end
+                };
+                Some((node_name, name_range, expression))
+            });
+            if let Some((node_name, name_range, expression)) = node_name {
+                self.check_node_name(&node_name, Some(name_range), expression.as_ref(), || {
+                    format!("{function_name} refers to {node_name}, but there is no node with that name")
+                });
+            }
+        }
+        self.visit_children(ctx)
+    }
+}
+
+fn token_range(token: &(impl Token<Data = str> + ?Sized)) -> Range<Position> {
+    let line = token.get_line_as_usize().saturating_sub(1);
+    let character = token.get_column_as_usize();
+    Position { line, character }..Position {
+        line,
+        character: character + token.get_text().chars().count(),
+    }
+}
+
+/// The node name that was most likely meant when writing `node_name`, if any is close enough.
+fn suggest_node_name<'a>(node_name: &str, node_names: &'a HashSet<String>) -> Option<&'a str> {
+    let max_distance = (node_name.chars().count() / 3).max(2);
+    node_names
+        .iter()
+        .map(|candidate| {
+            let distance = if candidate.eq_ignore_ascii_case(node_name) {
+                0
+            } else {
+                edit_distance(node_name, candidate)
+            };
+            (distance, candidate)
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, candidate)| candidate.as_str())
+}
+
+/// The Levenshtein distance between two strings, i.e. the number of characters that need to be
+/// inserted, removed or replaced to turn one into the other.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<_> = b.chars().collect();
+    let mut previous_row: Vec<_> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut row = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let replacement = previous_row[j] + usize::from(a_char != *b_char);
+            let insertion = row[j] + 1;
+            let deletion = previous_row[j + 1] + 1;
+            row.push(replacement.min(insertion).min(deletion));
+        }
+        previous_row = row;
+    }
+    previous_row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggests_close_node_names() {
+        let node_names: HashSet<_> = ["Start", "Shop", "Greeting"]
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .collect();
+        assert_eq!(Some("Start"), suggest_node_name("Strat", &node_names));
+        assert_eq!(Some("Greeting"), suggest_node_name("greeting", &node_names));
+        assert_eq!(Some("Shop"), suggest_node_name("Shopp", &node_names));
+        assert_eq!(None, suggest_node_name("Tavern", &node_names));
+    }
+
+    #[test]
+    fn computes_edit_distance() {
+        assert_eq!(0, edit_distance("Start", "Start"));
+        assert_eq!(2, edit_distance("Strat", "Start"));
+        assert_eq!(1, edit_distance("Shop", "Shopp"));
+        assert_eq!(3, edit_distance("", "abc"));
+    }
+}
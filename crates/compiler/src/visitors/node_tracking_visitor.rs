@@ -1,5 +1,7 @@
 use crate::parser::generated::yarnspinnerparser::{self, *};
 use crate::prelude::generated::yarnspinnerparservisitor::YarnSpinnerParserVisitorCompat;
+use crate::prelude::*;
+use crate::visitors::CodeGenerationVisitor;
 use antlr_rust::parser_rule_context::ParserRuleContext;
 use antlr_rust::token::Token;
 use antlr_rust::tree::{ParseTree, ParseTreeVisitorCompat};
@@ -9,13 +11,44 @@ use std::collections::HashSet;
 pub(crate) struct NodeTrackingVisitor {
     pub(crate) tracking_nodes: HashSet<String>,
     pub(crate) ignoring_nodes: HashSet<String>,
+    /// The `<<once>>` blocks and once-only options, in the order they were written in.
+    pub(crate) once_sites: Vec<OnceSite>,
+    /// Whether any `<<detour>>` was found.
+    pub(crate) contains_detours: bool,
+    /// The token index of the `title` header of the node being visited, and the title itself.
+    current_node: Option<(isize, String)>,
     _dummy: Option<String>,
 }
 
+/// A `<<once>>` block or once-only option that needs a variable to remember whether it has run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct OnceSite {
+    /// The token index of the `title` header of the node containing the content.
+    pub(crate) title_token_index: isize,
+    pub(crate) node_title: String,
+    /// The token index at which the `<<once>>` block or the condition of the option starts.
+    pub(crate) token_index: isize,
+    /// Identifies the content within its node independently of its position:
+    /// the line ID of a once-only line, or a hash of the contents of a `<<once>>` block.
+    pub(crate) key: String,
+}
+
 impl NodeTrackingVisitor {
     pub(crate) fn new() -> Self {
         Default::default()
     }
+
+    fn add_once_site(&mut self, token_index: isize, key: String) {
+        // Nodes without a title are reported elsewhere
+        if let Some((title_token_index, node_title)) = self.current_node.clone() {
+            self.once_sites.push(OnceSite {
+                title_token_index,
+                node_title,
+                token_index,
+                key,
+            });
+        }
+    }
 }
 
 impl ParseTreeVisitorCompat<'_> for NodeTrackingVisitor {
@@ -29,6 +62,7 @@ impl ParseTreeVisitorCompat<'_> for NodeTrackingVisitor {
 
 impl<'input> YarnSpinnerParserVisitorCompat<'input> for NodeTrackingVisitor {
     fn visit_node(&mut self, ctx: &NodeContext<'input>) -> Self::Return {
+        self.current_node = None;
         let mut title = None;
         let mut tracking = None;
         for header in ctx.header_all() {
@@ -39,6 +73,9 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for NodeTrackingVisitor {
                 .map(|val| val.get_text().to_owned());
             match key {
                 "title" => {
+                    self.current_node = value
+                        .clone()
+                        .map(|title| (header.start().get_token_index(), title));
                     title = value;
                 }
                 "tracking" => {
@@ -60,12 +97,46 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for NodeTrackingVisitor {
                 }
             }
         }
+        // `when` headers can check whether nodes were visited, too
+        for condition in ctx.header_all().iter().filter_map(|h| h.expression()) {
+            self.visit(condition.as_ref());
+        }
         if let Some(body) = ctx.body() {
             return self.visit(body.as_ref());
         }
         None
     }
 
+    fn visit_once_statement(&mut self, ctx: &Once_statementContext<'input>) -> Self::Return {
+        // The text of the parse tree leaves out whitespace and comments,
+        // so changing the indentation keeps the key
+        self.add_once_site(ctx.start().get_token_index(), stable_hash(&ctx.get_text()));
+        self.visit_children(ctx)
+    }
+
+    fn visit_line_statement(&mut self, ctx: &Line_statementContext<'input>) -> Self::Return {
+        let once_condition = ctx
+            .line_condition()
+            .filter(|condition| condition.COMMAND_ONCE().is_some());
+        if let Some(condition) = once_condition {
+            // Lines have been given their line IDs by the time this runs
+            let key = get_line_id_tag(&ctx.hashtag_all())
+                .and_then(|tag| tag.text.as_ref().map(|text| text.get_text().to_owned()))
+                .unwrap_or_else(|| stable_hash(&ctx.get_text()));
+            self.add_once_site(condition.start().get_token_index(), key);
+        }
+        self.visit_children(ctx)
+    }
+
+    fn visit_command_statement(&mut self, ctx: &Command_statementContext<'input>) -> Self::Return {
+        let (command, expression_count) =
+            CodeGenerationVisitor::compose_command_text(&ctx.command_formatted_text().unwrap());
+        if CodeGenerationVisitor::is_detour(&command, expression_count) {
+            self.contains_detours = true;
+        }
+        self.visit_children(ctx)
+    }
+
     fn visit_valueString(&mut self, ctx: &ValueStringContext<'input>) -> Self::Return {
         ctx.get_token(yarnspinnerparser::STRING, 0)
             .unwrap()
@@ -87,7 +158,7 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for NodeTrackingVisitor {
         // we aren't bothering to test anything about the value itself
         // if it isn't a static string we'll get back null so can ignore it
         // if the func has more than one parameter later on it will cause an error so again can ignore
-        let expression = ctx.expression(0).unwrap();
+        let expression = ctx.expression(0)?;
         let result = self.visit(expression.as_ref());
         if let Some(result) = result {
             self.tracking_nodes.insert(result);
@@ -100,7 +171,6 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for NodeTrackingVisitor {
 mod tests {
     use super::*;
     use crate::prelude::generated::yarnspinnerparser::YarnSpinnerParser;
-    use crate::prelude::*;
     use antlr_rust::common_token_stream::CommonTokenStream;
     use antlr_rust::InputStream;
 
@@ -51,11 +51,12 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for StringTableGeneratorVisi
         for header in ctx.header_all() {
             let header_key = header.header_key.as_ref().unwrap().get_text();
             if header_key == "title" {
+                // A title without a value was already reported as a syntax error
                 header
                     .header_value
                     .as_ref()
-                    .unwrap()
-                    .get_text()
+                    .map(|header| header.get_text())
+                    .unwrap_or_default()
                     .clone_into(&mut self.current_node_name)
             } else if header_key == "tags" {
                 let header_value = header
@@ -108,9 +109,12 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for StringTableGeneratorVisi
                 let diagnostic_context = line_id_tag.clone().unwrap();
                 let line_id = line_id.get_text();
                 self.diagnostics.push(
-                    Diagnostic::from_message(format!("Duplicate line ID {line_id}"))
-                        .with_parser_context(diagnostic_context.as_ref(), self.file.tokens())
-                        .with_file_name(&self.file.name),
+                    Diagnostic::new(
+                        DiagnosticCode::DuplicateLineId,
+                        format!("Duplicate line ID {line_id}"),
+                    )
+                    .with_parser_context(diagnostic_context.as_ref(), self.file.tokens())
+                    .with_file_name(&self.file.name),
                 );
                 return;
             }
@@ -170,14 +174,8 @@ fn generate_formatted_text(ctx: &Line_formatted_textContext) -> String {
 pub(crate) fn get_hashtag_texts(hashtags: &[Rc<HashtagContext>]) -> Vec<String> {
     hashtags
         .iter()
-        .map(|t| {
-            t.text
-                .as_ref()
-                .expect("No text in hashtag")
-                .get_text()
-                .trim()
-                .to_owned()
-        })
+        // Hashtags that the parser recovered from an error may have no text
+        .filter_map(|t| Some(t.text.as_ref()?.get_text().trim().to_owned()))
         .collect()
 }
 
@@ -260,6 +258,7 @@ a {1 + 3} cool expression
             library: Default::default(),
             compilation_type: CompilationType::FullCompilation,
             variable_declarations: vec![],
+            ..Default::default()
         }
         .compile()
         .unwrap();
@@ -318,6 +317,7 @@ a {very} cool expression
             library: Default::default(),
             compilation_type: CompilationType::FullCompilation,
             variable_declarations: vec![],
+            ..Default::default()
         }
         .compile();
 
@@ -332,21 +332,25 @@ a {very} cool expression
             character: 8,
         };
         let context = "a {very} cool expression\n       ^".to_owned();
-        let first_expected =
-            Diagnostic::from_message("Unexpected \"}\" while reading a function call".to_string())
-                .with_file_name("test.yarn".to_string())
-                .with_range(range.clone())
-                .with_context(context.clone())
-                .with_start_line(4)
-                .with_severity(DiagnosticSeverity::Error);
+        let first_expected = Diagnostic::new(
+            DiagnosticCode::SyntaxError,
+            "Unexpected \"}\" while reading a value".to_string(),
+        )
+        .with_file_name("test.yarn".to_string())
+        .with_range(range.clone())
+        .with_context(context.clone())
+        .with_start_line(4)
+        .with_severity(DiagnosticSeverity::Error);
 
-        let second_expected =
-            Diagnostic::from_message("mismatched input '}' expecting '('".to_string())
-                .with_file_name("test.yarn".to_string())
-                .with_range(range)
-                .with_context(context)
-                .with_start_line(4)
-                .with_severity(DiagnosticSeverity::Error);
+        let second_expected = Diagnostic::new(
+            DiagnosticCode::SyntaxError,
+            "no viable alternative at input 'very}'".to_string(),
+        )
+        .with_file_name("test.yarn".to_string())
+        .with_range(range)
+        .with_context(context)
+        .with_start_line(4)
+        .with_severity(DiagnosticSeverity::Error);
         if diagnostics[0] == first_expected {
             assert_eq!(diagnostics[1], second_expected);
         } else {
@@ -5,7 +5,9 @@ use crate::prelude::generated::yarnspinnerlexer;
 use crate::prelude::generated::yarnspinnerparser::*;
 use crate::prelude::generated::yarnspinnerparservisitor::YarnSpinnerParserVisitorCompat;
 use crate::prelude::*;
-use crate::visitors::{CodeGenerationVisitor, KnownTypes};
+use crate::visitors::{
+    is_constant, resolve_enum_case, validate_enum_case, CodeGenerationVisitor, KnownTypes,
+};
 use antlr_rust::parser_rule_context::ParserRuleContext;
 use antlr_rust::token::Token;
 use antlr_rust::tree::{ParseTree, ParseTreeVisitorCompat};
@@ -40,6 +42,9 @@ pub(crate) struct TypeCheckVisitor<'input> {
     // starting our work
     existing_declarations: Vec<Declaration>,
 
+    // The enums declared anywhere in the compilation job
+    known_enums: Vec<EnumType>,
+
     // The name of the node that we're currently visiting.
     current_node_name: Option<String>,
 
@@ -75,11 +80,13 @@ pub(crate) struct TypeCheckVisitor<'input> {
 impl<'input> TypeCheckVisitor<'input> {
     pub(crate) fn new(
         existing_declarations: Vec<Declaration>,
+        known_enums: Vec<EnumType>,
         file: FileParseResult<'input>,
     ) -> Self {
         Self {
             file,
             existing_declarations,
+            known_enums,
             diagnostics: Default::default(),
             new_declarations: Default::default(),
             deferred_types: Default::default(),
@@ -92,10 +99,15 @@ impl<'input> TypeCheckVisitor<'input> {
 
     /// Gets the collection of all declarations - both the ones we received
     /// at the start, and the new ones we've derived ourselves.
+    ///
+    /// Variables declared with `<<local>>` are only part of this collection
+    /// while we're visiting the node that declared them.
     pub(crate) fn declarations(&self) -> impl Iterator<Item = &Declaration> + '_ {
+        let current_node_name = &self.current_node_name;
         self.existing_declarations
             .iter()
             .chain(self.new_declarations.iter())
+            .filter(move |decl| !decl.is_local || &decl.source_node_name == current_node_name)
     }
 
     /// Gets the collection of all declarations mutably - both the ones we received
@@ -122,10 +134,14 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
         for header in ctx.header_all() {
             let key = header.header_key.as_ref().unwrap().get_text();
             if key == "title" {
-                let value = header.header_value.as_ref().unwrap().get_text();
-                self.current_node_name = Some(value.to_owned());
+                // A title without a value was already reported as a syntax error
+                let value = header.header_value.as_ref().map(|value| value.get_text());
+                self.current_node_name = value.map(ToOwned::to_owned);
             }
         }
+        for header in ctx.header_all() {
+            self.visit(header.as_ref());
+        }
         if let Some(body) = ctx.body() {
             self.visit(body.as_ref());
         }
@@ -250,9 +266,12 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
 
     fn visit_valueNull(&mut self, ctx: &ValueNullContext<'input>) -> Self::Return {
         self.diagnostics.push(
-            Diagnostic::from_message("Null is not a permitted type in Yarn Spinner 2.0 and later")
-                .with_file_name(&self.file.name)
-                .with_parser_context(ctx, self.file.tokens()),
+            Diagnostic::new(
+                DiagnosticCode::NullValue,
+                "Null is not a permitted type in Yarn Spinner 2.0 and later",
+            )
+            .with_file_name(&self.file.name)
+            .with_parser_context(ctx, self.file.tokens()),
         );
 
         None
@@ -330,13 +349,16 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
             } else {
                 "parameters"
             };
-            let diagnostic = Diagnostic::from_message(format!(
-                "Function \"{}\" expects {} {}, but received {}",
-                function_name,
-                expected_parameter_types.len(),
-                parameters,
-                supplied_parameters.len()
-            ))
+            let diagnostic = Diagnostic::new(
+                DiagnosticCode::WrongParameterCount,
+                format!(
+                    "Function \"{}\" expects {} {}, but received {}",
+                    function_name,
+                    expected_parameter_types.len(),
+                    parameters,
+                    supplied_parameters.len()
+                ),
+            )
             .with_file_name(&self.file.name)
             .with_parser_context(ctx, self.file.tokens());
             self.diagnostics.push(diagnostic);
@@ -365,13 +387,16 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
                 expected_type = &supplied_type;
             }
             if !supplied_type.is_sub_type_of(expected_type) {
-                let diagnostic = Diagnostic::from_message(format!(
-                    "{} parameter {} expects a {}, not a {}",
-                    function_name,
-                    i + 1,
-                    expected_type.format(),
-                    supplied_type.format()
-                ))
+                let diagnostic = Diagnostic::new(
+                    DiagnosticCode::WrongParameterType,
+                    format!(
+                        "{} parameter {} expects a {}, not a {}",
+                        function_name,
+                        i + 1,
+                        expected_type.format(),
+                        supplied_type.format()
+                    ),
+                )
                 .with_file_name(&self.file.name)
                 .with_parser_context(ctx, self.file.tokens());
                 self.diagnostics.push(diagnostic);
@@ -384,6 +409,38 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
         *function_type.return_type
     }
 
+    fn visit_valueTypeMemberReference(
+        &mut self,
+        ctx: &ValueTypeMemberReferenceContext<'input>,
+    ) -> Self::Return {
+        let reference = ctx.typeMemberReference().unwrap();
+        let case_name = reference.memberName.as_ref()?.get_text();
+        let result = match reference.typeName.as_ref() {
+            Some(type_name) => {
+                resolve_enum_case(&self.known_enums, type_name.get_text(), case_name)
+            }
+            None => match self.hints.get(ctx) {
+                Some(Type::Enum(enum_type)) => {
+                    validate_enum_case(enum_type, case_name).map(|_| Type::Enum(enum_type.clone()))
+                }
+                // Shorthand cases without a hint are resolved by `check_operation`
+                // once the type of the surrounding expression is known.
+                _ => return None,
+            },
+        };
+        match result {
+            Ok(r#type) => Some(r#type),
+            Err(diagnostic) => {
+                self.diagnostics.push(
+                    diagnostic
+                        .with_file_name(&self.file.name)
+                        .with_parser_context(ctx, self.file.tokens()),
+                );
+                None
+            }
+        }
+    }
+
     fn visit_variable(&mut self, ctx: &VariableContext<'input>) -> Self::Return {
         // The type of the value depends on the declared type of the
         // variable
@@ -392,8 +449,40 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
         // this Variable context; here, we'll bail out.
         let var_id = ctx.get_token(yarnspinnerlexer::VAR_ID, 0)?;
         let name = var_id.get_text();
-        if let Some(declaration) = self.declarations().find(|decl| decl.name == name) {
-            return Some(declaration.r#type.clone());
+        let declaration = self.declarations().find(|decl| decl.name == name).cloned();
+        if let Some(declaration) = declaration {
+            let usage = ctx.range().start;
+            let is_used_before_declaration = declaration.is_local
+                && declaration.range.as_ref().is_some_and(|range| {
+                    (usage.line, usage.character) < (range.start.line, range.start.character)
+                });
+            if is_used_before_declaration {
+                let diagnostic = Diagnostic::new(
+                    DiagnosticCode::LocalVariableUsedBeforeDeclaration,
+                    format!("Local variable {name} is used before it is declared"),
+                )
+                .with_file_name(&self.file.name)
+                .with_parser_context(ctx, self.file.tokens());
+                self.diagnostics.push(diagnostic);
+            }
+            return Some(declaration.r#type);
+        }
+
+        // Local variables of other nodes don't exist here
+        if let Some(node_name) = self
+            .existing_declarations
+            .iter()
+            .find(|decl| decl.is_local && decl.name == name)
+            .and_then(|decl| decl.source_node_name.as_ref())
+        {
+            let diagnostic = Diagnostic::new(
+                DiagnosticCode::LocalVariableOutOfScope,
+                format!("{name} is local to node {node_name} and can't be used outside of it"),
+            )
+            .with_file_name(&self.file.name)
+            .with_parser_context(ctx, self.file.tokens());
+            self.diagnostics.push(diagnostic);
+            return None;
         }
 
         // do we already have a potential warning about this?
@@ -409,10 +498,12 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
         // creating a new diagnostic for us having an undefined variable
         // this won't get added into the existing diags though because its possible a later pass will clear it up
         // so we save this as a potential diagnostic for the compiler itself to resolve
-        let diagnostic =
-            Diagnostic::from_message(format_cannot_determine_variable_type_error(&name))
-                .with_file_name(&self.file.name)
-                .with_parser_context(ctx, self.file.tokens());
+        let diagnostic = Diagnostic::new(
+            DiagnosticCode::UndefinedVariable,
+            format_cannot_determine_variable_type_error(&name),
+        )
+        .with_file_name(&self.file.name)
+        .with_parser_context(ctx, self.file.tokens());
         self.deferred_types
             .push(DeferredTypeDiagnostic { name, diagnostic });
 
@@ -422,6 +513,13 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
         None
     }
 
+    fn visit_header(&mut self, ctx: &HeaderContext<'input>) -> Self::Return {
+        ParseTreeVisitorCompat::visit_children(self, ctx);
+        // `when` headers are the conditions of node group members, so they are required to be boolean
+        let expressions = &[ctx.expression()?.into()];
+        self.check_operation(ctx, expressions, None, "when header", &[Type::Boolean])
+    }
+
     fn visit_if_clause(&mut self, ctx: &If_clauseContext<'input>) -> Self::Return {
         ParseTreeVisitorCompat::visit_children(self, ctx);
         // If clauses are required to be boolean
@@ -436,9 +534,33 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
         self.check_operation(ctx, expressions, None, "elseif statement", &[Type::Boolean])
     }
 
+    fn visit_declare_statement(&mut self, ctx: &Declare_statementContext<'input>) -> Self::Return {
+        // The expressions of smart variables have already been checked while resolving their types
+        let expression = ctx.expression()?;
+        if is_constant(&expression) {
+            ParseTreeVisitorCompat::visit_children(self, ctx);
+        }
+        None
+    }
+
     fn visit_set_statement(&mut self, ctx: &Set_statementContext<'input>) -> Self::Return {
         let variable_context = ctx.variable()?;
         let expression_context = ctx.expression()?;
+        let variable_name = variable_context.get_text();
+        if self
+            .declarations()
+            .any(|decl| decl.is_smart && decl.name == variable_name)
+        {
+            // Smart variables are computed from their expression, so they are read-only
+            let diagnostic = Diagnostic::new(
+                DiagnosticCode::SmartVariableModified,
+                format!("{variable_name} is a smart variable and can't be modified"),
+            )
+            .with_file_name(&self.file.name)
+            .with_parser_context(ctx, self.file.tokens());
+            self.diagnostics.push(diagnostic);
+            return None;
+        }
         let variable_type = self.visit(variable_context.as_ref());
         if let Some(variable_type) = variable_type.as_ref() {
             // giving the expression a hint just in case it is needed to help resolve any ambiguity on the expression
@@ -447,7 +569,6 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
                 .insert(expression_context.as_ref(), variable_type.clone());
         }
         let mut expression_type = self.visit(expression_context.as_ref());
-        let variable_name = variable_context.get_text();
         let terms: &[Term] = &[
             variable_context.clone().into(),
             expression_context.clone().into(),
@@ -462,11 +583,14 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
                 // to the type of the variable.
                 match (variable_type.as_ref(), expression_type.as_ref()) {
                     (Some(variable_type), _) if !expression_type.is_sub_type_of(variable_type) => {
-                        let diagnostic = Diagnostic::from_message(format!(
-                            "{variable_name} ({}) cannot be assigned a {}",
-                            variable_type.format(),
-                            expression_type.format(),
-                        ))
+                        let diagnostic = Diagnostic::new(
+                            DiagnosticCode::TypeMismatch,
+                            format!(
+                                "{variable_name} ({}) cannot be assigned a {}",
+                                variable_type.format(),
+                                expression_type.format(),
+                            ),
+                        )
                         .with_file_name(&self.file.name)
                         .with_parser_context(ctx, self.file.tokens());
                         self.diagnostics.push(diagnostic);
@@ -484,7 +608,7 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
                                 .with_description(format!(
                                     "Implicitly declared in {}, node {}",
                                     get_filename(&self.file.name),
-                                    self.current_node_name.as_ref().unwrap()
+                                    self.current_node_name.as_deref().unwrap_or_default()
                                 ))
                                 .with_default_value(default_value)
                                 .with_source_file_name(self.file.name.clone())
@@ -494,7 +618,8 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
                             self.new_declarations.push(decl);
                         } else {
                             self.diagnostics.push(
-                                Diagnostic::from_message(
+                                Diagnostic::new(
+                                    DiagnosticCode::UndefinedVariable,
                                     format_cannot_determine_variable_type_error(&variable_name),
                                 )
                                 .with_file_name(&self.file.name)
@@ -537,7 +662,8 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
         }
         if variable_type.is_none() && expression_type.is_none() {
             self.diagnostics.push(
-                            Diagnostic::from_message(
+                            Diagnostic::new(
+                                DiagnosticCode::UndeterminedType,
                                 format!("Type of expression \"{}\" can't be determined without more context. Please declare one or more terms.", ctx.get_text_with_whitespace(self.file.tokens())))
                                 .with_file_name(&self.file.name)
                                 .with_parser_context(ctx, self.file.tokens()));
@@ -594,6 +720,7 @@ impl DefaultValue for Type {
             Type::String => Some(YarnValue::String(Default::default())),
             Type::Number => Some(YarnValue::Number(Default::default())),
             Type::Boolean => Some(YarnValue::Boolean(Default::default())),
+            Type::Enum(enum_type) => enum_type.cases.first().cloned().map(YarnValue::String),
             _ => None,
         }
     }
@@ -629,6 +756,7 @@ mod tests {
             library: Default::default(),
             compilation_type: CompilationType::FullCompilation,
             variable_declarations: vec![],
+            ..Default::default()
         }
         .compile()
         .unwrap();
@@ -654,6 +782,7 @@ mod tests {
             library: Default::default(),
             compilation_type: CompilationType::FullCompilation,
             variable_declarations: vec![],
+            ..Default::default()
         }
         .compile();
 
@@ -663,47 +792,56 @@ mod tests {
 
         assert_contains(
             &diagnostics,
-            &Diagnostic::from_message("$foo (Number) cannot be assigned a String")
-                .with_file_name("test.yarn")
-                .with_range(
-                    Position {
-                        line: 3,
-                        character: 0,
-                    }..Position {
-                        line: 3,
-                        character: 25,
-                    },
-                ),
+            &Diagnostic::new(
+                DiagnosticCode::TypeMismatch,
+                "$foo (Number) cannot be assigned a String",
+            )
+            .with_file_name("test.yarn")
+            .with_range(
+                Position {
+                    line: 3,
+                    character: 0,
+                }..Position {
+                    line: 3,
+                    character: 25,
+                },
+            ),
         );
 
         assert_contains(
             &diagnostics,
-            &Diagnostic::from_message("$bar (Bool) cannot be assigned a Number")
-                .with_file_name("test.yarn")
-                .with_range(
-                    Position {
-                        line: 6,
-                        character: 0,
-                    }..Position {
-                        line: 6,
-                        character: 19,
-                    },
-                ),
+            &Diagnostic::new(
+                DiagnosticCode::TypeMismatch,
+                "$bar (Bool) cannot be assigned a Number",
+            )
+            .with_file_name("test.yarn")
+            .with_range(
+                Position {
+                    line: 6,
+                    character: 0,
+                }..Position {
+                    line: 6,
+                    character: 19,
+                },
+            ),
         );
 
         assert_contains(
             &diagnostics,
-            &Diagnostic::from_message("$baz (String) cannot be assigned a Bool")
-                .with_file_name("test.yarn")
-                .with_range(
-                    Position {
-                        line: 7,
-                        character: 0,
-                    }..Position {
-                        line: 7,
-                        character: 21,
-                    },
-                ),
+            &Diagnostic::new(
+                DiagnosticCode::TypeMismatch,
+                "$baz (String) cannot be assigned a Bool",
+            )
+            .with_file_name("test.yarn")
+            .with_range(
+                Position {
+                    line: 7,
+                    character: 0,
+                }..Position {
+                    line: 7,
+                    character: 21,
+                },
+            ),
         );
     }
 
@@ -741,6 +879,7 @@ mod tests {
             library: Default::default(),
             compilation_type: CompilationType::FullCompilation,
             variable_declarations: vec![],
+            ..Default::default()
         }
         .compile()
         .unwrap();
@@ -764,6 +903,7 @@ mod tests {
             library: Default::default(),
             compilation_type: CompilationType::FullCompilation,
             variable_declarations: vec![],
+            ..Default::default()
         }
         .compile();
 
@@ -773,62 +913,74 @@ mod tests {
 
         assert_contains(
             &diagnostics,
-            &Diagnostic::from_message("$foo (Number) cannot be assigned a undefined")
-                .with_file_name("test.yarn")
-                .with_range(
-                    Position {
-                        line: 4,
-                        character: 0,
-                    }..Position {
-                        line: 4,
-                        character: 27,
-                    },
-                ),
+            &Diagnostic::new(
+                DiagnosticCode::TypeMismatch,
+                "$foo (Number) cannot be assigned a undefined",
+            )
+            .with_file_name("test.yarn")
+            .with_range(
+                Position {
+                    line: 4,
+                    character: 0,
+                }..Position {
+                    line: 4,
+                    character: 27,
+                },
+            ),
         );
 
         assert_contains(
             &diagnostics,
-            &Diagnostic::from_message("$foo (Number) cannot be assigned a undefined")
-                .with_file_name("test.yarn")
-                .with_range(
-                    Position {
-                        line: 5,
-                        character: 0,
-                    }..Position {
-                        line: 5,
-                        character: 32,
-                    },
-                ),
+            &Diagnostic::new(
+                DiagnosticCode::TypeMismatch,
+                "$foo (Number) cannot be assigned a undefined",
+            )
+            .with_file_name("test.yarn")
+            .with_range(
+                Position {
+                    line: 5,
+                    character: 0,
+                }..Position {
+                    line: 5,
+                    character: 32,
+                },
+            ),
         );
 
         assert_contains(
             &diagnostics,
-            &Diagnostic::from_message("All terms of + must be the same, not Number, String")
-                .with_file_name("test.yarn")
-                .with_range(
-                    Position {
-                        line: 4,
-                        character: 14,
-                    }..Position {
-                        line: 4,
-                        character: 25,
-                    },
-                ),
+            &Diagnostic::new(
+                DiagnosticCode::InvalidOperands,
+                "All terms of + must be the same, not Number, String",
+            )
+            .with_file_name("test.yarn")
+            .with_range(
+                Position {
+                    line: 4,
+                    character: 14,
+                }..Position {
+                    line: 4,
+                    character: 25,
+                },
+            ),
         );
 
         assert_contains(
             &diagnostics,
-            &Diagnostic::from_message("All terms of * must be the same, not Number, String")
-                .with_file_name("test.yarn")
-                .with_range(
-                    Position {
-                        line: 5,
-                        character: 14,
-                    }..Position {
-                        line: 5,
-                        character: 30,
-                    },
-                ),
+            &Diagnostic::new(
+                DiagnosticCode::InvalidOperands,
+                "All terms of * must be the same, not Number, String",
+            )
+            .with_file_name("test.yarn")
+            .with_range(
+                Position {
+                    line: 5,
+                    character: 14,
+                }..Position {
+                    line: 5,
+                    character: 30,
+                },
+            ),
         );
     }
 }
@@ -93,7 +93,7 @@ impl<'input> TypeCheckVisitor<'input> {
                         "Type of expression \"{}\" can't be determined without more context (the compiler thinks it could be {type_names}). Use a type cast on at least one of the terms (e.g. the string(), number(), bool() functions)",
                         context.get_text_with_whitespace(self.file.tokens()),
                     );
-                        let diagnostic = Diagnostic::from_message(message)
+                        let diagnostic = Diagnostic::new(DiagnosticCode::UndeterminedType, message)
                             .with_file_name(&self.file.name)
                             .with_parser_context(context, self.file.tokens());
                         self.diagnostics.push(diagnostic);
@@ -105,7 +105,7 @@ impl<'input> TypeCheckVisitor<'input> {
                         "Type of expression \"{}\" can't be determined without more context. Use a type cast on at least one of the terms (e.g. the string(), number(), bool() functions)",
                         context.get_text_with_whitespace(self.file.tokens()),
                     );
-                        let diagnostic = Diagnostic::from_message(message)
+                        let diagnostic = Diagnostic::new(DiagnosticCode::UndeterminedType, message)
                             .with_file_name(&self.file.name)
                             .with_parser_context(context, self.file.tokens());
                         self.diagnostics.push(diagnostic);
@@ -253,7 +253,8 @@ impl<'input> TypeCheckVisitor<'input> {
             } else {
                 // If we can't produce this, then we can't generate the
                 // declaration.
-                let diagnostic = Diagnostic::from_message(
+                let diagnostic = Diagnostic::new(
+                    DiagnosticCode::UndefinedVariable,
                     format_cannot_determine_variable_type_error(&var_name),
                 )
                 .with_file_name(&self.file.name)
@@ -279,7 +280,7 @@ impl<'input> TypeCheckVisitor<'input> {
                 .join(", ");
             let message =
                 format!("All terms of {operation_description} must be the same, not {type_list}");
-            let diagnostic = Diagnostic::from_message(message)
+            let diagnostic = Diagnostic::new(DiagnosticCode::InvalidOperands, message)
                 .with_file_name(&self.file.name)
                 .with_parser_context(context, self.file.tokens());
             self.diagnostics.push(diagnostic);
@@ -292,6 +293,7 @@ impl<'input> TypeCheckVisitor<'input> {
         for term in terms {
             if let Term::Expression(expression) = term {
                 if self.known_types.get(expression.as_ref()).is_none() {
+                    self.check_enum_case_shorthand(expression, expression_type.as_ref());
                     self.known_types
                         .insert(expression.as_ref(), expression_type.clone());
                 }
@@ -314,7 +316,7 @@ impl<'input> TypeCheckVisitor<'input> {
                     "{} has no implementation defined for {operation_description}",
                     expression_type.format(),
                 );
-                let diagnostic = Diagnostic::from_message(message)
+                let diagnostic = Diagnostic::new(DiagnosticCode::InvalidOperands, message)
                     .with_file_name(&self.file.name)
                     .with_parser_context(context, self.file.tokens());
                 self.diagnostics.push(diagnostic);
@@ -348,7 +350,7 @@ impl<'input> TypeCheckVisitor<'input> {
             let message = format!(
                 "Terms of '{operation_description}' must be {permitted_types_list}, not {type_list}",
             );
-            let diagnostic = Diagnostic::from_message(message)
+            let diagnostic = Diagnostic::new(DiagnosticCode::InvalidOperands, message)
                 .with_file_name(&self.file.name)
                 .with_parser_context(context, self.file.tokens());
             self.diagnostics.push(diagnostic);
@@ -374,7 +376,7 @@ impl<'input> TypeCheckVisitor<'input> {
                 expression_type.format()
             );
             self.diagnostics.push(
-                Diagnostic::from_message(message)
+                Diagnostic::new(DiagnosticCode::InvalidOperands, message)
                     .with_file_name(&self.file.name)
                     .with_parser_context(context, self.file.tokens()),
             );
@@ -384,6 +386,45 @@ impl<'input> TypeCheckVisitor<'input> {
     }
 }
 
+impl<'input> TypeCheckVisitor<'input> {
+    /// Shorthand enum cases like `.Happy` have no type on their own,
+    /// so they can only be checked once the type of the surrounding expression is known.
+    fn check_enum_case_shorthand(
+        &mut self,
+        expression: &ExpressionContextAll<'input>,
+        expression_type: Option<&Type>,
+    ) {
+        let ExpressionContextAll::ExpValueContext(value_context) = expression else {
+            return;
+        };
+        let Some(value) = value_context.value() else {
+            return;
+        };
+        let ValueContextAll::ValueTypeMemberReferenceContext(reference_context) = value.as_ref()
+        else {
+            return;
+        };
+        let reference = reference_context.typeMemberReference().unwrap();
+        if reference.typeName.is_some() {
+            return;
+        }
+        let Some(case_name) = reference.memberName.as_ref().map(|t| t.get_text()) else {
+            return;
+        };
+        let result = match expression_type {
+            Some(Type::Enum(enum_type)) => validate_enum_case(enum_type, case_name),
+            _ => Err(unresolved_enum_case_error(case_name)),
+        };
+        if let Err(diagnostic) = result {
+            self.diagnostics.push(
+                diagnostic
+                    .with_file_name(&self.file.name)
+                    .with_parser_context(reference_context, self.file.tokens()),
+            );
+        }
+    }
+}
+
 /// Bandaid enum to allow static type checks that work via dynamic dispatch on C#
 pub(super) enum Term<'input> {
     Expression(Rc<ExpressionContextAll<'input>>),
@@ -148,3 +148,53 @@ impl Instruction {
             .unwrap_or_else(|e| panic!("Failed to convert operand {index}: {e:?}",))
     }
 }
+
+impl Node {
+    /// The key of the header the compiler adds to a node that declares variables with `<<local>>`.
+    /// The value of the header is the space-separated list of the variables' names.
+    pub const LOCAL_VARIABLE_HEADER: &'static str = "$Yarn.Internal.Local";
+
+    /// The key of the header the compiler adds to every member of a node group, i.e. to the nodes that share a title and have `when` headers.
+    /// The value of such a header is the title of the group, which is also the name of the node that selects one of the members when run.
+    pub const NODE_GROUP_HEADER: &'static str = "$Yarn.Internal.NodeGroup";
+
+    /// The key of the header the compiler adds to the nodes that compute smart variables, i.e. variables declared with an expression like `<<declare $can_afford = $gold >= $price>>`.
+    /// Such a node is named after its variable and leaves the variable's current value on the stack. The value of the header is the source text of the expression.
+    pub const SMART_VARIABLE_HEADER: &'static str = "$Yarn.Internal.SmartVariable";
+
+    /// Iterates over the names of the variables that are local to this node.
+    pub fn local_variables(&self) -> impl Iterator<Item = &str> {
+        self.headers
+            .iter()
+            .filter(|header| header.key == Self::LOCAL_VARIABLE_HEADER)
+            .flat_map(|header| header.value.split_whitespace())
+    }
+
+    /// Returns whether `variable_name` was declared with `<<local>>` in this node.
+    pub fn is_local_variable(&self, variable_name: &str) -> bool {
+        self.local_variables().any(|name| name == variable_name)
+    }
+
+    /// Returns the name of the node group this node is a member of, if any.
+    pub fn node_group(&self) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|header| header.key == Self::NODE_GROUP_HEADER)
+            .map(|header| header.value.as_str())
+    }
+
+    /// Returns whether this node computes the value of the smart variable it is named after.
+    pub fn is_smart_variable(&self) -> bool {
+        self.headers
+            .iter()
+            .any(|header| header.key == Self::SMART_VARIABLE_HEADER)
+    }
+}
+
+impl Header {
+    /// Returns whether the compiler added this header for its own bookkeeping, like [`Node::LOCAL_VARIABLE_HEADER`],
+    /// instead of it being written in the node's source code.
+    pub fn is_internal(&self) -> bool {
+        self.key.starts_with("$Yarn.Internal.")
+    }
+}
@@ -176,6 +176,25 @@ pub mod instruction {
         /// that name.
         /// No operands.
         RunNode = 16,
+        /// Pops a string off the top of the stack, and runs the node with
+        /// that name. When that node completes or reaches a `Return`,
+        /// execution resumes after this instruction.
+        /// No operands.
+        DetourToNode = 17,
+        /// Returns from the current node to the node that detoured into
+        /// it. Stops execution if there is no such node.
+        /// No operands.
+        Return = 18,
+        /// Pops a bool from the stack. If it is true, adds a candidate to the
+        /// list of content that the next SelectSaliencyCandidate picks from.
+        /// - opA = string: ID of the candidate's content, e.g. its line ID
+        /// - opB = string: label to jump to if the candidate is selected
+        AddSaliencyCandidate = 19,
+        /// Lets the saliency strategy pick one of the current candidates,
+        /// then clears the list. Pushes the label of the selected candidate,
+        /// or opA if no candidate was selected.
+        /// - opA = string: label to use if no candidate was selected
+        SelectSaliencyCandidate = 20,
     }
     impl OpCode {
         /// String value of the enum field names used in the ProtoBuf definition.
@@ -201,6 +220,10 @@ pub mod instruction {
                 OpCode::StoreVariable => "STORE_VARIABLE",
                 OpCode::Stop => "STOP",
                 OpCode::RunNode => "RUN_NODE",
+                OpCode::DetourToNode => "DETOUR_TO_NODE",
+                OpCode::Return => "RETURN",
+                OpCode::AddSaliencyCandidate => "ADD_SALIENCY_CANDIDATE",
+                OpCode::SelectSaliencyCandidate => "SELECT_SALIENCY_CANDIDATE",
             }
         }
         /// Creates an enum from field names used in the ProtoBuf definition.
@@ -223,6 +246,10 @@ pub mod instruction {
                 "STORE_VARIABLE" => Some(Self::StoreVariable),
                 "STOP" => Some(Self::Stop),
                 "RUN_NODE" => Some(Self::RunNode),
+                "DETOUR_TO_NODE" => Some(Self::DetourToNode),
+                "RETURN" => Some(Self::Return),
+                "ADD_SALIENCY_CANDIDATE" => Some(Self::AddSaliencyCandidate),
+                "SELECT_SALIENCY_CANDIDATE" => Some(Self::SelectSaliencyCandidate),
                 _ => None,
             }
         }
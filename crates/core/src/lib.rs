@@ -49,7 +49,7 @@ pub mod prelude {
         line_id::*,
         operator::*,
         position::*,
-        types::Type,
+        types::{EnumType, Type},
         yarn_fn::*,
         yarn_value::*,
     };
@@ -65,19 +65,38 @@ impl Library {
         format!("$Yarn.Internal.Visiting.{node_name}")
     }
 
+    /// Generates the name of the variable that remembers whether a `<<once>>` block or once-only option has run.
+    /// The key identifies the content within the node, e.g. by the line ID of a once-only option,
+    /// so that the variable keeps its name when other content is added to or removed from the node.
+    pub fn generate_unique_once_variable_for_node(node_name: &str, key: &str) -> String {
+        format!("$Yarn.Internal.Once.{node_name}.{key}")
+    }
+
+    /// Returns `true` if the variable `name` is one that Yarn Spinner keeps for itself, like the ones generated by
+    /// [`Library::generate_unique_visited_variable_for_node`] and [`Library::generate_unique_once_variable_for_node`].
+    pub fn is_internal_variable(name: &str) -> bool {
+        name.starts_with("$Yarn.Internal.")
+    }
+
     /// Creates a [`Library`] with the standard functions that are included in Yarn Spinner.
     /// These are:
     /// - `string`: Converts a value to a string.
     /// - `number`: Converts a value to a number.
     /// - `bool`: Converts a value to a boolean.
     /// - Comparison operators for numbers, strings, and booleans. (`==`, `!=`, `<`, `<=`, `>`, `>=`)
+    /// - Equality operators for enums. (`==`, `!=`)
     pub fn standard_library() -> Self {
         let mut library = yarn_library!(
             "string" => <String as From<YarnValue >>::from,
             "number" => |value: YarnValue| f32::try_from(value).expect("Failed to convert a Yarn value to a number"),
             "bool" => |value: YarnValue| bool::try_from(value).expect("Failed to convert a Yarn value to a bool"),
         );
-        for r#type in [Type::Number, Type::String, Type::Boolean] {
+        for r#type in [
+            Type::Number,
+            Type::String,
+            Type::Boolean,
+            Type::Enum(Default::default()),
+        ] {
             library.add_methods(r#type);
         }
         library
@@ -0,0 +1,100 @@
+//! Types declared in Yarn scripts via `<<enum>>`.
+
+use crate::prelude::*;
+use crate::types::{Type, TypeProperties};
+use core::fmt::Display;
+
+/// Enum values are stored as the [`String`] name of their case, so all enums share the same
+/// equality operators.
+pub(crate) fn enum_type_properties(enum_type: &EnumType) -> TypeProperties {
+    TypeProperties::from_name("Enum")
+        .with_description(format!("Enum {}", enum_type.name))
+        .with_methods(yarn_library! {
+            Operator::EqualTo => <RustType as PartialEq>::eq,
+            Operator::NotEqualTo => <RustType as PartialEq>::ne,
+        })
+}
+
+type RustType = String;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy", reflect(Debug, PartialEq, Default, Hash))]
+#[cfg_attr(
+    all(feature = "bevy", feature = "serde"),
+    reflect(Serialize, Deserialize)
+)]
+/// A type that represents an enum declared in a Yarn script, e.g.
+/// ```text
+/// <<enum Mood>>
+///     <<case Happy>>
+///     <<case Angry>>
+/// <<endenum>>
+/// ```
+///
+/// At runtime, a value of an enum is represented by the name of its case as a [`YarnValue::String`].
+pub struct EnumType {
+    /// The name of the enum as declared in the script.
+    pub name: String,
+
+    /// The names of the cases of this enum, in the order they were declared in.
+    pub cases: Vec<String>,
+}
+
+impl From<EnumType> for Type {
+    fn from(enum_type: EnumType) -> Self {
+        Type::Enum(enum_type)
+    }
+}
+
+impl EnumType {
+    /// Creates a new enum type with the given name and no cases.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            cases: Vec::new(),
+        }
+    }
+
+    /// Adds a case to this enum.
+    pub fn with_case(mut self, case: impl Into<String>) -> Self {
+        self.cases.push(case.into());
+        self
+    }
+
+    /// Returns whether this enum has a case with the given name.
+    pub fn has_case(&self, case: &str) -> bool {
+        self.cases.iter().any(|c| c == case)
+    }
+}
+
+impl Display for EnumType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enum_types_display_their_declared_name() {
+        let mood = Type::from(EnumType::new("Mood").with_case("Happy"));
+        assert_eq!("Mood", mood.to_string());
+        assert_eq!("Enum", mood.name());
+    }
+
+    #[test]
+    fn enum_types_share_canonical_method_names() {
+        let mood = Type::from(EnumType::new("Mood"));
+        let weather = Type::from(EnumType::new("Weather"));
+        assert_eq!(
+            mood.get_canonical_name_for_method("EqualTo"),
+            weather.get_canonical_name_for_method("EqualTo")
+        );
+        assert!(mood.has_method("EqualTo"));
+        assert!(!mood.has_method("Add"));
+    }
+}
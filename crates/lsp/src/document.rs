@@ -0,0 +1,258 @@
+//! Helpers for finding things in the text of a Yarn file.
+//!
+//! These work on the raw text instead of the parse tree, so that they keep working while a file is being edited
+//! and can't be parsed.
+
+use lsp_types::{Position, Range};
+
+/// A node as written in a Yarn file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NodeSymbol {
+    pub(crate) title: String,
+    /// The range of the title in the `title:` header.
+    pub(crate) title_range: Range,
+    /// The range of the whole node, from its first header to its `===`.
+    pub(crate) range: Range,
+}
+
+/// Finds all nodes with a title in the given source, in the order they appear.
+pub(crate) fn find_nodes(source: &str) -> Vec<NodeSymbol> {
+    let mut nodes = Vec::new();
+    let mut in_header = true;
+    let mut start = None;
+    let mut title = None;
+    let mut last_line = 0;
+    for (line_index, line) in source.lines().enumerate() {
+        last_line = line_index;
+        let text = line.trim();
+        if in_header {
+            if text == "---" {
+                in_header = false;
+            } else if let Some(value) = text.strip_prefix("title:") {
+                let value = value.trim();
+                let value_start = line.len() - line.trim_start().len() + "title:".len();
+                let character = value_start + line[value_start..].find(value).unwrap_or_default();
+                let start = position(line, line_index, character);
+                let end = position(line, line_index, character + value.len());
+                title = (!value.is_empty()).then(|| (value.to_owned(), Range { start, end }));
+            }
+            // File tags and comments above a node don't belong to it
+            if start.is_none()
+                && !text.is_empty()
+                && !text.starts_with('#')
+                && !text.starts_with("//")
+            {
+                start = Some(line_index);
+            }
+        } else if text == "===" {
+            nodes.extend(node_symbol(title.take(), start.take(), line_index, line));
+            in_header = true;
+        }
+    }
+    if !in_header {
+        // The node has not been closed yet
+        let line = source.lines().last().unwrap_or_default();
+        nodes.extend(node_symbol(title, start, last_line, line));
+    }
+    nodes
+}
+
+fn node_symbol(
+    title: Option<(String, Range)>,
+    start: Option<usize>,
+    end_line_index: usize,
+    end_line: &str,
+) -> Option<NodeSymbol> {
+    let (title, title_range) = title?;
+    let start = Position::new(start.unwrap_or(title_range.start.line as usize) as u32, 0);
+    let end = position(end_line, end_line_index, end_line.len());
+    Some(NodeSymbol {
+        title,
+        title_range,
+        range: Range { start, end },
+    })
+}
+
+/// The identifier at the given position, e.g. a variable including its `$`, a node name or a function name.
+pub(crate) fn identifier_at(source: &str, position: Position) -> Option<(String, Range)> {
+    let line = source.lines().nth(position.line as usize)?;
+    let offset = byte_offset(line, position.character);
+    let start = line[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|(_, char)| is_identifier_char(*char))
+        .last()
+        .map_or(offset, |(index, _)| index);
+    let end = line[offset..]
+        .char_indices()
+        .find(|(_, char)| !is_identifier_char(*char))
+        .map_or(line.len(), |(index, _)| offset + index);
+    let start = identifier_start(line, start, end)?;
+    let identifier = &line[start..end];
+    let range = Range {
+        start: self::position(line, position.line as usize, start),
+        end: self::position(line, position.line as usize, end),
+    };
+    Some((identifier.to_owned(), range))
+}
+
+/// The part of an identifier that comes before the given position, e.g. `$go` while typing `$gold`.
+/// Returns an empty identifier if the position is not after an identifier.
+pub(crate) fn identifier_before(source: &str, position: Position) -> (String, Range) {
+    let line = source
+        .lines()
+        .nth(position.line as usize)
+        .unwrap_or_default();
+    let end = byte_offset(line, position.character);
+    let start = line[..end]
+        .char_indices()
+        .rev()
+        .take_while(|(_, char)| is_identifier_char(*char))
+        .last()
+        .map_or(end, |(index, _)| index);
+    let start = identifier_start(line, start, end).unwrap_or(end);
+    let range = Range {
+        start: self::position(line, position.line as usize, start),
+        end: self::position(line, position.line as usize, end),
+    };
+    (line[start..end].to_owned(), range)
+}
+
+/// Variables may only start with a `$`, so leave out any `$` that comes after the start of the identifier.
+fn identifier_start(line: &str, start: usize, end: usize) -> Option<usize> {
+    let identifier = &line[start..end];
+    let start = start + identifier.rfind('$').unwrap_or_default();
+    (start < end).then_some(start)
+}
+
+fn is_identifier_char(char: char) -> bool {
+    char.is_alphanumeric() || matches!(char, '_' | '.' | '$')
+}
+
+/// Converts a position counted in code points, like the compiler's, to the UTF-16 position used by LSP.
+pub(crate) fn from_compiler_position(
+    source: &str,
+    position: &yarnspinner_core::prelude::Position,
+) -> Position {
+    let line = source.lines().nth(position.line).unwrap_or_default();
+    let character: usize = line
+        .chars()
+        .take(position.character)
+        .map(char::len_utf16)
+        .sum();
+    Position::new(position.line as u32, character as u32)
+}
+
+/// The LSP position of the given byte offset in a line.
+fn position(line: &str, line_index: usize, byte_offset: usize) -> Position {
+    let character: usize = line[..byte_offset].chars().map(char::len_utf16).sum();
+    Position::new(line_index as u32, character as u32)
+}
+
+/// The byte offset of a UTF-16 position in a line, clamped to the end of the line.
+fn byte_offset(line: &str, utf16_character: u32) -> usize {
+    let mut utf16_offset = 0;
+    for (index, char) in line.char_indices() {
+        if utf16_offset >= utf16_character as usize {
+            return index;
+        }
+        utf16_offset += char.len_utf16();
+    }
+    line.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "\
+#file_tag
+// A comment
+title: Start
+tags: intro
+---
+Hello, {$näme}! <<jump Shop>>
+===
+
+title: Shop
+---
+<<if visited(\"Start\")>>";
+
+    #[test]
+    fn finds_nodes() {
+        let nodes = find_nodes(SOURCE);
+        assert_eq!(
+            vec![
+                NodeSymbol {
+                    title: "Start".to_owned(),
+                    title_range: Range::new(Position::new(2, 7), Position::new(2, 12)),
+                    range: Range::new(Position::new(2, 0), Position::new(6, 3)),
+                },
+                NodeSymbol {
+                    title: "Shop".to_owned(),
+                    title_range: Range::new(Position::new(8, 7), Position::new(8, 11)),
+                    range: Range::new(Position::new(8, 0), Position::new(10, 23)),
+                },
+            ],
+            nodes
+        );
+    }
+
+    #[test]
+    fn finds_identifiers() {
+        assert_eq!(
+            Some((
+                "$näme".to_owned(),
+                Range::new(Position::new(5, 8), Position::new(5, 13))
+            )),
+            identifier_at(SOURCE, Position::new(5, 11))
+        );
+        assert_eq!(
+            Some((
+                "Shop".to_owned(),
+                Range::new(Position::new(5, 23), Position::new(5, 27))
+            )),
+            identifier_at(SOURCE, Position::new(5, 27))
+        );
+        assert_eq!(
+            Some((
+                "Start".to_owned(),
+                Range::new(Position::new(10, 14), Position::new(10, 19))
+            )),
+            identifier_at(SOURCE, Position::new(10, 15))
+        );
+        assert_eq!(None, identifier_at(SOURCE, Position::new(5, 6)));
+        assert_eq!(None, identifier_at(SOURCE, Position::new(20, 0)));
+    }
+
+    #[test]
+    fn finds_identifiers_being_typed() {
+        assert_eq!(
+            (
+                "$nä".to_owned(),
+                Range::new(Position::new(5, 8), Position::new(5, 11))
+            ),
+            identifier_before(SOURCE, Position::new(5, 11))
+        );
+        assert_eq!(
+            (
+                String::new(),
+                Range::new(Position::new(5, 7), Position::new(5, 7))
+            ),
+            identifier_before(SOURCE, Position::new(5, 7))
+        );
+    }
+
+    #[test]
+    fn converts_compiler_positions_to_utf16() {
+        let source = "😀 $a";
+        let position = yarnspinner_core::prelude::Position {
+            line: 0,
+            character: 2,
+        };
+        assert_eq!(
+            Position::new(0, 3),
+            from_compiler_position(source, &position)
+        );
+    }
+}
@@ -0,0 +1,44 @@
+//! # Yarn Spinner language server
+//!
+//! Speaks the [Language Server Protocol](https://microsoft.github.io/language-server-protocol/) over stdio,
+//! so that editors can show the errors in Yarn files while they are being written instead of only when the game starts.
+//!
+//! Supports diagnostics, going to the definition of nodes and variables, hovering over variables and functions,
+//! completing variables, node names and functions, as well as listing the nodes of a file as document symbols.
+//!
+//! All Yarn files in the workspace folders are compiled together, so that e.g. jumps to nodes in other files resolve.
+#![warn(missing_docs, missing_debug_implementations)]
+
+use crate::server::Server;
+use lsp_server::Connection;
+use lsp_types::InitializeParams;
+use std::error::Error;
+
+mod document;
+mod server;
+
+fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let (connection, io_threads) = Connection::stdio();
+    let capabilities = serde_json::to_value(Server::capabilities())?;
+    let params: InitializeParams = serde_json::from_value(connection.initialize(capabilities)?)?;
+
+    let mut server = Server::new();
+    #[allow(deprecated)]
+    let root_uri = params.root_uri;
+    let folders = params
+        .workspace_folders
+        .into_iter()
+        .flatten()
+        .map(|folder| folder.uri)
+        .chain(root_uri);
+    for folder in folders {
+        if let Ok(path) = folder.to_file_path() {
+            server.add_workspace_folder(&path);
+        }
+    }
+
+    server.run(&connection)?;
+    drop(connection);
+    io_threads.join()?;
+    Ok(())
+}
@@ -0,0 +1,707 @@
+use crate::document::{self, NodeSymbol};
+use crossbeam_channel::TryRecvError;
+use lsp_server::{Connection, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as NotificationTrait, PublishDiagnostics,
+};
+use lsp_types::request::{
+    Completion, DocumentSymbolRequest, GotoDefinition, HoverRequest, Request as RequestTrait,
+};
+use lsp_types::*;
+use std::collections::{BTreeMap, HashSet};
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use yarnspinner_compiler::prelude::{
+    CompilationType, Compiler, CompilerError, Declaration, DeclarationSource, File,
+    IncrementalCompiler,
+};
+use yarnspinner_core::prelude::Library;
+use yarnspinner_core::types::{FunctionType, Type, TypeFormat};
+
+/// The state of the language server: the Yarn files of the workspace and what the compiler found out about them.
+#[derive(Debug, Default)]
+pub(crate) struct Server {
+    /// The sources of all Yarn files, keyed by their URI. Open documents replace the contents on disk.
+    files: BTreeMap<Url, String>,
+    incremental_compiler: IncrementalCompiler,
+    /// The declarations of the latest compilation. Kept while the files have errors,
+    /// so that writers can still look up variables while editing.
+    declarations: Vec<Declaration>,
+    /// The functions that every dialogue provides.
+    functions: Vec<(String, FunctionType)>,
+    /// The files that diagnostics were published for by the latest compilation.
+    files_with_diagnostics: HashSet<Url>,
+}
+
+impl Server {
+    pub(crate) fn new() -> Self {
+        let mut library = Library::standard_library();
+        // Added by every `Dialogue` of the runtime
+        library
+            .add_function("visited", |_node: String| false)
+            .add_function("visited_count", |_node: String| 0.0_f32);
+        let mut functions: Vec<_> = library
+            .iter()
+            // Leave out the methods of types, as they are only called by operators
+            .filter(|(name, _)| !name.contains('.'))
+            .map(|(name, function)| {
+                let mut function_type = FunctionType::default();
+                for parameter in function.parameter_types() {
+                    function_type.add_parameter(Type::try_from(parameter).ok());
+                }
+                function_type.set_return_type(Type::try_from(function.return_type()).ok());
+                (name.to_owned(), function_type)
+            })
+            .collect();
+        functions.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Self {
+            functions,
+            ..Default::default()
+        }
+    }
+
+    /// Reads all Yarn files in the given directory and its subdirectories.
+    pub(crate) fn add_workspace_folder(&mut self, path: &Path) {
+        let Ok(entries) = fs::read_dir(path) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let is_hidden = entry.file_name().to_string_lossy().starts_with('.');
+            if path.is_dir() && !is_hidden {
+                self.add_workspace_folder(&path);
+            } else if path
+                .extension()
+                .is_some_and(|extension| extension == "yarn")
+            {
+                let (Ok(uri), Ok(source)) = (Url::from_file_path(&path), fs::read_to_string(&path))
+                else {
+                    continue;
+                };
+                self.files.entry(uri).or_insert(source);
+            }
+        }
+    }
+
+    pub(crate) fn capabilities() -> ServerCapabilities {
+        ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            definition_provider: Some(OneOf::Left(true)),
+            completion_provider: Some(CompletionOptions {
+                trigger_characters: Some(vec!["$".to_owned()]),
+                ..Default::default()
+            }),
+            document_symbol_provider: Some(OneOf::Left(true)),
+            ..Default::default()
+        }
+    }
+
+    /// Handles messages until the client asks the server to shut down.
+    ///
+    /// Changes are only compiled once no more messages are queued,
+    /// so that typing doesn't recompile the workspace for every keystroke.
+    pub(crate) fn run(
+        &mut self,
+        connection: &Connection,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.publish_diagnostics(connection)?;
+        let mut files_changed = false;
+        loop {
+            let message = match connection.receiver.try_recv() {
+                Ok(message) => message,
+                Err(TryRecvError::Empty) => {
+                    if files_changed {
+                        self.publish_diagnostics(connection)?;
+                        files_changed = false;
+                    }
+                    match connection.receiver.recv() {
+                        Ok(message) => message,
+                        Err(_) => break,
+                    }
+                }
+                Err(TryRecvError::Disconnected) => break,
+            };
+            match message {
+                Message::Request(request) => {
+                    if connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    // Answer with what the latest changes declare
+                    if files_changed {
+                        self.publish_diagnostics(connection)?;
+                        files_changed = false;
+                    }
+                    let response = self.handle_request(request);
+                    connection.sender.send(Message::Response(response))?;
+                }
+                Message::Notification(notification) => {
+                    files_changed |= self.handle_notification(notification);
+                }
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_request(&self, request: Request) -> Response {
+        let id = request.id.clone();
+        let result = match request.method.as_str() {
+            HoverRequest::METHOD => handle::<HoverRequest>(request, |params| {
+                self.hover(params.text_document_position_params)
+            }),
+            GotoDefinition::METHOD => handle::<GotoDefinition>(request, |params| {
+                self.goto_definition(params.text_document_position_params)
+                    .map(GotoDefinitionResponse::Scalar)
+            }),
+            Completion::METHOD => handle::<Completion>(request, |params| {
+                Some(CompletionResponse::Array(
+                    self.completion(params.text_document_position),
+                ))
+            }),
+            DocumentSymbolRequest::METHOD => handle::<DocumentSymbolRequest>(request, |params| {
+                Some(DocumentSymbolResponse::Nested(
+                    self.document_symbols(&params.text_document.uri),
+                ))
+            }),
+            _ => {
+                return Response::new_err(
+                    id,
+                    lsp_server::ErrorCode::MethodNotFound as i32,
+                    format!("Unsupported request: {}", request.method),
+                )
+            }
+        };
+        match result {
+            Ok(result) => Response::new_ok(id, result),
+            Err(error) => Response::new_err(
+                id,
+                lsp_server::ErrorCode::InvalidParams as i32,
+                error.to_string(),
+            ),
+        }
+    }
+
+    /// Returns whether the files changed.
+    fn handle_notification(&mut self, notification: Notification) -> bool {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let Ok(params) = parse_params::<DidOpenTextDocument>(notification.params) else {
+                    return false;
+                };
+                self.set_file(params.text_document.uri, params.text_document.text);
+                true
+            }
+            DidChangeTextDocument::METHOD => {
+                let Ok(params) = parse_params::<DidChangeTextDocument>(notification.params) else {
+                    return false;
+                };
+                // Only full updates are requested, so the last change contains the whole text
+                let Some(change) = params.content_changes.into_iter().last() else {
+                    return false;
+                };
+                self.set_file(params.text_document.uri, change.text);
+                true
+            }
+            DidCloseTextDocument::METHOD => {
+                let Ok(params) = parse_params::<DidCloseTextDocument>(notification.params) else {
+                    return false;
+                };
+                // Unsaved changes are gone, so go back to what is on disk
+                let uri = params.text_document.uri;
+                match uri
+                    .to_file_path()
+                    .ok()
+                    .and_then(|path| fs::read_to_string(path).ok())
+                {
+                    Some(source) => self.set_file(uri, source),
+                    None => {
+                        self.files.remove(&uri);
+                    }
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn set_file(&mut self, uri: Url, source: String) {
+        self.files.insert(uri, source);
+    }
+
+    fn publish_diagnostics(
+        &mut self,
+        connection: &Connection,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        for params in self.compile() {
+            let notification = Notification::new(PublishDiagnostics::METHOD.to_owned(), params);
+            connection
+                .sender
+                .send(Message::Notification(notification))?;
+        }
+        Ok(())
+    }
+
+    /// Compiles all files and returns their diagnostics. Files whose diagnostics were all fixed get an empty list.
+    pub(crate) fn compile(&mut self) -> Vec<PublishDiagnosticsParams> {
+        let mut compiler = Compiler::new();
+        compiler.with_compilation_type(CompilationType::DeclarationsOnly);
+        for (uri, source) in &self.files {
+            // Files without any nodes can't be parsed
+            if source.lines().any(|line| {
+                let text = line.trim();
+                !text.is_empty() && !text.starts_with("//")
+            }) {
+                compiler.add_file(File {
+                    file_name: uri.to_string(),
+                    source: source.clone(),
+                });
+            }
+        }
+
+        let result = self
+            .incremental_compiler
+            .compile(&compiler)
+            .map_err(|error| {
+                // Still find out about the declarations in the nodes without errors
+                let declarations = compiler
+                    .compile_partial()
+                    .map(|partial_compilation| partial_compilation.compilation.declarations);
+                (error, declarations.ok())
+            });
+        let diagnostics = match result {
+            Ok(compilation) => {
+                self.declarations = compilation.declarations;
+                compilation.warnings
+            }
+            Err((CompilerError(diagnostics), declarations)) => {
+                if let Some(mut declarations) = declarations {
+                    // Nodes with errors are left out of partial compilations, so keep what was declared in them before
+                    let names: HashSet<_> = declarations
+                        .iter()
+                        .map(|declaration| declaration.name.clone())
+                        .collect();
+                    declarations.extend(
+                        self.declarations
+                            .drain(..)
+                            .filter(|declaration| !names.contains(&declaration.name)),
+                    );
+                    self.declarations = declarations;
+                }
+                diagnostics
+            }
+        };
+
+        let mut diagnostics_by_file: BTreeMap<Url, Vec<Diagnostic>> = BTreeMap::new();
+        for diagnostic in diagnostics {
+            let Some(uri) = diagnostic
+                .file_name
+                .as_ref()
+                .and_then(|file_name| Url::parse(file_name).ok())
+            else {
+                continue;
+            };
+            let source = self.files.get(&uri).map(String::as_str).unwrap_or_default();
+            let diagnostic = self.convert_diagnostic(source, diagnostic);
+            diagnostics_by_file.entry(uri).or_default().push(diagnostic);
+        }
+        let files_with_diagnostics: HashSet<_> = diagnostics_by_file.keys().cloned().collect();
+        for uri in self
+            .files_with_diagnostics
+            .difference(&files_with_diagnostics)
+        {
+            diagnostics_by_file.entry(uri.clone()).or_default();
+        }
+        self.files_with_diagnostics = files_with_diagnostics;
+        diagnostics_by_file
+            .into_iter()
+            .map(|(uri, diagnostics)| PublishDiagnosticsParams::new(uri, diagnostics, None))
+            .collect()
+    }
+
+    fn convert_diagnostic(
+        &self,
+        source: &str,
+        diagnostic: yarnspinner_compiler::prelude::Diagnostic,
+    ) -> Diagnostic {
+        let range = diagnostic.range.map_or_else(Range::default, |range| Range {
+            start: document::from_compiler_position(source, &range.start),
+            end: document::from_compiler_position(source, &range.end),
+        });
+        let severity = match diagnostic.severity {
+            yarnspinner_compiler::prelude::DiagnosticSeverity::Error => DiagnosticSeverity::ERROR,
+            yarnspinner_compiler::prelude::DiagnosticSeverity::Warning => {
+                DiagnosticSeverity::WARNING
+            }
+        };
+        Diagnostic {
+            range,
+            severity: Some(severity),
+            code: Some(NumberOrString::String(diagnostic.code.to_string())),
+            source: Some("yarnspinner".to_owned()),
+            message: diagnostic.message,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn hover(&self, params: TextDocumentPositionParams) -> Option<Hover> {
+        let source = self.files.get(&params.text_document.uri)?;
+        let (identifier, range) = document::identifier_at(source, params.position)?;
+        let (signature, description) = if let Some(declaration) = self.find_variable(&identifier) {
+            let kind = if declaration.is_smart {
+                "smart variable"
+            } else {
+                "variable"
+            };
+            (
+                format!(
+                    "({kind}) {}: {}",
+                    declaration.name,
+                    declaration.r#type.format()
+                ),
+                declaration.description.clone(),
+            )
+        } else if let Some((name, function_type)) = self.find_function(&identifier) {
+            (format!("(function) {name}: {function_type}"), None)
+        } else {
+            return None;
+        };
+        let mut value = format!("```yarnspinner\n{signature}\n```");
+        if let Some(description) = description.filter(|description| !description.is_empty()) {
+            value += &format!("\n\n{description}");
+        }
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value,
+            }),
+            range: Some(range),
+        })
+    }
+
+    pub(crate) fn goto_definition(&self, params: TextDocumentPositionParams) -> Option<Location> {
+        let source = self.files.get(&params.text_document.uri)?;
+        let (identifier, _) = document::identifier_at(source, params.position)?;
+        if identifier.starts_with('$') {
+            let declaration = self.find_variable(&identifier)?;
+            let DeclarationSource::File(file_name) = &declaration.source_file_name else {
+                return None;
+            };
+            let uri = Url::parse(file_name).ok()?;
+            let source = self.files.get(&uri)?;
+            let range = declaration.range.as_ref()?;
+            let range = Range {
+                start: document::from_compiler_position(source, &range.start),
+                end: document::from_compiler_position(source, &range.end),
+            };
+            Some(Location::new(uri, range))
+        } else {
+            self.nodes()
+                .find(|(_, node)| node.title == identifier)
+                .map(|(uri, node)| Location::new(uri.clone(), node.title_range))
+        }
+    }
+
+    pub(crate) fn completion(&self, params: TextDocumentPositionParams) -> Vec<CompletionItem> {
+        let Some(source) = self.files.get(&params.text_document.uri) else {
+            return Vec::new();
+        };
+        let (prefix, range) = document::identifier_before(source, params.position);
+        let item = |label: String, kind, detail: Option<String>, documentation: Option<String>| {
+            CompletionItem {
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(
+                    range,
+                    label.clone(),
+                ))),
+                label,
+                kind: Some(kind),
+                detail,
+                documentation: documentation.map(Documentation::String),
+                ..Default::default()
+            }
+        };
+        if prefix.starts_with('$') {
+            self.declarations
+                .iter()
+                .filter(|declaration| is_visible_variable(declaration))
+                .map(|declaration| {
+                    item(
+                        declaration.name.clone(),
+                        CompletionItemKind::VARIABLE,
+                        Some(declaration.r#type.format()),
+                        declaration.description.clone(),
+                    )
+                })
+                .collect()
+        } else {
+            let mut node_titles: Vec<_> = self.nodes().map(|(_, node)| node.title).collect();
+            node_titles.sort();
+            node_titles.dedup();
+            let nodes = node_titles.into_iter().map(|title| {
+                item(
+                    title,
+                    CompletionItemKind::MODULE,
+                    Some("node".to_owned()),
+                    None,
+                )
+            });
+            let functions = self.functions.iter().map(|(name, function_type)| {
+                item(
+                    name.clone(),
+                    CompletionItemKind::FUNCTION,
+                    Some(function_type.to_string()),
+                    None,
+                )
+            });
+            nodes.chain(functions).collect()
+        }
+    }
+
+    pub(crate) fn document_symbols(&self, uri: &Url) -> Vec<DocumentSymbol> {
+        let Some(source) = self.files.get(uri) else {
+            return Vec::new();
+        };
+        document::find_nodes(source)
+            .into_iter()
+            .map(|node| {
+                #[allow(deprecated)]
+                DocumentSymbol {
+                    name: node.title,
+                    detail: None,
+                    kind: SymbolKind::MODULE,
+                    tags: None,
+                    deprecated: None,
+                    range: node.range,
+                    selection_range: node.title_range,
+                    children: None,
+                }
+            })
+            .collect()
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = (&Url, NodeSymbol)> {
+        self.files.iter().flat_map(|(uri, source)| {
+            document::find_nodes(source)
+                .into_iter()
+                .map(move |node| (uri, node))
+        })
+    }
+
+    fn find_variable(&self, name: &str) -> Option<&Declaration> {
+        self.declarations
+            .iter()
+            .find(|declaration| declaration.name == name && is_visible_variable(declaration))
+    }
+
+    fn find_function(&self, name: &str) -> Option<&(String, FunctionType)> {
+        self.functions
+            .iter()
+            .find(|(function_name, _)| function_name == name)
+    }
+}
+
+/// Whether the declaration is a variable written in Yarn, as opposed to e.g. the compiler's internal variables for tracking nodes.
+fn is_visible_variable(declaration: &Declaration) -> bool {
+    declaration.name.starts_with('$')
+        && !Library::is_internal_variable(&declaration.name)
+        && !matches!(declaration.r#type, Type::Function(_))
+}
+
+fn parse_params<N: NotificationTrait>(params: serde_json::Value) -> serde_json::Result<N::Params> {
+    serde_json::from_value(params)
+}
+
+fn handle<R: RequestTrait>(
+    request: Request,
+    handler: impl FnOnce(R::Params) -> R::Result,
+) -> serde_json::Result<serde_json::Value> {
+    let params = serde_json::from_value(request.params)?;
+    serde_json::to_value(handler(params))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uri(file_name: &str) -> Url {
+        Url::parse(&format!("file:///project/{file_name}")).unwrap()
+    }
+
+    fn position(file_name: &str, line: u32, character: u32) -> TextDocumentPositionParams {
+        TextDocumentPositionParams::new(
+            TextDocumentIdentifier::new(uri(file_name)),
+            Position::new(line, character),
+        )
+    }
+
+    fn server() -> Server {
+        let mut server = Server::new();
+        server.set_file(
+            uri("start.yarn"),
+            "\
+title: Start
+---
+/// The player's money
+<<declare $gold = 10>>
+You have {$gold} coins.
+<<if visited(\"Shop\")>>
+    <<jump Shop>>
+<<endif>>
+==="
+            .to_owned(),
+        );
+        server.set_file(
+            uri("shop.yarn"),
+            "\
+title: Shop
+---
+<<set $gold to $gold - 1>>
+==="
+            .to_owned(),
+        );
+        server
+    }
+
+    #[test]
+    fn publishes_diagnostics_until_they_are_fixed() {
+        let mut server = server();
+        assert!(server.compile().is_empty());
+
+        server.set_file(
+            uri("shop.yarn"),
+            "title: Shop\n---\n<<set $gold to \"none\">>\n===".to_owned(),
+        );
+        let diagnostics = server.compile();
+        assert_eq!(1, diagnostics.len());
+        assert_eq!(uri("shop.yarn"), diagnostics[0].uri);
+        assert_eq!(1, diagnostics[0].diagnostics.len());
+        let diagnostic = &diagnostics[0].diagnostics[0];
+        assert_eq!(Some(DiagnosticSeverity::ERROR), diagnostic.severity);
+        assert_eq!(
+            Some(NumberOrString::String("YS0015".to_owned())),
+            diagnostic.code
+        );
+        assert_eq!(2, diagnostic.range.start.line);
+        // The declarations are still known while there are errors
+        assert!(server.find_variable("$gold").is_some());
+
+        server.set_file(
+            uri("start.yarn"),
+            "title: Start\n---\n<<declare $gold = 10>>\n{$nowhere}\n===".to_owned(),
+        );
+        server.compile();
+        assert!(server.find_variable("$gold").is_some());
+
+        server.set_file(
+            uri("start.yarn"),
+            "title: Start\n---\n<<declare $gold = 10>>\n===".to_owned(),
+        );
+        server.set_file(uri("shop.yarn"), "title: Shop\n---\n===".to_owned());
+        let diagnostics = server.compile();
+        assert_eq!(2, diagnostics.len());
+        assert!(diagnostics
+            .iter()
+            .all(|params| params.diagnostics.is_empty()));
+        assert!(server.compile().is_empty());
+    }
+
+    #[test]
+    fn reports_half_written_files() {
+        let mut server = server();
+        server.compile();
+        for source in [
+            "title:",
+            "title: Shop\n---\n<<declare",
+            "title: Shop\n---\n{",
+        ] {
+            server.set_file(uri("shop.yarn"), source.to_owned());
+            let diagnostics = server.compile();
+            assert!(diagnostics
+                .iter()
+                .any(|params| params.uri == uri("shop.yarn") && !params.diagnostics.is_empty()));
+            assert!(server.find_variable("$gold").is_some());
+        }
+    }
+
+    #[test]
+    fn goes_to_definitions() {
+        let mut server = server();
+        server.compile();
+        let jump = server
+            .goto_definition(position("start.yarn", 6, 12))
+            .unwrap();
+        assert_eq!(
+            Location::new(
+                uri("shop.yarn"),
+                Range::new(Position::new(0, 7), Position::new(0, 11))
+            ),
+            jump
+        );
+        let visited = server
+            .goto_definition(position("start.yarn", 5, 15))
+            .unwrap();
+        assert_eq!(jump, visited);
+        let variable = server.goto_definition(position("shop.yarn", 2, 9)).unwrap();
+        assert_eq!(uri("start.yarn"), variable.uri);
+        assert_eq!(3, variable.range.start.line);
+        assert_eq!(None, server.goto_definition(position("start.yarn", 4, 2)));
+    }
+
+    #[test]
+    fn hovers_over_variables_and_functions() {
+        let mut server = server();
+        server.compile();
+        let Some(HoverContents::Markup(variable)) = server
+            .hover(position("start.yarn", 4, 12))
+            .map(|hover| hover.contents)
+        else {
+            panic!("Expected a hover for $gold");
+        };
+        assert_eq!(
+            "```yarnspinner\n(variable) $gold: Number\n```\n\nThe player's money",
+            variable.value
+        );
+        let Some(HoverContents::Markup(function)) = server
+            .hover(position("start.yarn", 5, 7))
+            .map(|hover| hover.contents)
+        else {
+            panic!("Expected a hover for visited");
+        };
+        assert_eq!(
+            "```yarnspinner\n(function) visited: Fn(String) -> Bool\n```",
+            function.value
+        );
+    }
+
+    #[test]
+    fn completes_variables_nodes_and_functions() {
+        let mut server = server();
+        server.compile();
+        let labels = |items: Vec<CompletionItem>| -> Vec<String> {
+            items.into_iter().map(|item| item.label).collect()
+        };
+        let variables = server.completion(position("start.yarn", 4, 12));
+        assert_eq!(vec!["$gold"], labels(variables));
+
+        let others = labels(server.completion(position("start.yarn", 6, 11)));
+        assert!(others.contains(&"Shop".to_owned()));
+        assert!(others.contains(&"Start".to_owned()));
+        assert!(others.contains(&"visited".to_owned()));
+        assert!(others.contains(&"string".to_owned()));
+        assert!(!others.iter().any(|label| label.contains('.')));
+    }
+
+    #[test]
+    fn lists_nodes_as_document_symbols() {
+        let server = server();
+        let symbols = server.document_symbols(&uri("start.yarn"));
+        assert_eq!(1, symbols.len());
+        assert_eq!("Start", symbols[0].name);
+        assert_eq!(
+            Range::new(Position::new(0, 0), Position::new(8, 3)),
+            symbols[0].range
+        );
+    }
+}
@@ -0,0 +1,362 @@
+//! A step debugger for [`Dialogue`]s, for finding out why a script took the path it did without adding commands to it.
+
+use crate::prelude::*;
+use crate::Result;
+#[cfg(feature = "bevy")]
+use bevy::prelude::World;
+use bevy_platform::collections::HashMap;
+
+/// Wraps a [`Dialogue`] to pause it at breakpoints or after single steps and to inspect it while it is paused.
+///
+/// Breakpoints are set by file and line, so the debugger needs to know where each instruction came from.
+/// Pass it the `DebugInfo` of every node in the `Compilation` with [`DialogueDebugger::add_debug_info`] before setting any.
+///
+/// ## Example
+///
+/// ```rust
+/// # use yarnspinner_runtime::prelude::*;
+/// # use yarnspinner_core::prelude::*;
+/// # fn debug(dialogue: Dialogue, debug_info: Vec<(String, String, Vec<(usize, Option<Position>)>)>) -> yarnspinner_runtime::Result<()> {
+/// let mut debugger = DialogueDebugger::new(dialogue);
+/// // For every `DebugInfo` in `Compilation::debug_info`
+/// for (file_name, node_name, line_positions) in debug_info {
+///     debugger.add_debug_info(file_name, node_name, line_positions);
+/// }
+/// debugger.add_breakpoint("intro.yarn", 12);
+///
+/// debugger.dialogue_mut().set_node("Start")?;
+/// let events = debugger.continue_()?;
+/// if debugger.is_paused() {
+///     println!("Paused at {:?} with stack {:?}", debugger.location(), debugger.stack());
+///     debugger.step_over(StepGranularity::Line)?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DialogueDebugger {
+    dialogue: Dialogue,
+    debug_info: HashMap<String, NodeDebugInfo>,
+    /// The file and one-based line of each breakpoint, mapped to the node names and instruction indices they resolved to.
+    breakpoints: HashMap<(String, usize), Vec<(String, usize)>>,
+    paused: bool,
+}
+
+#[derive(Debug, Clone)]
+struct NodeDebugInfo {
+    file_name: String,
+    line_positions: HashMap<usize, Option<Position>>,
+}
+
+/// How far [`DialogueDebugger::step_into`] and [`DialogueDebugger::step_over`] run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StepGranularity {
+    /// Run a single instruction.
+    Instruction,
+    /// Run until an instruction from another line of the Yarn file. Instructions without a known line are run through.
+    Line,
+}
+
+/// Where a [`DialogueDebugger`] currently is, i.e. the instruction that will run next.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DebugLocation {
+    /// The name of the node that is running.
+    pub node_name: String,
+    /// The index of the next instruction in the node.
+    pub instruction_index: usize,
+    /// The Yarn file the node was compiled from, if its debug info was added.
+    pub file_name: Option<String>,
+    /// The one-based line in [`DebugLocation::file_name`] the instruction was compiled from, if known.
+    pub line: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum RunMode {
+    Continue,
+    StepInto(StepGranularity),
+    StepOver(StepGranularity),
+}
+
+impl DialogueDebugger {
+    /// Wraps `dialogue`. It can still be used directly via [`DialogueDebugger::dialogue_mut`], e.g. to select options.
+    #[must_use]
+    pub fn new(dialogue: Dialogue) -> Self {
+        Self {
+            dialogue,
+            debug_info: HashMap::default(),
+            breakpoints: HashMap::default(),
+            paused: false,
+        }
+    }
+
+    /// The wrapped [`Dialogue`].
+    #[must_use]
+    pub fn dialogue(&self) -> &Dialogue {
+        &self.dialogue
+    }
+
+    /// The wrapped [`Dialogue`]. Running it directly ignores all breakpoints.
+    pub fn dialogue_mut(&mut self) -> &mut Dialogue {
+        &mut self.dialogue
+    }
+
+    /// Returns the wrapped [`Dialogue`].
+    #[must_use]
+    pub fn into_inner(self) -> Dialogue {
+        self.dialogue
+    }
+
+    /// Registers the positions of the instructions of a node, which are the fields of the compiler's `DebugInfo` of the same name.
+    pub fn add_debug_info(
+        &mut self,
+        file_name: impl Into<String>,
+        node_name: impl Into<String>,
+        line_positions: impl IntoIterator<Item = (usize, Option<Position>)>,
+    ) -> &mut Self {
+        self.debug_info.insert(
+            node_name.into(),
+            NodeDebugInfo {
+                file_name: file_name.into(),
+                line_positions: line_positions.into_iter().collect(),
+            },
+        );
+        self
+    }
+
+    /// Pauses the dialogue before it runs the first instruction of every node that was compiled from the one-based `line` of `file_name`.
+    ///
+    /// Returns `false` and sets no breakpoint if no instruction was compiled from that line, e.g. because it is empty or a comment,
+    /// or if no debug info for the file was added.
+    pub fn add_breakpoint(&mut self, file_name: impl Into<String>, line: usize) -> bool {
+        let file_name = file_name.into();
+        let locations: Vec<_> = self
+            .debug_info
+            .iter()
+            .filter(|(_, debug_info)| debug_info.file_name == file_name)
+            .filter_map(|(node_name, debug_info)| {
+                let instruction_index = debug_info
+                    .line_positions
+                    .iter()
+                    .filter(|(_, position)| position.is_some_and(|p| p.line + 1 == line))
+                    .map(|(index, _)| *index)
+                    .min()?;
+                Some((node_name.clone(), instruction_index))
+            })
+            .collect();
+        if locations.is_empty() {
+            return false;
+        }
+        self.breakpoints.insert((file_name, line), locations);
+        true
+    }
+
+    /// Removes the breakpoint at the one-based `line` of `file_name`. Returns `false` if there was none.
+    pub fn remove_breakpoint(&mut self, file_name: impl Into<String>, line: usize) -> bool {
+        self.breakpoints.remove(&(file_name.into(), line)).is_some()
+    }
+
+    /// Removes all breakpoints.
+    pub fn clear_breakpoints(&mut self) -> &mut Self {
+        self.breakpoints.clear();
+        self
+    }
+
+    /// Returns the file names and one-based lines of all breakpoints.
+    pub fn breakpoints(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.breakpoints
+            .keys()
+            .map(|(file_name, line)| (file_name.as_str(), *line))
+    }
+
+    /// Returns `true` if the last call to [`DialogueDebugger::continue_`] or a step method stopped at a breakpoint or at the end of the step,
+    /// rather than because the dialogue delivered content or completed.
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Where the dialogue currently is, or [`None`] if it is not running a node.
+    #[must_use]
+    pub fn location(&self) -> Option<DebugLocation> {
+        let node_name = self.dialogue.vm.current_node()?;
+        let instruction_index = self.dialogue.vm.state().program_counter;
+        let debug_info = self.debug_info.get(&node_name);
+        let line = debug_info
+            .and_then(|debug_info| debug_info.line_positions.get(&instruction_index).copied())
+            .flatten()
+            .map(|position| position.line + 1);
+        Some(DebugLocation {
+            file_name: debug_info.map(|debug_info| debug_info.file_name.clone()),
+            node_name,
+            instruction_index,
+            line,
+        })
+    }
+
+    /// The instruction that will run next, or [`None`] if the dialogue is not running a node.
+    #[must_use]
+    pub fn next_instruction(&self) -> Option<&Instruction> {
+        let node_name = self.dialogue.vm.current_node()?;
+        self.dialogue
+            .program()?
+            .program()
+            .nodes
+            .get(&node_name)?
+            .instructions
+            .get(self.dialogue.vm.state().program_counter)
+    }
+
+    /// The value stack, with the top of the stack last.
+    #[must_use]
+    pub fn stack(&self) -> Vec<YarnValue> {
+        let stack = &self.dialogue.vm.state().stack;
+        stack.iter().cloned().map(YarnValue::from).collect()
+    }
+
+    /// The values of the variables declared with `<<local>>` in the current node.
+    #[must_use]
+    pub fn local_variables(&self) -> HashMap<String, YarnValue> {
+        let local_variables = &self.dialogue.vm.state().local_variables;
+        local_variables
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone().into()))
+            .collect()
+    }
+
+    /// The values of all variables in the [`VariableStorage`].
+    #[must_use]
+    pub fn variables(&self) -> HashMap<String, YarnValue> {
+        self.dialogue.variable_storage().variables()
+    }
+
+    /// Runs the dialogue like [`Dialogue::continue_`], but pauses before an instruction with a breakpoint.
+    ///
+    /// Note that when compiling with the `bevy` feature, you should use [`DialogueDebugger::continue_with_world`] instead.
+    pub fn continue_(&mut self) -> Result<Vec<DialogueEvent>> {
+        self.run(RunMode::Continue, |function, parameters| {
+            function.call(parameters)
+        })
+    }
+
+    /// Runs a single instruction or line. If that `<<detour>>`s into or `<<jump>>`s to another node, pauses in that node.
+    /// Also pauses early at breakpoints and when the dialogue delivers content.
+    ///
+    /// Note that when compiling with the `bevy` feature, you should use [`DialogueDebugger::step_into_with_world`] instead.
+    pub fn step_into(&mut self, granularity: StepGranularity) -> Result<Vec<DialogueEvent>> {
+        self.run(RunMode::StepInto(granularity), |function, parameters| {
+            function.call(parameters)
+        })
+    }
+
+    /// Like [`DialogueDebugger::step_into`], but runs a `<<detour>>` to the end instead of pausing inside it.
+    ///
+    /// Note that when compiling with the `bevy` feature, you should use [`DialogueDebugger::step_over_with_world`] instead.
+    pub fn step_over(&mut self, granularity: StepGranularity) -> Result<Vec<DialogueEvent>> {
+        self.run(RunMode::StepOver(granularity), |function, parameters| {
+            function.call(parameters)
+        })
+    }
+
+    /// The Bevy version of [`DialogueDebugger::continue_`].
+    #[cfg(feature = "bevy")]
+    pub fn continue_with_world(&mut self, world: &mut World) -> Result<Vec<DialogueEvent>> {
+        self.run(RunMode::Continue, |function, parameters| {
+            function.call_with_world(parameters, world)
+        })
+    }
+
+    /// The Bevy version of [`DialogueDebugger::step_into`].
+    #[cfg(feature = "bevy")]
+    pub fn step_into_with_world(
+        &mut self,
+        granularity: StepGranularity,
+        world: &mut World,
+    ) -> Result<Vec<DialogueEvent>> {
+        self.run(RunMode::StepInto(granularity), |function, parameters| {
+            function.call_with_world(parameters, world)
+        })
+    }
+
+    /// The Bevy version of [`DialogueDebugger::step_over`].
+    #[cfg(feature = "bevy")]
+    pub fn step_over_with_world(
+        &mut self,
+        granularity: StepGranularity,
+        world: &mut World,
+    ) -> Result<Vec<DialogueEvent>> {
+        self.run(RunMode::StepOver(granularity), |function, parameters| {
+            function.call_with_world(parameters, world)
+        })
+    }
+
+    fn run(
+        &mut self,
+        mode: RunMode,
+        mut function_call_fn: impl FnMut(&dyn UntypedYarnFn, Vec<YarnValue>) -> YarnValue,
+    ) -> Result<Vec<DialogueEvent>> {
+        // Don't pause again at the breakpoint we are paused at
+        let resumes_from_pause = core::mem::take(&mut self.paused);
+        let start = self.line_location();
+        let start_depth = self.dialogue.vm.state().call_stack.len();
+        let mut executed_instructions = 0_usize;
+        let mut paused = false;
+
+        let debug_info = &self.debug_info;
+        let breakpoints = &self.breakpoints;
+        let events = self.dialogue.vm.continue_(|vm, instruction| {
+            let node_name = vm.current_node().unwrap();
+            let instruction_index = vm.state().program_counter;
+            let has_started = executed_instructions > 0;
+            let is_outside_detour = vm.state().call_stack.len() <= start_depth;
+            let has_left_line = || {
+                let line = line_of(debug_info, &node_name, instruction_index);
+                line.is_some() && (&node_name, line) != (&start.0, start.1)
+            };
+            let has_finished_step = has_started
+                && match mode {
+                    RunMode::Continue => false,
+                    RunMode::StepInto(StepGranularity::Instruction) => true,
+                    RunMode::StepOver(StepGranularity::Instruction) => is_outside_detour,
+                    RunMode::StepInto(StepGranularity::Line) => has_left_line(),
+                    RunMode::StepOver(StepGranularity::Line) => {
+                        is_outside_detour && has_left_line()
+                    }
+                };
+            let is_at_breakpoint = (has_started || !resumes_from_pause)
+                && breakpoints
+                    .values()
+                    .flatten()
+                    .any(|location| location.0 == node_name && location.1 == instruction_index);
+            if has_finished_step || is_at_breakpoint {
+                paused = true;
+                vm.pause();
+                return Ok(());
+            }
+            executed_instructions += 1;
+            vm.run_instruction(instruction, &mut function_call_fn)
+        })?;
+        self.paused = paused;
+        Ok(events)
+    }
+
+    fn line_location(&self) -> (String, Option<usize>) {
+        let node_name = self.dialogue.vm.current_node().unwrap_or_default();
+        let instruction_index = self.dialogue.vm.state().program_counter;
+        let line = line_of(&self.debug_info, &node_name, instruction_index);
+        (node_name, line)
+    }
+}
+
+fn line_of(
+    debug_info: &HashMap<String, NodeDebugInfo>,
+    node_name: &str,
+    instruction_index: usize,
+) -> Option<usize> {
+    debug_info
+        .get(node_name)?
+        .line_positions
+        .get(&instruction_index)
+        .copied()
+        .flatten()
+        .map(|position| position.line)
+}
@@ -15,7 +15,7 @@ use yarnspinner_core::prelude::*;
 /// The main functions of interest are [`Dialogue::continue_`] and [`Dialogue::set_selected_option`].
 #[derive(Debug, Clone)]
 pub struct Dialogue {
-    vm: VirtualMachine,
+    pub(crate) vm: VirtualMachine,
     language_code: Option<Language>,
 }
 
@@ -41,11 +41,30 @@ pub enum DialogueError {
     InvalidNode {
         node_name: String,
     },
+    InvalidNodeGroup {
+        node_name: String,
+    },
     VariableStorageError(VariableStorageError),
     FunctionNotFound {
         function_name: String,
         library: Library,
     },
+    /// The [`Program`] contains an instruction or label that can't be run, e.g. because it was compiled for a different version of Yarn Spinner or was corrupted.
+    /// `instruction_index` is [`None`] if a label is at fault.
+    InvalidProgram {
+        node_name: String,
+        instruction_index: Option<usize>,
+        reason: String,
+    },
+    /// A [`DialogueSnapshot`] can't be restored because the node it was taken in, or one that `<<detour>>`ed into it,
+    /// was removed or its bytecode has changed since, e.g. because the Yarn files were edited and recompiled.
+    IncompatibleSnapshot {
+        node_name: String,
+    },
+    /// A function in the [`Library`] returns a type that can't be used in Yarn.
+    UnsupportedFunctionReturnType {
+        function_name: String,
+    },
 }
 
 impl Error for DialogueError {
@@ -71,8 +90,13 @@ impl Display for DialogueError {
             NoNodeSelectedOnContinue => f.write_str("Cannot continue running dialogue. No node has been selected."),
             NoProgramLoaded => f.write_str("No program has been loaded. Cannot continue running dialogue."),
             InvalidNode { node_name } => write!(f, "No node named \"{node_name}\" has been loaded."),
+            InvalidNodeGroup { node_name } => write!(f, "The node \"{node_name}\" is not a node group."),
             VariableStorageError(e) => Display::fmt(e, f),
             FunctionNotFound { function_name, library } => write!(f, "Function \"{function_name}\" not found in library: {library}"),
+            InvalidProgram { node_name, instruction_index: Some(index), reason } => write!(f, "Invalid instruction {index} in node \"{node_name}\": {reason}"),
+            InvalidProgram { node_name, instruction_index: None, reason } => write!(f, "Invalid node \"{node_name}\": {reason}"),
+            IncompatibleSnapshot { node_name } => write!(f, "Cannot restore the dialogue snapshot because the node \"{node_name}\" was removed or has changed since the snapshot was taken."),
+            UnsupportedFunctionReturnType { function_name } => write!(f, "The function \"{function_name}\" returns a type that can't be used in Yarn."),
         }
     }
 }
@@ -198,6 +222,49 @@ impl Dialogue {
         self
     }
 
+    /// Gets the [`SaliencyStrategy`] that decides which line of a line group is run.
+    /// The default is [`FirstSaliencyStrategy`].
+    pub fn saliency_strategy(&self) -> &dyn SaliencyStrategy {
+        self.vm.saliency_strategy.as_ref()
+    }
+
+    /// Sets the [`SaliencyStrategy`] that decides which line of a line group is run.
+    pub fn set_saliency_strategy(
+        &mut self,
+        saliency_strategy: impl SaliencyStrategy + 'static,
+    ) -> &mut Self {
+        self.vm.saliency_strategy = Box::new(saliency_strategy);
+        self
+    }
+
+    /// Registers a [`DialogueObserver`] that gets notified about what the [`Dialogue`] does while it runs.
+    /// Keep a clone of the [`Arc`](alloc::sync::Arc) to read what it recorded. Observers are shared with clones of the [`Dialogue`].
+    pub fn add_observer(&mut self, observer: alloc::sync::Arc<dyn DialogueObserver>) -> &mut Self {
+        self.vm.observers.push(observer);
+        self
+    }
+
+    /// Removes all observers registered with [`Dialogue::add_observer`].
+    pub fn clear_observers(&mut self) -> &mut Self {
+        self.vm.observers.clear();
+        self
+    }
+
+    /// Registers an [`AttributeMarkerProcessor`] that replaces the markers named `attribute_name` in lines and options with text when they are delivered,
+    /// e.g. `[player_name /]` or `[gender m="he" f="she" n="they" /]`.
+    /// The processor is told the current language right away and whenever it changes through [`Dialogue::set_language_code`].
+    ///
+    /// Replaces the processor previously registered for `attribute_name`, including the built-in ones for `select`, `plural`, `ordinal` and `nomarkup`.
+    pub fn register_markup_processor(
+        &mut self,
+        attribute_name: impl Into<String>,
+        processor: impl AttributeMarkerProcessor + 'static,
+    ) -> &mut Self {
+        self.vm
+            .register_markup_processor(attribute_name.into(), Box::new(processor));
+        self
+    }
+
     /// Gets the currently registered [`TextProvider`].
     pub fn text_provider(&self) -> &dyn TextProvider {
         self.vm.text_provider()
@@ -282,9 +349,12 @@ impl Dialogue {
     }
 
     fn extend_variable_storage_from(&mut self, program: &Program) {
+        let storage = self.variable_storage();
+        let keep_stored_values = storage.keeps_values_on_program_load();
         let initial: HashMap<String, YarnValue> = program
             .initial_values
             .iter()
+            .filter(|(k, _)| !(keep_stored_values && storage.contains(k)))
             .map(|(k, v)| (k.clone(), v.clone().into()))
             .collect();
 
@@ -298,25 +368,77 @@ impl Dialogue {
     }
 
     /// Sets or replaces the [`Dialogue`]'s current [`Program`]. The program is replaced, all current state is reset.
+    /// For the fallible version, see [`Dialogue::try_replace_program`].
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the program can't be run, see [`Dialogue::try_replace_program`].
     pub fn replace_program(&mut self, program: Program) -> &mut Self {
-        self.vm.program.replace(program.clone());
+        self.try_replace_program(program)
+            .unwrap_or_else(|e| panic!("Failed to load program: {e}"))
+    }
+
+    /// Sets or replaces the [`Dialogue`]'s current [`Program`]. The program is replaced, all current state is reset.
+    ///
+    /// The program is checked and prepared for running once here, so that a broken program is noticed right away instead of in the middle of a dialogue.
+    /// To run the same program in many [`Dialogue`]s, prepare it only once with [`SharedProgram::new`] and use [`Dialogue::set_program`] instead.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`DialogueError::InvalidProgram`] if an instruction can't be run, e.g. because it jumps to a label that doesn't exist.
+    /// The current program is kept in that case.
+    pub fn try_replace_program(&mut self, program: Program) -> Result<&mut Self> {
+        Ok(self.set_program(SharedProgram::new(program)?))
+    }
+
+    /// Sets or replaces the [`Dialogue`]'s current program with one that may also be run by other [`Dialogue`]s. All current state is reset.
+    ///
+    /// The program's initial values are written to the variable storage. If [`VariableStorage::keeps_values_on_program_load`]
+    /// is `true`, as it is for the file-backed and the layered storage, variables that the storage already contains
+    /// keep their values, e.g. ones loaded from a save file, and the initial values are only stored for the others.
+    pub fn set_program(&mut self, program: SharedProgram) -> &mut Self {
+        self.extend_variable_storage_from(program.program());
+        self.vm.program = Some(program);
         self.vm.reset_state();
-        self.extend_variable_storage_from(&program);
         self
     }
 
+    /// Gets the currently set program, if any. Clone it to run it in another [`Dialogue`] via [`Dialogue::set_program`].
+    #[must_use]
+    pub fn program(&self) -> Option<&SharedProgram> {
+        self.vm.program.as_ref()
+    }
+
     /// Merges the currently set [`Program`] with the given one. If there is no program set, the given one is set.
+    /// For the fallible version, see [`Dialogue::try_add_program`].
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the program can't be run, see [`Dialogue::try_replace_program`].
     pub fn add_program(&mut self, program: Program) -> &mut Self {
-        if let Some(existing_program) = self.vm.program.as_mut() {
-            *existing_program =
-                Program::combine(vec![existing_program.clone(), program.clone()]).unwrap();
-        } else {
-            self.vm.program.replace(program.clone());
-            self.vm.reset_state();
-        }
+        self.try_add_program(program)
+            .unwrap_or_else(|e| panic!("Failed to load program: {e}"))
+    }
+
+    /// Merges the currently set [`Program`] with the given one. If there is no program set, the given one is set.
+    ///
+    /// The merged program is no longer shared with other [`Dialogue`]s that run the previous one.
+    /// The program's initial values are written to the variable storage like in [`Dialogue::set_program`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`DialogueError::InvalidProgram`] if an instruction can't be run, see [`Dialogue::try_replace_program`].
+    /// The current program is kept in that case.
+    pub fn try_add_program(&mut self, program: Program) -> Result<&mut Self> {
+        let Some(existing_program) = self.vm.program.as_ref() else {
+            return self.try_replace_program(program);
+        };
+        let combined =
+            Program::combine(vec![existing_program.program().clone(), program.clone()]).unwrap();
+        self.vm.program = Some(SharedProgram::new(combined)?);
         self.extend_variable_storage_from(&program);
 
-        self
+        Ok(self)
     }
 
     /// Prepares the [`Dialogue`] that the user intends to start running a node.
@@ -328,12 +450,52 @@ impl Dialogue {
     ///
     /// ## Errors
     ///
+    /// If `node_name` is the title of a node group, i.e. of several nodes with `when` headers, the group's members are
+    /// evaluated once the node runs and the [`SaliencyStrategy`] picks which one of the eligible ones is run.
+    ///
     /// Returns an error if no node with the value of `node_name` has been loaded.
+    /// The nodes that the compiler generates for smart variables can't be run this way either.
     pub fn set_node(&mut self, node_name: impl Into<String>) -> Result<&mut Self> {
         self.vm.set_node(node_name)?;
         Ok(self)
     }
 
+    /// Returns the names of the members of the node group `group_name` whose `when` headers currently pass, in the order they were written in.
+    /// These are the nodes that the [`SaliencyStrategy`] chooses from when the group is run. None of them are run by this method,
+    /// and the [`DialogueObserver`]s are not told about the variables and functions that the `when` headers use.
+    ///
+    /// Note that when compiling with the `bevy` feature, you should use [`Dialogue::eligible_node_group_members_with_world`] instead.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if no node named `group_name` has been loaded or if that node is not a node group.
+    pub fn eligible_node_group_members(&mut self, group_name: &str) -> Result<Vec<String>> {
+        let candidates = self
+            .vm
+            .node_group_candidates(group_name, |function, parameters| function.call(parameters))?;
+        Ok(candidates.into_iter().map(|c| c.content_id).collect())
+    }
+
+    #[cfg(feature = "bevy")]
+    /// The Bevy version of [`Dialogue::eligible_node_group_members`].
+    /// Returns the names of the members of the node group `group_name` whose `when` headers currently pass, in the order they were written in.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if no node named `group_name` has been loaded or if that node is not a node group.
+    pub fn eligible_node_group_members_with_world(
+        &mut self,
+        group_name: &str,
+        world: &mut World,
+    ) -> Result<Vec<String>> {
+        let candidates = self
+            .vm
+            .node_group_candidates(group_name, |function, parameters| {
+                function.call_with_world(parameters, world)
+            })?;
+        Ok(candidates.into_iter().map(|c| c.content_id).collect())
+    }
+
     /// Attempts to pop the line hints that were generated by the last [`Dialogue::set_node`] call.
     ///
     /// Panics if [`Dialogue::line_hints_enabled`] is `false`.
@@ -358,12 +520,17 @@ impl Dialogue {
     }
 
     /// Gets the names of the nodes in the currently loaded Program, if there is one.
+    /// The nodes that the compiler generates for smart variables are not included.
     #[must_use]
     pub fn node_names(&self) -> Option<impl Iterator<Item = &str>> {
-        self.vm
-            .program
-            .as_ref()
-            .map(|program| program.nodes.keys().map(|s| s.as_str()))
+        self.vm.program.as_ref().map(|program| {
+            program
+                .program()
+                .nodes
+                .iter()
+                .filter(|(_, node)| !node.is_smart_variable())
+                .map(|(name, _)| name.as_str())
+        })
     }
 
     /// Returns the line ID that contains the original, uncompiled source
@@ -397,7 +564,7 @@ impl Dialogue {
     /// Returns the headers for the node `node_name`.
     ///
     /// The headers are all the key-value pairs defined in the node's source code
-    /// including the `tags` and `title` headers.
+    /// including the `tags` and `title` headers. The headers the compiler adds for itself are left out, see [`Header::is_internal`].
     ///
     /// Returns [`None`] if the node is not present in the program.
     #[must_use]
@@ -405,17 +572,23 @@ impl Dialogue {
         self.get_node_logging_errors(node_name).map(|node| {
             node.headers
                 .iter()
+                .filter(|header| !header.is_internal())
                 .map(|header| (header.key.clone(), header.value.clone()))
                 .collect()
         })
     }
 
     /// Gets a value indicating whether a specified node exists in the [`Program`].
+    /// The nodes that the compiler generates for smart variables are not included.
     #[must_use]
     pub fn node_exists(&self, node_name: &str) -> bool {
         // Not calling `get_node_logging_errors` because this method does not write errors when there are no nodes.
         if let Some(program) = self.vm.program.as_ref() {
-            program.nodes.contains_key(node_name)
+            program
+                .program()
+                .nodes
+                .get(node_name)
+                .is_some_and(|node| !node.is_smart_variable())
         } else {
             error!("Tried to call NodeExists, but no program has been loaded");
             false
@@ -430,6 +603,28 @@ impl Dialogue {
         self.vm.current_node()
     }
 
+    /// Saves where the [`Dialogue`] currently is, e.g. while it is waiting on a line or an option selection, so that it can be resumed later with [`Dialogue::restore`].
+    /// The variables are not included, save the [`VariableStorage`] alongside it.
+    #[must_use]
+    pub fn snapshot(&self) -> DialogueSnapshot {
+        self.vm.snapshot()
+    }
+
+    /// Continues from where [`Dialogue::snapshot`] was taken. The next call to [`Dialogue::continue_`] runs the instruction after the
+    /// last delivered line, command or options. If the snapshot was taken while waiting on an option selection, call [`Dialogue::set_selected_option`]
+    /// with one of [`DialogueSnapshot::options`] first.
+    ///
+    /// The snapshot can be restored into any [`Dialogue`] running the same program, including one that was recompiled from unchanged Yarn files.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`DialogueError::IncompatibleSnapshot`] if the node the snapshot was taken in, or one that `<<detour>>`ed into it,
+    /// no longer exists or contains different instructions. The current state is kept in that case.
+    pub fn restore(&mut self, snapshot: DialogueSnapshot) -> Result<&mut Self> {
+        self.vm.restore(snapshot)?;
+        Ok(self)
+    }
+
     /// Analyses the currently loaded Yarn program with the given [`Context`]. Call [`Context::finish_analysis`] afterwards to get the results.
     pub fn analyse(&self, context: &mut Context) -> &Self {
         let program = self
@@ -437,16 +632,20 @@ impl Dialogue {
             .program
             .as_ref()
             .expect("Failed to analyse program: No program loaded");
-        context.diagnose_program(program);
+        context.diagnose_program(program.program());
         self
     }
 
     fn get_node_logging_errors(&self, node_name: &str) -> Option<Node> {
-        if let Some(program) = self.vm.program.as_ref() {
+        if let Some(program) = self.vm.program.as_ref().map(SharedProgram::program) {
             if program.nodes.is_empty() {
                 error!("No nodes are loaded");
                 None
-            } else if let Some(node) = program.nodes.get(node_name) {
+            } else if let Some(node) = program
+                .nodes
+                .get(node_name)
+                .filter(|node| !node.is_smart_variable())
+            {
                 Some(node.clone())
             } else {
                 error!("No node named {node_name}");
@@ -502,4 +701,58 @@ mod tests {
     }
 
     fn accept_send_sync(_: impl Send + Sync) {}
+
+    #[test]
+    fn loading_a_program_overwrites_stored_variables_by_default() {
+        let mut storage = MemoryVariableStorage::new();
+        storage.set("$gold".to_owned(), 50.into()).unwrap();
+        let mut dialogue = Dialogue::new(
+            Box::new(storage.clone()),
+            Box::new(StringTableTextProvider::new()),
+        );
+        let program = Program {
+            initial_values: [("$gold".to_owned(), 0.into())].into_iter().collect(),
+            ..Default::default()
+        };
+        dialogue.add_program(program);
+
+        assert_eq!(YarnValue::from(0), storage.get("$gold").unwrap());
+    }
+
+    #[test]
+    fn reads_variables_that_only_exist_in_storage() {
+        let mut storage = MemoryVariableStorage::new();
+        storage.set("$gold".to_owned(), 50.into()).unwrap();
+        let mut dialogue = Dialogue::new(
+            Box::new(storage.clone()),
+            Box::new(StringTableTextProvider::new()),
+        );
+        let instructions = vec![
+            Instruction {
+                opcode: OpCode::PushVariable.into(),
+                operands: vec!["$gold".to_owned().into()],
+            },
+            Instruction {
+                opcode: OpCode::StoreVariable.into(),
+                operands: vec!["$copy".to_owned().into()],
+            },
+            Instruction {
+                opcode: OpCode::Stop.into(),
+                operands: vec![],
+            },
+        ];
+        let node = Node {
+            name: "Start".to_owned(),
+            instructions,
+            ..Default::default()
+        };
+        let program = Program {
+            nodes: [("Start".to_owned(), node)].into_iter().collect(),
+            ..Default::default()
+        };
+        dialogue.add_program(program).set_node("Start").unwrap();
+        dialogue.continue_().unwrap();
+
+        assert_eq!(YarnValue::from(50), storage.get("$copy").unwrap());
+    }
 }
@@ -0,0 +1,64 @@
+//! Hooks for watching a [`Dialogue`] run, e.g. for tracing or analytics.
+
+use crate::prelude::*;
+use core::fmt::Debug;
+
+/// Gets notified about what a [`Dialogue`] does while it runs. Register it with [`Dialogue::add_observer`].
+///
+/// All methods do nothing by default, so only implement the ones you need.
+/// They take `&self` because the observer is shared with clones of the [`Dialogue`], so use interior mutability to record anything.
+///
+/// ## Example
+///
+/// ```rust
+/// # use yarnspinner_runtime::prelude::*;
+/// # use yarnspinner_core::prelude::*;
+/// use std::sync::{Arc, Mutex};
+///
+/// #[derive(Debug, Default)]
+/// struct SeenLines(Mutex<Vec<LineId>>);
+///
+/// impl DialogueObserver for SeenLines {
+///     fn line_delivered(&self, line: &Line) {
+///         self.0.lock().unwrap().push(line.id.clone());
+///     }
+/// }
+///
+/// let seen_lines = Arc::new(SeenLines::default());
+/// let mut dialogue = Dialogue::new(
+///     Box::new(MemoryVariableStorage::new()),
+///     Box::new(StringTableTextProvider::new()),
+/// );
+/// dialogue.add_observer(seen_lines.clone());
+/// ```
+pub trait DialogueObserver: Debug + Send + Sync {
+    /// Called when a node starts running, i.e. after [`Dialogue::set_node`], a `<<jump>>` or a `<<detour>>`.
+    /// For a node group, this is called with the name of the member that was selected.
+    fn node_entered(&self, _node_name: &str) {}
+
+    /// Called when a node completes, including when a `<<jump>>` leaves it and when a `<<detour>>` returns from it.
+    fn node_exited(&self, _node_name: &str) {}
+
+    /// Called for every line that is delivered in a [`DialogueEvent::Line`].
+    fn line_delivered(&self, _line: &Line) {}
+
+    /// Called for every set of options that is delivered in a [`DialogueEvent::Options`].
+    fn options_delivered(&self, _options: &[DialogueOption]) {}
+
+    /// Called when [`Dialogue::set_selected_option`] selects an option.
+    fn option_selected(&self, _option: &DialogueOption) {}
+
+    /// Called when the script reads a variable, including `<<local>>` and smart variables.
+    /// The variables that Yarn Spinner keeps for itself, e.g. to track `<<once>>`, are left out.
+    fn variable_read(&self, _name: &str, _value: &YarnValue) {}
+
+    /// Called after the script wrote a variable with `<<set>>` or `<<local>>`.
+    /// Not called if the [`VariableStorage`] rejected the value, and not called for the variables Yarn Spinner keeps for itself.
+    fn variable_written(&self, _name: &str, _value: &YarnValue) {}
+
+    /// Called after the script called a function, including the operators of the standard library like `Number.Add`.
+    fn function_called(&self, _name: &str, _arguments: &[YarnValue], _result: &YarnValue) {}
+
+    /// Called for every command that is delivered in a [`DialogueEvent::Command`].
+    fn command_dispatched(&self, _command: &Command) {}
+}
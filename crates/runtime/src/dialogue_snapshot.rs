@@ -0,0 +1,112 @@
+//! Saving the execution state of a [`Dialogue`] to resume it later, e.g. after loading a save game.
+
+use crate::prelude::*;
+use bevy_platform::collections::HashMap;
+
+/// The execution state of a [`Dialogue`] while it waits for the game, e.g. on a line or an option selection.
+/// Created by [`Dialogue::snapshot`] and resumed with [`Dialogue::restore`].
+///
+/// Contains the current node, the position in it, the value stack, the pending options and the nodes that `<<detour>>`ed into the current one.
+/// The variables are not part of it, save them together with the [`VariableStorage`] instead.
+///
+/// With the `serde` feature, this can be serialized and written to a save file.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy", reflect(Debug, PartialEq, Default))]
+#[cfg_attr(
+    all(feature = "bevy", feature = "serde"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DialogueSnapshot {
+    pub(crate) node_name: Option<String>,
+    pub(crate) execution_state: ExecutionState,
+    pub(crate) state: State,
+    /// The [`fingerprint`]s of the current node and of all nodes in the call stack.
+    pub(crate) node_fingerprints: HashMap<String, u64>,
+}
+
+impl DialogueSnapshot {
+    /// The name of the node the [`Dialogue`] was running, or [`None`] if it was not running at all.
+    #[must_use]
+    pub fn node_name(&self) -> Option<&str> {
+        self.node_name.as_deref()
+    }
+
+    /// Returns `true` if the [`Dialogue`] was waiting for an option to be selected.
+    /// A [`Dialogue`] restored from this is waiting for [`Dialogue::set_selected_option`] again, so show [`DialogueSnapshot::options`] to the player.
+    #[must_use]
+    pub fn is_waiting_for_option_selection(&self) -> bool {
+        self.execution_state == ExecutionState::WaitingOnOptionSelection
+    }
+
+    /// The options the [`Dialogue`] was waiting on a selection for. Empty unless [`DialogueSnapshot::is_waiting_for_option_selection`] is `true`.
+    #[must_use]
+    pub fn options(&self) -> &[DialogueOption] {
+        &self.state.current_options
+    }
+}
+
+/// Hashes the instructions of a node with 64 bit FNV-1a. Unlike [`core::hash::Hash`] implementations,
+/// the result does not depend on the platform or on the version of the hashing crate, so it can be saved.
+pub(crate) fn fingerprint(node: &Node) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    let mut write = |bytes: &[u8]| {
+        for byte in bytes {
+            hash = (hash ^ u64::from(*byte)).wrapping_mul(PRIME);
+        }
+    };
+    for instruction in &node.instructions {
+        write(&instruction.opcode.to_le_bytes());
+        write(&(instruction.operands.len() as u64).to_le_bytes());
+        for operand in &instruction.operands {
+            match &operand.value {
+                None => write(&[0]),
+                Some(OperandValue::StringValue(string)) => {
+                    write(&[1]);
+                    write(&(string.len() as u64).to_le_bytes());
+                    write(string.as_bytes());
+                }
+                Some(OperandValue::BoolValue(bool)) => write(&[2, u8::from(*bool)]),
+                Some(OperandValue::FloatValue(float)) => {
+                    write(&[3]);
+                    write(&float.to_bits().to_le_bytes());
+                }
+            }
+        }
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprint_changes_with_instructions() {
+        let node = |instructions: Vec<Instruction>| Node {
+            name: "Start".to_owned(),
+            instructions,
+            ..Default::default()
+        };
+        let line = |id: &str| Instruction {
+            opcode: OpCode::RunLine.into(),
+            operands: vec![id.to_owned().into(), 0_usize.into()],
+        };
+
+        let original = fingerprint(&node(vec![line("line:a"), line("line:b")]));
+        assert_eq!(
+            original,
+            fingerprint(&node(vec![line("line:a"), line("line:b")]))
+        );
+        assert_ne!(
+            original,
+            fingerprint(&node(vec![line("line:b"), line("line:a")]))
+        );
+        assert_ne!(original, fingerprint(&node(vec![line("line:a")])));
+        assert_ne!(fingerprint(&node(vec![])), original);
+    }
+}
@@ -34,6 +34,7 @@ pub enum DialogueEvent {
     /// The node with the given name was completed.
     NodeComplete(String),
     /// The node with the given name was entered.
+    /// Running a node group only reports the member that was selected, not the group itself.
     NodeStart(String),
     /// Only emitted if `Dialogue::should_send_line_hints` is enabled.
     ///
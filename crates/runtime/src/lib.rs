@@ -15,13 +15,18 @@ extern crate std;
 
 mod analyser;
 mod command;
+mod debugger;
 mod dialogue;
+mod dialogue_observer;
 mod dialogue_option;
+mod dialogue_snapshot;
 mod events;
 mod language;
 mod line;
 pub mod markup;
 mod pluralization;
+mod saliency;
+mod shared_program;
 mod text_provider;
 mod variable_storage;
 mod virtual_machine;
@@ -43,15 +48,20 @@ pub mod prelude {
     pub use crate::{
         analyser::*,
         command::*,
+        debugger::*,
         dialogue::{Dialogue, DialogueError},
+        dialogue_observer::*,
         dialogue_option::*,
+        dialogue_snapshot::DialogueSnapshot,
         events::*,
         language::*,
         line::*,
-        markup::MarkupParseError,
+        markup::{AttributeMarkerProcessor, MarkupAttributeMarker, MarkupParseError},
+        saliency::*,
+        shared_program::*,
         text_provider::*,
         variable_storage::*,
     };
-    pub(crate) use crate::{pluralization::*, virtual_machine::*};
+    pub(crate) use crate::{dialogue_snapshot::fingerprint, pluralization::*, virtual_machine::*};
     pub(crate) use yarnspinner_core::prelude::*;
 }
@@ -10,6 +10,7 @@ mod line_parser;
 mod markup_parse_error;
 mod parsed_markup;
 
+pub use self::attribute_marker_processor::AttributeMarkerProcessor;
 pub use self::line_parser::{
     Result, CHARACTER_ATTRIBUTE, CHARACTER_ATTRIBUTE_NAME_PROPERTY, TRIM_WHITESPACE_PROPERTY,
 };
@@ -9,15 +9,42 @@ mod dialogue_text_processor;
 mod no_markup_text_processor;
 
 /// Provides a mechanism for producing replacement text for a marker.
-pub(crate) trait AttributeMarkerProcessor: Debug + Send + Sync {
+///
+/// Register an implementation with [`Dialogue::register_markup_processor`] to replace markers like `[player_name /]`
+/// with text when a line is delivered. The built-in `select`, `plural` and `ordinal` markers are implemented this way.
+///
+/// ## Example
+///
+/// ```rust
+/// # use yarnspinner_runtime::prelude::*;
+/// #[derive(Debug, Clone)]
+/// struct PlayerNameProcessor(String);
+///
+/// impl AttributeMarkerProcessor for PlayerNameProcessor {
+///     fn replacement_text_for_marker(&self, _marker: &MarkupAttributeMarker) -> String {
+///         self.0.clone()
+///     }
+///
+///     fn clone_box(&self) -> Box<dyn AttributeMarkerProcessor> {
+///         Box::new(self.clone())
+///     }
+/// }
+///
+/// # let mut dialogue = Dialogue::new(Box::new(MemoryVariableStorage::new()), Box::new(StringTableTextProvider::new()));
+/// dialogue.register_markup_processor("player_name", PlayerNameProcessor("Sally".to_owned()));
+/// ```
+pub trait AttributeMarkerProcessor: Debug + Send + Sync {
     /// Produces the replacement text that should be inserted into a parse
     /// result for a given attribute.
     ///
     /// If the marker is an `open` marker, the text from the marker's
     /// position to its corresponding closing marker is provided as a string
-    /// property called `contents`.
+    /// property called `contents`, see [`MarkupAttributeMarker::contents`].
     fn replacement_text_for_marker(&self, marker: &MarkupAttributeMarker) -> String;
-    fn set_language_code(&mut self, language_code: Option<Language>);
+    /// Called with the [`Dialogue`]'s language when the processor is registered and whenever [`Dialogue::set_language_code`] changes it.
+    /// Does nothing by default.
+    fn set_language_code(&mut self, _language_code: Option<Language>) {}
+    /// Creates a clone of this processor, which is used when the [`Dialogue`] is cloned.
     fn clone_box(&self) -> Box<dyn AttributeMarkerProcessor>;
 }
 
@@ -84,6 +84,16 @@ impl LineParser {
         self
     }
 
+    /// Like [`LineParser::register_marker_processor`], but replaces the processor already registered for `attribute_name`, if any.
+    pub(crate) fn replace_marker_processor(
+        &mut self,
+        attribute_name: impl Into<String>,
+        processor: Box<dyn AttributeMarkerProcessor>,
+    ) -> Option<Box<dyn AttributeMarkerProcessor>> {
+        self.marker_processors
+            .insert(attribute_name.into(), processor)
+    }
+
     /// Parses a line of text, and produces a [`ParsedMarkup`] containing the processed text
     ///
     /// ## Implementation notes
@@ -1,7 +1,8 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner/YarnSpinner.Markup/MarkupParseResult.cs>
 
+pub use self::markup_attribute_marker::MarkupAttributeMarker;
+pub(crate) use self::tag_type::*;
 pub use self::{markup_attribute::*, markup_value::*};
-pub(crate) use self::{markup_attribute_marker::*, tag_type::*};
 use crate::prelude::*;
 use core::fmt::Debug;
 
@@ -1,16 +1,16 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner/YarnSpinner.Markup/MarkupParseResult.cs>
 //! which was split into multiple files.
 
-use crate::markup::{MarkupValue, TagType};
+use crate::markup::{MarkupValue, TagType, REPLACEMENT_MARKER_CONTENTS};
 use crate::prelude::*;
 use bevy_platform::collections::HashMap;
 
 /// Represents a marker (e.g. `[a]`) in line of marked up text.
 ///
 /// You do not create instances of this struct yourself. It is created
-/// by objects that can parse markup, such as [`Dialogue`].
+/// by objects that can parse markup, such as [`Dialogue`], and passed to [`AttributeMarkerProcessor`]s.
 #[derive(Debug, Clone, PartialEq)]
-pub(crate) struct MarkupAttributeMarker {
+pub struct MarkupAttributeMarker {
     /// The name of the marker.
     /// For example, the marker `[wave]` has the name `wave`.
     pub(crate) name: Option<String>,
@@ -23,3 +23,41 @@ pub(crate) struct MarkupAttributeMarker {
     /// The position of this marker in the original source text.
     pub(crate) source_position: usize,
 }
+
+impl MarkupAttributeMarker {
+    /// The name of the marker.
+    /// For example, the marker `[wave]` has the name `wave`. Only the close-all marker `[/]` has no name.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The position of the marker in the plain text.
+    #[must_use]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The list of properties associated with this marker.
+    /// For example, the marker `[gender m="he" f="she" /]` has the properties `m` and `f`.
+    #[must_use]
+    pub fn properties(&self) -> &HashMap<String, MarkupValue> {
+        &self.properties
+    }
+
+    /// Returns the value of the property with the given name, if it exists.
+    #[must_use]
+    pub fn property(&self, name: &str) -> Option<&MarkupValue> {
+        self.properties.get(name)
+    }
+
+    /// The text between an open marker and its closing marker, e.g. `text` in `[shout]text[/shout]`.
+    /// Returns [`None`] for self-closing markers like `[shout /]`.
+    #[must_use]
+    pub fn contents(&self) -> Option<&str> {
+        match self.properties.get(REPLACEMENT_MARKER_CONTENTS) {
+            Some(MarkupValue::String(contents)) => Some(contents),
+            _ => None,
+        }
+    }
+}
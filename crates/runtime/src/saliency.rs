@@ -0,0 +1,175 @@
+//! Strategies for picking one piece of content out of several that are eligible to run, such as the lines of a line group:
+//!
+//! ```yarn
+//! => Hi there! <<if $met_before>>
+//! => Nice to meet you.
+//! => Hello.
+//! ```
+
+use crate::prelude::*;
+use bevy_platform::collections::HashMap;
+use core::fmt::Debug;
+
+/// A piece of content that a [`SaliencyStrategy`] can select, e.g. a line of a line group whose condition passed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy", reflect(Debug, PartialEq))]
+#[cfg_attr(
+    all(feature = "bevy", feature = "serde"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct SaliencyCandidate {
+    /// The ID of the content. For a line of a line group, this is the line's [`LineId`].
+    pub content_id: String,
+
+    /// Where execution continues if this candidate is selected.
+    pub(crate) destination: String,
+}
+
+/// Decides which [`SaliencyCandidate`] is run when there are several eligible ones.
+/// Register one with [`Dialogue::set_saliency_strategy`]. The default is [`FirstSaliencyStrategy`].
+pub trait SaliencyStrategy: Debug + Send + Sync {
+    /// Returns the index of the candidate to run, or `None` if none of them should run.
+    /// The candidates are in the order they were written in and are never empty.
+    fn select(&mut self, candidates: &[SaliencyCandidate]) -> Option<usize>;
+    /// Clones this strategy, including any state it keeps about previous selections.
+    fn clone_box(&self) -> Box<dyn SaliencyStrategy>;
+}
+
+impl Clone for Box<dyn SaliencyStrategy> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+/// Always selects the first eligible candidate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FirstSaliencyStrategy;
+
+impl SaliencyStrategy for FirstSaliencyStrategy {
+    fn select(&mut self, candidates: &[SaliencyCandidate]) -> Option<usize> {
+        (!candidates.is_empty()).then_some(0)
+    }
+
+    fn clone_box(&self) -> Box<dyn SaliencyStrategy> {
+        Box::new(*self)
+    }
+}
+
+/// Selects a random eligible candidate.
+#[derive(Debug, Clone)]
+pub struct RandomSaliencyStrategy {
+    rng: fastrand::Rng,
+}
+
+impl RandomSaliencyStrategy {
+    /// Creates a new [`RandomSaliencyStrategy`] with a random seed.
+    #[cfg(feature = "std")]
+    pub fn new() -> Self {
+        Self {
+            rng: fastrand::Rng::new(),
+        }
+    }
+
+    /// Creates a new [`RandomSaliencyStrategy`] that always makes the same selections for the same seed.
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            rng: fastrand::Rng::with_seed(seed),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for RandomSaliencyStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SaliencyStrategy for RandomSaliencyStrategy {
+    fn select(&mut self, candidates: &[SaliencyCandidate]) -> Option<usize> {
+        (!candidates.is_empty()).then(|| self.rng.usize(..candidates.len()))
+    }
+
+    fn clone_box(&self) -> Box<dyn SaliencyStrategy> {
+        Box::new(self.clone())
+    }
+}
+
+/// Selects the eligible candidate that was selected the longest time ago, preferring candidates that were never selected.
+/// Ties are resolved in favor of the candidate written first.
+#[derive(Debug, Clone, Default)]
+pub struct LeastRecentlySeenSaliencyStrategy {
+    last_seen: HashMap<String, usize>,
+    selection_count: usize,
+}
+
+impl LeastRecentlySeenSaliencyStrategy {
+    /// Creates a new [`LeastRecentlySeenSaliencyStrategy`] that has not seen any content yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SaliencyStrategy for LeastRecentlySeenSaliencyStrategy {
+    fn select(&mut self, candidates: &[SaliencyCandidate]) -> Option<usize> {
+        let (index, candidate) = candidates.iter().enumerate().min_by_key(|(_, candidate)| {
+            self.last_seen
+                .get(&candidate.content_id)
+                .map(|&seen| seen + 1)
+        })?;
+        self.selection_count += 1;
+        self.last_seen
+            .insert(candidate.content_id.clone(), self.selection_count);
+        Some(index)
+    }
+
+    fn clone_box(&self) -> Box<dyn SaliencyStrategy> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(ids: &[&str]) -> Vec<SaliencyCandidate> {
+        ids.iter()
+            .map(|id| SaliencyCandidate {
+                content_id: id.to_string(),
+                destination: format!("L_{id}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn first_strategy_selects_first_candidate() {
+        let mut strategy = FirstSaliencyStrategy;
+        assert_eq!(Some(0), strategy.select(&candidates(&["a", "b"])));
+        assert_eq!(Some(0), strategy.select(&candidates(&["a", "b"])));
+        assert_eq!(None, strategy.select(&[]));
+    }
+
+    #[test]
+    fn random_strategy_selects_every_candidate_eventually() {
+        let mut strategy = RandomSaliencyStrategy::with_seed(42);
+        let candidates = candidates(&["a", "b", "c"]);
+        let mut selected = [false; 3];
+        for _ in 0..100 {
+            selected[strategy.select(&candidates).unwrap()] = true;
+        }
+        assert_eq!([true; 3], selected);
+    }
+
+    #[test]
+    fn least_recently_seen_strategy_cycles_through_candidates() {
+        let mut strategy = LeastRecentlySeenSaliencyStrategy::new();
+        let all = candidates(&["a", "b", "c"]);
+        let selected: Vec<_> = (0..4).map(|_| strategy.select(&all).unwrap()).collect();
+        assert_eq!(vec![0, 1, 2, 0], selected);
+
+        // "b" was seen longer ago than "c"
+        assert_eq!(Some(0), strategy.select(&candidates(&["b", "c"])));
+    }
+}
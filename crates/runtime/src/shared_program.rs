@@ -0,0 +1,76 @@
+//! A [`Program`] that many [`Dialogue`]s can run at the same time without copying it.
+
+use crate::prelude::*;
+use crate::Result;
+use alloc::sync::Arc;
+
+/// An immutable, reference-counted [`Program`] that was prepared for running once.
+/// Cloning it is cheap, and all clones share the same instructions.
+///
+/// Pass it to [`Dialogue::set_program`] to run the same program in many [`Dialogue`]s, e.g. one for every NPC,
+/// which then only keep their own execution state and [`VariableStorage`].
+/// [`Dialogue::replace_program`] creates a new [`SharedProgram`] for every call instead.
+///
+/// ## Example
+///
+/// ```rust
+/// # use yarnspinner_runtime::prelude::*;
+/// # use yarnspinner_core::prelude::*;
+/// # let program = Program::default();
+/// let program = SharedProgram::new(program)?;
+/// let dialogues: Vec<_> = (0..200)
+///     .map(|_| {
+///         let mut dialogue = Dialogue::new(
+///             Box::new(MemoryVariableStorage::new()),
+///             Box::new(StringTableTextProvider::new()),
+///         );
+///         dialogue.set_program(program.clone());
+///         dialogue
+///     })
+///     .collect();
+/// # Ok::<(), DialogueError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct SharedProgram(Arc<LoadedProgram>);
+
+#[derive(Debug)]
+struct LoadedProgram {
+    program: Program,
+    linked: LinkedProgram,
+}
+
+impl SharedProgram {
+    /// Prepares `program` for running.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`DialogueError::InvalidProgram`] if an instruction can't be run, e.g. because it jumps to a label that doesn't exist.
+    pub fn new(program: Program) -> Result<Self> {
+        let linked = LinkedProgram::link(&program)?;
+        Ok(Self(Arc::new(LoadedProgram { program, linked })))
+    }
+
+    /// The [`Program`] this was created from.
+    #[must_use]
+    pub fn program(&self) -> &Program {
+        &self.0.program
+    }
+
+    /// Returns `true` if both are clones of the same [`SharedProgram`].
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    pub(crate) fn linked(&self) -> &LinkedProgram {
+        &self.0.linked
+    }
+}
+
+impl TryFrom<Program> for SharedProgram {
+    type Error = DialogueError;
+
+    fn try_from(program: Program) -> Result<Self> {
+        Self::new(program)
+    }
+}
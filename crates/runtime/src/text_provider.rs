@@ -1,5 +1,6 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner/Dialogue.cs>, which we split off into multiple files
 use crate::prelude::*;
+use alloc::sync::Arc;
 use bevy_platform::collections::HashMap;
 use core::any::Any;
 use core::fmt::Debug;
@@ -45,10 +46,13 @@ pub type StringTable = HashMap<LineId, String>;
 
 /// A basic implementation of [`TextProvider`] which keeps the text for the base language,
 /// i.e. the language the Yarn files are written in, and the text for the currently selected translation in memory.
+///
+/// Clones share their string tables until one of them is extended, so giving every [`Dialogue`](crate::prelude::Dialogue)
+/// a clone of the same provider doesn't copy any text.
 #[derive(Debug, Clone, Default)]
 pub struct StringTableTextProvider {
-    base_language_table: StringTable,
-    translation_table: Option<(Language, StringTable)>,
+    base_language_table: Arc<StringTable>,
+    translation_table: Option<(Language, Arc<StringTable>)>,
     /// Set to `None` to select base language.
     translation_language: Option<Language>,
 }
@@ -64,7 +68,7 @@ impl StringTableTextProvider {
     where
         StringTable: Extend<T>,
     {
-        self.base_language_table.extend(string_table);
+        Arc::make_mut(&mut self.base_language_table).extend(string_table);
     }
 
     /// Adds strings for the a specific language. If this is not the language used selected by [`TextProvider::set_language`], the strings will be ignored.
@@ -78,20 +82,15 @@ impl StringTableTextProvider {
         let language = language.into();
         if let Some((current_language, translation_table)) = self.translation_table.as_mut() {
             if language == *current_language {
-                translation_table.extend(string_table);
+                Arc::make_mut(translation_table).extend(string_table);
                 return;
             }
         }
 
-        let (language, mut table) = self
-            .translation_table
-            .take()
-            .unwrap_or_else(|| (language, StringTable::new()));
-
-        table.clear();
+        let mut table = StringTable::new();
         table.extend(string_table);
 
-        self.translation_table = Some((language, table));
+        self.translation_table = Some((language, Arc::new(table)));
     }
 }
 
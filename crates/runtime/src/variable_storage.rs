@@ -5,6 +5,15 @@ use bevy_platform::sync::{Arc, RwLock};
 use core::any::Any;
 use core::error::Error;
 use core::fmt::{self, Debug, Display};
+#[cfg(any(feature = "json", feature = "ron"))]
+pub use file_variable_storage::{FileVariableStorage, VariableFileFormat};
+pub use layered_variable_storage::LayeredVariableStorage;
+pub use typed_variable_storage::TypedVariableStorage;
+
+#[cfg(any(feature = "json", feature = "ron"))]
+mod file_variable_storage;
+mod layered_variable_storage;
+mod typed_variable_storage;
 
 #[allow(missing_docs)]
 pub type Result<T> = core::result::Result<T, VariableStorageError>;
@@ -34,6 +43,12 @@ pub trait VariableStorage: Debug + Send + Sync {
     /// Extends this variable storage with the given values. Must fail with a [`VariableStorageError::InvalidVariableName`] if any of the variable names do not start with a `$`.
     /// Existing variables must be overwritten.
     fn extend(&mut self, values: HashMap<String, YarnValue>) -> Result<()>;
+    /// Returns `true` if variables that are already stored keep their values when a program is loaded,
+    /// e.g. through [`Dialogue::set_program`]. The program's initial values are then only stored for the missing variables.
+    /// Defaults to `false`, in which case the initial values overwrite the stored ones.
+    fn keeps_values_on_program_load(&self) -> bool {
+        false
+    }
     /// Returns a map of all variables in this variable storage.
     fn variables(&self) -> HashMap<String, YarnValue>;
     /// Clears all variables in this variable storage.
@@ -54,12 +69,30 @@ impl Extend<(String, YarnValue)> for Box<dyn VariableStorage> {
     }
 }
 
+/// The errors a [`VariableStorage`] can fail with.
 #[allow(missing_docs)]
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum VariableStorageError {
-    InvalidVariableName { name: String },
-    VariableNotFound { name: String },
-    InternalError { error: Box<dyn Error + Send + Sync> },
+    InvalidVariableName {
+        name: String,
+    },
+    VariableNotFound {
+        name: String,
+    },
+    InternalError {
+        error: Box<dyn Error + Send + Sync>,
+    },
+    /// A [`TypedVariableStorage`] was asked for a variable that the program does not declare.
+    UndeclaredVariable {
+        name: String,
+    },
+    /// A [`TypedVariableStorage`] was given a value, or asked for one, of another type than the variable was declared with.
+    TypeMismatch {
+        name: String,
+        expected_type: Type,
+        actual_type: Type,
+    },
 }
 
 impl Error for VariableStorageError {}
@@ -71,6 +104,8 @@ impl Display for VariableStorageError {
             InvalidVariableName { name } => write!(f, "{name} is not a valid variable name: Variable names must start with a \'$\'. (Did you mean to use \'${name}\'?)"),
             VariableNotFound { name } => write!(f, "Variable name {name} is not defined"),
             InternalError { error } => write!(f, "Internal variable storage error: {error}"),
+            UndeclaredVariable { name } => write!(f, "Variable {name} is not declared in the program"),
+            TypeMismatch { name, expected_type, actual_type } => write!(f, "Variable {name} is a {expected_type}, not a {actual_type}"),
         }
     }
 }
@@ -0,0 +1,291 @@
+use crate::prelude::*;
+use crate::variable_storage::Result;
+use alloc::collections::BTreeMap;
+use bevy_platform::collections::HashMap;
+use core::any::Any;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// A [`VariableStorage`] that keeps its variables in memory like [`MemoryVariableStorage`] and reads and writes them to a file,
+/// e.g. a save slot or the player's profile.
+///
+/// The variables are only written when calling [`FileVariableStorage::save`], which replaces the file atomically:
+/// if the game crashes while saving, the file contains either the old or the new variables, never a mix of both.
+///
+/// Shallow clones, like the one owned by a [`Dialogue`], share the variables and the file with the original.
+///
+/// ```rust,no_run
+/// # use yarnspinner_runtime::prelude::*;
+/// let path = "saves/slot_1.json";
+/// let storage = FileVariableStorage::open(path, VariableFileFormat::from_path(path).unwrap())?;
+/// let mut dialogue = Dialogue::new(
+///     Box::new(storage.clone()),
+///     Box::new(StringTableTextProvider::new()),
+/// );
+/// // ...run the dialogue...
+/// storage.save()?;
+/// # Ok::<(), VariableStorageError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileVariableStorage {
+    storage: MemoryVariableStorage,
+    path: PathBuf,
+    format: VariableFileFormat,
+}
+
+/// The format a [`FileVariableStorage`] writes its variables in. Each format needs the crate feature of the same name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariableFileFormat {
+    /// JSON, written by [`serde_json`].
+    #[cfg(feature = "json")]
+    Json,
+    /// [RON](https://github.com/ron-rs/ron), written by [`ron`].
+    #[cfg(feature = "ron")]
+    Ron,
+}
+
+impl VariableFileFormat {
+    /// Picks the format from the extension of `path`, i.e. `.json` or `.ron`.
+    #[must_use]
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        match path.as_ref().extension()?.to_str()? {
+            #[cfg(feature = "json")]
+            "json" => Some(Self::Json),
+            #[cfg(feature = "ron")]
+            "ron" => Some(Self::Ron),
+            _ => None,
+        }
+    }
+
+    fn serialize(self, variables: &BTreeMap<String, YarnValue>) -> Result<String> {
+        match self {
+            #[cfg(feature = "json")]
+            Self::Json => serde_json::to_string_pretty(variables).map_err(internal_error),
+            #[cfg(feature = "ron")]
+            Self::Ron => ron::ser::to_string_pretty(variables, ron::ser::PrettyConfig::default())
+                .map_err(internal_error),
+        }
+    }
+
+    fn deserialize(self, contents: &str) -> Result<HashMap<String, YarnValue>> {
+        match self {
+            #[cfg(feature = "json")]
+            Self::Json => serde_json::from_str(contents).map_err(internal_error),
+            #[cfg(feature = "ron")]
+            Self::Ron => ron::from_str(contents).map_err(internal_error),
+        }
+    }
+}
+
+impl FileVariableStorage {
+    /// Creates a storage for the file at `path` and reads the variables in it.
+    /// If the file does not exist yet, the storage starts out empty and [`FileVariableStorage::save`] creates it.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`VariableStorageError::InternalError`] if the file can't be read or is not valid in `format`,
+    /// or a [`VariableStorageError::InvalidVariableName`] if it contains a variable name that does not start with a `$`.
+    pub fn open(path: impl Into<PathBuf>, format: VariableFileFormat) -> Result<Self> {
+        let mut storage = Self {
+            storage: MemoryVariableStorage::new(),
+            path: path.into(),
+            format,
+        };
+        storage.reload()?;
+        Ok(storage)
+    }
+
+    /// The file the variables are read from and written to.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The format of the file.
+    #[must_use]
+    pub fn format(&self) -> VariableFileFormat {
+        self.format
+    }
+
+    /// Replaces the variables with the ones in the file, discarding all changes since the last [`FileVariableStorage::save`].
+    /// If the file does not exist, all variables are removed.
+    ///
+    /// ## Errors
+    ///
+    /// See [`FileVariableStorage::open`]. On failure, the variables are left unchanged.
+    pub fn reload(&mut self) -> Result<()> {
+        let variables = match fs::read_to_string(&self.path) {
+            Ok(contents) => self.format.deserialize(&contents)?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => HashMap::default(),
+            Err(error) => return Err(internal_error(error)),
+        };
+        let mut storage = MemoryVariableStorage::new();
+        VariableStorage::extend(&mut storage, variables)?;
+        self.storage.clear();
+        VariableStorage::extend(&mut self.storage, storage.variables())
+    }
+
+    /// Writes all variables to the file, creating it if needed.
+    ///
+    /// The variables are first written to a temporary file next to it, which then replaces the file.
+    /// The variables are sorted by name so that saves can be diffed.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`VariableStorageError::InternalError`] if the file can't be written. The previous file is left unchanged in that case.
+    pub fn save(&self) -> Result<()> {
+        let variables: BTreeMap<_, _> = self.storage.variables().into_iter().collect();
+        let contents = self.format.serialize(&variables)?;
+
+        let mut temporary_file_name = self.path.file_name().unwrap_or_default().to_owned();
+        temporary_file_name.push(".tmp");
+        let temporary_path = self.path.with_file_name(temporary_file_name);
+        let result = write_synced(&temporary_path, contents.as_bytes())
+            .and_then(|()| fs::rename(&temporary_path, &self.path));
+        if result.is_err() {
+            let _ = fs::remove_file(&temporary_path);
+        }
+        result.map_err(internal_error)
+    }
+}
+
+fn write_synced(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let mut file = fs::File::create(path)?;
+    file.write_all(contents)?;
+    file.sync_all()
+}
+
+fn internal_error(error: impl core::error::Error + Send + Sync + 'static) -> VariableStorageError {
+    VariableStorageError::InternalError {
+        error: Box::new(error),
+    }
+}
+
+impl VariableStorage for FileVariableStorage {
+    fn clone_shallow(&self) -> Box<dyn VariableStorage> {
+        Box::new(self.clone())
+    }
+
+    fn set(&mut self, name: String, value: YarnValue) -> Result<()> {
+        self.storage.set(name, value)
+    }
+
+    fn get(&self, name: &str) -> Result<YarnValue> {
+        self.storage.get(name)
+    }
+
+    fn extend(&mut self, values: HashMap<String, YarnValue>) -> Result<()> {
+        VariableStorage::extend(&mut self.storage, values)
+    }
+
+    fn keeps_values_on_program_load(&self) -> bool {
+        true
+    }
+
+    fn variables(&self) -> HashMap<String, YarnValue> {
+        self.storage.variables()
+    }
+
+    fn clear(&mut self) {
+        self.storage.clear();
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn temporary_path(file_name: &str) -> PathBuf {
+        let directory = env::temp_dir().join(format!(
+            "yarnspinner_file_variable_storage_{}_{file_name}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        directory.join(file_name)
+    }
+
+    fn variables() -> HashMap<String, YarnValue> {
+        [
+            ("$gold".to_owned(), 10.5.into()),
+            ("$name".to_owned(), "Sally \"the Brave\"".into()),
+            ("$is_brave".to_owned(), true.into()),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    fn assert_round_trip(file_name: &str) {
+        let path = temporary_path(file_name);
+        let format = VariableFileFormat::from_path(&path).unwrap();
+        let mut storage = FileVariableStorage::open(&path, format).unwrap();
+        assert!(storage.variables().is_empty());
+
+        VariableStorage::extend(&mut storage, variables()).unwrap();
+        storage.save().unwrap();
+        assert!(!path.with_file_name(format!("{file_name}.tmp")).exists());
+
+        let loaded = FileVariableStorage::open(&path, format).unwrap();
+        assert_eq!(variables(), loaded.variables());
+
+        // Unsaved changes are discarded by reloading
+        storage.set("$gold".to_owned(), 0.into()).unwrap();
+        storage.reload().unwrap();
+        assert_eq!(variables(), storage.variables());
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_round_trip() {
+        assert_round_trip("variables.json");
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn ron_round_trip() {
+        assert_round_trip("variables.ron");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn loading_a_program_keeps_saved_variables() {
+        let path = temporary_path("variables.json");
+        let format = VariableFileFormat::Json;
+        let mut storage = FileVariableStorage::open(&path, format).unwrap();
+        storage.set("$gold".to_owned(), 50.into()).unwrap();
+        storage.save().unwrap();
+
+        let storage = FileVariableStorage::open(&path, format).unwrap();
+        let mut dialogue = Dialogue::new(
+            Box::new(storage.clone()),
+            Box::new(StringTableTextProvider::new()),
+        );
+        let program = Program {
+            initial_values: [
+                ("$gold".to_owned(), 0.into()),
+                ("$is_brave".to_owned(), true.into()),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+        dialogue.add_program(program);
+
+        assert_eq!(YarnValue::from(50), storage.get("$gold").unwrap());
+        assert_eq!(YarnValue::from(true), storage.get("$is_brave").unwrap());
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
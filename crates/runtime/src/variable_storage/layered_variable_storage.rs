@@ -0,0 +1,336 @@
+use crate::prelude::*;
+use crate::variable_storage::Result;
+use bevy_platform::collections::HashMap;
+use core::any::Any;
+
+/// A [`VariableStorage`] that keeps the variables starting with a prefix, `$Global.` by default, in a separate storage.
+///
+/// This is meant for games with multiple save slots, where some variables belong to the player's profile
+/// and are shared by all slots, e.g. `$Global.has_finished_game`, while all others belong to the current slot.
+/// Reads and writes of a variable go to the storage its name belongs to, so loading another save slot with
+/// [`LayeredVariableStorage::replace_save_slot`] leaves the global variables untouched.
+///
+/// ```rust
+/// # use yarnspinner_runtime::prelude::*;
+/// let global = MemoryVariableStorage::new();
+/// let mut storage = LayeredVariableStorage::new(
+///     Box::new(global.clone()),
+///     Box::new(MemoryVariableStorage::new()),
+/// );
+/// storage.set("$Global.endings_seen".to_owned(), 1.into())?;
+/// storage.set("$gold".to_owned(), 10.into())?;
+/// assert!(global.contains("$Global.endings_seen"));
+/// assert!(!global.contains("$gold"));
+///
+/// storage.replace_save_slot(Box::new(MemoryVariableStorage::new()));
+/// assert!(storage.contains("$Global.endings_seen"));
+/// assert!(!storage.contains("$gold"));
+/// # Ok::<(), VariableStorageError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct LayeredVariableStorage {
+    global: Box<dyn VariableStorage>,
+    save_slot: Box<dyn VariableStorage>,
+    global_prefix: String,
+}
+
+impl LayeredVariableStorage {
+    /// The default for [`LayeredVariableStorage::with_global_prefix`].
+    pub const DEFAULT_GLOBAL_PREFIX: &'static str = "$Global.";
+
+    /// Creates a storage that keeps the variables starting with `$Global.` in `global` and all others in `save_slot`.
+    #[must_use]
+    pub fn new(global: Box<dyn VariableStorage>, save_slot: Box<dyn VariableStorage>) -> Self {
+        Self {
+            global,
+            save_slot,
+            global_prefix: Self::DEFAULT_GLOBAL_PREFIX.to_owned(),
+        }
+    }
+
+    /// Sets the prefix of the variables that are kept in the global storage. It includes the `$`.
+    #[must_use]
+    pub fn with_global_prefix(mut self, global_prefix: impl Into<String>) -> Self {
+        self.global_prefix = global_prefix.into();
+        self
+    }
+
+    /// The prefix of the variables that are kept in the global storage.
+    #[must_use]
+    pub fn global_prefix(&self) -> &str {
+        &self.global_prefix
+    }
+
+    /// Returns `true` if the variable `name` is kept in the global storage.
+    #[must_use]
+    pub fn is_global(&self, name: &str) -> bool {
+        name.starts_with(&self.global_prefix)
+    }
+
+    /// The storage of the variables shared by all save slots.
+    #[must_use]
+    pub fn global(&self) -> &dyn VariableStorage {
+        self.global.as_ref()
+    }
+
+    /// The storage of the variables shared by all save slots.
+    pub fn global_mut(&mut self) -> &mut dyn VariableStorage {
+        self.global.as_mut()
+    }
+
+    /// The storage of the variables of the current save slot.
+    #[must_use]
+    pub fn save_slot(&self) -> &dyn VariableStorage {
+        self.save_slot.as_ref()
+    }
+
+    /// The storage of the variables of the current save slot.
+    pub fn save_slot_mut(&mut self) -> &mut dyn VariableStorage {
+        self.save_slot.as_mut()
+    }
+
+    /// Clears the variables shared by all save slots, e.g. when the player resets their profile.
+    /// [`VariableStorage::clear`] only clears the current save slot.
+    pub fn clear_global(&mut self) {
+        self.global.clear();
+    }
+
+    /// Switches to another save slot and returns the storage of the previous one.
+    pub fn replace_save_slot(
+        &mut self,
+        save_slot: Box<dyn VariableStorage>,
+    ) -> Box<dyn VariableStorage> {
+        core::mem::replace(&mut self.save_slot, save_slot)
+    }
+
+    fn layer(&self, name: &str) -> &dyn VariableStorage {
+        if self.is_global(name) {
+            self.global.as_ref()
+        } else {
+            self.save_slot.as_ref()
+        }
+    }
+
+    fn layer_mut(&mut self, name: &str) -> &mut dyn VariableStorage {
+        if self.is_global(name) {
+            self.global.as_mut()
+        } else {
+            self.save_slot.as_mut()
+        }
+    }
+}
+
+impl VariableStorage for LayeredVariableStorage {
+    fn clone_shallow(&self) -> Box<dyn VariableStorage> {
+        Box::new(Self {
+            global: self.global.clone_shallow(),
+            save_slot: self.save_slot.clone_shallow(),
+            global_prefix: self.global_prefix.clone(),
+        })
+    }
+
+    fn set(&mut self, name: String, value: YarnValue) -> Result<()> {
+        self.layer_mut(&name).set(name, value)
+    }
+
+    fn get(&self, name: &str) -> Result<YarnValue> {
+        self.layer(name).get(name)
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.layer(name).contains(name)
+    }
+
+    /// Nothing is written if either storage rejects its values.
+    fn extend(&mut self, values: HashMap<String, YarnValue>) -> Result<()> {
+        if let Some(name) = values.keys().find(|name| !name.starts_with('$')) {
+            return Err(VariableStorageError::InvalidVariableName { name: name.clone() });
+        }
+        let (global, save_slot): (HashMap<_, _>, HashMap<_, _>) = values
+            .into_iter()
+            .partition(|(name, _)| self.is_global(name));
+        if global.is_empty() || save_slot.is_empty() {
+            return self
+                .global
+                .as_mut()
+                .extend(global)
+                .and_then(|()| self.save_slot.as_mut().extend(save_slot));
+        }
+        // Storages may reject values for other reasons than their names, e.g. a `TypedVariableStorage`
+        // rejects values of the wrong type, so the global variables are restored if the save slot fails
+        let previous_global = self.global.variables();
+        self.global.as_mut().extend(global)?;
+        self.save_slot.as_mut().extend(save_slot).inspect_err(|_| {
+            self.global.clear();
+            // These values were all accepted before
+            let _ = self.global.as_mut().extend(previous_global);
+        })
+    }
+
+    fn keeps_values_on_program_load(&self) -> bool {
+        true
+    }
+
+    /// Returns the variables of both storages. Only the global ones are taken from the global storage,
+    /// so that a variable without the global prefix that was written to it directly does not shadow the one in the save slot.
+    fn variables(&self) -> HashMap<String, YarnValue> {
+        let global = self
+            .global
+            .variables()
+            .into_iter()
+            .filter(|(name, _)| self.is_global(name));
+        let save_slot = self
+            .save_slot
+            .variables()
+            .into_iter()
+            .filter(|(name, _)| !self.is_global(name));
+        global.chain(save_slot).collect()
+    }
+
+    /// Clears the variables of the current save slot, e.g. to start a new game.
+    /// The global variables are kept, see [`LayeredVariableStorage::clear_global`].
+    fn clear(&mut self) {
+        self.save_slot.clear();
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_variables_by_prefix() {
+        let global = MemoryVariableStorage::new();
+        let save_slot = MemoryVariableStorage::new();
+        let mut storage =
+            LayeredVariableStorage::new(Box::new(global.clone()), Box::new(save_slot.clone()))
+                .with_global_prefix("$Profile.");
+
+        storage.set("$Profile.deaths".to_owned(), 3.into()).unwrap();
+        storage
+            .extend(
+                [
+                    ("$Profile.name".to_owned(), "Sally".into()),
+                    ("$gold".to_owned(), 10.into()),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .unwrap();
+        assert_eq!(2, global.variables().len());
+        assert_eq!(1, save_slot.variables().len());
+        assert_eq!(YarnValue::Number(10.0), storage.get("$gold").unwrap());
+        assert_eq!(3, storage.variables().len());
+
+        assert!(matches!(
+            storage.get("gold"),
+            Err(VariableStorageError::InvalidVariableName { .. })
+        ));
+
+        let previous = storage.replace_save_slot(Box::new(MemoryVariableStorage::new()));
+        assert!(previous.contains("$gold"));
+        assert!(!storage.contains("$gold"));
+        assert!(storage.contains("$Profile.deaths"));
+
+        storage.set("$gold".to_owned(), 5.into()).unwrap();
+        storage.clear();
+        assert!(!storage.contains("$gold"));
+        assert_eq!(2, storage.variables().len());
+        storage.clear_global();
+        assert!(global.variables().is_empty());
+    }
+
+    #[test]
+    fn extends_both_storages_or_neither() {
+        let global = MemoryVariableStorage::new();
+        let save_slot = TypedVariableStorage::new(
+            Box::new(MemoryVariableStorage::new()),
+            [("$gold".to_owned(), Type::Number, None)],
+        );
+        let mut storage =
+            LayeredVariableStorage::new(Box::new(global.clone()), Box::new(save_slot));
+        storage
+            .set("$Global.endings_seen".to_owned(), 1.into())
+            .unwrap();
+
+        let values = [
+            ("$Global.endings_seen".to_owned(), 2.into()),
+            ("$Global.deaths".to_owned(), 3.into()),
+            ("$gold".to_owned(), "lots".into()),
+        ];
+        assert!(matches!(
+            storage.extend(values.into_iter().collect()),
+            Err(VariableStorageError::TypeMismatch { .. })
+        ));
+        let values = [
+            ("$Global.deaths".to_owned(), 3.into()),
+            ("gold".to_owned(), 10.into()),
+        ];
+        assert!(matches!(
+            storage.extend(values.into_iter().collect()),
+            Err(VariableStorageError::InvalidVariableName { .. })
+        ));
+        assert_eq!(
+            [("$Global.endings_seen".to_owned(), YarnValue::Number(1.0))]
+                .into_iter()
+                .collect::<HashMap<_, _>>(),
+            global.variables()
+        );
+    }
+
+    #[cfg(any(feature = "json", feature = "ron"))]
+    #[test]
+    fn round_trips_through_files() {
+        use std::path::Path;
+        use std::{env, fs};
+
+        #[cfg(feature = "json")]
+        let (global_file, save_slot_file) = ("profile.json", "slot_1.json");
+        #[cfg(all(feature = "ron", not(feature = "json")))]
+        let (global_file, save_slot_file) = ("profile.ron", "slot_1.ron");
+        let directory = env::temp_dir().join(format!(
+            "yarnspinner_layered_variable_storage_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        let global_path = directory.join(global_file);
+        let save_slot_path = directory.join(save_slot_file);
+        let open = |path: &Path| {
+            FileVariableStorage::open(path, VariableFileFormat::from_path(path).unwrap()).unwrap()
+        };
+
+        let global = open(&global_path);
+        let save_slot = open(&save_slot_path);
+        let mut storage =
+            LayeredVariableStorage::new(Box::new(global.clone()), Box::new(save_slot.clone()));
+        storage
+            .set("$Global.endings_seen".to_owned(), 2.into())
+            .unwrap();
+        storage.set("$gold".to_owned(), 10.into()).unwrap();
+        global.save().unwrap();
+        save_slot.save().unwrap();
+
+        let storage = LayeredVariableStorage::new(
+            Box::new(open(&global_path)),
+            Box::new(open(&save_slot_path)),
+        );
+        assert_eq!(
+            YarnValue::Number(2.0),
+            storage.get("$Global.endings_seen").unwrap()
+        );
+        assert_eq!(YarnValue::Number(10.0), storage.get("$gold").unwrap());
+        assert!(!storage.save_slot().contains("$Global.endings_seen"));
+        assert!(!storage.global().contains("$gold"));
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,314 @@
+use crate::prelude::*;
+use crate::variable_storage::Result;
+use alloc::sync::Arc;
+use bevy_platform::collections::HashMap;
+use core::any::Any;
+
+/// A [`VariableStorage`] that only accepts the variables declared in a compiled program, and only values of their declared types.
+/// Variables that were never set have their declared default value.
+///
+/// The variables are stored in another [`VariableStorage`], which this wraps. Build it from the compiler's
+/// `Compilation::stored_variable_declarations`:
+///
+/// ```rust
+/// # use yarnspinner_runtime::prelude::*;
+/// # use yarnspinner_core::prelude::*;
+/// # let declarations = vec![("$gold".to_owned(), Type::Number, Some(YarnValue::Number(10.0)))];
+/// // let declarations = compilation.stored_variable_declarations();
+/// let mut storage = TypedVariableStorage::new(Box::new(MemoryVariableStorage::new()), declarations);
+/// assert_eq!(10.0, storage.get_number("$gold")?);
+///
+/// assert!(storage.set("$gold".to_owned(), "lots".into()).is_err());
+/// assert!(storage.set("$silver".to_owned(), 1.into()).is_err());
+/// # Ok::<(), VariableStorageError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct TypedVariableStorage {
+    storage: Box<dyn VariableStorage>,
+    declarations: Arc<HashMap<String, VariableDeclaration>>,
+}
+
+#[derive(Debug, Clone)]
+struct VariableDeclaration {
+    r#type: Type,
+    default_value: Option<YarnValue>,
+}
+
+impl TypedVariableStorage {
+    /// Wraps `storage` so that it only accepts the variables in `declarations`, which are their names, types and default values.
+    #[must_use]
+    pub fn new(
+        storage: Box<dyn VariableStorage>,
+        declarations: impl IntoIterator<Item = (String, Type, Option<YarnValue>)>,
+    ) -> Self {
+        let declarations = declarations
+            .into_iter()
+            .map(|(name, r#type, default_value)| {
+                let declaration = VariableDeclaration {
+                    r#type,
+                    default_value,
+                };
+                (name, declaration)
+            })
+            .collect();
+        Self {
+            storage,
+            declarations: Arc::new(declarations),
+        }
+    }
+
+    /// The [`VariableStorage`] this wraps.
+    #[must_use]
+    pub fn inner(&self) -> &dyn VariableStorage {
+        self.storage.as_ref()
+    }
+
+    /// The declared type of the variable `name`, or [`None`] if it was not declared.
+    #[must_use]
+    pub fn declared_type(&self, name: &str) -> Option<&Type> {
+        self.declarations
+            .get(name)
+            .map(|declaration| &declaration.r#type)
+    }
+
+    /// Gets the value of a variable declared as a [`Type::Number`].
+    ///
+    /// ## Errors
+    ///
+    /// Fails like [`VariableStorage::get`], or with a [`VariableStorageError::TypeMismatch`] if the variable has another type
+    /// or the wrapped storage holds a value of another type.
+    pub fn get_number(&self, name: &str) -> Result<f32> {
+        match self.get_typed(name, Type::Number)? {
+            YarnValue::Number(number) => Ok(number),
+            value => Err(type_mismatch(name, Type::Number, &value)),
+        }
+    }
+
+    /// Gets the value of a variable declared as a [`Type::String`].
+    ///
+    /// ## Errors
+    ///
+    /// Fails like [`VariableStorage::get`], or with a [`VariableStorageError::TypeMismatch`] if the variable has another type
+    /// or the wrapped storage holds a value of another type.
+    pub fn get_string(&self, name: &str) -> Result<String> {
+        match self.get_typed(name, Type::String)? {
+            YarnValue::String(string) => Ok(string),
+            value => Err(type_mismatch(name, Type::String, &value)),
+        }
+    }
+
+    /// Gets the value of a variable declared as a [`Type::Boolean`].
+    ///
+    /// ## Errors
+    ///
+    /// Fails like [`VariableStorage::get`], or with a [`VariableStorageError::TypeMismatch`] if the variable has another type
+    /// or the wrapped storage holds a value of another type.
+    pub fn get_bool(&self, name: &str) -> Result<bool> {
+        match self.get_typed(name, Type::Boolean)? {
+            YarnValue::Boolean(bool) => Ok(bool),
+            value => Err(type_mismatch(name, Type::Boolean, &value)),
+        }
+    }
+
+    fn get_typed(&self, name: &str, expected_type: Type) -> Result<YarnValue> {
+        let declaration = self.declaration(name)?;
+        if declaration.r#type != expected_type {
+            return Err(VariableStorageError::TypeMismatch {
+                name: name.to_owned(),
+                expected_type,
+                actual_type: declaration.r#type.clone(),
+            });
+        }
+        self.get(name)
+    }
+
+    fn declaration(&self, name: &str) -> Result<&VariableDeclaration> {
+        if !name.starts_with('$') {
+            return Err(VariableStorageError::InvalidVariableName {
+                name: name.to_owned(),
+            });
+        }
+        self.declarations
+            .get(name)
+            .ok_or_else(|| VariableStorageError::UndeclaredVariable {
+                name: name.to_owned(),
+            })
+    }
+
+    fn validate(&self, name: &str, value: &YarnValue) -> Result<()> {
+        let declaration = self.declaration(name)?;
+        let is_valid = match (&declaration.r#type, value) {
+            (Type::Any, _)
+            | (Type::Number, YarnValue::Number(_))
+            | (Type::String, YarnValue::String(_))
+            | (Type::Boolean, YarnValue::Boolean(_)) => true,
+            (Type::Enum(enum_type), YarnValue::String(case)) => enum_type.cases.contains(case),
+            _ => false,
+        };
+        if is_valid {
+            return Ok(());
+        }
+        Err(type_mismatch(name, declaration.r#type.clone(), value))
+    }
+}
+
+fn type_mismatch(name: &str, expected_type: Type, value: &YarnValue) -> VariableStorageError {
+    let actual_type = match value {
+        YarnValue::Number(_) => Type::Number,
+        YarnValue::String(_) => Type::String,
+        YarnValue::Boolean(_) => Type::Boolean,
+    };
+    VariableStorageError::TypeMismatch {
+        name: name.to_owned(),
+        expected_type,
+        actual_type,
+    }
+}
+
+impl VariableStorage for TypedVariableStorage {
+    fn clone_shallow(&self) -> Box<dyn VariableStorage> {
+        Box::new(Self {
+            storage: self.storage.clone_shallow(),
+            declarations: self.declarations.clone(),
+        })
+    }
+
+    fn set(&mut self, name: String, value: YarnValue) -> Result<()> {
+        self.validate(&name, &value)?;
+        self.storage.set(name, value)
+    }
+
+    fn get(&self, name: &str) -> Result<YarnValue> {
+        let declaration = self.declaration(name)?;
+        match self.storage.get(name) {
+            Err(VariableStorageError::VariableNotFound { .. })
+                if declaration.default_value.is_some() =>
+            {
+                Ok(declaration.default_value.clone().unwrap())
+            }
+            result => result,
+        }
+    }
+
+    fn extend(&mut self, values: HashMap<String, YarnValue>) -> Result<()> {
+        for (name, value) in &values {
+            self.validate(name, value)?;
+        }
+        VariableStorage::extend(self.storage.as_mut(), values)
+    }
+
+    fn variables(&self) -> HashMap<String, YarnValue> {
+        let defaults = self.declarations.iter().filter_map(|(name, declaration)| {
+            let default_value = declaration.default_value.clone()?;
+            Some((name.clone(), default_value))
+        });
+        defaults.chain(self.storage.variables()).collect()
+    }
+
+    fn clear(&mut self) {
+        self.storage.clear();
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn storage() -> TypedVariableStorage {
+        let mood = EnumType::new("Mood").with_case("Happy").with_case("Sad");
+        TypedVariableStorage::new(
+            Box::new(MemoryVariableStorage::new()),
+            [
+                ("$gold".to_owned(), Type::Number, Some(10.0.into())),
+                ("$name".to_owned(), Type::String, None),
+                ("$mood".to_owned(), mood.into(), Some("Happy".into())),
+            ],
+        )
+    }
+
+    #[test]
+    fn serves_defaults_until_set() {
+        let mut storage = storage();
+        assert_eq!(10.0, storage.get_number("$gold").unwrap());
+        assert!(matches!(
+            storage.get("$name"),
+            Err(VariableStorageError::VariableNotFound { .. })
+        ));
+        assert_eq!(2, storage.variables().len());
+
+        storage.set("$gold".to_owned(), 3.into()).unwrap();
+        storage.set("$name".to_owned(), "Sally".into()).unwrap();
+        assert_eq!(3.0, storage.get_number("$gold").unwrap());
+        assert_eq!("Sally", storage.get_string("$name").unwrap());
+        assert_eq!(3, storage.variables().len());
+
+        storage.clear();
+        assert_eq!(10.0, storage.get_number("$gold").unwrap());
+    }
+
+    #[test]
+    fn rejects_undeclared_variables_and_wrong_types() {
+        let mut storage = storage();
+        assert!(matches!(
+            storage.set("$silver".to_owned(), 1.into()),
+            Err(VariableStorageError::UndeclaredVariable { ref name }) if name == "$silver"
+        ));
+        assert!(matches!(
+            storage.get("$silver"),
+            Err(VariableStorageError::UndeclaredVariable { .. })
+        ));
+        assert!(matches!(
+            storage.set("$gold".to_owned(), "lots".into()),
+            Err(VariableStorageError::TypeMismatch {
+                expected_type: Type::Number,
+                actual_type: Type::String,
+                ..
+            })
+        ));
+        assert!(matches!(
+            storage.get_bool("$gold"),
+            Err(VariableStorageError::TypeMismatch {
+                expected_type: Type::Boolean,
+                actual_type: Type::Number,
+                ..
+            })
+        ));
+
+        storage.set("$mood".to_owned(), "Sad".into()).unwrap();
+        assert!(storage.set("$mood".to_owned(), "Angry".into()).is_err());
+
+        // Nothing is written if any value is invalid
+        let values = [
+            ("$gold".to_owned(), 1.into()),
+            ("$name".to_owned(), false.into()),
+        ];
+        assert!(storage.extend(values.into_iter().collect()).is_err());
+        assert_eq!(10.0, storage.get_number("$gold").unwrap());
+    }
+
+    #[test]
+    fn reports_wrongly_typed_values_of_the_wrapped_storage() {
+        let mut inner = MemoryVariableStorage::new();
+        inner.set("$gold".to_owned(), "lots".into()).unwrap();
+        let storage = TypedVariableStorage::new(
+            Box::new(inner),
+            [("$gold".to_owned(), Type::Number, Some(10.0.into()))],
+        );
+        assert!(matches!(
+            storage.get_number("$gold"),
+            Err(VariableStorageError::TypeMismatch {
+                expected_type: Type::Number,
+                actual_type: Type::String,
+                ..
+            })
+        ));
+    }
+}
@@ -3,26 +3,30 @@
 //! ## Implementation Notes
 //! The `Operand` extensions and the `Operator` enum were moved into upstream crates to make them not depend on the runtime.
 
-pub(crate) use self::{execution_state::*, state::*};
+pub(crate) use self::{execution_state::*, linked_program::*, state::*};
 use crate::markup::{LineParser, ParsedMarkup};
 use crate::prelude::*;
 use crate::Result;
+use alloc::sync::Arc;
 use core::fmt::Debug;
 use log::*;
 
 mod execution_state;
+mod linked_program;
 mod state;
 
 #[derive(Debug, Clone)]
 pub(crate) struct VirtualMachine {
     pub(crate) library: Library,
-    pub(crate) program: Option<Program>,
+    pub(crate) program: Option<SharedProgram>,
     pub(crate) variable_storage: Box<dyn VariableStorage>,
     pub(crate) line_hints_enabled: bool,
+    pub(crate) saliency_strategy: Box<dyn SaliencyStrategy>,
+    pub(crate) observers: Vec<Arc<dyn DialogueObserver>>,
     current_node_name: Option<String>,
     state: State,
     execution_state: ExecutionState,
-    current_node: Option<Node>,
+    current_node: Option<Arc<LinkedNode>>,
     batched_events: Vec<DialogueEvent>,
     line_parser: LineParser,
     text_provider: Box<dyn TextProvider>,
@@ -49,6 +53,8 @@ impl VirtualMachine {
             current_node: Default::default(),
             batched_events: Default::default(),
             line_hints_enabled: Default::default(),
+            saliency_strategy: Box::new(FirstSaliencyStrategy),
+            observers: Default::default(),
         }
     }
 
@@ -68,6 +74,16 @@ impl VirtualMachine {
         self.variable_storage.as_mut()
     }
 
+    pub(crate) fn register_markup_processor(
+        &mut self,
+        attribute_name: String,
+        mut processor: Box<dyn AttributeMarkerProcessor>,
+    ) {
+        processor.set_language_code(self.language_code.clone());
+        self.line_parser
+            .replace_marker_processor(attribute_name, processor);
+    }
+
     pub(crate) fn set_language_code(&mut self, language_code: impl Into<Option<Language>>) {
         let language_code = language_code.into();
         self.language_code.clone_from(&language_code);
@@ -92,7 +108,7 @@ impl VirtualMachine {
     /// The original does not reset the state upon calling this. I suspect that's a bug.
     pub(crate) fn stop(&mut self) -> Vec<DialogueEvent> {
         self.set_execution_state(ExecutionState::Stopped);
-        self.batched_events.push(DialogueEvent::DialogueComplete);
+        self.push_event(DialogueEvent::DialogueComplete);
         core::mem::take(&mut self.batched_events)
     }
 
@@ -100,14 +116,13 @@ impl VirtualMachine {
         let node_name = node_name.into();
         debug!("Loading node \"{node_name}\"");
         let current_node = self.get_node_from_name(&node_name)?;
-        self.current_node = Some(current_node.clone());
+        self.current_node = Some(current_node);
 
         self.reset_state();
 
         self.current_node_name = Some(node_name.clone());
 
-        self.batched_events
-            .push(DialogueEvent::NodeStart(node_name));
+        self.push_event(DialogueEvent::NodeStart(node_name));
 
         if self.line_hints_enabled {
             self.send_line_hints();
@@ -130,22 +145,47 @@ impl VirtualMachine {
             // Loop over every instruction and find the ones that run a
             // line or add an option; these are the two instructions
             // that will signal a line can appear to the player
-            .filter_map(|instruction| {
-                let opcode: OpCode = instruction.opcode.try_into().unwrap();
-                [OpCode::RunLine, OpCode::AddOption]
-                    .contains(&opcode)
-                    .then(|| {
-                        // Both RunLine and AddOption have the string ID
-                        // they want to show as their first operand, so
-                        // store that
-                        let id: String = instruction.operands[0].clone().try_into().unwrap();
-                        LineId(id)
-                    })
+            .filter_map(|instruction| match instruction {
+                LinkedInstruction::RunLine { line_id, .. }
+                | LinkedInstruction::AddOption { line_id, .. } => Some(LineId(line_id.to_string())),
+                _ => None,
             })
             .collect();
         self.text_provider.accept_line_hints(&string_ids);
-        self.batched_events
-            .push(DialogueEvent::LineHints(string_ids));
+        self.push_event(DialogueEvent::LineHints(string_ids));
+    }
+
+    /// Batches `event` for the next return of [`VirtualMachine::continue_`] and tells the observers about it.
+    fn push_event(&mut self, event: DialogueEvent) {
+        match &event {
+            // The node of a node group only picks the member to run, so only the member is reported as started and completed
+            DialogueEvent::NodeStart(node_name) | DialogueEvent::NodeComplete(node_name)
+                if self.is_node_group(node_name) =>
+            {
+                return;
+            }
+            DialogueEvent::Line(line) => self.notify(|observer| observer.line_delivered(line)),
+            DialogueEvent::Options(options) => {
+                self.notify(|observer| observer.options_delivered(options))
+            }
+            DialogueEvent::Command(command) => {
+                self.notify(|observer| observer.command_dispatched(command))
+            }
+            DialogueEvent::NodeStart(node_name) => {
+                self.notify(|observer| observer.node_entered(node_name))
+            }
+            DialogueEvent::NodeComplete(node_name) => {
+                self.notify(|observer| observer.node_exited(node_name))
+            }
+            DialogueEvent::DialogueComplete | DialogueEvent::LineHints(_) => {}
+        }
+        self.batched_events.push(event);
+    }
+
+    fn notify(&self, notify_fn: impl Fn(&dyn DialogueObserver)) {
+        for observer in &self.observers {
+            notify_fn(observer.as_ref());
+        }
     }
 
     pub(crate) fn pop_line_hints(&mut self) -> Option<Vec<LineId>> {
@@ -159,19 +199,23 @@ impl VirtualMachine {
         }
     }
 
-    fn get_node_from_name(&self, node_name: &str) -> Result<&Node> {
+    fn get_node_from_name(&self, node_name: &str) -> Result<Arc<LinkedNode>> {
         let program = self
             .program
             .as_ref()
-            .ok_or_else(|| DialogueError::NoProgramLoaded)?;
+            .ok_or_else(|| DialogueError::NoProgramLoaded)?
+            .linked();
         assert!(
             !program.nodes.is_empty(),
             "Cannot load node \"{node_name}\": No nodes have been loaded.",
         );
 
+        // The nodes that compute smart variables can only be run by reading the variable
         program
             .nodes
             .get(node_name)
+            .filter(|node| !node.is_smart_variable)
+            .cloned()
             .ok_or_else(|| DialogueError::InvalidNode {
                 node_name: node_name.to_owned(),
             })
@@ -180,7 +224,7 @@ impl VirtualMachine {
     /// Resumes execution.
     pub(crate) fn continue_(
         &mut self,
-        mut instruction_fn: impl FnMut(&mut Self, &Instruction) -> crate::Result<()>,
+        mut instruction_fn: impl FnMut(&mut Self, &LinkedInstruction) -> crate::Result<()>,
     ) -> crate::Result<Vec<DialogueEvent>> {
         self.assert_can_continue()?;
         self.set_execution_state(ExecutionState::Running);
@@ -193,14 +237,17 @@ impl VirtualMachine {
             // The original increments the program counter here, but that leads to intentional underflow on [`OpCode::RunNode`],
             // so we do the incrementation in [`VirtualMachine::run_instruction`] instead.
 
-            if self.state.program_counter < current_node.instructions.len() {
+            // The instruction may have changed the current node, so check against that one
+            let instruction_count = self.current_node.as_ref().unwrap().instructions.len();
+            if self.state.program_counter < instruction_count {
                 continue;
             }
 
-            self.batched_events
-                .push(DialogueEvent::NodeComplete(current_node.name.clone()));
-            self.set_execution_state(ExecutionState::Stopped);
-            self.batched_events.push(DialogueEvent::DialogueComplete);
+            // Falling off the end of a detoured node returns to its caller
+            if self.return_from_node()? {
+                continue;
+            }
+            self.complete_dialogue();
             debug!("Run complete.");
         }
         Ok(core::mem::take(&mut self.batched_events))
@@ -238,6 +285,9 @@ impl VirtualMachine {
             });
         }
 
+        let selected_option = &self.state.current_options[selected_option_id.0];
+        self.notify(|observer| observer.option_selected(selected_option));
+
         // We now know what number option was selected; push the
         // corresponding node name to the stack.
         let destination_node = self.state.current_options[selected_option_id.0]
@@ -266,44 +316,103 @@ impl VirtualMachine {
         self.current_node_name.clone()
     }
 
+    pub(crate) fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Stops running instructions until the next [`VirtualMachine::continue_`] without delivering any content.
+    pub(crate) fn pause(&mut self) {
+        self.set_execution_state(ExecutionState::WaitingForContinue);
+    }
+
+    pub(crate) fn snapshot(&self) -> DialogueSnapshot {
+        let program = self.program.as_ref().map(SharedProgram::program);
+        let node_fingerprints = self
+            .current_node_name
+            .iter()
+            .chain(self.state.call_stack.iter().map(|caller| &caller.node_name))
+            .filter_map(|node_name| {
+                let node = program?.nodes.get(node_name)?;
+                Some((node_name.clone(), fingerprint(node)))
+            })
+            .collect();
+        DialogueSnapshot {
+            node_name: self.current_node_name.clone(),
+            execution_state: self.execution_state,
+            state: self.state.clone(),
+            node_fingerprints,
+        }
+    }
+
+    /// Checks that the bytecode of every node in `snapshot` is still the same before continuing where it left off.
+    pub(crate) fn restore(&mut self, snapshot: DialogueSnapshot) -> Result<()> {
+        let Some(node_name) = snapshot.node_name else {
+            self.set_execution_state(ExecutionState::Stopped);
+            self.batched_events.clear();
+            return Ok(());
+        };
+        let program = self
+            .program
+            .as_ref()
+            .ok_or(DialogueError::NoProgramLoaded)?
+            .program();
+        let callers = snapshot
+            .state
+            .call_stack
+            .iter()
+            .map(|caller| &caller.node_name);
+        for name in core::iter::once(&node_name).chain(callers) {
+            let expected = snapshot.node_fingerprints.get(name).copied();
+            let actual = program.nodes.get(name).map(fingerprint);
+            if expected.is_none() || expected != actual {
+                return Err(DialogueError::IncompatibleSnapshot {
+                    node_name: name.clone(),
+                });
+            }
+        }
+
+        self.current_node = Some(self.get_node_from_name(&node_name)?);
+        self.current_node_name = Some(node_name);
+        self.state = snapshot.state;
+        self.execution_state = snapshot.execution_state;
+        self.batched_events.clear();
+        Ok(())
+    }
+
     /// ## Implementation note
     ///
     /// Increments the program counter here instead of in `continue_` for cleaner code
     pub(crate) fn run_instruction(
         &mut self,
-        instruction: &Instruction,
+        instruction: &LinkedInstruction,
         mut function_call_fn: impl FnMut(&dyn UntypedYarnFn, Vec<YarnValue>) -> YarnValue,
     ) -> crate::Result<()> {
-        let opcode: OpCode = instruction.opcode.try_into().unwrap();
-        match opcode {
-            OpCode::JumpTo => {
-                // Jumps to a named label
-                let label_name: String = instruction.read_operand(0);
-                self.state.program_counter = self.find_instruction_point_for_label(&label_name);
-            }
-            OpCode::Jump => {
+        match instruction {
+            LinkedInstruction::JumpTo(instruction_point) => {
+                // Jumps to a named label, which was resolved when linking
+                self.state.program_counter = *instruction_point;
+            }
+            LinkedInstruction::Jump => {
                 // Jumps to a label whose name is on the stack.
-                let jump_destination: String = self.state.peek();
+                let jump_destination: String = self.peek()?;
                 self.state.program_counter =
-                    self.find_instruction_point_for_label(&jump_destination);
+                    self.find_instruction_point_for_label(&jump_destination)?;
             }
-            OpCode::RunLine => {
+            LinkedInstruction::RunLine {
+                line_id,
+                substitution_count,
+            } => {
                 // Looks up a string from the string table and passes it to the client as a line
+                let string_id = LineId(line_id.to_string());
 
-                let string_id: String = instruction.read_operand(0);
-                let string_id: LineId = string_id.into();
-
-                // The second operand, if provided (compilers prior
-                // to v1.1 don't include it), indicates the number
+                // The second operand indicates the number
                 // of expressions in the line. We need to pop these
                 // values off the stack and deliver them to the
                 // line handler.
-                assert_up_to_date_compiler(instruction.operands.len() >= 2);
-
-                let substitutions = self.pop_substitutions_with_count_at_operand(instruction, 1);
+                let substitutions = self.pop_substitutions(*substitution_count)?;
                 let line = self.prepare_line(string_id, &substitutions)?;
 
-                self.batched_events.push(DialogueEvent::Line(line));
+                self.push_event(DialogueEvent::Line(line));
 
                 // Implementation note:
                 // In the original, this is only done if `execution_state` is still `DeliveringContent`,
@@ -313,20 +422,21 @@ impl VirtualMachine {
                 self.set_execution_state(ExecutionState::WaitingForContinue);
                 self.state.program_counter += 1;
             }
-            OpCode::RunCommand => {
+            LinkedInstruction::RunCommand {
+                text,
+                substitution_count,
+            } => {
                 // Passes a string to the client as a custom command
-                let command_text: String = instruction.read_operand(0);
-                assert_up_to_date_compiler(instruction.operands.len() >= 2);
                 let command_text = self
-                    .pop_substitutions_with_count_at_operand(instruction, 1)
+                    .pop_substitutions(*substitution_count)?
                     .into_iter()
                     .enumerate()
-                    .fold(command_text, |command_text, (i, substitution)| {
+                    .fold(text.to_string(), |command_text, (i, substitution)| {
                         command_text.replace(&format!("{{{i}}}"), &substitution)
                     });
                 let command = Command::parse(command_text);
 
-                self.batched_events.push(DialogueEvent::Command(command));
+                self.push_event(DialogueEvent::Command(command));
 
                 // Implementation note:
                 // In the original, this is only done if `execution_state` is still `DeliveringContent`,
@@ -336,46 +446,48 @@ impl VirtualMachine {
                 self.set_execution_state(ExecutionState::WaitingForContinue);
                 self.state.program_counter += 1;
             }
-            OpCode::AddOption => {
+            LinkedInstruction::AddOption {
+                line_id,
+                destination,
+                substitution_count,
+                has_condition,
+            } => {
                 // Add an option to the current state
-                let string_id: String = instruction.read_operand(0);
-                let string_id: LineId = string_id.into();
-                assert_up_to_date_compiler(instruction.operands.len() >= 4);
-                let substitutions = self.pop_substitutions_with_count_at_operand(instruction, 2);
+                let string_id = LineId(line_id.to_string());
+                let substitutions = self.pop_substitutions(*substitution_count)?;
                 let line = self.prepare_line(string_id, &substitutions)?;
 
                 // Indicates whether the VM believes that the
                 // option should be shown to the user, based on any
                 // conditions that were attached to the option.
-                let line_condition_passed = if instruction.read_operand(3) {
+                let line_condition_passed = if *has_condition {
                     // The fourth operand is a bool that indicates
                     // whether this option had a condition or not.
                     // If it does, then a bool value will exist on
                     // the stack indicating whether the condition
                     // passed or not. We pass that information to
                     // the game.
-                    self.state.pop()
+                    self.pop()?
                 } else {
                     true
                 };
 
                 let index = self.state.current_options.len();
-                let node_name = instruction.read_operand(1);
                 // ## Implementation note:
                 // The original calculates the ID in the `ShowOptions` opcode,
                 // but this way is cleaner because it allows us to store a `DialogueOption` instead of a bunch of values in a big tuple.
                 self.state.current_options.push(DialogueOption {
                     line,
                     id: OptionId(index),
-                    destination_node: node_name,
+                    destination_node: destination.to_string(),
                     is_available: line_condition_passed,
                 });
                 self.state.program_counter += 1;
             }
-            OpCode::ShowOptions => {
+            LinkedInstruction::ShowOptions => {
                 // If we have no options to show, immediately stop.
                 if self.state.current_options.is_empty() {
-                    self.batched_events.push(DialogueEvent::DialogueComplete);
+                    self.push_event(DialogueEvent::DialogueComplete);
                     self.set_execution_state(ExecutionState::Stopped);
                     self.state.program_counter += 1;
                     return Ok(());
@@ -388,88 +500,88 @@ impl VirtualMachine {
                 // delegate for them to call when the user has made
                 // a selection
                 let current_options = self.state.current_options.clone();
-                self.batched_events
-                    .push(DialogueEvent::Options(current_options));
+                self.push_event(DialogueEvent::Options(current_options));
 
                 // Implementation note:
                 // Not checking the execution state now since we have no line handler to call `continue_` from.
                 self.state.program_counter += 1;
             }
-            OpCode::PushString => {
-                // Pushes a string value onto the stack. The operand is an index into the string table, so that's looked up first.
-                let string_table_index: String = instruction.read_operand(0);
-                self.state.push(string_table_index);
+            LinkedInstruction::PushString(string) => {
+                // Pushes a string value onto the stack.
+                self.state.push(string.to_string());
                 self.state.program_counter += 1;
             }
-            OpCode::PushFloat => {
+            LinkedInstruction::PushFloat(float) => {
                 // Pushes a floating point onto the stack.
-                let float: f32 = instruction.read_operand(0);
-                self.state.push(float);
+                self.state.push(*float);
                 self.state.program_counter += 1;
             }
-            OpCode::PushBool => {
+            LinkedInstruction::PushBool(boolean) => {
                 // Pushes a boolean value onto the stack.
-                let boolean: bool = instruction.read_operand(0);
-                self.state.push(boolean);
+                self.state.push(*boolean);
                 self.state.program_counter += 1;
             }
-
-            OpCode::PushNull => {
-                panic!("PushNull is no longer valid op code, because null is no longer a valid value from Yarn Spinner 2.0 onwards. To fix this error, re-compile the original source code.");
-            }
-            OpCode::JumpIfFalse => {
+            LinkedInstruction::JumpIfFalse(instruction_point) => {
                 // Jumps to a named label if the value on the top of the stack evaluates to the boolean value 'false'.
-                let is_top_value_true: bool = self.state.peek();
+                let is_top_value_true: bool = self.peek()?;
                 if !is_top_value_true {
-                    let label_name: String = instruction.read_operand(0);
-                    let instruction_point = self.find_instruction_point_for_label(&label_name);
-                    self.state.program_counter = instruction_point;
+                    self.state.program_counter = *instruction_point;
                 } else {
                     self.state.program_counter += 1;
                 }
             }
-            OpCode::Pop => {
+            LinkedInstruction::Pop => {
                 // Pops a value from the stack.
-                self.state.pop_value();
+                self.pop_value()?;
                 self.state.program_counter += 1;
             }
-            OpCode::CallFunc => {
-                let actual_parameter_count: usize = self.state.pop();
+            LinkedInstruction::CallFunc {
+                function_name,
+                parameter_count,
+            } => {
+                // Linking already read the number of parameters from the instruction that pushed it
+                self.pop_value()?;
                 // Get the parameters, which were pushed in reverse
                 let parameters = {
-                    let mut parameters: Vec<_> = (0..actual_parameter_count)
-                        .rev()
-                        .map(|_| self.state.pop_value().raw_value)
-                        .collect();
+                    let mut parameters = (0..*parameter_count)
+                        .map(|_| self.pop_value().map(|value| value.raw_value))
+                        .collect::<Result<Vec<_>>>()?;
                     parameters.reverse();
                     parameters
                 };
 
                 // Call a function, whose parameters are expected to be on the stack. Pushes the function's return value, if it returns one.
-                let function_name: String = instruction.read_operand(0);
                 let function =
                     self.library
-                        .get(&function_name)
+                        .get(function_name)
                         .ok_or(DialogueError::FunctionNotFound {
                             function_name: function_name.to_string(),
                             library: self.library.clone(),
                         })?;
 
-                // Expect the compiler to have placed the number of parameters
-                // actually passed at the top of the stack.
+                // The library may have been changed since the program was linked, so the number of parameters can only be checked now.
                 let expected_parameter_count = function.parameter_types().len();
-
-                assert_eq!(
-                    expected_parameter_count, actual_parameter_count,
-                    "Function {function_name} expected {expected_parameter_count} parameters, but received {actual_parameter_count}",
-                );
+                if expected_parameter_count != *parameter_count {
+                    return Err(DialogueError::InvalidProgram {
+                        node_name: self.current_node_name.clone().unwrap_or_default(),
+                        instruction_index: Some(self.state.program_counter),
+                        reason: format!("The function {function_name} expects {expected_parameter_count} parameters, but is called with {parameter_count}"),
+                    });
+                }
 
                 // Invoke the function
+                let arguments = (!self.observers.is_empty()).then(|| parameters.clone());
                 let return_value = function_call_fn(function, parameters);
-                let return_type = function
-                    .return_type()
-                    .try_into()
-                    .unwrap_or_else(|e| panic!("Failed to get Yarn type for return type id of function {function_name}: {e:?}"));
+                if let Some(arguments) = arguments {
+                    self.notify(|observer| {
+                        observer.function_called(function_name, &arguments, &return_value)
+                    });
+                }
+                let return_type = function.return_type().try_into().map_err(|_| {
+                    DialogueError::UnsupportedFunctionReturnType {
+                        function_name: function_name.to_string(),
+                    }
+                })?;
                 let typed_return_value = InternalValue {
                     raw_value: return_value,
                     r#type: return_type,
@@ -480,71 +592,280 @@ impl VirtualMachine {
                 self.state.push(typed_return_value);
                 self.state.program_counter += 1;
             }
-            OpCode::PushVariable => {
+            LinkedInstruction::PushVariable {
+                variable_name,
+                source,
+            } => {
                 // Get the contents of a variable, push that onto the stack.
-                let variable_name: String = instruction.read_operand(0);
-                let loaded_value = self
-                    .variable_storage
-                    .get(&variable_name)
-                    .or_else(|e| {
-                        if let VariableStorageError::VariableNotFound { .. } = e {
-                            // We don't have a value for this. The initial
-                            // value may be found in the program. (If it's
-                            // not, then the variable's value is undefined,
-                            // which isn't allowed.)
+                let value = match source {
+                    VariableSource::Local => self
+                        .state
+                        .local_variables
+                        .get(variable_name.as_ref())
+                        .cloned()
+                        .ok_or_else(|| VariableStorageError::VariableNotFound {
+                            name: variable_name.to_string(),
+                        })?,
+                    VariableSource::Smart => {
+                        self.evaluate_smart_variable(variable_name, &mut function_call_fn)?
+                    }
+                    VariableSource::Storage => {
+                        let value = self.variable_storage.get(variable_name).or_else(|e| {
+                            let VariableStorageError::VariableNotFound { .. } = e else {
+                                return Err(e);
+                            };
+                            // We don't have a value for this, so fall back to the program's
+                            // initial value. Variables without one must have been set by the game.
                             let initial_value = self
                                 .program
                                 .as_ref()
                                 .unwrap()
+                                .program()
                                 .initial_values
-                                .get(&variable_name)
-                                .unwrap_or_else(|| panic!("The loaded program does not contain an initial value for the variable {variable_name}"))
+                                .get(variable_name.as_ref())
+                                .ok_or(e)?
                                 .clone();
 
                             // Store the initial value in the variable_storage
-                            self.variable_storage.set(variable_name.clone(), initial_value.clone().into())?;
+                            self.variable_storage
+                                .set(variable_name.to_string(), initial_value.clone().into())?;
 
                             Ok(initial_value.into())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
-                self.state.push(loaded_value);
+                        })?;
+                        InternalValue::from(value)
+                    }
+                };
+                if !Library::is_internal_variable(variable_name) {
+                    self.notify(|observer| observer.variable_read(variable_name, &value.raw_value));
+                }
+                self.state.push(value);
                 self.state.program_counter += 1;
             }
-            OpCode::StoreVariable => {
+            LinkedInstruction::StoreVariable(variable_name) => {
                 // Store the top value on the stack in a variable.
-                let top_value = self.state.peek_value().clone();
-                let variable_name: String = instruction.read_operand(0);
-                self.variable_storage.set(variable_name, top_value.into())?;
+                let top_value = self.peek_value()?.clone();
+                let raw_value = top_value.raw_value.clone();
+                if self.is_local_variable(variable_name) {
+                    self.state
+                        .local_variables
+                        .insert(variable_name.to_string(), top_value);
+                } else {
+                    self.variable_storage
+                        .set(variable_name.to_string(), top_value.into())?;
+                }
+                if !Library::is_internal_variable(variable_name) {
+                    self.notify(|observer| observer.variable_written(variable_name, &raw_value));
+                }
                 self.state.program_counter += 1;
             }
-            OpCode::Stop => {
+            LinkedInstruction::Stop => {
                 // Immediately stop execution, and report that fact.
-                let current_node_name = self.current_node_name.clone().unwrap();
-                self.batched_events
-                    .push(DialogueEvent::NodeComplete(current_node_name));
-                self.batched_events.push(DialogueEvent::DialogueComplete);
-                self.set_execution_state(ExecutionState::Stopped);
+                self.complete_dialogue();
 
                 self.state.program_counter += 1;
             }
-            OpCode::RunNode => {
+            LinkedInstruction::RunNode => {
                 // Run a node
 
                 // Pop a string from the stack, and jump to a node
                 // with that name.
-                let node_name: String = self.state.pop();
-                self.batched_events
-                    .push(DialogueEvent::NodeComplete(node_name.clone()));
+                let node_name: String = self.pop()?;
+                self.get_node_from_name(&node_name)?;
+                let current_node_name = self.current_node_name.clone().unwrap();
+                self.push_event(DialogueEvent::NodeComplete(current_node_name));
+
+                // A jump replaces the current node, but not the nodes that detoured into it,
+                // so a `<<return>>` in the new node still goes back to the caller.
+                let call_stack = core::mem::take(&mut self.state.call_stack);
                 self.set_node(&node_name)?;
+                self.state.call_stack = call_stack;
 
                 // No need to increment the program counter, since otherwise we'd skip the first instruction
             }
+            LinkedInstruction::AddSaliencyCandidate {
+                content_id,
+                destination,
+            } => {
+                // Only candidates whose condition passed are eligible
+                let condition_passed: bool = self.pop()?;
+                if condition_passed {
+                    self.state.saliency_candidates.push(SaliencyCandidate {
+                        content_id: content_id.to_string(),
+                        destination: destination.to_string(),
+                    });
+                }
+                self.state.program_counter += 1;
+            }
+            LinkedInstruction::SelectSaliencyCandidate { fallback } => {
+                // Push the label of the selected candidate, so that a `Jump` can go there
+                let candidates = core::mem::take(&mut self.state.saliency_candidates);
+                let selection = if candidates.is_empty() {
+                    None
+                } else {
+                    self.saliency_strategy.select(&candidates)
+                };
+                let destination = match selection.and_then(|index| candidates.get(index)) {
+                    Some(candidate) => candidate.destination.clone(),
+                    None => fallback.to_string(),
+                };
+                self.state.push(destination);
+                self.state.program_counter += 1;
+            }
+            LinkedInstruction::DetourToNode => {
+                // Pop a string from the stack, and run the node with that name
+                // until it returns.
+                let node_name: String = self.pop()?;
+                self.detour_to_node(&node_name)?;
+
+                // No need to increment the program counter, since the caller's frame already points past this instruction
+            }
+            LinkedInstruction::Return => {
+                // Go back to the node that detoured into this one.
+                // If there is none, this behaves like `Stop`.
+                if !self.return_from_node()? {
+                    self.complete_dialogue();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluates the conditions of the members of the node group `group_name` and returns the ones that passed,
+    /// without selecting or running any of them.
+    pub(crate) fn node_group_candidates(
+        &mut self,
+        group_name: &str,
+        mut function_call_fn: impl FnMut(&dyn UntypedYarnFn, Vec<YarnValue>) -> YarnValue,
+    ) -> Result<Vec<SaliencyCandidate>> {
+        let node = self.get_node_from_name(group_name)?;
+        if !node.is_node_group {
+            return Err(DialogueError::InvalidNodeGroup {
+                node_name: group_name.to_owned(),
+            });
+        }
+
+        // The group's node starts with the conditions. Run them on a scratch state so that the current dialogue is not affected.
+        // Nothing actually runs, so don't tell the observers about the variables and functions the conditions use either.
+        let observers = core::mem::take(&mut self.observers);
+        let state = core::mem::take(&mut self.state);
+        let current_node = self.current_node.replace(node.clone());
+        let current_node_name = self.current_node_name.replace(group_name.to_owned());
+        let mut result = Ok(());
+        while let Some(instruction) = node.instructions.get(self.state.program_counter) {
+            if matches!(
+                instruction,
+                LinkedInstruction::SelectSaliencyCandidate { .. }
+            ) {
+                break;
+            }
+            result = self.run_instruction(instruction, &mut function_call_fn);
+            if result.is_err() {
+                break;
+            }
         }
+        let candidates = core::mem::take(&mut self.state.saliency_candidates);
+        self.observers = observers;
+        self.state = state;
+        self.current_node = current_node;
+        self.current_node_name = current_node_name;
+        result.map(|_| candidates)
+    }
+
+    /// Computes the current value of a smart variable by running the node named after it on a scratch state.
+    fn evaluate_smart_variable(
+        &mut self,
+        variable_name: &str,
+        function_call_fn: &mut dyn FnMut(&dyn UntypedYarnFn, Vec<YarnValue>) -> YarnValue,
+    ) -> Result<InternalValue> {
+        // Linking made sure that the node exists. Only the `Arc` is cloned, not the node.
+        let node = self.program.as_ref().unwrap().linked().nodes[variable_name].clone();
+        let state = core::mem::take(&mut self.state);
+        let current_node = self.current_node.replace(node.clone());
+        let mut result = Ok(());
+        while let Some(instruction) = node.instructions.get(self.state.program_counter) {
+            result = self.run_instruction(instruction, &mut *function_call_fn);
+            if result.is_err() {
+                break;
+            }
+        }
+        let value = self.state.stack.pop();
+        self.state = state;
+        self.current_node = current_node;
+        result?;
+        value.ok_or_else(|| DialogueError::InvalidProgram {
+            node_name: node.name.clone(),
+            instruction_index: None,
+            reason: "The smart variable did not produce a value".to_owned(),
+        })
+    }
+
+    /// Runs `node_name`, remembering where to resume once it returns.
+    fn detour_to_node(&mut self, node_name: &str) -> Result<()> {
+        // Check that the node exists before taking the caller's state apart,
+        // so that it stays intact if it doesn't
+        self.get_node_from_name(node_name)?;
+        let caller = CallFrame {
+            node_name: self.current_node_name.clone().unwrap(),
+            program_counter: self.state.program_counter + 1,
+            local_variables: core::mem::take(&mut self.state.local_variables),
+        };
+        let mut call_stack = core::mem::take(&mut self.state.call_stack);
+        call_stack.push(caller);
+        let stack = core::mem::take(&mut self.state.stack);
+
+        self.set_node(node_name)?;
+
+        self.state.call_stack = call_stack;
+        self.state.stack = stack;
         Ok(())
     }
 
+    /// Completes the current node and resumes the node that detoured into it.
+    /// Returns `false` without doing anything if there is no such node.
+    fn return_from_node(&mut self) -> Result<bool> {
+        let Some(caller) = self.state.call_stack.pop() else {
+            return Ok(false);
+        };
+        let current_node_name = self.current_node_name.clone().unwrap();
+        debug!(
+            "Returning from node \"{current_node_name}\" to \"{}\"",
+            caller.node_name
+        );
+        self.push_event(DialogueEvent::NodeComplete(current_node_name));
+
+        self.current_node = Some(self.get_node_from_name(&caller.node_name)?);
+        self.current_node_name = Some(caller.node_name);
+        self.state.program_counter = caller.program_counter;
+        self.state.local_variables = caller.local_variables;
+        Ok(true)
+    }
+
+    /// Completes the current node and every node that detoured into it, then stops.
+    fn complete_dialogue(&mut self) {
+        let current_node_name = self.current_node_name.clone().unwrap();
+        self.push_event(DialogueEvent::NodeComplete(current_node_name));
+        let call_stack = core::mem::take(&mut self.state.call_stack);
+        for caller in call_stack.into_iter().rev() {
+            self.push_event(DialogueEvent::NodeComplete(caller.node_name));
+        }
+        self.push_event(DialogueEvent::DialogueComplete);
+        self.set_execution_state(ExecutionState::Stopped);
+    }
+
+    fn is_node_group(&self, node_name: &str) -> bool {
+        self.program
+            .as_ref()
+            .and_then(|program| program.linked().nodes.get(node_name))
+            .is_some_and(|node| node.is_node_group)
+    }
+
+    /// Local variables live in the [`State`] instead of the variable storage.
+    fn is_local_variable(&self, variable_name: &str) -> bool {
+        self.current_node
+            .as_ref()
+            .is_some_and(|node| node.is_local_variable(variable_name))
+    }
+
     fn prepare_line(&mut self, string_id: LineId, substitutions: &[String]) -> Result<Line> {
         let line_text = self.text_provider.get_text(&string_id).ok_or_else(|| {
             DialogueError::LineProviderError {
@@ -565,51 +886,78 @@ impl VirtualMachine {
     }
 
     /// Looks up the instruction number for a named label in the current node.
-    ///
-    /// # Panics
-    ///
-    /// Panics in the following cases:
-    /// - The label is not found in the current node
-    /// - The current node is unset
-    /// - The found instruction point is negative
-    fn find_instruction_point_for_label(&self, label_name: &str) -> usize {
-        self.current_node
-            .as_ref()
-            .unwrap()
+    /// Only needed for [`LinkedInstruction::Jump`], since all other jumps were resolved when linking.
+    fn find_instruction_point_for_label(&self, label_name: &str) -> Result<usize> {
+        let current_node = self.current_node.as_ref().unwrap();
+        current_node
             .labels
             .get(label_name)
             .copied()
-            .unwrap_or_else(|| {
-                panic!(
-                    "Unknown label {label_name} in node {}",
-                    self.current_node_name.as_ref().unwrap()
-                )
+            .ok_or_else(|| DialogueError::InvalidProgram {
+                node_name: current_node.name.clone(),
+                instruction_index: Some(self.state.program_counter),
+                reason: format!("Unknown label \"{label_name}\""),
             })
-            .try_into()
-            .unwrap()
     }
 
-    fn pop_substitutions_with_count_at_operand(
-        &mut self,
-        instruction: &Instruction,
-        index: usize,
-    ) -> Vec<String> {
-        let expression_count: usize = instruction.operands[index].clone().try_into().unwrap();
-        let mut values: Vec<_> = (0..expression_count)
-            .rev()
-            .map(|_| self.state.pop())
-            .collect();
+    fn pop_substitutions(&mut self, expression_count: usize) -> Result<Vec<String>> {
+        let mut values = (0..expression_count)
+            .map(|_| self.pop())
+            .collect::<Result<Vec<_>>>()?;
         values.reverse();
-        values
+        Ok(values)
+    }
+
+    /// Pops a value from the stack and converts it to the type the current instruction expects.
+    fn pop<T>(&mut self) -> Result<T>
+    where
+        T: TryFrom<InternalValue>,
+        <T as TryFrom<InternalValue>>::Error: Debug,
+    {
+        let value = self.pop_value()?;
+        self.convert_stack_value(value)
+    }
+
+    fn pop_value(&mut self) -> Result<InternalValue> {
+        self.state.stack.pop().ok_or_else(|| {
+            self.invalid_instruction("Tried to pop a value, but the stack was empty")
+        })
+    }
+
+    /// Copies the top value of the stack and converts it to the type the current instruction expects.
+    fn peek<T>(&self) -> Result<T>
+    where
+        T: TryFrom<InternalValue>,
+        <T as TryFrom<InternalValue>>::Error: Debug,
+    {
+        let value = self.peek_value()?.clone();
+        self.convert_stack_value(value)
     }
-}
 
-fn assert_up_to_date_compiler(predicate: bool) {
-    assert!(
-        predicate,
-        "The Yarn script provided was compiled using an older compiler. \
-        Please recompile it using the latest version of either Yarn Spinner or Yarn Spinner."
-    )
+    fn peek_value(&self) -> Result<&InternalValue> {
+        self.state.stack.last().ok_or_else(|| {
+            self.invalid_instruction("Tried to peek a value, but the stack was empty")
+        })
+    }
+
+    fn convert_stack_value<T>(&self, value: InternalValue) -> Result<T>
+    where
+        T: TryFrom<InternalValue>,
+        <T as TryFrom<InternalValue>>::Error: Debug,
+    {
+        value.try_into().map_err(|e| {
+            self.invalid_instruction(format!("Failed to convert the value on the stack: {e:?}"))
+        })
+    }
+
+    /// The error for the current instruction not being able to run, e.g. because it expects a value that isn't on the stack.
+    fn invalid_instruction(&self, reason: impl Into<String>) -> DialogueError {
+        DialogueError::InvalidProgram {
+            node_name: self.current_node_name.clone().unwrap_or_default(),
+            instruction_index: Some(self.state.program_counter),
+            reason: reason.into(),
+        }
+    }
 }
 
 /// Replaces all substitution markers in a text with the given substitution list.
@@ -0,0 +1,553 @@
+//! The form of a [`Program`] that the [`VirtualMachine`] executes.
+//!
+//! Linking happens once when a program is loaded. It decodes every opcode and its operands,
+//! resolves the labels of jumps to instruction indices and shares equal strings between all instructions,
+//! so that running an instruction neither looks anything up by name nor allocates for its operands.
+//! Malformed programs are rejected while linking instead of failing halfway through a dialogue.
+
+use crate::prelude::*;
+use crate::Result;
+use alloc::sync::Arc;
+use bevy_platform::collections::{HashMap, HashSet};
+use core::fmt::Display;
+
+/// A [`Program`] whose nodes were linked, see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct LinkedProgram {
+    pub(crate) nodes: HashMap<String, Arc<LinkedNode>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LinkedNode {
+    pub(crate) name: String,
+    pub(crate) instructions: Vec<LinkedInstruction>,
+    /// The instruction index of every label, for the jumps whose destination is only known at runtime.
+    pub(crate) labels: HashMap<String, usize>,
+    pub(crate) local_variables: Vec<Arc<str>>,
+    pub(crate) is_smart_variable: bool,
+    /// Whether this node only selects which member of the node group named after it is run.
+    pub(crate) is_node_group: bool,
+}
+
+/// An [`Instruction`] with its [`OpCode`] and operands decoded. See [`OpCode`] for what each instruction does.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum LinkedInstruction {
+    JumpTo(usize),
+    Jump,
+    RunLine {
+        line_id: Arc<str>,
+        substitution_count: usize,
+    },
+    RunCommand {
+        text: Arc<str>,
+        substitution_count: usize,
+    },
+    AddOption {
+        line_id: Arc<str>,
+        destination: Arc<str>,
+        substitution_count: usize,
+        has_condition: bool,
+    },
+    ShowOptions,
+    PushString(Arc<str>),
+    PushFloat(f32),
+    PushBool(bool),
+    JumpIfFalse(usize),
+    Pop,
+    CallFunc {
+        function_name: Arc<str>,
+        /// The count pushed by the preceding [`LinkedInstruction::PushFloat`].
+        parameter_count: usize,
+    },
+    PushVariable {
+        variable_name: Arc<str>,
+        source: VariableSource,
+    },
+    StoreVariable(Arc<str>),
+    Stop,
+    RunNode,
+    AddSaliencyCandidate {
+        content_id: Arc<str>,
+        destination: Arc<str>,
+    },
+    SelectSaliencyCandidate {
+        fallback: Arc<str>,
+    },
+    DetourToNode,
+    Return,
+}
+
+/// Where the value of a variable read by [`LinkedInstruction::PushVariable`] comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum VariableSource {
+    /// The [`State`] of the current node, see [`LinkedNode::local_variables`].
+    Local,
+    /// Running the node named after the smart variable.
+    Smart,
+    /// The variable storage, which is given the program's initial value for the variable if it doesn't have one yet.
+    /// Variables without an initial value must be set in the storage before they are read.
+    Storage,
+}
+
+impl LinkedProgram {
+    /// Links all nodes of `program`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`DialogueError::InvalidProgram`] for the first instruction or label of a node that can't be run.
+    pub(crate) fn link(program: &Program) -> Result<Self> {
+        let mut strings = Interner::default();
+        let node_groups: HashSet<&str> = program
+            .nodes
+            .values()
+            .filter_map(Node::node_group)
+            .collect();
+        let nodes = program
+            .nodes
+            .iter()
+            .map(|(name, node)| {
+                let node = link_node(program, node, &node_groups, &mut strings)?;
+                Ok((name.clone(), Arc::new(node)))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { nodes })
+    }
+}
+
+impl LinkedNode {
+    /// Local variables live in the [`State`] instead of the variable storage.
+    pub(crate) fn is_local_variable(&self, variable_name: &str) -> bool {
+        self.local_variables
+            .iter()
+            .any(|name| name.as_ref() == variable_name)
+    }
+}
+
+fn link_node(
+    program: &Program,
+    node: &Node,
+    node_groups: &HashSet<&str>,
+    strings: &mut Interner,
+) -> Result<LinkedNode> {
+    let instruction_count = node.instructions.len();
+    let mut labels = HashMap::new();
+    for (label, &index) in &node.labels {
+        let index = usize::try_from(index)
+            .ok()
+            .filter(|&index| index <= instruction_count)
+            .ok_or_else(|| {
+                invalid_program(
+                    node,
+                    None,
+                    format!("The label \"{label}\" points to the nonexistent instruction {index}"),
+                )
+            })?;
+        labels.insert(label.clone(), index);
+    }
+    let local_variables: Vec<_> = node
+        .local_variables()
+        .map(|name| strings.intern(name))
+        .collect();
+    let instructions = node
+        .instructions
+        .iter()
+        .enumerate()
+        .map(|(index, instruction)| {
+            let linker = InstructionLinker {
+                program,
+                instruction,
+                previous: index
+                    .checked_sub(1)
+                    .and_then(|previous| node.instructions.get(previous)),
+                labels: &labels,
+                local_variables: &local_variables,
+            };
+            linker
+                .link(strings)
+                .map_err(|reason| invalid_program(node, Some(index), reason))
+        })
+        .collect::<Result<_>>()?;
+    Ok(LinkedNode {
+        name: node.name.clone(),
+        instructions,
+        labels,
+        local_variables,
+        is_smart_variable: node.is_smart_variable(),
+        is_node_group: node_groups.contains(node.name.as_str()),
+    })
+}
+
+fn invalid_program(
+    node: &Node,
+    instruction_index: Option<usize>,
+    reason: impl Display,
+) -> DialogueError {
+    DialogueError::InvalidProgram {
+        node_name: node.name.clone(),
+        instruction_index,
+        reason: reason.to_string(),
+    }
+}
+
+struct InstructionLinker<'a> {
+    program: &'a Program,
+    instruction: &'a Instruction,
+    previous: Option<&'a Instruction>,
+    labels: &'a HashMap<String, usize>,
+    local_variables: &'a [Arc<str>],
+}
+
+impl InstructionLinker<'_> {
+    fn link(&self, strings: &mut Interner) -> core::result::Result<LinkedInstruction, String> {
+        let opcode = OpCode::try_from(self.instruction.opcode)
+            .map_err(|_| format!("{} is not a valid opcode", self.instruction.opcode))?;
+        let instruction = match opcode {
+            OpCode::JumpTo => LinkedInstruction::JumpTo(self.label(0)?),
+            OpCode::Jump => LinkedInstruction::Jump,
+            OpCode::RunLine => LinkedInstruction::RunLine {
+                line_id: self.string(0, strings)?,
+                substitution_count: self.count(1)?,
+            },
+            OpCode::RunCommand => LinkedInstruction::RunCommand {
+                text: self.string(0, strings)?,
+                substitution_count: self.count(1)?,
+            },
+            OpCode::AddOption => {
+                self.label(1)?;
+                LinkedInstruction::AddOption {
+                    line_id: self.string(0, strings)?,
+                    destination: self.string(1, strings)?,
+                    substitution_count: self.count(2)?,
+                    has_condition: self.bool(3)?,
+                }
+            }
+            OpCode::ShowOptions => LinkedInstruction::ShowOptions,
+            OpCode::PushString => LinkedInstruction::PushString(self.string(0, strings)?),
+            OpCode::PushFloat => LinkedInstruction::PushFloat(self.float(0)?),
+            OpCode::PushBool => LinkedInstruction::PushBool(self.bool(0)?),
+            OpCode::PushNull => {
+                return Err("PushNull is no longer valid op code, because null is no longer a valid value from Yarn Spinner 2.0 onwards. To fix this error, re-compile the original source code.".to_owned());
+            }
+            OpCode::JumpIfFalse => LinkedInstruction::JumpIfFalse(self.label(0)?),
+            OpCode::Pop => LinkedInstruction::Pop,
+            OpCode::CallFunc => LinkedInstruction::CallFunc {
+                function_name: self.string(0, strings)?,
+                parameter_count: self.parameter_count()?,
+            },
+            OpCode::PushVariable => {
+                let variable_name = self.string(0, strings)?;
+                let source = self.variable_source(&variable_name);
+                LinkedInstruction::PushVariable {
+                    variable_name,
+                    source,
+                }
+            }
+            OpCode::StoreVariable => LinkedInstruction::StoreVariable(self.string(0, strings)?),
+            OpCode::Stop => LinkedInstruction::Stop,
+            OpCode::RunNode => LinkedInstruction::RunNode,
+            OpCode::AddSaliencyCandidate => {
+                self.label(1)?;
+                LinkedInstruction::AddSaliencyCandidate {
+                    content_id: self.string(0, strings)?,
+                    destination: self.string(1, strings)?,
+                }
+            }
+            OpCode::SelectSaliencyCandidate => {
+                self.label(0)?;
+                LinkedInstruction::SelectSaliencyCandidate {
+                    fallback: self.string(0, strings)?,
+                }
+            }
+            OpCode::DetourToNode => LinkedInstruction::DetourToNode,
+            OpCode::Return => LinkedInstruction::Return,
+        };
+        Ok(instruction)
+    }
+
+    fn operand(&self, index: usize) -> core::result::Result<&OperandValue, String> {
+        self.instruction
+            .operands
+            .get(index)
+            .and_then(|operand| operand.value.as_ref())
+            .ok_or_else(|| {
+                format!(
+                    "Missing operand {index}. The program was probably compiled with an older compiler, please recompile it"
+                )
+            })
+    }
+
+    fn string(
+        &self,
+        index: usize,
+        strings: &mut Interner,
+    ) -> core::result::Result<Arc<str>, String> {
+        match self.operand(index)? {
+            OperandValue::StringValue(value) => Ok(strings.intern(value)),
+            other => Err(format!(
+                "Expected operand {index} to be a string, but found {other:?}"
+            )),
+        }
+    }
+
+    fn float(&self, index: usize) -> core::result::Result<f32, String> {
+        match self.operand(index)? {
+            OperandValue::FloatValue(value) => Ok(*value),
+            other => Err(format!(
+                "Expected operand {index} to be a number, but found {other:?}"
+            )),
+        }
+    }
+
+    fn bool(&self, index: usize) -> core::result::Result<bool, String> {
+        match self.operand(index)? {
+            OperandValue::BoolValue(value) => Ok(*value),
+            other => Err(format!(
+                "Expected operand {index} to be a bool, but found {other:?}"
+            )),
+        }
+    }
+
+    /// Counts are stored as floats, since there are no integer operands.
+    fn count(&self, index: usize) -> core::result::Result<usize, String> {
+        let count = self.float(index)?;
+        if count >= 0.0 && count.fract() == 0.0 {
+            Ok(count as usize)
+        } else {
+            Err(format!(
+                "Expected operand {index} to be a count, but found {count}"
+            ))
+        }
+    }
+
+    /// The compiler pushes the number of parameters right before calling a function.
+    fn parameter_count(&self) -> core::result::Result<usize, String> {
+        let previous = self
+            .previous
+            .filter(|previous| previous.opcode == i32::from(OpCode::PushFloat))
+            .ok_or(
+                "Expected the number of parameters to be pushed right before the function call",
+            )?;
+        let linker = InstructionLinker {
+            instruction: previous,
+            ..*self
+        };
+        linker.count(0)
+    }
+
+    /// Variables that are neither local nor smart are read from the variable storage,
+    /// even without an initial value in the program, e.g. ones that are only set by the game.
+    fn variable_source(&self, variable_name: &str) -> VariableSource {
+        if self
+            .local_variables
+            .iter()
+            .any(|name| name.as_ref() == variable_name)
+        {
+            VariableSource::Local
+        } else if self
+            .program
+            .nodes
+            .get(variable_name)
+            .is_some_and(Node::is_smart_variable)
+        {
+            VariableSource::Smart
+        } else {
+            VariableSource::Storage
+        }
+    }
+
+    fn label(&self, index: usize) -> core::result::Result<usize, String> {
+        match self.operand(index)? {
+            OperandValue::StringValue(label) => self
+                .labels
+                .get(label)
+                .copied()
+                .ok_or_else(|| format!("Unknown label \"{label}\"")),
+            other => Err(format!(
+                "Expected operand {index} to be a label, but found {other:?}"
+            )),
+        }
+    }
+}
+
+/// Hands out a single shared allocation for every distinct string.
+#[derive(Debug, Default)]
+struct Interner(HashSet<Arc<str>>);
+
+impl Interner {
+    fn intern(&mut self, string: &str) -> Arc<str> {
+        if let Some(interned) = self.0.get(string) {
+            return interned.clone();
+        }
+        let interned: Arc<str> = Arc::from(string);
+        self.0.insert(interned.clone());
+        interned
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instruction(opcode: OpCode, operands: impl IntoIterator<Item = Operand>) -> Instruction {
+        Instruction {
+            opcode: opcode.into(),
+            operands: operands.into_iter().collect(),
+        }
+    }
+
+    fn program(instructions: Vec<Instruction>, labels: &[(&str, i32)]) -> Program {
+        let node = Node {
+            name: "Start".to_owned(),
+            instructions,
+            labels: labels
+                .iter()
+                .map(|(label, index)| (label.to_string(), *index))
+                .collect(),
+            ..Default::default()
+        };
+        Program {
+            nodes: [("Start".to_owned(), node)].into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    fn link_error(program: &Program) -> (Option<usize>, String) {
+        match LinkedProgram::link(program) {
+            Err(DialogueError::InvalidProgram {
+                node_name,
+                instruction_index,
+                reason,
+            }) => {
+                assert_eq!("Start", node_name);
+                (instruction_index, reason)
+            }
+            other => panic!("Expected a link error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn resolves_labels_and_shares_strings() {
+        let program = program(
+            vec![
+                instruction(OpCode::PushVariable, ["$gold".to_owned().into()]),
+                instruction(OpCode::JumpIfFalse, ["end".to_owned().into()]),
+                instruction(OpCode::StoreVariable, ["$gold".to_owned().into()]),
+                instruction(
+                    OpCode::RunLine,
+                    ["line:a".to_owned().into(), 0_usize.into()],
+                ),
+            ],
+            &[("end", 4)],
+        );
+        let mut program = program;
+        program
+            .initial_values
+            .insert("$gold".to_owned(), 0.0.into());
+        let linked = LinkedProgram::link(&program).unwrap();
+        let node = &linked.nodes["Start"];
+        assert_eq!(LinkedInstruction::JumpIfFalse(4), node.instructions[1]);
+        let (
+            LinkedInstruction::PushVariable {
+                variable_name: pushed,
+                source: VariableSource::Storage,
+            },
+            LinkedInstruction::StoreVariable(stored),
+        ) = (&node.instructions[0], &node.instructions[2])
+        else {
+            panic!("Unexpected instructions {:?}", node.instructions);
+        };
+        assert!(Arc::ptr_eq(pushed, stored));
+        assert_eq!(
+            LinkedInstruction::RunLine {
+                line_id: "line:a".into(),
+                substitution_count: 0
+            },
+            node.instructions[3]
+        );
+    }
+
+    #[test]
+    fn reads_variables_without_initial_value_from_storage() {
+        let program = program(
+            vec![instruction(
+                OpCode::PushVariable,
+                ["$gold".to_owned().into()],
+            )],
+            &[],
+        );
+        let linked = LinkedProgram::link(&program).unwrap();
+        assert_eq!(
+            LinkedInstruction::PushVariable {
+                variable_name: "$gold".into(),
+                source: VariableSource::Storage,
+            },
+            linked.nodes["Start"].instructions[0]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_instructions() {
+        let jump = program(
+            vec![instruction(OpCode::JumpTo, ["nowhere".to_owned().into()])],
+            &[],
+        );
+        assert_eq!(
+            (Some(0), "Unknown label \"nowhere\"".to_owned()),
+            link_error(&jump)
+        );
+
+        let mut opcode = program(vec![instruction(OpCode::Stop, [])], &[]);
+        opcode.nodes.get_mut("Start").unwrap().instructions[0].opcode = 1000;
+        assert_eq!(
+            (Some(0), "1000 is not a valid opcode".to_owned()),
+            link_error(&opcode)
+        );
+
+        let operand = program(
+            vec![
+                instruction(OpCode::Pop, []),
+                instruction(OpCode::PushVariable, [true.into()]),
+            ],
+            &[],
+        );
+        assert_eq!(
+            (
+                Some(1),
+                "Expected operand 0 to be a string, but found BoolValue(true)".to_owned()
+            ),
+            link_error(&operand)
+        );
+
+        let old_line = program(
+            vec![instruction(OpCode::RunLine, ["line:a".to_owned().into()])],
+            &[],
+        );
+        assert_eq!(Some(0), link_error(&old_line).0);
+
+        let call = program(
+            vec![
+                instruction(OpCode::PushFloat, [1.0.into()]),
+                instruction(OpCode::PushString, ["count".to_owned().into()]),
+                instruction(OpCode::CallFunc, ["dice".to_owned().into()]),
+            ],
+            &[],
+        );
+        assert_eq!(
+            (
+                Some(2),
+                "Expected the number of parameters to be pushed right before the function call"
+                    .to_owned()
+            ),
+            link_error(&call)
+        );
+
+        let label = program(vec![instruction(OpCode::Stop, [])], &[("after", 2)]);
+        assert_eq!(
+            (
+                None,
+                "The label \"after\" points to the nonexistent instruction 2".to_owned()
+            ),
+            link_error(&label)
+        );
+    }
+}
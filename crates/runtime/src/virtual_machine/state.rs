@@ -1,6 +1,7 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner/VirtualMachine.cs>, which we split into multiple files
 
 use crate::prelude::*;
+use bevy_platform::collections::HashMap;
 use core::fmt::Debug;
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -21,55 +22,40 @@ pub(crate) struct State {
 
     /// The value stack.
     pub(crate) stack: Vec<InternalValue>,
-}
 
-impl State {
-    pub(crate) fn push(&mut self, value: impl Into<InternalValue>) {
-        self.stack.push(value.into())
-    }
+    /// The values of the variables declared with `<<local>>` in the current node.
+    /// These are dropped together with the rest of the state when the node completes.
+    pub(crate) local_variables: HashMap<String, InternalValue>,
 
-    /// Pops a value from the stack and tries to convert it to the specified type.
-    ///
-    /// ## Panics
-    /// - Panics on an empty stack to mirror C# behavior.
-    /// - Panics if the value cannot be converted to the specified type.
-    pub(crate) fn pop<T>(&mut self) -> T
-    where
-        T: TryFrom<InternalValue>,
-        <T as TryFrom<InternalValue>>::Error: Debug,
-    {
-        self.pop_value()
-            .try_into()
-            .unwrap_or_else(|e| panic!("Failed to convert popped value: {e:?}",))
-    }
+    /// The nodes that `<<detour>>`ed into the current one, with the innermost caller last.
+    pub(crate) call_stack: Vec<CallFrame>,
 
-    /// Pops a value from the stack. Panics on an empty stack to mirror C# behavior.
-    pub(crate) fn pop_value(&mut self) -> InternalValue {
-        self.stack
-            .pop()
-            .unwrap_or_else(|| panic!("Tried to pop value, but the stack was empty."))
-    }
+    /// The candidates that the next `SelectSaliencyCandidate` instruction picks from.
+    pub(crate) saliency_candidates: Vec<SaliencyCandidate>,
+}
 
-    /// Peeks the top value of the stack. Panics on an empty stack to mirror C# behavior.
-    pub(crate) fn peek<T>(&self) -> T
-    where
-        T: TryFrom<InternalValue>,
-        <T as TryFrom<InternalValue>>::Error: Debug,
-    {
-        self.peek_value()
-            .clone()
-            .try_into()
-            .unwrap_or_else(|e| panic!("Failed to convert popped value: {e:?}",))
-    }
+/// Where to resume execution after returning from a `<<detour>>`.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy", reflect(Debug, PartialEq, Default))]
+#[cfg_attr(
+    all(feature = "bevy", feature = "serde"),
+    reflect(Serialize, Deserialize)
+)]
+pub(crate) struct CallFrame {
+    /// The name of the node that detoured.
+    pub(crate) node_name: String,
+
+    /// The instruction to resume at, i.e. the one after the detour.
+    pub(crate) program_counter: usize,
+
+    /// The local variables of the node that detoured.
+    pub(crate) local_variables: HashMap<String, InternalValue>,
+}
 
-    /// Copies the top value of the stack and tries to convert it to the specified type.
-    ///
-    /// ## Panics
-    /// - Panics on an empty stack to mirror C# behavior.
-    /// - Panics if the value cannot be converted to the specified type.
-    pub(crate) fn peek_value(&self) -> &InternalValue {
-        self.stack
-            .last()
-            .unwrap_or_else(|| panic!("Tried to peek value, but the stack was empty."))
+impl State {
+    pub(crate) fn push(&mut self, value: impl Into<InternalValue>) {
+        self.stack.push(value.into())
     }
 }
@@ -0,0 +1,414 @@
+//! `ysc`, the Yarn Spinner compiler for the command line.
+//!
+//! ```text
+//! ysc compile [--output-directory DIR] [--output-name NAME] [--optimize] PATH...
+//! ysc check [--format text|json|sarif] PATH...
+//! ysc tag PATH...
+//! ysc strings [--output FILE] PATH...
+//! ysc dump PATH...
+//! ysc graph [--output FILE] PATH...
+//! ```
+//!
+//! Every `PATH` is either a Yarn file or a directory that is searched recursively for Yarn files.
+//! `dump` additionally accepts a compiled `.yarnc` program.
+//!
+//! - `compile` writes the program as `NAME.yarnc` (serialized with protobuf, like the original Yarn Spinner does)
+//!   and its string table as `NAME-Lines.csv` into `DIR`. `NAME` defaults to `Output` and `DIR` to the current directory.
+//!   `--optimize` optimizes the program, see [`Compiler::with_optimization`].
+//! - `check` prints all diagnostics without writing anything.
+//! - `tag` adds `#line:` tags to all lines that don't have one yet, rewriting the files in place.
+//! - `strings` writes the string table as CSV to `FILE` or stdout.
+//! - `dump` prints the instructions of every node, see [`ProgramDisassembly`].
+//! - `graph` writes the jumps and detours between nodes as a [DOT](https://graphviz.org/doc/info/lang.html) graph to `FILE` or stdout.
+//!
+//! `ysc` is built with the `ysc` feature, e.g. `cargo install yarnspinner --features ysc`.
+//!
+//! The exit code is 1 if the Yarn files contain errors and 2 if `ysc` could not run at all, e.g. because a file is missing.
+
+use prost::Message;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::error::Error;
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use yarnspinner::compiler::*;
+use yarnspinner::core::{LineId, OpCode, OperandValue, Program};
+
+const USAGE: &str = "\
+Usage: ysc <COMMAND> [OPTIONS] PATH...
+
+Commands:
+  compile  Compile to NAME.yarnc and NAME-Lines.csv
+           [--output-directory DIR] [--output-name NAME] [--optimize]
+  check    Print diagnostics, exit with 1 if there are errors
+           [--format text|json|sarif]
+  tag      Add #line: tags to untagged lines in place
+  strings  Export the string table as CSV
+           [--output FILE]
+  dump     Print the instructions of every node, also accepts .yarnc files
+  graph    Export the jumps and detours between nodes as a DOT graph
+           [--output FILE]";
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+fn main() -> ExitCode {
+    let arguments: Vec<_> = std::env::args().skip(1).collect();
+    let Some((command, arguments)) = arguments.split_first() else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+    if matches!(command.as_str(), "-h" | "--help" | "help") {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+    let result = match command.as_str() {
+        "compile" => compile(arguments),
+        "check" => check(arguments),
+        "tag" => tag(arguments),
+        "strings" => strings(arguments),
+        "dump" => dump(arguments),
+        "graph" => graph(arguments),
+        _ => Err(format!("Unknown command {command}").into()),
+    };
+    match result {
+        Ok(exit_code) => exit_code,
+        Err(error) => {
+            eprintln!("{error}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Arguments {
+    paths: Vec<PathBuf>,
+    options: BTreeMap<String, String>,
+    flags: HashSet<String>,
+}
+
+impl Arguments {
+    /// Parses the arguments of a command. The given `options` take a value, while the `flags` don't.
+    fn parse(arguments: &[String], options: &[&str], flags: &[&str]) -> Result<Self> {
+        let mut parsed = Self::default();
+        let mut arguments = arguments.iter();
+        while let Some(argument) = arguments.next() {
+            if let Some(option) = argument.strip_prefix("--") {
+                if flags.contains(&option) {
+                    parsed.flags.insert(option.to_owned());
+                } else if options.contains(&option) {
+                    let value = arguments
+                        .next()
+                        .ok_or_else(|| format!("Missing value for --{option}"))?;
+                    parsed.options.insert(option.to_owned(), value.clone());
+                } else {
+                    return Err(format!("Unknown option --{option}").into());
+                }
+            } else {
+                parsed.paths.push(PathBuf::from(argument));
+            }
+        }
+        if parsed.paths.is_empty() {
+            return Err("No files given".into());
+        }
+        Ok(parsed)
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.flags.contains(name)
+    }
+
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    /// All Yarn files in [`Arguments::paths`], with directories expanded.
+    fn yarn_files(&self) -> Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        for path in &self.paths {
+            collect_yarn_files(path, &mut files)?;
+        }
+        Ok(files)
+    }
+
+    fn compiler(&self) -> Result<Compiler> {
+        let mut compiler = Compiler::new();
+        for file in self.yarn_files()? {
+            compiler
+                .try_read_file(&file)
+                .map_err(|error| format!("{}: {error}", file.display()))?;
+        }
+        Ok(compiler)
+    }
+}
+
+fn collect_yarn_files(path: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    if !path.is_dir() {
+        files.push(path.to_owned());
+        return Ok(());
+    }
+    let mut entries = std::fs::read_dir(path)
+        .map_err(|error| format!("{}: {error}", path.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+    for entry in entries {
+        if entry.is_dir()
+            || entry
+                .extension()
+                .is_some_and(|extension| extension == "yarn")
+        {
+            collect_yarn_files(&entry, files)?;
+        }
+    }
+    Ok(())
+}
+
+/// Compiles the files, printing warnings and errors to stderr.
+/// Returns [`None`] if there were errors.
+fn compile_or_report(compiler: &Compiler) -> Option<Compilation> {
+    match compiler.compile() {
+        Ok(compilation) => {
+            for warning in &compilation.warnings {
+                eprintln!("{warning}");
+            }
+            Some(compilation)
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            None
+        }
+    }
+}
+
+fn compile(arguments: &[String]) -> Result<ExitCode> {
+    let arguments = Arguments::parse(
+        arguments,
+        &["output-directory", "output-name"],
+        &["optimize"],
+    )?;
+    let directory = arguments.option("output-directory").unwrap_or(".");
+    let name = arguments.option("output-name").unwrap_or("Output");
+    let mut compiler = arguments.compiler()?;
+    compiler.with_optimization(arguments.flag("optimize"));
+    let Some(compilation) = compile_or_report(&compiler) else {
+        return Ok(ExitCode::FAILURE);
+    };
+    let directory = Path::new(directory);
+    std::fs::create_dir_all(directory)?;
+    let program = compilation.program.unwrap_or_default();
+    std::fs::write(
+        directory.join(format!("{name}.yarnc")),
+        program.encode_to_vec(),
+    )?;
+    std::fs::write(
+        directory.join(format!("{name}-Lines.csv")),
+        string_table_csv(&compilation.string_table),
+    )?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn check(arguments: &[String]) -> Result<ExitCode> {
+    let arguments = Arguments::parse(arguments, &["format"], &[])?;
+    let format = arguments.option("format").unwrap_or("text");
+    let (diagnostics, has_errors) = match arguments.compiler()?.compile() {
+        Ok(compilation) => (compilation.warnings, false),
+        Err(error) => (error.0, true),
+    };
+    match format {
+        "text" => {
+            for diagnostic in &diagnostics {
+                println!("{diagnostic}");
+            }
+        }
+        "json" => println!("{}", diagnostics.to_json()),
+        "sarif" => println!("{}", diagnostics.to_sarif()),
+        _ => return Err(format!("Unknown format {format}").into()),
+    }
+    Ok(if has_errors {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+fn tag(arguments: &[String]) -> Result<ExitCode> {
+    let arguments = Arguments::parse(arguments, &[], &[])?;
+    let mut compiler = arguments.compiler()?;
+    compiler.with_compilation_type(CompilationType::StringsOnly);
+    let compilation = match compiler.compile() {
+        Ok(compilation) => compilation,
+        Err(error) => {
+            eprintln!("{error}");
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+    let mut existing_tags = explicit_line_ids(&compilation);
+    for file in compiler.files {
+        let tagged = match Compiler::add_tags_to_lines(file.source, existing_tags.clone()) {
+            Ok(Some(tagged)) => tagged,
+            Ok(None) => continue,
+            Err(error) => {
+                eprintln!("{error}");
+                return Ok(ExitCode::FAILURE);
+            }
+        };
+        // Make sure the next files don't get the tags that were just added to this one.
+        // Picking up a `#line:` that is not a tag is harmless, it just won't be generated.
+        existing_tags.extend(
+            tagged
+                .split_whitespace()
+                .filter(|word| word.starts_with("#line:"))
+                .map(|word| LineId(word[1..].to_owned())),
+        );
+        std::fs::write(&file.file_name, tagged)?;
+        println!("Tagged {}", file.file_name);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn explicit_line_ids(compilation: &Compilation) -> Vec<LineId> {
+    compilation
+        .string_table
+        .iter()
+        .filter(|(_, string_info)| !string_info.is_implicit_tag)
+        .map(|(line_id, _)| line_id.clone())
+        .collect()
+}
+
+fn strings(arguments: &[String]) -> Result<ExitCode> {
+    let arguments = Arguments::parse(arguments, &["output"], &[])?;
+    let output = arguments.option("output");
+    let mut compiler = arguments.compiler()?;
+    compiler.with_compilation_type(CompilationType::StringsOnly);
+    let Some(compilation) = compile_or_report(&compiler) else {
+        return Ok(ExitCode::FAILURE);
+    };
+    write_output(output, &string_table_csv(&compilation.string_table))?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// The string table in the format of the original Yarn Spinner's `-Lines.csv` files, sorted by file and line number.
+fn string_table_csv(string_table: &std::collections::HashMap<LineId, StringInfo>) -> String {
+    let mut entries: Vec<_> = string_table.iter().collect();
+    entries.sort_by(|(lhs_id, lhs), (rhs_id, rhs)| {
+        (&lhs.file_name, lhs.line_number, &lhs_id.0).cmp(&(
+            &rhs.file_name,
+            rhs.line_number,
+            &rhs_id.0,
+        ))
+    });
+    let mut csv = String::from("id,text,file,node,lineNumber\n");
+    for (line_id, string_info) in entries {
+        let fields = [
+            line_id.0.as_str(),
+            &string_info.text,
+            &string_info.file_name,
+            &string_info.node_name,
+            &string_info.line_number.to_string(),
+        ];
+        let fields: Vec<_> = fields.iter().map(|field| csv_field(field)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+fn dump(arguments: &[String]) -> Result<ExitCode> {
+    let arguments = Arguments::parse(arguments, &[], &[])?;
+    let mut disassemblies = Vec::new();
+    let mut compiler = Compiler::new();
+    for path in &arguments.paths {
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "yarnc")
+        {
+            let bytes =
+                std::fs::read(path).map_err(|error| format!("{}: {error}", path.display()))?;
+            let program = Program::decode(bytes.as_slice())
+                .map_err(|error| format!("{}: {error}", path.display()))?;
+            // Compiled programs don't come with debug info
+            disassemblies.push(program.disassemble(&HashMap::new()));
+        } else {
+            let mut files = Vec::new();
+            collect_yarn_files(path, &mut files)?;
+            for file in files {
+                compiler
+                    .try_read_file(&file)
+                    .map_err(|error| format!("{}: {error}", file.display()))?;
+            }
+        }
+    }
+    if !compiler.files.is_empty() {
+        let Some(compilation) = compile_or_report(&compiler) else {
+            return Ok(ExitCode::FAILURE);
+        };
+        let program = compilation.program.unwrap_or_default();
+        disassemblies
+            .push(program.disassemble_with_sources(&compilation.debug_info, &compiler.files));
+    }
+    print!("{}", disassemblies.join("\n"));
+    Ok(ExitCode::SUCCESS)
+}
+
+fn graph(arguments: &[String]) -> Result<ExitCode> {
+    let arguments = Arguments::parse(arguments, &["output"], &[])?;
+    let output = arguments.option("output");
+    let Some(compilation) = compile_or_report(&arguments.compiler()?) else {
+        return Ok(ExitCode::FAILURE);
+    };
+    let program = compilation.program.unwrap_or_default();
+    write_output(output, &node_graph(&program))?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Nodes that compute smart variables are left out, since they are evaluated like functions.
+/// Jumps are drawn as solid edges and detours as dashed edges.
+/// Jumps and detours to nodes whose name is only known at runtime are not part of the graph.
+fn node_graph(program: &Program) -> String {
+    let mut dot = String::from("digraph dialogue {\n");
+    for node in program.nodes.values() {
+        if node.is_smart_variable() {
+            continue;
+        }
+        writeln!(dot, "    {:?};", node.name).unwrap();
+        let mut edges = HashSet::new();
+        for instructions in node.instructions.windows(2) {
+            let (OpCode::PushString, Some(OperandValue::StringValue(destination))) = (
+                instructions[0].opcode(),
+                instructions[0]
+                    .operands
+                    .first()
+                    .and_then(|o| o.value.as_ref()),
+            ) else {
+                continue;
+            };
+            let attributes = match instructions[1].opcode() {
+                OpCode::RunNode => "",
+                OpCode::DetourToNode => " [style=dashed]",
+                _ => continue,
+            };
+            if edges.insert((destination, attributes)) {
+                writeln!(dot, "    {:?} -> {destination:?}{attributes};", node.name).unwrap();
+            }
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+fn write_output(path: Option<&str>, contents: &str) -> Result<()> {
+    match path {
+        Some(path) => std::fs::write(path, contents)?,
+        None => io::stdout().write_all(contents.as_bytes())?,
+    }
+    Ok(())
+}
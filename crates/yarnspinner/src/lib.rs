@@ -20,7 +20,7 @@ pub mod prelude {
     pub use crate::runtime::{
         Command as YarnCommand, CompiledProgramAnalyser as YarnAnalyser,
         Context as YarnAnalysisContext, Dialogue, DialogueError, DialogueEvent, DialogueOption,
-        Language, Line as YarnLine, MarkupAttribute, MarkupValue, OptionId,
+        DialogueSnapshot, Language, Line as YarnLine, MarkupAttribute, MarkupValue, OptionId,
         Result as YarnRuntimeResult, StringTable, TextProvider, VariableStorage,
     };
 }
@@ -28,10 +28,10 @@ pub mod prelude {
 pub mod core {
     //! Core types and traits that are used by both the compiler and runtime.
     pub use yarnspinner_core::prelude::{
-        optionality, yarn_fn_type, yarn_library, Header, Instruction,
-        IntoYarnValueFromNonYarnValue, InvalidOpCodeError, Library, LineId, Node, Position,
-        Program, Type, UntypedYarnFn, YarnFn, YarnFnParam, YarnFnParamItem, YarnValue,
-        YarnValueCastError, YarnValueWrapper, YarnValueWrapperIter,
+        optionality, yarn_fn_type, yarn_library, EnumType, Header, Instruction,
+        IntoYarnValueFromNonYarnValue, InvalidOpCodeError, Library, LineId, Node, OpCode,
+        OperandValue, Position, Program, Type, UntypedYarnFn, YarnFn, YarnFnParam, YarnFnParamItem,
+        YarnValue, YarnValueCastError, YarnValueWrapper, YarnValueWrapperIter,
     };
 }
 pub mod compiler {
@@ -43,8 +43,9 @@ pub mod compiler {
 pub mod runtime {
     //! Types and traits used by the runtime, in particular the [`Dialogue`] struct.
     pub use yarnspinner_runtime::markup::{
-        MarkupAttribute, MarkupParseError, MarkupValue, CHARACTER_ATTRIBUTE,
-        CHARACTER_ATTRIBUTE_NAME_PROPERTY, TRIM_WHITESPACE_PROPERTY,
+        AttributeMarkerProcessor, MarkupAttribute, MarkupAttributeMarker, MarkupParseError,
+        MarkupValue, CHARACTER_ATTRIBUTE, CHARACTER_ATTRIBUTE_NAME_PROPERTY,
+        TRIM_WHITESPACE_PROPERTY,
     };
     pub use yarnspinner_runtime::prelude::*;
     pub use yarnspinner_runtime::Result;
@@ -0,0 +1,183 @@
+//! Tests for [`DialogueDebugger`], which pauses a dialogue at breakpoints and steps through it.
+
+use test_base::prelude::*;
+use yarnspinner::compiler::*;
+use yarnspinner::core::*;
+use yarnspinner::runtime::*;
+
+mod test_base;
+
+const SOURCE: &str = "title: Start
+---
+<<declare $gold = 5>>
+<<if $gold > 3>>
+    Rich #line:rich
+<<else>>
+    Poor #line:poor
+<<endif>>
+<<detour Shop>>
+Bye #line:bye
+===
+title: Shop
+---
+<<set $gold = $gold - 1>>
+===
+";
+
+fn debugger() -> DialogueDebugger {
+    let mut compiler = Compiler::new();
+    compiler.add_file(File {
+        file_name: "debug.yarn".to_string(),
+        source: SOURCE.to_string(),
+    });
+    let compilation = compiler.compile().unwrap();
+    let debug_info = compilation.debug_info.clone();
+
+    let mut debugger =
+        DialogueDebugger::new(TestBase::new().with_compilation(compilation).dialogue);
+    for debug_info in debug_info.into_values() {
+        debugger.add_debug_info(
+            debug_info.file_name,
+            debug_info.node_name,
+            debug_info.line_positions,
+        );
+    }
+    debugger
+}
+
+fn lines(events: Vec<DialogueEvent>) -> Vec<String> {
+    events
+        .into_iter()
+        .filter_map(|event| match event {
+            DialogueEvent::Line(line) => Some(line.text),
+            _ => None,
+        })
+        .collect()
+}
+
+fn location(node_name: &str, instruction_index: usize, line: usize) -> Option<DebugLocation> {
+    Some(DebugLocation {
+        node_name: node_name.to_owned(),
+        instruction_index,
+        file_name: Some("debug.yarn".to_owned()),
+        line: Some(line),
+    })
+}
+
+#[test]
+fn test_breakpoints_pause_before_the_line_runs() {
+    let mut debugger = debugger();
+    assert!(debugger.add_breakpoint("debug.yarn", 4));
+    assert!(!debugger.add_breakpoint("debug.yarn", 2));
+    assert!(!debugger.add_breakpoint("other.yarn", 4));
+    assert_eq!(
+        vec![("debug.yarn", 4)],
+        debugger.breakpoints().collect::<Vec<_>>()
+    );
+
+    debugger.dialogue_mut().set_node("Start").unwrap();
+    let events = debugger.continue_().unwrap();
+    assert!(lines(events).is_empty());
+    assert!(debugger.is_paused());
+    assert_eq!(location("Start", 0, 4), debugger.location());
+    assert_eq!(
+        OpCode::PushVariable as i32,
+        debugger.next_instruction().unwrap().opcode
+    );
+    assert_eq!(
+        Some(&YarnValue::Number(5.0)),
+        debugger.variables().get("$gold")
+    );
+
+    // Continuing from a breakpoint does not pause at it again
+    let events = debugger.continue_().unwrap();
+    assert_eq!(vec!["Rich"], lines(events));
+    assert!(!debugger.is_paused());
+
+    assert!(debugger.remove_breakpoint("debug.yarn", 4));
+    assert_eq!(vec!["Bye"], lines(debugger.continue_().unwrap()));
+    let events = debugger.continue_().unwrap();
+    assert_eq!(Some(&DialogueEvent::DialogueComplete), events.last());
+    assert!(!debugger.dialogue().is_active());
+}
+
+#[test]
+fn test_stepping_through_instructions_and_lines() {
+    let mut debugger = debugger();
+    debugger.dialogue_mut().set_node("Start").unwrap();
+
+    debugger.step_into(StepGranularity::Instruction).unwrap();
+    assert!(debugger.is_paused());
+    assert_eq!(location("Start", 1, 4), debugger.location());
+    assert_eq!(vec![YarnValue::Number(5.0)], debugger.stack());
+
+    debugger.step_over(StepGranularity::Line).unwrap();
+    assert_eq!(location("Start", 5, 5), debugger.location());
+    // The condition that made this branch run
+    assert_eq!(vec![YarnValue::Boolean(true)], debugger.stack());
+
+    // Delivering content ends a step early
+    let events = debugger.step_over(StepGranularity::Line).unwrap();
+    assert_eq!(vec!["Rich"], lines(events));
+    assert!(!debugger.is_paused());
+
+    debugger.step_into(StepGranularity::Line).unwrap();
+    assert_eq!(location("Start", 10, 9), debugger.location());
+
+    // Stepping into a detour pauses in the detoured node
+    debugger.step_into(StepGranularity::Line).unwrap();
+    assert_eq!(location("Shop", 0, 14), debugger.location());
+
+    debugger.step_over(StepGranularity::Line).unwrap();
+    assert_eq!(location("Start", 12, 10), debugger.location());
+    assert_eq!(
+        Some(&YarnValue::Number(4.0)),
+        debugger.variables().get("$gold")
+    );
+}
+
+#[test]
+fn test_stepping_over_a_detour_stops_at_breakpoints_in_it() {
+    let mut debugger = debugger();
+    debugger.dialogue_mut().set_node("Start").unwrap();
+    assert!(debugger.add_breakpoint("debug.yarn", 9));
+    assert_eq!(vec!["Rich"], lines(debugger.continue_().unwrap()));
+    debugger.continue_().unwrap();
+    assert_eq!(location("Start", 10, 9), debugger.location());
+
+    debugger.step_over(StepGranularity::Line).unwrap();
+    assert_eq!(location("Start", 12, 10), debugger.location());
+    assert_eq!(
+        Some(&YarnValue::Number(4.0)),
+        debugger.variables().get("$gold")
+    );
+
+    let mut debugger = self::debugger();
+    debugger.dialogue_mut().set_node("Start").unwrap();
+    assert!(debugger.add_breakpoint("debug.yarn", 9));
+    assert!(debugger.add_breakpoint("debug.yarn", 14));
+    debugger.continue_().unwrap();
+    debugger.continue_().unwrap();
+    debugger.step_over(StepGranularity::Line).unwrap();
+    assert_eq!(location("Shop", 0, 14), debugger.location());
+}
+
+#[test]
+fn test_detour_to_missing_node_keeps_caller_state() {
+    let source = "<<local $greeting = \"Hello\">>
+<<declare $destination = \"Nowhere\">>
+<<detour {$destination}>>
+";
+    let compilation = Compiler::from_test_source(source).compile().unwrap();
+    let mut debugger =
+        DialogueDebugger::new(TestBase::new().with_compilation(compilation).dialogue);
+    debugger.dialogue_mut().set_node("Start").unwrap();
+
+    let error = debugger.continue_().unwrap_err();
+    assert!(matches!(error, DialogueError::InvalidNode { .. }));
+    assert_eq!(Some("Start".to_owned()), debugger.dialogue().current_node());
+    assert_eq!(
+        Some(&YarnValue::String("Hello".to_owned())),
+        debugger.local_variables().get("$greeting")
+    );
+}
@@ -6,8 +6,10 @@
 #[cfg(feature = "bevy")]
 use bevy::prelude::World;
 use bevy_platform::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use test_base::prelude::*;
 use yarnspinner::compiler::*;
+use yarnspinner::core::*;
 use yarnspinner::runtime::*;
 
 mod test_base;
@@ -186,6 +188,35 @@ fn test_getting_headers() {
     assert_eq!(headers, expected_headers);
 }
 
+#[test]
+fn test_getting_headers_leaves_out_internal_headers() {
+    let source = "title: Start
+mood: happy
+---
+<<local $a = 1>>
+<<local $b = 2>>
+{$a} {$b}
+===";
+    let result = compile_source(source);
+    let node = &result.program.as_ref().unwrap().nodes["Start"];
+    let local_headers: Vec<_> = node
+        .headers
+        .iter()
+        .filter(|header| header.key == Node::LOCAL_VARIABLE_HEADER)
+        .map(|header| header.value.as_str())
+        .collect();
+    assert_eq!(vec!["$a $b"], local_headers);
+    assert_eq!(vec!["$a", "$b"], node.local_variables().collect::<Vec<_>>());
+
+    let test_base = TestBase::new().with_compilation(result);
+    let headers = test_base.dialogue.get_headers_for_node("Start").unwrap();
+
+    let mut expected_headers = HashMap::default();
+    expected_headers.insert("title".to_string(), "Start".to_string());
+    expected_headers.insert("mood".to_string(), "happy".to_string());
+    assert_eq!(headers, expected_headers);
+}
+
 /// ## Implementation note
 /// Corresponds to `TestPrepareForLine`
 #[test]
@@ -273,6 +304,39 @@ fn test_function_argument_type_inference() {
     assert!(!bool_value);
 }
 
+#[test]
+fn test_calling_a_function_with_the_wrong_number_of_parameters_is_an_error() {
+    let mut test_base = TestBase::new()
+        .extend_library(|library| {
+            library.add_function("Double", |a: f32| a * 2.0);
+        })
+        .with_runtime_errors_do_not_cause_failure();
+
+    let result = Compiler::from_test_source("<<declare $x = 0>>\n<<set $x = Double(2)>>\n")
+        .extend_library(test_base.dialogue.library().clone())
+        .compile()
+        .unwrap();
+    test_base = test_base.with_compilation(result);
+    // Replace the function after compiling, so that the program no longer matches it
+    test_base
+        .dialogue
+        .library_mut()
+        .add_function("Double", || 2.0_f32);
+
+    test_base.dialogue.set_node("Start").unwrap();
+    #[cfg(feature = "bevy")]
+    let result = test_base
+        .dialogue
+        .continue_with_world(&mut World::default());
+    #[cfg(not(feature = "bevy"))]
+    let result = test_base.dialogue.continue_();
+    assert!(matches!(
+        result,
+        Err(DialogueError::InvalidProgram { reason, .. })
+            if reason == "The function Double expects 0 parameters, but is called with 1"
+    ));
+}
+
 #[test]
 fn test_selecting_option_from_inside_option_callback() {
     let result = Compiler::from_test_source("-> option 1\n->option 2\nfinal line\n")
@@ -342,3 +406,1055 @@ fn test_selecting_option_from_inside_option_callback() {
         }
     }
 }
+
+#[test]
+fn test_detour_returns_to_caller() {
+    let source = "title: Start
+---
+Before
+<<detour Shop>>
+After
+===
+title: Shop
+---
+Welcome to the shop
+===";
+    let events = run_source_to_completion(source);
+    assert_eq!(
+        vec![
+            "start Start",
+            "line Before",
+            "start Shop",
+            "line Welcome to the shop",
+            "complete Shop",
+            "line After",
+            "complete Start",
+            "dialogue complete",
+        ],
+        events
+    );
+}
+
+#[test]
+fn test_return_leaves_detour_early() {
+    let source = "title: Start
+---
+<<local $greeting = \"Hello\">>
+<<set $destination to \"Shop\">>
+<<detour {$destination}>>
+{$greeting} again
+===
+title: Shop
+---
+<<local $greeting = \"Welcome\">>
+{$greeting}
+<<jump Counter>>
+===
+title: Counter
+---
+Anything else?
+<<return>>
+Never shown
+===";
+    let events = run_source_to_completion(source);
+    assert_eq!(
+        vec![
+            "start Start",
+            "start Shop",
+            "line Welcome",
+            "complete Shop",
+            "start Counter",
+            "line Anything else?",
+            "complete Counter",
+            "line Hello again",
+            "complete Start",
+            "dialogue complete",
+        ],
+        events
+    );
+}
+
+#[test]
+fn test_stop_inside_detour_completes_every_node() {
+    let source = "title: Start
+---
+<<detour Shop>>
+Never shown
+===
+title: Shop
+---
+<<detour Counter>>
+===
+title: Counter
+---
+Closed
+<<stop>>
+===";
+    let events = run_source_to_completion(source);
+    assert_eq!(
+        vec![
+            "start Start",
+            "start Shop",
+            "start Counter",
+            "line Closed",
+            "complete Counter",
+            "complete Shop",
+            "complete Start",
+            "dialogue complete",
+        ],
+        events
+    );
+}
+
+#[test]
+fn test_return_outside_detour_stops() {
+    let result = Compiler::from_test_source("Before\n<<return>>\nAfter\n")
+        .compile()
+        .unwrap();
+    let mut test_base = TestBase::new()
+        .with_test_plan(TestPlan::new().expect_line("Before").expect_stop())
+        .with_compilation(result);
+    test_base.run_standard_testcase();
+}
+
+#[test]
+fn test_nodes_only_end_in_return_when_detoured_into() {
+    let last_op_code = |source: &str| {
+        let result = Compiler::from_test_source(source).compile().unwrap();
+        let program = result.program.unwrap();
+        program.nodes["Start"].instructions.last().unwrap().opcode()
+    };
+    assert_eq!(OpCode::Stop, last_op_code("Hello\n<<jump Start>>\n"));
+    assert_eq!(OpCode::Return, last_op_code("Hello\n<<detour Start>>\n"));
+}
+
+#[test]
+fn test_line_groups_run_one_eligible_line() {
+    let source = "title: Start
+---
+<<set $met_before to false>>
+=> Hi again! <<if $met_before>>
+=> Nice to meet you.
+    <<set $met_before to true>>
+=> Hello.
+After the group
+=> Never shown <<if false>>
+===";
+    let events = run_source_to_completion(source);
+    assert_eq!(
+        vec![
+            "start Start",
+            "line Nice to meet you.",
+            "line After the group",
+            "complete Start",
+            "dialogue complete",
+        ],
+        events
+    );
+}
+
+#[test]
+fn test_line_groups_use_saliency_strategy() {
+    let source = "title: Start
+---
+=> One
+=> Two
+=> Three <<if false>>
+=> Four
+===";
+    let result = Compiler::new()
+        .add_file(File {
+            file_name: "<input>".to_string(),
+            source: source.to_string(),
+        })
+        .compile()
+        .unwrap();
+    let mut test_base = TestBase::new().with_compilation(result);
+    test_base
+        .dialogue
+        .set_saliency_strategy(LeastRecentlySeenSaliencyStrategy::new());
+
+    let lines: Vec<_> = (0..4)
+        .map(|_| {
+            let lines = run_dialogue_to_completion(&mut test_base);
+            assert_eq!(1, lines.len());
+            lines[0].clone()
+        })
+        .collect();
+    assert_eq!(vec!["One", "Two", "Four", "One"], lines);
+}
+
+#[test]
+fn test_node_groups_run_one_eligible_member() {
+    let source = "title: Start
+---
+<<declare $gold = 5>>
+<<jump Greeting>>
+===
+title: Greeting
+when: $gold > 10
+---
+Hello, rich person!
+===
+title: Greeting
+when: $gold > 1
+when: $gold < 10
+---
+Hello, traveller.
+===
+title: Greeting
+when: true
+---
+Hello!
+===";
+    let events = run_source_to_completion(source);
+    // The node that selects the member is not reported, only the member it runs
+    let member = events
+        .iter()
+        .find_map(|event| event.strip_prefix("start Greeting."))
+        .unwrap();
+    assert_eq!(
+        vec![
+            "start Start".to_owned(),
+            "complete Start".to_owned(),
+            format!("start Greeting.{member}"),
+            "line Hello, traveller.".to_owned(),
+            format!("complete Greeting.{member}"),
+            "dialogue complete".to_owned(),
+        ],
+        events
+    );
+}
+
+#[test]
+fn test_node_group_member_names_do_not_depend_on_other_members() {
+    let member = "title: Greeting
+when: $gold > 10
+---
+Hello, rich person!
+===
+";
+    let other_member = "title: Greeting
+when: true
+---
+Hello!
+===
+";
+    let member_names = |source: String| -> Vec<String> {
+        let result = compile_source(&source);
+        let mut names: Vec<_> = result
+            .program
+            .unwrap()
+            .nodes
+            .into_keys()
+            .filter(|name| name.starts_with("Greeting."))
+            .collect();
+        names.sort();
+        names
+    };
+
+    let alone = member_names(format!("{member}{other_member}"));
+    let reordered = member_names(format!("{other_member}{member}"));
+    assert_eq!(2, alone.len());
+    assert_eq!(alone, reordered);
+    // Identical members are told apart by their order
+    let duplicated = member_names(format!("{member}{member}{other_member}"));
+    assert_eq!(3, duplicated.len());
+    assert!(alone.iter().all(|name| duplicated.contains(name)));
+}
+
+#[test]
+fn test_smart_variables_are_recomputed_when_read() {
+    let source = "title: Start
+---
+<<declare $gold = 5>>
+<<declare $price = 10>>
+<<declare $can_afford = $gold >= $price>>
+<<declare $summary = $can_afford and $price > 0>>
+Affordable: {$can_afford}, {$summary}
+<<set $gold to 20>>
+Affordable: {$can_afford}, {$summary}
+===";
+    let events = run_source_to_completion(source);
+    assert_eq!(
+        vec![
+            "start Start",
+            "line Affordable: false, false",
+            "line Affordable: true, true",
+            "complete Start",
+            "dialogue complete",
+        ],
+        events
+    );
+}
+
+#[test]
+fn test_smart_variable_nodes_are_not_exposed_as_nodes() {
+    let result = Compiler::new()
+        .add_file(File {
+            file_name: "<input>".to_string(),
+            source: "title: Start
+---
+<<declare $gold = 5>>
+<<declare $can_afford = $gold >= 10>>
+==="
+            .to_string(),
+        })
+        .compile()
+        .unwrap();
+    let mut test_base = TestBase::new()
+        .with_compilation(result)
+        .with_runtime_errors_do_not_cause_failure();
+
+    let dialogue = &mut test_base.dialogue;
+    assert!(dialogue.node_exists("Start"));
+    assert!(!dialogue.node_exists("$can_afford"));
+    assert!(dialogue.get_tags_for_node("$can_afford").is_none());
+    assert_eq!(
+        vec!["Start"],
+        dialogue.node_names().unwrap().collect::<Vec<_>>()
+    );
+    assert!(matches!(
+        dialogue.set_node("$can_afford"),
+        Err(DialogueError::InvalidNode { .. })
+    ));
+}
+
+#[test]
+fn test_once_content_only_runs_the_first_time() {
+    let source = "title: Start
+---
+<<detour Shop>>
+<<detour Shop>>
+===
+title: Shop
+---
+<<once>>
+Welcome to my shop!
+<<endonce>>
+-> Ask about the ring <<once>>
+    It's not for sale.
+-> Leave
+    Bye.
+===";
+    let events = run_source_to_completion(source);
+    assert_eq!(
+        vec![
+            "start Start",
+            "start Shop",
+            "line Welcome to my shop!",
+            "line It's not for sale.",
+            "complete Shop",
+            "start Shop",
+            "line Bye.",
+            "complete Shop",
+            "complete Start",
+            "dialogue complete",
+        ],
+        events
+    );
+}
+
+#[test]
+fn test_once_variables_keep_their_names_when_content_is_added() {
+    let once_variables = |source: &str| -> Vec<String> {
+        let result = Compiler::from_test_source(source).compile().unwrap();
+        result
+            .declarations
+            .into_iter()
+            .map(|declaration| declaration.name)
+            .filter(|name| name.starts_with("$Yarn.Internal.Once."))
+            .collect()
+    };
+    let source = "<<once>>
+    Welcome!
+<<endonce>>
+-> Ask about the ring <<once>> #line:ring
+    It's not for sale.
+-> Leave
+";
+    let before = once_variables(source);
+    let after = once_variables(&format!(
+        "<<once>>\nFirst time here?\n<<endonce>>\n-> Look around <<once>>\n{source}"
+    ));
+    assert_eq!(2, before.len());
+    assert_eq!(4, after.len());
+    for variable in &before {
+        assert!(after.contains(variable), "{variable} was renamed");
+    }
+    assert!(before.contains(&"$Yarn.Internal.Once.Start.line:ring".to_owned()));
+}
+
+#[test]
+fn test_once_variables_are_kept_in_variable_storage() {
+    let source = "title: Start
+---
+=> Hello! <<once>>
+=> Hello again.
+===";
+    let result = Compiler::new()
+        .add_file(File {
+            file_name: "<input>".to_string(),
+            source: source.to_string(),
+        })
+        .compile()
+        .unwrap();
+    let once_variable = result
+        .declarations
+        .iter()
+        .find(|declaration| declaration.name.starts_with("$Yarn.Internal.Once."))
+        .unwrap()
+        .name
+        .clone();
+    let mut test_base = TestBase::new().with_compilation(result);
+    assert_eq!(vec!["Hello!"], run_dialogue_to_completion(&mut test_base));
+    assert_eq!(
+        YarnValue::Boolean(true),
+        test_base
+            .dialogue
+            .variable_storage()
+            .get(&once_variable)
+            .unwrap()
+    );
+    assert_eq!(
+        vec!["Hello again."],
+        run_dialogue_to_completion(&mut test_base)
+    );
+
+    // Forgetting the variable, e.g. by loading an older save, brings the content back
+    test_base
+        .dialogue
+        .variable_storage_mut()
+        .set(once_variable, false.into())
+        .unwrap();
+    assert_eq!(vec!["Hello!"], run_dialogue_to_completion(&mut test_base));
+}
+
+#[test]
+fn test_node_groups_list_eligible_members() {
+    let source = "title: Greeting
+when: $gold > 10
+---
+Hello, rich person!
+===
+title: Greeting
+when: $gold <= 10
+---
+Hello!
+===
+title: Greeting
+when: $gold > 15
+---
+Welcome back!
+===";
+    let result = Compiler::new()
+        .add_file(File {
+            file_name: "<input>".to_string(),
+            source: source.to_string(),
+        })
+        .compile()
+        .unwrap();
+    let mut test_base = TestBase::new().with_compilation(result);
+    let observer = Arc::new(TraceObserver::default());
+    let dialogue = &mut test_base.dialogue;
+    dialogue.add_observer(observer.clone());
+
+    let poor = dialogue.eligible_node_group_members("Greeting").unwrap();
+    assert_eq!(1, poor.len());
+    dialogue
+        .variable_storage_mut()
+        .set("$gold".to_string(), 12.0.into())
+        .unwrap();
+    let rich = dialogue.eligible_node_group_members("Greeting").unwrap();
+    assert_eq!(1, rich.len());
+    assert_ne!(poor, rich);
+    dialogue
+        .variable_storage_mut()
+        .set("$gold".to_string(), 20.0.into())
+        .unwrap();
+    let very_rich = dialogue.eligible_node_group_members("Greeting").unwrap();
+    assert_eq!(2, very_rich.len());
+    assert_eq!(rich[0], very_rich[0]);
+    assert_ne!(poor[0], very_rich[1]);
+    assert!(dialogue.eligible_node_group_members(&rich[0]).is_err());
+    // Nothing ran, so there was nothing to observe
+    assert!(observer.0.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_optimized_code_behaves_like_unoptimized_code() {
+    let source = "title: Start
+---
+<<declare $gold = 10>>
+<<declare $smart = 2 * 3 > 5>>
+<<if $gold > 2 * 2 and true>>
+    Rich {1 + 2}
+<<elseif false>>
+    Impossible
+<<else>>
+    Poor
+<<endif>>
+<<if $smart>>
+    Smart
+<<endif>>
+-> Unavailable <<if 1 > 2>>
+-> Buy for {\"a\" + \"b\"}
+    <<set $gold -= 2 * 5>>
+    <<detour Shop>>
+-> Leave
+=> Group {true and not false}
+=> Never <<if false>>
+<<once>>
+    Once
+<<endonce>>
+Gold: {$gold}
+===
+title: Shop
+---
+<<if true>>
+    Shopping
+<<endif>>
+===";
+    let run = |optimize: bool| {
+        let result = Compiler::new()
+            .add_file(File {
+                file_name: "<input>".to_string(),
+                source: source.to_string(),
+            })
+            .with_optimization(optimize)
+            .compile()
+            .unwrap();
+        let mut test_base = TestBase::new().with_compilation(result);
+        run_dialogue_to_completion_with_events(&mut test_base)
+    };
+    let optimized = run(true);
+    assert_eq!(run(false), optimized);
+    assert!(optimized.contains(&"line Rich 3".to_owned()));
+    assert!(optimized.contains(&"line Gold: 0".to_owned()));
+}
+
+#[test]
+fn test_malformed_programs_are_rejected_when_loaded() {
+    let result = Compiler::from_test_source("<<if true>>\nHello\n<<endif>>\n")
+        .compile()
+        .unwrap();
+    let mut broken = result.program.clone().unwrap();
+    let node = broken.nodes.get_mut("Start").unwrap();
+    let jump = node
+        .instructions
+        .iter_mut()
+        .find(|instruction| instruction.opcode() == OpCode::JumpIfFalse)
+        .unwrap();
+    jump.operands[0] = "nowhere".to_owned().into();
+
+    let mut test_base = TestBase::new().with_compilation(result);
+    let error = test_base
+        .dialogue
+        .try_replace_program(broken)
+        .map(|_| ())
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        DialogueError::InvalidProgram {
+            ref node_name,
+            instruction_index: Some(_),
+            ref reason,
+        } if node_name == "Start" && reason == "Unknown label \"nowhere\""
+    ));
+
+    // The previous program is still loaded
+    let lines = run_dialogue_to_completion(&mut test_base);
+    assert_eq!(vec!["Hello"], lines);
+}
+
+#[test]
+fn test_instructions_missing_their_values_are_an_error() {
+    let result = Compiler::from_test_source("<<if true>>\nHello\n<<endif>>\n")
+        .compile()
+        .unwrap();
+    let mut program = result.program.clone().unwrap();
+    // Without the condition, the stack is empty when the `if` checks it
+    let instructions = &mut program.nodes.get_mut("Start").unwrap().instructions;
+    let condition = instructions
+        .iter()
+        .position(|instruction| instruction.opcode() == OpCode::PushBool)
+        .unwrap();
+    instructions.remove(condition);
+
+    let mut test_base = TestBase::new()
+        .with_compilation(result)
+        .with_runtime_errors_do_not_cause_failure();
+    test_base.dialogue.replace_program(program);
+    test_base.dialogue.set_node("Start").unwrap();
+    #[cfg(feature = "bevy")]
+    let result = test_base
+        .dialogue
+        .continue_with_world(&mut World::default());
+    #[cfg(not(feature = "bevy"))]
+    let result = test_base.dialogue.continue_();
+    assert!(matches!(
+        result,
+        Err(DialogueError::InvalidProgram { ref node_name, instruction_index: Some(_), ref reason })
+            if node_name == "Start" && reason == "Tried to peek a value, but the stack was empty"
+    ));
+}
+
+#[test]
+fn test_dialogues_share_one_program() {
+    let source = "title: Start
+---
+<<declare $visits = 0>>
+<<set $visits = $visits + 1>>
+Visit {$visits}
+===
+";
+    let mut compiler = Compiler::new();
+    compiler.add_file(File {
+        file_name: "<input>".to_string(),
+        source: source.to_string(),
+    });
+    let result = compiler.compile().unwrap();
+    let program = SharedProgram::new(result.program.unwrap()).unwrap();
+
+    let mut test_bases: Vec<_> = (0..3)
+        .map(|_| {
+            let mut test_base = TestBase::new().with_string_table(result.string_table.clone());
+            test_base.dialogue.set_program(program.clone());
+            test_base
+        })
+        .collect();
+    assert!(test_bases.iter().all(|test_base| test_base
+        .dialogue
+        .program()
+        .unwrap()
+        .ptr_eq(&program)));
+
+    // Only the execution state and variables are per dialogue
+    assert_eq!(
+        vec!["Visit 1"],
+        run_dialogue_to_completion(&mut test_bases[0])
+    );
+    assert_eq!(
+        vec!["Visit 2"],
+        run_dialogue_to_completion(&mut test_bases[0])
+    );
+    assert_eq!(
+        vec!["Visit 1"],
+        run_dialogue_to_completion(&mut test_bases[1])
+    );
+
+    // Replacing the program of one dialogue leaves the others alone
+    test_bases[2]
+        .dialogue
+        .replace_program(program.program().clone());
+    assert!(!test_bases[2].dialogue.program().unwrap().ptr_eq(&program));
+    assert!(test_bases[1].dialogue.program().unwrap().ptr_eq(&program));
+}
+
+const SHOP: &str = "title: Start
+---
+Hello #line:hello
+<<detour Shop>>
+Bye #line:bye
+===
+title: Shop
+---
+What do you want? #line:want
+-> Sword #line:sword
+    Here is your sword #line:here_sword
+-> Shield #line:shield
+    Here is your shield #line:here_shield
+===
+";
+
+#[test]
+fn test_snapshot_resumes_dialogue_waiting_on_options() {
+    let mut test_base = TestBase::new().with_compilation(compile_source(SHOP));
+    test_base.dialogue.set_node("Start").unwrap();
+    let lines = continue_until_options(&mut test_base);
+    assert_eq!(vec!["Hello", "What do you want?"], lines);
+    let snapshot = test_base.dialogue.snapshot();
+    assert_eq!(Some("Shop"), snapshot.node_name());
+    assert!(snapshot.is_waiting_for_option_selection());
+    assert_eq!(2, snapshot.options().len());
+
+    // Restore into a dialogue running a program recompiled from the same source
+    let mut restored = TestBase::new().with_compilation(compile_source(SHOP));
+    restored.dialogue.restore(snapshot.clone()).unwrap();
+    assert!(restored.dialogue.is_waiting_for_option_selection());
+    assert_eq!(Some("Shop".to_owned()), restored.dialogue.current_node());
+    restored
+        .dialogue
+        .set_selected_option(snapshot.options()[1].id)
+        .unwrap();
+    assert_eq!(
+        vec!["Here is your shield", "Bye"],
+        continue_until_options(&mut restored)
+    );
+    assert!(!restored.dialogue.is_active());
+}
+
+#[test]
+fn test_snapshot_of_changed_node_is_rejected() {
+    let mut test_base = TestBase::new().with_compilation(compile_source(SHOP));
+    test_base.dialogue.set_node("Start").unwrap();
+    continue_until_options(&mut test_base);
+    let snapshot = test_base.dialogue.snapshot();
+
+    // The node that detoured into the current one got a new line
+    let changed = SHOP.replace("Bye #line:bye", "Bye #line:bye\nSee you #line:see_you");
+    let mut restored = TestBase::new().with_compilation(compile_source(&changed));
+    let error = restored
+        .dialogue
+        .restore(snapshot.clone())
+        .map(|_| ())
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        DialogueError::IncompatibleSnapshot { ref node_name } if node_name == "Start"
+    ));
+    assert!(!restored.dialogue.is_active());
+
+    // Editing the text of lines or other nodes does not change the bytecode the snapshot depends on
+    let changed = format!(
+        "{}title: Other\n---\nUnrelated\n===\n",
+        SHOP.replace("Bye", "Goodbye")
+    );
+    let mut restored = TestBase::new().with_compilation(compile_source(&changed));
+    restored.dialogue.restore(snapshot).unwrap();
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_snapshot_survives_serialization() {
+    let mut test_base = TestBase::new().with_compilation(compile_source(SHOP));
+    test_base.dialogue.set_node("Start").unwrap();
+    continue_until_options(&mut test_base);
+    test_base.dialogue.set_selected_option(OptionId(0)).unwrap();
+    // Stops after delivering the line of the selected option
+    #[cfg(feature = "bevy")]
+    let events = test_base
+        .dialogue
+        .continue_with_world(&mut World::default())
+        .unwrap();
+    #[cfg(not(feature = "bevy"))]
+    let events = test_base.dialogue.continue_().unwrap();
+    assert!(
+        matches!(events.last(), Some(DialogueEvent::Line(line)) if line.text == "Here is your sword")
+    );
+
+    let json = serde_json::to_string(&test_base.dialogue.snapshot()).unwrap();
+    let snapshot: DialogueSnapshot = serde_json::from_str(&json).unwrap();
+    assert_eq!(test_base.dialogue.snapshot(), snapshot);
+
+    let mut restored = TestBase::new().with_compilation(compile_source(SHOP));
+    restored.dialogue.restore(snapshot).unwrap();
+    assert_eq!(vec!["Bye"], continue_until_options(&mut restored));
+}
+
+#[derive(Debug, Default)]
+struct TraceObserver(Mutex<Vec<String>>);
+
+impl TraceObserver {
+    fn record(&self, entry: String) {
+        self.0.lock().unwrap().push(entry);
+    }
+}
+
+impl DialogueObserver for TraceObserver {
+    fn node_entered(&self, node_name: &str) {
+        self.record(format!("enter {node_name}"));
+    }
+
+    fn node_exited(&self, node_name: &str) {
+        self.record(format!("exit {node_name}"));
+    }
+
+    fn line_delivered(&self, line: &Line) {
+        self.record(format!("line {}", line.id));
+    }
+
+    fn options_delivered(&self, options: &[DialogueOption]) {
+        self.record(format!("{} options", options.len()));
+    }
+
+    fn option_selected(&self, option: &DialogueOption) {
+        self.record(format!("select {}", option.line.id));
+    }
+
+    fn variable_read(&self, name: &str, value: &YarnValue) {
+        self.record(format!("read {name} = {value}"));
+    }
+
+    fn variable_written(&self, name: &str, value: &YarnValue) {
+        self.record(format!("write {name} = {value}"));
+    }
+
+    fn function_called(&self, name: &str, arguments: &[YarnValue], result: &YarnValue) {
+        let arguments: Vec<_> = arguments.iter().map(ToString::to_string).collect();
+        self.record(format!("call {name}({}) = {result}", arguments.join(", ")));
+    }
+
+    fn command_dispatched(&self, command: &Command) {
+        self.record(format!("command {}", command.name));
+    }
+}
+
+#[test]
+fn test_observers_see_everything_the_dialogue_does() {
+    let source = "title: Start
+---
+<<declare $gold = 5>>
+<<set $gold = $gold + 1>>
+Gold: {$gold} #line:gold
+<<once>>
+    Welcome! #line:welcome
+<<endonce>>
+<<give sword>>
+-> Buy #line:buy
+    <<detour Shop>>
+-> Leave #line:leave
+===
+title: Shop
+---
+Thanks #line:thanks
+===
+";
+    let observer = Arc::new(TraceObserver::default());
+    let mut test_base = TestBase::new().with_compilation(compile_source(source));
+    test_base.dialogue.add_observer(observer.clone());
+    run_dialogue_to_completion_with_events(&mut test_base);
+
+    assert_eq!(
+        vec![
+            "enter Start",
+            "read $gold = 5",
+            "call Number.Add(5, 1) = 6",
+            "write $gold = 6",
+            "read $gold = 6",
+            "line line:gold",
+            "line line:welcome",
+            "command give",
+            "2 options",
+            "select line:buy",
+            "enter Shop",
+            "line line:thanks",
+            "exit Shop",
+            "exit Start",
+        ],
+        *observer.0.lock().unwrap()
+    );
+
+    test_base.dialogue.clear_observers();
+    run_dialogue_to_completion_with_events(&mut test_base);
+    assert_eq!(14, observer.0.lock().unwrap().len());
+}
+
+#[test]
+fn test_observers_only_see_stored_writes() {
+    let source = "title: Start
+---
+<<declare $gold = 5>>
+<<set $gold = 6>>
+===
+";
+    let observer = Arc::new(TraceObserver::default());
+    // The storage doesn't know about $gold, so it rejects the write
+    let storage = TypedVariableStorage::new(Box::new(MemoryVariableStorage::new()), []);
+    let mut test_base = TestBase::new();
+    test_base.dialogue = Dialogue::new(Box::new(storage), Box::new(test_base.string_table.clone()));
+    let mut test_base = test_base.with_compilation(compile_source(source));
+    test_base.dialogue.add_observer(observer.clone());
+    test_base.dialogue.set_node("Start").unwrap();
+    assert!(matches!(
+        test_base.dialogue.continue_(),
+        Err(DialogueError::VariableStorageError(
+            VariableStorageError::UndeclaredVariable { .. }
+        ))
+    ));
+    assert_eq!(vec!["enter Start"], *observer.0.lock().unwrap());
+}
+
+#[test]
+fn test_typed_variable_storage_enforces_declarations() {
+    let source = "title: Start
+---
+<<declare $gold = 5>>
+<<declare $name = \"Sally\">>
+<<set $gold = $gold + 1>>
+{$name} has {$gold} gold #line:gold
+===
+";
+    let compilation = compile_source(source);
+    let storage = TypedVariableStorage::new(
+        Box::new(MemoryVariableStorage::new()),
+        compilation.stored_variable_declarations(),
+    );
+    let mut test_base = TestBase::new();
+    test_base.dialogue = Dialogue::new(Box::new(storage), Box::new(test_base.string_table.clone()));
+    let mut test_base = test_base.with_compilation(compilation);
+    assert_eq!(
+        vec!["Sally has 6 gold"],
+        run_dialogue_to_completion(&mut test_base)
+    );
+
+    let storage = test_base.dialogue.variable_storage_mut();
+    assert!(matches!(
+        storage.set("$gold".to_owned(), "lots".into()),
+        Err(VariableStorageError::TypeMismatch { .. })
+    ));
+    assert!(matches!(
+        storage.set("$silver".to_owned(), 1.into()),
+        Err(VariableStorageError::UndeclaredVariable { .. })
+    ));
+    storage.set("$name".to_owned(), "Bob".into()).unwrap();
+
+    let storage = storage
+        .as_any()
+        .downcast_ref::<TypedVariableStorage>()
+        .unwrap();
+    assert_eq!(6.0, storage.get_number("$gold").unwrap());
+    assert_eq!("Bob", storage.get_string("$name").unwrap());
+    assert_eq!(Some(&Type::Number), storage.declared_type("$gold"));
+}
+
+/// Replaces `[gender m="..." f="..." n="..." /]` with the property for the player's gender
+/// and `[greeting /]` with a greeting in the current language.
+#[derive(Debug, Clone)]
+struct PlayerMarkupProcessor {
+    gender: &'static str,
+    language_code: Option<Language>,
+}
+
+impl AttributeMarkerProcessor for PlayerMarkupProcessor {
+    fn replacement_text_for_marker(&self, marker: &MarkupAttributeMarker) -> String {
+        match marker.name() {
+            Some("gender") => marker.property(self.gender).unwrap().to_string(),
+            Some("greeting") => match self.language_code.as_ref().map(ToString::to_string) {
+                Some(language) if language == "de-CH" => "Grüezi".to_owned(),
+                _ => "Hello".to_owned(),
+            },
+            Some("shout") => marker.contents().unwrap().to_uppercase(),
+            name => panic!("Unexpected marker {name:?}"),
+        }
+    }
+
+    fn set_language_code(&mut self, language_code: Option<Language>) {
+        self.language_code = language_code;
+    }
+
+    fn clone_box(&self) -> Box<dyn AttributeMarkerProcessor> {
+        Box::new(self.clone())
+    }
+}
+
+#[test]
+fn test_custom_markup_processors_replace_markers() {
+    let source = r#"title: Start
+---
+[greeting /], [gender m="he" f="she" n="they" /] said. #line:said
+[shout]Run[/shout]! #line:run
+===
+"#;
+    let mut test_base = TestBase::new().with_compilation(compile_source(source));
+    let processor = PlayerMarkupProcessor {
+        gender: "n",
+        language_code: None,
+    };
+    test_base
+        .dialogue
+        .register_markup_processor("gender", processor.clone())
+        .register_markup_processor("greeting", processor.clone())
+        .register_markup_processor("shout", processor);
+    assert_eq!(
+        vec!["Hello, they said.", "RUN!"],
+        run_dialogue_to_completion(&mut test_base)
+    );
+
+    test_base
+        .dialogue
+        .set_language_code(Language::from("de-CH"));
+    assert_eq!(
+        vec!["Grüezi, they said.", "RUN!"],
+        run_dialogue_to_completion(&mut test_base)
+    );
+}
+
+fn compile_source(source: &str) -> Compilation {
+    let mut compiler = Compiler::new();
+    compiler.add_file(File {
+        file_name: "<input>".to_string(),
+        source: source.to_string(),
+    });
+    compiler.compile().unwrap()
+}
+
+/// Continues the dialogue until it waits on an option selection or completes and returns the text of the delivered lines.
+fn continue_until_options(test_base: &mut TestBase) -> Vec<String> {
+    #[cfg(feature = "bevy")]
+    let mut world = World::default();
+
+    let mut lines = Vec::new();
+    while test_base.dialogue.can_continue() {
+        #[cfg(feature = "bevy")]
+        let events = test_base.dialogue.continue_with_world(&mut world);
+        #[cfg(not(feature = "bevy"))]
+        let events = test_base.dialogue.continue_();
+        for event in events.unwrap() {
+            if let DialogueEvent::Line(line) = event {
+                lines.push(line.text);
+            }
+        }
+    }
+    lines
+}
+
+/// Runs the "Start" node of `source` until the dialogue completes, always selecting the first available option,
+/// and returns the node, line and completion events in order.
+fn run_source_to_completion(source: &str) -> Vec<String> {
+    let mut compiler = Compiler::new();
+    compiler.add_file(File {
+        file_name: "<input>".to_string(),
+        source: source.to_string(),
+    });
+    let result = compiler.compile().unwrap();
+    let mut test_base = TestBase::new().with_compilation(result);
+    run_dialogue_to_completion_with_events(&mut test_base)
+}
+
+/// Like [`run_source_to_completion`], but only returns the text of the lines.
+fn run_dialogue_to_completion(test_base: &mut TestBase) -> Vec<String> {
+    run_dialogue_to_completion_with_events(test_base)
+        .into_iter()
+        .filter_map(|event| event.strip_prefix("line ").map(ToOwned::to_owned))
+        .collect()
+}
+
+fn run_dialogue_to_completion_with_events(test_base: &mut TestBase) -> Vec<String> {
+    test_base.dialogue.set_node("Start").unwrap();
+
+    #[cfg(feature = "bevy")]
+    let mut world = World::default();
+
+    let mut recorded = Vec::new();
+    while test_base.dialogue.can_continue() {
+        #[cfg(feature = "bevy")]
+        let events = test_base.dialogue.continue_with_world(&mut world);
+        #[cfg(not(feature = "bevy"))]
+        let events = test_base.dialogue.continue_();
+        for event in events.unwrap() {
+            match event {
+                DialogueEvent::Line(line) => recorded.push(format!("line {}", line.text)),
+                DialogueEvent::NodeStart(node) => recorded.push(format!("start {node}")),
+                DialogueEvent::NodeComplete(node) => recorded.push(format!("complete {node}")),
+                DialogueEvent::DialogueComplete => recorded.push("dialogue complete".to_owned()),
+                DialogueEvent::Options(options) => {
+                    let option = options.iter().find(|option| option.is_available).unwrap();
+                    test_base.dialogue.set_selected_option(option.id).unwrap();
+                }
+                DialogueEvent::Command(_) | DialogueEvent::LineHints(_) => {}
+            }
+        }
+    }
+    recorded
+}
@@ -3,6 +3,7 @@
 use crate::test_base::*;
 use test_base::prelude::*;
 use yarnspinner::compiler::*;
+use yarnspinner::core::{Position, Type};
 
 mod test_base;
 
@@ -101,3 +102,533 @@ fn test_compiling_same_file_twice_fails() {
         .iter()
         .any(|d| d.message.contains("Duplicate line ID line:794945")));
 }
+
+#[test]
+fn test_node_group_members_need_when_headers() {
+    let source = "title: Greeting
+when: $gold > 10
+---
+Hello, rich person!
+===
+title: Greeting
+---
+Hello!
+===";
+    let result = Compiler::new()
+        .add_file(File {
+            file_name: "<input>".to_string(),
+            source: source.to_string(),
+        })
+        .compile()
+        .unwrap_err();
+
+    println!("{}", result);
+    assert!(result.0.iter().any(|d| d
+        .message
+        .contains("All nodes in the group Greeting must have a 'when' header")));
+}
+
+#[test]
+fn test_jumps_to_unknown_nodes_are_reported() {
+    let source = "title: Start
+---
+<<jump Strat>>
+<<detour Shop>>
+Been to the shop: {visited(\"Shopp\")}
+===
+title: Shop
+---
+===";
+    let mut compiler = Compiler::new();
+    compiler.add_file(File {
+        file_name: "<input>".to_string(),
+        source: source.to_string(),
+    });
+    let result = compiler.compile().unwrap_err();
+
+    println!("{}", result);
+    let messages: Vec<_> = result.0.iter().map(|d| d.message.as_str()).collect();
+    assert_eq!(
+        vec![
+            "Can't jump to Strat, because there is no node with that name. Did you mean Start?",
+            "visited refers to Shopp, but there is no node with that name. Did you mean Shop?",
+        ],
+        messages
+    );
+    let range = result.0[0].range.clone().unwrap();
+    assert_eq!(2, range.start.line);
+
+    // The node may be in a file that is compiled separately
+    let result = compiler
+        .with_diagnostic_severity(DiagnosticCode::UnknownNode, DiagnosticSeverity::Warning)
+        .compile()
+        .unwrap();
+
+    assert_eq!(2, result.warnings.len());
+    assert!(result.program.is_some());
+}
+
+#[test]
+fn test_strings_only_compilation_does_not_check_node_references() {
+    let result = Compiler::from_test_source("Hello #line:hello\n<<jump Shop>>")
+        .with_compilation_type(CompilationType::StringsOnly)
+        .with_diagnostic_severity(DiagnosticCode::UnknownNode, DiagnosticSeverity::Error)
+        .compile()
+        .unwrap();
+    assert!(result.warnings.is_empty());
+    assert!(result.string_table.contains_key(&"line:hello".into()));
+}
+
+#[test]
+fn test_jumps_to_node_groups_are_valid() {
+    let source = "title: Start
+---
+<<detour Greeting>>
+<<jump Greeting>>
+===
+title: Greeting
+when: true
+---
+===
+title: Greeting
+when: visited(\"Start\")
+---
+===";
+    let mut compiler = Compiler::new();
+    compiler.add_file(File {
+        file_name: "<input>".to_string(),
+        source: source.to_string(),
+    });
+    let result = compiler.compile().unwrap();
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn test_dynamic_jumps_are_warned_about() {
+    let source = "title: Start
+---
+<<declare $destination = \"Start\">>
+<<detour {$destination}>>
+<<jump {$destination}>>
+===";
+    let mut compiler = Compiler::new();
+    compiler.add_file(File {
+        file_name: "<input>".to_string(),
+        source: source.to_string(),
+    });
+    let result = compiler.compile().unwrap();
+
+    let warnings: Vec<_> = result.warnings.iter().map(|d| d.message.as_str()).collect();
+    assert_eq!(
+        vec![
+            "The destination of this detour is only known when it runs, so it can't be checked to exist",
+            "The destination of this jump is only known when it runs, so it can't be checked to exist",
+        ],
+        warnings
+    );
+    assert!(result
+        .warnings
+        .iter()
+        .all(|d| d.severity == DiagnosticSeverity::Warning));
+}
+
+#[test]
+fn test_commands_are_checked_against_their_declarations() {
+    let result = Compiler::from_test_source(
+        "<<fade_in 1.5 \"very slow\">>
+<<fade_in 1 2 3>>
+<<fade_in slowly>>
+<<fade_in {1 + 2}>>
+<<fade_in {\"fast\"}>>
+<<fade_out>>
+<<stop>>",
+    )
+    .declare_command("fade_in", [Type::Number])
+    .compile()
+    .unwrap_err();
+
+    println!("{}", result);
+    let messages: Vec<_> = result.0.iter().map(|d| d.message.as_str()).collect();
+    assert_eq!(
+        vec![
+            "The command fade_in expects 1 parameters, but was given 2",
+            "The command fade_in expects 1 parameters, but was given 3",
+            "Parameter 1 of the command fade_in expects a Number, but was given a String",
+            "Parameter 1 of the command fade_in expects a Number, but was given a String",
+            "The command fade_out was not declared",
+        ],
+        messages
+    );
+    assert_eq!(DiagnosticSeverity::Warning, result.0[4].severity);
+}
+
+#[test]
+fn test_wait_does_not_need_to_be_declared() {
+    let result = Compiler::from_test_source("<<wait 2>>")
+        .declare_command("fade_in", [Type::Number])
+        .compile()
+        .unwrap();
+    assert!(result.warnings.is_empty());
+
+    // Dialogue runners that declare it get it checked like any other command
+    let result = Compiler::from_test_source("<<wait soon>>")
+        .declare_command("wait", [Type::Number])
+        .compile()
+        .unwrap_err();
+    assert_eq!(DiagnosticCode::WrongCommandParameterType, result.0[0].code);
+}
+
+#[test]
+fn test_built_in_commands_cant_be_declared() {
+    let result = Compiler::from_test_source("<<detour Start>>")
+        .declare_command("detour", [Type::String])
+        .declare_command("fade_in", [Type::Number])
+        .compile()
+        .unwrap_err();
+    assert_eq!(1, result.0.len());
+    assert_eq!(DiagnosticCode::ReservedCommandName, result.0[0].code);
+    assert_eq!(
+        "The command detour can't be declared, because <<detour>> is built into Yarn Spinner",
+        result.0[0].message
+    );
+}
+
+#[test]
+fn test_malformed_detours_and_returns_are_reported() {
+    for source in [
+        "<<detour>>",
+        "<<detour Start Shop>>",
+        "<<detour {\"Start\"} Shop>>",
+        "<<declare $x = \"Start\">>\n<<detour {$x} y>>",
+        "<<return 1>>",
+    ] {
+        let result = Compiler::from_test_source(source).compile().unwrap_err();
+        println!("{}", result);
+        assert_eq!(1, result.0.len());
+        assert_eq!(DiagnosticCode::MalformedBuiltInCommand, result.0[0].code);
+    }
+
+    // Other commands may start with the same letters
+    let result = Compiler::from_test_source("<<detouring>>\n<<returned>>")
+        .compile()
+        .unwrap();
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn test_unknown_commands_can_be_allowed() {
+    let result = Compiler::from_test_source(
+        "<<set_sprite ship \"very happy\" true>>\n<<shake>>\n<<play_music>>",
+    )
+    .declare_command("set_sprite", [Type::String, Type::String, Type::Boolean])
+    .with_diagnostic_severity(DiagnosticCode::UnknownCommand, None)
+    .compile()
+    .unwrap();
+    assert!(result.warnings.is_empty());
+
+    let result = Compiler::from_test_source("<<shake>>")
+        .declare_command("set_sprite", [Type::String])
+        .with_diagnostic_severity(DiagnosticCode::UnknownCommand, DiagnosticSeverity::Error)
+        .compile()
+        .unwrap_err();
+    assert_eq!(
+        vec!["The command shake was not declared"],
+        result
+            .0
+            .iter()
+            .map(|d| d.message.as_str())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_partial_compilation_excludes_nodes_with_errors() {
+    let healthy = "title: Start
+---
+Hello!
+<<jump Broken>>
+===";
+    let broken = "title: Broken
+---
+<<set $gold to 1 + \"one\">>
+===
+title: Fine
+---
+Still fine. {visited(\"Broken\")}
+===";
+    let result = Compiler::new()
+        .add_file(File {
+            file_name: "healthy.yarn".to_string(),
+            source: healthy.to_string(),
+        })
+        .add_file(File {
+            file_name: "broken.yarn".to_string(),
+            source: broken.to_string(),
+        })
+        .compile_partial()
+        .unwrap();
+
+    assert_eq!(vec!["Broken"], result.excluded_nodes);
+    assert!(!result.errors.is_empty());
+    assert!(result
+        .errors
+        .iter()
+        .all(|d| d.file_name.as_deref() == Some("broken.yarn")));
+
+    let mut node_names: Vec<_> = result
+        .compilation
+        .program
+        .as_ref()
+        .unwrap()
+        .nodes
+        .keys()
+        .cloned()
+        .collect();
+    node_names.sort();
+    assert_eq!(vec!["Fine", "Start"], node_names);
+
+    let warnings: Vec<_> = result
+        .compilation
+        .warnings
+        .iter()
+        .map(|d| (d.file_name.as_deref().unwrap(), d.message.as_str()))
+        .collect();
+    assert_eq!(
+        vec![
+            (
+                "healthy.yarn",
+                "Broken was left out of the compilation because it has errors"
+            ),
+            (
+                "broken.yarn",
+                "Broken was left out of the compilation because it has errors"
+            ),
+        ],
+        warnings
+    );
+    // Positions in the rest of the file are kept
+    assert_eq!(
+        6,
+        result.compilation.warnings[1]
+            .range
+            .as_ref()
+            .unwrap()
+            .start
+            .line
+    );
+}
+
+#[test]
+fn test_partial_compilation_keeps_nodes_next_to_broken_ones() {
+    // No blank lines between the nodes, and the unclosed expression makes the parser read past the end of its node
+    let source = "title: Start
+---
+Hello!
+===
+title: Unclosed
+---
+<<if true>>
+Never closed
+===
+title: Middle
+---
+Still here
+===
+title: Expression
+---
+-> {
+Oops
+===
+title: End
+---
+Also still here
+===";
+    let result = Compiler::new()
+        .add_file(File {
+            file_name: "<input>".to_string(),
+            source: source.to_string(),
+        })
+        .compile_partial()
+        .unwrap();
+
+    assert_eq!(vec!["Unclosed", "Expression"], result.excluded_nodes);
+    let mut node_names: Vec<_> = result
+        .compilation
+        .program
+        .as_ref()
+        .unwrap()
+        .nodes
+        .keys()
+        .cloned()
+        .collect();
+    node_names.sort();
+    assert_eq!(vec!["End", "Middle", "Start"], node_names);
+}
+
+#[test]
+fn test_partial_compilation_of_healthy_files_is_complete() {
+    let result = Compiler::from_test_source("Hello!")
+        .compile_partial()
+        .unwrap();
+    assert!(result.errors.is_empty());
+    assert!(result.excluded_nodes.is_empty());
+    assert_eq!(
+        Compiler::from_test_source("Hello!").compile().unwrap(),
+        result.compilation
+    );
+}
+
+#[test]
+fn test_partial_compilation_leaves_out_files_without_healthy_nodes() {
+    let result = Compiler::new()
+        .add_file(File {
+            file_name: "healthy.yarn".to_string(),
+            source: "// Comments at the start of a file are fine\ntitle: Start\n---\nHello!\n==="
+                .to_string(),
+        })
+        .add_file(File {
+            file_name: "broken.yarn".to_string(),
+            source: "title: Broken\n---\n<<set $gold to 1 + \"one\">>\n===\n// The end\n"
+                .to_string(),
+        })
+        .compile_partial()
+        .unwrap();
+
+    assert_eq!(vec!["Broken"], result.excluded_nodes);
+    let node_names: Vec<_> = result
+        .compilation
+        .program
+        .as_ref()
+        .unwrap()
+        .nodes
+        .keys()
+        .collect();
+    assert_eq!(vec!["Start"], node_names);
+}
+
+#[test]
+fn test_diagnostics_have_stable_codes() {
+    let result =
+        Compiler::from_test_source("<<set $gold to 1 + \"one\">>\n{$undefined}\n<<jump Nowhere>>")
+            .compile()
+            .unwrap_err();
+
+    let codes: Vec<_> = result.0.iter().map(|d| d.code.as_str()).collect();
+    assert_eq!(
+        vec!["YS0009", "YS0016", "YS0017", "YS0012", "YS0012"],
+        codes
+    );
+    assert_eq!(DiagnosticCode::UndefinedVariable, "YS0012".parse().unwrap());
+    assert!(result.to_string().contains("error[YS0012]"));
+}
+
+#[test]
+fn test_misspelled_node_names_come_with_a_fix() {
+    let result = Compiler::from_test_source("<<jump Strat>>\n{visited(\"start\")}")
+        .compile()
+        .unwrap_err();
+
+    let fixes: Vec<_> = result.0.iter().map(|d| d.fixes.clone()).collect();
+    let fix = |line, start, end| {
+        vec![DiagnosticFix {
+            description: "Replace with Start".to_owned(),
+            edits: vec![DiagnosticEdit {
+                range: Position {
+                    line,
+                    character: start,
+                }..Position {
+                    line,
+                    character: end,
+                },
+                new_text: "Start".to_owned(),
+            }],
+        }]
+    };
+    assert_eq!(vec![fix(2, 7, 12), fix(3, 10, 15)], fixes);
+}
+
+#[test]
+fn test_warnings_can_be_turned_into_errors_or_silenced() {
+    let source = "<<declare $destination = \"Start\">>\n<<jump {$destination}>>";
+    let result = Compiler::from_test_source(source)
+        .with_diagnostic_severity(
+            DiagnosticCode::DynamicNodeReference,
+            DiagnosticSeverity::Error,
+        )
+        .compile()
+        .unwrap_err();
+    assert_eq!(1, result.0.len());
+    assert_eq!(DiagnosticSeverity::Error, result.0[0].severity);
+
+    let result = Compiler::from_test_source(source)
+        .with_diagnostic_severity(DiagnosticCode::DynamicNodeReference, None)
+        .compile()
+        .unwrap();
+    assert!(result.warnings.is_empty());
+
+    // Errors stay errors unless the files can be compiled without treating them as errors
+    let result = Compiler::from_test_source("<<jump Nowhere>>")
+        .with_diagnostic_severity(DiagnosticCode::UnknownNode, None)
+        .compile()
+        .unwrap();
+    assert!(result.warnings.is_empty());
+
+    let result = Compiler::from_test_source("{$undefined}")
+        .with_diagnostic_severity(DiagnosticCode::UndefinedVariable, None)
+        .compile()
+        .unwrap_err();
+    assert_eq!(DiagnosticCode::UndefinedVariable, result.0[0].code);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_diagnostics_can_be_rendered_for_tools() {
+    let result = Compiler::from_test_source("<<jump Nowhere>>")
+        .compile()
+        .unwrap_err();
+
+    let json = result.0.to_json();
+    assert!(json
+        .starts_with(r#"[{"code":"YS0009","severity":"error","message":"Can't jump to Nowhere"#));
+    assert!(json.contains(r#""file":"<input>","range":{"start":{"line":2,"character":0}"#));
+
+    let sarif = result.0.to_sarif();
+    assert!(sarif.contains(r#""version":"2.1.0""#));
+    assert!(sarif.contains(r#""ruleId":"YS0009","ruleIndex":0,"level":"error""#));
+}
+
+#[test]
+fn test_unfinished_sources_are_reported_instead_of_panicking() {
+    // What a writer has typed so far when an editor asks for diagnostics
+    let source = "title: Start\n\
+        ---\n\
+        <<declare $gold = 5>>\n\
+        <<declare $price = (10)>>\n\
+        <<declare $can_afford = $gold >= $price>>\n\
+        <<local $x = -1>>\n\
+        <<set $x to $x + 1>>\n\
+        Hello {$gold} and {$can_afford} #line:hello #tag\n\
+        -> Buy <<if $can_afford>> <<once>>\n    \
+            <<detour Shop>>\n\
+        -> Leave\n    \
+            <<jump {\"Shop\"}>>\n\
+        <<if visited(\"Shop\")>>\n    \
+            Back\n\
+        <<endif>>\n\
+        ===\n\
+        title: Shop\n\
+        when: $gold > 10\n\
+        ---\n\
+        Thanks\n\
+        ===\n";
+
+    for (end, _) in source.char_indices().skip(1) {
+        let mut compiler = Compiler::new();
+        compiler.add_file(File {
+            file_name: "input.yarn".to_owned(),
+            source: source[..end].to_owned(),
+        });
+        let _ = compiler.compile();
+        let _ = compiler.compile_partial();
+    }
+}
@@ -292,3 +292,117 @@ fn test_debug_output_is_produced() {
     assert_eq!(2, first_line_info.position.unwrap().line);
     assert_eq!(0, first_line_info.position.unwrap().character);
 }
+
+#[test]
+fn test_incremental_compilation_matches_full_compilation() {
+    let mut compiler = Compiler::new();
+    compiler
+        .add_file(File {
+            file_name: "start.yarn".to_string(),
+            source: "title: Start
+---
+<<declare $gold = 10>>
+<<declare $rich = $gold > 100>>
+Hello!
+<<jump Shop>>
+==="
+            .to_string(),
+        })
+        .add_file(File {
+            file_name: "shop.yarn".to_string(),
+            source: "title: Shop
+---
+Welcome to the shop. #line:shop_welcome
+-> Buy something
+    <<set $gold to $gold - 1>>
+-> Leave <<if $rich>>
+Come again! {visited(\"Start\")}
+==="
+            .to_string(),
+        })
+        .add_file(File {
+            file_name: "ending.yarn".to_string(),
+            source: "title: Ending
+---
+The end.
+==="
+            .to_string(),
+        });
+    let mut incremental_compiler = IncrementalCompiler::new();
+    let mut assert_same_as_full_compilation = |compiler: &Compiler| {
+        assert_eq!(compiler.compile(), incremental_compiler.compile(compiler));
+    };
+    assert_same_as_full_compilation(&compiler);
+    // Nothing changed
+    assert_same_as_full_compilation(&compiler);
+
+    // More lines in the first file change the implicit line IDs of the ones after it
+    compiler.files[0].source = compiler.files[0].source.replace("Hello!", "Hello!\nHi!");
+    assert_same_as_full_compilation(&compiler);
+
+    compiler.files[1].source = compiler.files[1].source.replace("$gold - 1", "$gold - 2");
+    assert_same_as_full_compilation(&compiler);
+
+    // The unchanged files need new code once a node of theirs is tracked or any file detours
+    compiler.files[0].source = compiler.files[0]
+        .source
+        .replace("Hello!", "Hello! {visited(\"Ending\")}");
+    assert_same_as_full_compilation(&compiler);
+    compiler.files[0].source = compiler.files[0]
+        .source
+        .replace("<<jump Shop>>", "<<detour Ending>>\n<<jump Shop>>");
+    assert_same_as_full_compilation(&compiler);
+
+    // The unchanged shop uses $gold, whose declaration changes
+    compiler.files[0].source = compiler.files[0].source.replace("$gold = 10", "$gold = 20");
+    assert_same_as_full_compilation(&compiler);
+
+    compiler.files.remove(2);
+    assert_same_as_full_compilation(&compiler);
+
+    // Errors are reported the same way, too
+    compiler.files[1].source = compiler.files[1]
+        .source
+        .replace("$gold - 2", "$gold - \"two\"");
+    assert_same_as_full_compilation(&compiler);
+}
+
+#[test]
+fn test_incremental_compilation_notices_conflicts_with_changed_files() {
+    let first = File {
+        file_name: "first.yarn".to_string(),
+        source: "title: First\n---\nHi. #line:greeting\n===".to_string(),
+    };
+    let second = File {
+        file_name: "second.yarn".to_string(),
+        source: "title: Second\n---\n<<declare $gold = 0>>\nBye. #line:farewell\n===".to_string(),
+    };
+    let mut compiler = Compiler::new();
+    compiler.add_file(first).add_file(second);
+    let mut incremental_compiler = IncrementalCompiler::new();
+    assert!(incremental_compiler.compile(&compiler).is_ok());
+
+    // The unchanged second file now conflicts with the first one
+    compiler.files[0].source =
+        "title: First\n---\n<<declare $gold = 1>>\nHi. #line:farewell\n===".to_string();
+    let result = incremental_compiler.compile(&compiler);
+    assert_eq!(compiler.compile(), result);
+    let messages: Vec<_> = result
+        .unwrap_err()
+        .0
+        .into_iter()
+        .map(|d| d.message)
+        .collect();
+    assert!(messages.contains(&"Duplicate line ID line:farewell".to_string()));
+    assert!(messages
+        .iter()
+        .any(|message| message.starts_with("$gold has already been declared in first.yarn")));
+
+    // And stops conflicting again
+    compiler.files[0].source = "title: First\n---\nHi. #line:greeting\n===".to_string();
+    assert_eq!(compiler.compile(), incremental_compiler.compile(&compiler));
+    assert_eq!(
+        vec!["first.yarn", "second.yarn"],
+        incremental_compiler.cached_file_names().collect::<Vec<_>>()
+    );
+}
@@ -144,6 +144,7 @@ line before call #line:4
 <<call function()>>
             ",
     )
+    .with_diagnostic_severity(DiagnosticCode::UnknownNode, DiagnosticSeverity::Warning)
     .compile()
     .unwrap();
 
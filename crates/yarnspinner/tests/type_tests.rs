@@ -774,3 +774,321 @@ fn test_if_statement_expressions_must_be_boolean() {
         .message
         .contains("Terms of 'if statement' must be Bool, not String")));
 }
+
+#[test]
+fn test_enums() {
+    let source = "
+        <<enum Mood>>
+            <<case Happy>>
+            <<case Angry>>
+        <<endenum>>
+        <<declare $mood = Mood.Happy>>
+        <<declare $other_mood = .Angry as Mood>>
+        <<if $mood == .Happy>>
+        Happy
+        <<endif>>
+        <<set $mood to .Angry>>
+        <<if $mood == Mood.Angry && $mood == $other_mood>>
+        Angry
+        <<endif>>
+        {$mood}
+        ";
+    let test_base = TestBase::new().with_test_plan(
+        TestPlan::new()
+            .expect_line("Happy")
+            .expect_line("Angry")
+            .expect_line("Angry"),
+    );
+
+    let result = Compiler::from_test_source(source)
+        .extend_library(test_base.dialogue.library().clone())
+        .compile()
+        .unwrap();
+
+    let mood = EnumType::new("Mood").with_case("Happy").with_case("Angry");
+    let declaration = result
+        .declarations
+        .iter()
+        .find(|d| d.name == "$mood")
+        .unwrap();
+    assert_eq!(Type::Enum(mood), declaration.r#type);
+    assert_eq!(
+        Some(&YarnValue::from("Happy")),
+        declaration.default_value.as_ref()
+    );
+
+    test_base.with_compilation(result).run_standard_testcase();
+}
+
+#[test]
+fn test_enum_cases_must_exist() {
+    let result = Compiler::from_test_source(
+        "
+        <<enum Mood>>
+            <<case Happy>>
+        <<endenum>>
+        <<declare $mood = Mood.Happy>>
+        <<if $mood == .Sad>>
+        Hello
+        <<endif>>
+        ",
+    )
+    .compile()
+    .unwrap_err();
+
+    println!("{}", result);
+
+    assert!(result.0.iter().any(|d| d
+        .message
+        .contains("Sad is not a case of enum Mood (expected one of Happy)")));
+}
+
+#[test]
+fn test_enum_case_shorthand_requires_context() {
+    let result = Compiler::from_test_source(
+        "
+        <<enum Mood>>
+            <<case Happy>>
+        <<endenum>>
+        <<declare $mood = .Happy>>
+        ",
+    )
+    .compile()
+    .unwrap_err();
+
+    println!("{}", result);
+
+    assert!(result.0.iter().any(|d| d
+        .message
+        .contains("Can't figure out which enum .Happy belongs to given its context")));
+}
+
+#[test]
+fn test_local_variables() {
+    let source = "title: Start
+---
+<<local $count = 1>>
+<<set $count to $count + 1>>
+{$count}
+<<jump Other>>
+===
+title: Other
+---
+<<local $count = \"other\">>
+{$count}
+===";
+    let mut compiler = Compiler::new();
+    compiler.add_file(File {
+        file_name: "<input>".to_string(),
+        source: source.to_string(),
+    });
+    let result = compiler.compile().unwrap();
+
+    let local_declarations: Vec<_> = result
+        .declarations
+        .iter()
+        .filter(|d| d.name == "$count")
+        .collect();
+    assert_eq!(2, local_declarations.len());
+    assert!(local_declarations.iter().all(|d| d.is_local));
+    assert!(!result
+        .program
+        .as_ref()
+        .unwrap()
+        .initial_values
+        .contains_key("$count"));
+
+    let mut test_base = TestBase::new()
+        .with_compilation(result)
+        .with_test_plan(TestPlan::new().expect_line("2").expect_line("other"));
+    test_base.run_standard_testcase();
+
+    assert!(test_base.variable_storage.get("$count").is_err());
+}
+
+#[test]
+fn test_local_variables_are_scoped_to_their_node() {
+    let source = "title: Start
+---
+<<local $tmp = 1>>
+<<jump Other>>
+===
+title: Other
+---
+{$tmp}
+===";
+    let mut compiler = Compiler::new();
+    compiler.add_file(File {
+        file_name: "<input>".to_string(),
+        source: source.to_string(),
+    });
+    let result = compiler.compile().unwrap_err();
+
+    println!("{}", result);
+
+    assert!(result.0.iter().any(|d| d
+        .message
+        .contains("$tmp is local to node Start and can't be used outside of it")));
+}
+
+#[test]
+fn test_local_variables_cannot_be_used_before_declaration() {
+    let result = Compiler::from_test_source(
+        "
+        {$tmp}
+        <<local $tmp = 1>>
+        ",
+    )
+    .compile()
+    .unwrap_err();
+
+    println!("{}", result);
+
+    assert!(result.0.iter().any(|d| d
+        .message
+        .contains("Local variable $tmp is used before it is declared")));
+}
+
+#[test]
+fn test_local_variables_cannot_shadow_declarations() {
+    let result = Compiler::from_test_source(
+        "
+        <<declare $tmp = 1>>
+        <<local $tmp = 2>>
+        ",
+    )
+    .compile()
+    .unwrap_err();
+
+    println!("{}", result);
+
+    assert!(result
+        .0
+        .iter()
+        .any(|d| d.message.contains("$tmp has already been declared")));
+}
+
+#[test]
+fn test_when_headers_must_be_boolean() {
+    let result = Compiler::new()
+        .add_file(File {
+            file_name: "<input>".to_string(),
+            source: "title: Start
+---
+<<declare $gold = 0>>
+===
+title: Greeting
+when: $gold
+---
+Hello
+==="
+            .to_string(),
+        })
+        .compile()
+        .unwrap_err();
+
+    println!("{}", result);
+
+    assert!(result.0.iter().any(|d| d
+        .message
+        .contains("Terms of 'when header' must be Bool, not Number")));
+}
+
+#[test]
+fn test_smart_variables_have_the_type_of_their_expression() {
+    let result = Compiler::new()
+        .add_file(File {
+            file_name: "<input>".to_string(),
+            source: "title: Start
+---
+<<if $can_afford>>
+<<endif>>
+<<declare $can_afford = $gold >= 10>>
+<<declare $gold = 0>>
+==="
+            .to_string(),
+        })
+        .compile()
+        .unwrap();
+
+    let declaration = result
+        .declarations
+        .iter()
+        .find(|d| d.name == "$can_afford")
+        .unwrap();
+    assert_eq!(Type::Boolean, declaration.r#type);
+    assert!(declaration.is_smart);
+    assert!(declaration.default_value.is_none());
+}
+
+#[test]
+fn test_parenthesized_and_negative_constants_are_not_smart_variables() {
+    let result = Compiler::new()
+        .add_file(File {
+            file_name: "<input>".to_string(),
+            source: "title: Start
+---
+<<declare $parenthesized = (1)>>
+<<declare $negative = -1>>
+<<declare $nested = (-(2))>>
+==="
+            .to_string(),
+        })
+        .compile()
+        .unwrap();
+
+    for (name, value) in [
+        ("$parenthesized", 1.0),
+        ("$negative", -1.0),
+        ("$nested", -2.0),
+    ] {
+        let declaration = result.declarations.iter().find(|d| d.name == name).unwrap();
+        assert!(!declaration.is_smart, "{name} is a smart variable");
+        assert_eq!(Some(YarnValue::Number(value)), declaration.default_value);
+    }
+}
+
+#[test]
+fn test_smart_variables_cannot_be_modified() {
+    let result = Compiler::new()
+        .add_file(File {
+            file_name: "<input>".to_string(),
+            source: "title: Start
+---
+<<declare $gold = 0>>
+<<declare $can_afford = $gold >= 10>>
+<<set $can_afford to true>>
+==="
+            .to_string(),
+        })
+        .compile()
+        .unwrap_err();
+
+    println!("{}", result);
+
+    assert!(result.0.iter().any(|d| d
+        .message
+        .contains("$can_afford is a smart variable and can't be modified")));
+}
+
+#[test]
+fn test_smart_variables_cannot_depend_on_themselves() {
+    let result = Compiler::new()
+        .add_file(File {
+            file_name: "<input>".to_string(),
+            source: "title: Start
+---
+<<declare $a = $b + 1>>
+<<declare $b = $a + 1>>
+==="
+            .to_string(),
+        })
+        .compile()
+        .unwrap_err();
+
+    println!("{}", result);
+
+    assert!(result.0.iter().any(|d| d
+        .message
+        .contains("The smart variable $a depends on its own value")));
+}
@@ -0,0 +1,199 @@
+//! Tests for the `ysc` binary, which run it on Yarn files in a temporary directory.
+
+use prost::Message;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use yarnspinner::compiler::*;
+use yarnspinner::core::Program;
+
+const START: &str = "title: Start
+---
+Hello there! #line:hello
+<<jump Shop>>
+===
+";
+
+const SHOP: &str = "title: Shop
+---
+What do you want to buy? #line:shop
+<<detour Start>>
+===
+";
+
+/// Creates a fresh directory containing `start.yarn` and `shop.yarn`.
+fn project(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("ysc_tests_{name}"));
+    let _ = fs::remove_dir_all(&directory);
+    fs::create_dir_all(&directory).unwrap();
+    fs::write(directory.join("start.yarn"), START).unwrap();
+    fs::write(directory.join("shop.yarn"), SHOP).unwrap();
+    directory
+}
+
+fn ysc(arguments: &[&str], directory: &Path) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_ysc"))
+        .args(arguments)
+        .current_dir(directory)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_compile_writes_program_and_string_table() {
+    let directory = project("compile");
+    let output = ysc(&["compile", "--output-name", "Story", "."], &directory);
+    assert!(output.status.success());
+
+    let bytes = fs::read(directory.join("Story.yarnc")).unwrap();
+    let program = Program::decode(bytes.as_slice()).unwrap();
+    let expected = Compiler::new()
+        .read_file(directory.join("start.yarn"))
+        .read_file(directory.join("shop.yarn"))
+        .compile()
+        .unwrap()
+        .program
+        .unwrap();
+    assert_eq!(expected, program);
+
+    let lines = fs::read_to_string(directory.join("Story-Lines.csv")).unwrap();
+    let lines: Vec<_> = lines.lines().collect();
+    assert_eq!("id,text,file,node,lineNumber", lines[0]);
+    assert_eq!("line:hello,Hello there!,./start.yarn,Start,3", lines[2]);
+}
+
+#[test]
+fn test_compile_optimizes_on_request() {
+    let directory = project("optimize");
+    fs::write(
+        directory.join("start.yarn"),
+        START.replace("Hello there!", "Hello {1 + 2}!"),
+    )
+    .unwrap();
+    let output = ysc(
+        &["compile", "--optimize", "--output-name", "Story", "."],
+        &directory,
+    );
+    assert!(output.status.success());
+
+    let bytes = fs::read(directory.join("Story.yarnc")).unwrap();
+    let program = Program::decode(bytes.as_slice()).unwrap();
+    let expected = Compiler::new()
+        .read_file(directory.join("start.yarn"))
+        .read_file(directory.join("shop.yarn"))
+        .with_optimization(true)
+        .compile()
+        .unwrap()
+        .program
+        .unwrap();
+    assert_eq!(expected, program);
+
+    let output = ysc(&["compile", "--fast", "."], &directory);
+    assert_eq!(Some(2), output.status.code());
+}
+
+#[test]
+fn test_check_reports_errors() {
+    let directory = project("check");
+    let output = ysc(&["check", "."], &directory);
+    assert!(output.status.success());
+    assert_eq!("", stdout(&output));
+
+    // Warnings are printed, but don't fail the check
+    fs::write(
+        directory.join("shop.yarn"),
+        SHOP.replace("Start", "{\"Start\"}"),
+    )
+    .unwrap();
+    let output = ysc(&["check", "--format", "json", "."], &directory);
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with(r#"[{"code":"YS0011","severity":"warning""#));
+
+    fs::write(directory.join("shop.yarn"), SHOP.replace("Start", "Strat")).unwrap();
+    let output = ysc(&["check", "--format", "json", "."], &directory);
+    assert_eq!(Some(1), output.status.code());
+    assert!(stdout(&output).starts_with(r#"[{"code":"YS0009","severity":"error""#));
+
+    fs::write(
+        directory.join("shop.yarn"),
+        SHOP.replace("Start", "{$nowhere}"),
+    )
+    .unwrap();
+    let output = ysc(&["check", "--format", "json", "."], &directory);
+    assert_eq!(Some(1), output.status.code());
+    assert!(stdout(&output).contains(r#"{"code":"YS0012","severity":"error""#));
+
+    let output = ysc(&["check", "--format", "xml", "."], &directory);
+    assert_eq!(Some(2), output.status.code());
+}
+
+#[test]
+fn test_tag_adds_unique_line_ids() {
+    let directory = project("tag");
+    let other = "title: Other\n---\nLine one\nLine two\n===\n";
+    fs::write(directory.join("other.yarn"), other).unwrap();
+    fs::write(
+        directory.join("another.yarn"),
+        other.replace("Other", "Another"),
+    )
+    .unwrap();
+    let output = ysc(&["tag", "."], &directory);
+    assert!(output.status.success());
+
+    // Files that are already tagged are left alone
+    assert_eq!(
+        START,
+        fs::read_to_string(directory.join("start.yarn")).unwrap()
+    );
+    assert_eq!(
+        SHOP,
+        fs::read_to_string(directory.join("shop.yarn")).unwrap()
+    );
+
+    let output = ysc(&["strings", "."], &directory);
+    let strings = stdout(&output);
+    let line_ids: HashSet<_> = strings
+        .lines()
+        .skip(1)
+        .map(|line| line.split(',').next().unwrap())
+        .collect();
+    assert_eq!(6, line_ids.len());
+    assert!(line_ids.iter().all(|line_id| !line_id.contains(".yarn")));
+
+    // Tagging again changes nothing
+    let tagged = fs::read_to_string(directory.join("other.yarn")).unwrap();
+    ysc(&["tag", "."], &directory);
+    assert_eq!(
+        tagged,
+        fs::read_to_string(directory.join("other.yarn")).unwrap()
+    );
+}
+
+#[test]
+fn test_dump_and_graph() {
+    let directory = project("dump_and_graph");
+    let output = ysc(&["dump", "start.yarn", "shop.yarn"], &directory);
+    let dump = stdout(&output);
+    let program = Program::from_disassembly(&dump).unwrap();
+    assert_eq!(["Shop", "Start"], *program.nodes.keys().collect::<Vec<_>>());
+    assert!(dump.contains(
+        "    ; start.yarn:3: Hello there! #line:hello\n    0    RUN_LINE \"line:hello\" 0\n"
+    ));
+
+    let output = ysc(&["graph", "."], &directory);
+    assert_eq!(
+        "digraph dialogue {
+    \"Shop\";
+    \"Shop\" -> \"Start\" [style=dashed];
+    \"Start\";
+    \"Start\" -> \"Shop\";
+}
+",
+        stdout(&output)
+    );
+}